@@ -1,9 +1,9 @@
 use chrono::{NaiveDate, NaiveDateTime};
-use rusqlite::{Connection, Result, Row};
+use rusqlite::{params_from_iter, Connection, Result, Row};
 use sea_query::{ColumnDef, Expr, Func, Iden, Order, Query, SqliteQueryBuilder, Table};
 
 sea_query::sea_query_driver_rusqlite!();
-use sea_query_driver_rusqlite::RusqliteValues;
+use sea_query_driver_rusqlite::{RusqliteBinder, RusqliteValues};
 use serde_json::{json, Value as Json};
 use uuid::Uuid;
 
@@ -61,12 +61,9 @@ fn main() -> Result<()> {
             .into(),
             NaiveDate::from_ymd(2020, 8, 20).and_hms(0, 0, 0).into(),
         ])
-        .build(SqliteQueryBuilder);
+        .build_rusqlite(SqliteQueryBuilder);
 
-    let result = conn.execute(
-        sql.as_str(),
-        RusqliteValues::from(values).as_params().as_slice(),
-    );
+    let result = conn.execute(sql.as_str(), params_from_iter(&values));
     println!("Insert into character: {:?}\n", result);
     let id = conn.last_insert_rowid();
 
@@ -5,7 +5,7 @@ use sea_query::{ColumnDef, Expr, Func, Iden, MysqlQueryBuilder, Order, Query, Ta
 use sqlx::{types::chrono::NaiveDateTime, MySqlPool, Row};
 
 sea_query::sea_query_driver_mysql!();
-use sea_query_driver_mysql::{bind_query, bind_query_as};
+use sea_query_driver_mysql::{bind_query, bind_query_as, SqlxBinder};
 use serde_json::{json, Value as Json};
 use uuid::Uuid;
 
@@ -68,11 +68,9 @@ async fn main() {
                 .into(),
             NaiveDate::from_ymd(2020, 8, 20).and_hms(0, 0, 0).into(),
         ])
-        .build(MysqlQueryBuilder);
+        .build_sqlx(MysqlQueryBuilder);
 
-    let result = bind_query(sqlx::query(&sql), &values)
-        .execute(&mut pool)
-        .await;
+    let result = sqlx::query_with(&sql, &values).execute(&mut pool).await;
     println!("Insert into character: {:?}\n", result);
     let id = result.unwrap().last_insert_id();
 
@@ -5,7 +5,7 @@ use sea_query::{ColumnDef, Expr, Func, Iden, Order, PostgresQueryBuilder, Query,
 use sqlx::{PgPool, Row};
 
 sea_query::sea_query_driver_postgres!();
-use sea_query_driver_postgres::{bind_query, bind_query_as};
+use sea_query_driver_postgres::{bind_query, bind_query_as, SqlxBinder};
 use serde_json::{json, Value as Json};
 use uuid::Uuid;
 
@@ -69,9 +69,9 @@ async fn main() {
             NaiveDate::from_ymd(2020, 8, 20).and_hms(0, 0, 0).into(),
         ])
         .returning_col(Character::Id)
-        .build(PostgresQueryBuilder);
+        .build_sqlx(PostgresQueryBuilder);
 
-    let row = bind_query(sqlx::query(&sql), &values)
+    let row = sqlx::query_with(&sql, &values)
         .fetch_one(&mut pool)
         .await
         .unwrap();
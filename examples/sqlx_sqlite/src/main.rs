@@ -3,7 +3,7 @@ use sea_query::{ColumnDef, Expr, Func, Iden, Order, Query, SqliteQueryBuilder, T
 use sqlx::{Row, SqlitePool};
 
 sea_query::sea_query_driver_sqlite!();
-use sea_query_driver_sqlite::{bind_query, bind_query_as};
+use sea_query_driver_sqlite::{bind_query, bind_query_as, SqlxBinder};
 use serde_json::{json, Value as Json};
 use uuid::Uuid;
 
@@ -52,10 +52,10 @@ async fn main() {
             .into(),
             NaiveDate::from_ymd(2020, 8, 20).and_hms(0, 0, 0).into(),
         ])
-        .build(SqliteQueryBuilder);
+        .build_sqlx(SqliteQueryBuilder);
 
     //TODO: Implement RETURNING (returning_col) for the Sqlite driver.
-    let row = bind_query(sqlx::query(&sql), &values)
+    let row = sqlx::query_with(&sql, &values)
         .execute(&pool)
         .await
         .unwrap();
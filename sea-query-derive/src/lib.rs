@@ -18,6 +18,26 @@ fn find_attr(attrs: &[Attribute]) -> Option<&Attribute> {
         .find(|attr| attr.path.is_ident(&IdenPath::Iden) || attr.path.is_ident(&IdenPath::Method))
 }
 
+/// Implement `Iden` for an enum or a unit struct.
+///
+/// The name of a unit struct, and of the `Table` variant of an enum, is the type name in snake
+/// case; every other variant is named after itself in snake case. A name can be overridden with
+/// `#[iden = "..."]` (or `#[iden(rename = "...")]`) on the type or the variant, taken from a
+/// method of `self` with `#[method = "..."]`, or delegated to the single field of a variant with
+/// `#[iden(flatten)]`.
+///
+/// ```ignore
+/// #[derive(Iden)]
+/// enum User {
+///     Table,
+///     Id,
+///     #[iden = "name"]
+///     FirstName,
+/// }
+///
+/// assert_eq!(User::Table.to_string(), "user");
+/// assert_eq!(User::FirstName.to_string(), "name");
+/// ```
 #[proc_macro_derive(Iden, attributes(iden, method))]
 pub fn derive_iden(input: TokenStream) -> TokenStream {
     let DeriveInput {
@@ -0,0 +1,27 @@
+use super::*;
+
+impl DatabaseBuilder for BigQueryQueryBuilder {
+    fn prepare_database_create_statement(&self, _: &DatabaseCreateStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support database, create a schema as dataset instead");
+    }
+
+    fn prepare_database_drop_statement(&self, _: &DatabaseDropStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support database, drop a schema as dataset instead");
+    }
+
+    fn prepare_schema_create_statement(&self, create: &SchemaCreateStatement, sql: &mut SqlWriter) {
+        if create.authorization.is_some() {
+            panic!("BigQuery not support schema authorization");
+        }
+
+        write!(sql, "CREATE SCHEMA ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, '`');
+        }
+    }
+}
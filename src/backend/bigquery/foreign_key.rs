@@ -0,0 +1,16 @@
+use super::*;
+
+impl ForeignKeyBuilder for BigQueryQueryBuilder {
+    fn prepare_foreign_key_drop_statement(&self, _: &ForeignKeyDropStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support foreign key");
+    }
+
+    fn prepare_foreign_key_create_statement_internal(
+        &self,
+        _: &ForeignKeyCreateStatement,
+        _: &mut SqlWriter,
+        _: bool,
+    ) {
+        panic!("BigQuery not support foreign key");
+    }
+}
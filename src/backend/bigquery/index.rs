@@ -0,0 +1,41 @@
+use super::*;
+
+impl IndexBuilder for BigQueryQueryBuilder {
+    fn prepare_table_index_expression(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
+        if !create.primary {
+            panic!("BigQuery not support index");
+        }
+        // Primary keys are informational, they are never enforced
+        write!(sql, "PRIMARY KEY").unwrap();
+
+        self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include, sql);
+
+        write!(sql, " NOT ENFORCED").unwrap();
+    }
+
+    fn prepare_index_create_statement(&self, _: &IndexCreateStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support index");
+    }
+
+    fn prepare_index_drop_statement(&self, _: &IndexDropStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support index");
+    }
+
+    fn prepare_index_reindex_statement(&self, _: &IndexReindexStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support index");
+    }
+
+    fn write_column_index_prefix(&self, col_prefix: &Option<u32>, _sql: &mut SqlWriter) {
+        if col_prefix.is_some() {
+            panic!("BigQuery not support index prefix");
+        }
+    }
+
+    fn prepare_index_prefix(&self, _create: &IndexCreateStatement, _sql: &mut SqlWriter) {}
+
+    fn prepare_index_expr(&self, _expr: &SimpleExpr, _sql: &mut SqlWriter) {
+        panic!("BigQuery not support index");
+    }
+}
@@ -0,0 +1,38 @@
+pub(crate) mod database;
+pub(crate) mod foreign_key;
+pub(crate) mod index;
+pub(crate) mod query;
+pub(crate) mod role;
+pub(crate) mod sequence;
+pub(crate) mod table;
+pub(crate) mod transaction;
+pub(crate) mod trigger;
+pub(crate) mod view;
+
+use super::*;
+
+/// BigQuery query builder, for the Standard SQL dialect.
+///
+/// A table path is quoted as a whole, e.g. `` `project.dataset.table` ``, and parameters are
+/// named `@p1`, `@p2`, ... in the order of the collected values. Tuples are written as
+/// `(a, b)`, the tuple syntax of `STRUCT`, and arrays as `[a, b]`.
+#[derive(Debug)]
+pub struct BigQueryQueryBuilder;
+
+impl Default for BigQueryQueryBuilder {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl GenericBuilder for BigQueryQueryBuilder {}
+
+impl SchemaBuilder for BigQueryQueryBuilder {}
+
+impl PaginationBuilder for BigQueryQueryBuilder {}
+
+impl QuotedBuilder for BigQueryQueryBuilder {
+    fn quote(&self) -> char {
+        '`'
+    }
+}
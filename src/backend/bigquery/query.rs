@@ -0,0 +1,121 @@
+use super::*;
+
+impl PlaceholderBuilder for BigQueryQueryBuilder {
+    fn placeholder(&self) -> (&str, bool) {
+        ("@p", true)
+    }
+}
+
+impl QueryBuilder for BigQueryQueryBuilder {
+    fn backend_name(&self) -> &'static str {
+        "BigQuery"
+    }
+
+    fn on_conflict_supported(&self) -> bool {
+        false
+    }
+
+    fn returning_supported(&self) -> bool {
+        false
+    }
+
+    fn prepare_table_name(&self, parts: &[&DynIden], sql: &mut SqlWriter) {
+        // A path of project, dataset and table is quoted as a whole
+        write!(
+            sql,
+            "`{}`",
+            parts
+                .iter()
+                .map(|part| part.to_string().replace('`', "\\`"))
+                .collect::<Vec<String>>()
+                .join(".")
+        )
+        .unwrap();
+    }
+
+    fn prepare_update_delete_condition(
+        &self,
+        condition: &ConditionHolder,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        // The `WHERE` clause is mandatory, even when all rows are affected
+        if condition.is_empty() {
+            write!(sql, " WHERE TRUE").unwrap();
+        } else {
+            self.prepare_condition(condition, "WHERE", sql, collector);
+        }
+    }
+
+    fn update_delete_order_limit_supported(&self) -> bool {
+        false
+    }
+
+    fn full_text_search_supported(&self) -> bool {
+        false
+    }
+
+    fn prepare_full_text_match(
+        &self,
+        _columns: &[SimpleExpr],
+        _query: &SimpleExpr,
+        _mode: &SearchMode,
+        _sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        panic!("BigQuery not support full text search");
+    }
+
+    fn distinct_on_supported(&self) -> bool {
+        false
+    }
+
+    fn lateral_join_supported(&self) -> bool {
+        false
+    }
+
+    fn prepare_on_conflict(
+        &self,
+        _on_conflict: &OnConflict,
+        _sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        panic!("BigQuery not support ON CONFLICT");
+    }
+
+    fn prepare_returning(
+        &self,
+        returning: &Option<ReturningClause>,
+        _sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        if returning.is_some() {
+            panic!("BigQuery not support RETURNING");
+        }
+    }
+
+    fn write_array(&self, elements: &[Value], buffer: &mut String) {
+        write!(
+            buffer,
+            "[{}]",
+            elements
+                .iter()
+                .map(|element| self.value_to_string(element))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+        .unwrap()
+    }
+
+    fn write_bytes(&self, bytes: &[u8], buffer: &mut String) {
+        write!(buffer, "FROM_HEX('").unwrap();
+        for b in bytes {
+            write!(buffer, "{:02x}", b).unwrap();
+        }
+        write!(buffer, "')").unwrap();
+    }
+
+    fn typed_date_time_literal(&self) -> bool {
+        true
+    }
+}
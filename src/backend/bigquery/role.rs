@@ -0,0 +1,11 @@
+use super::*;
+
+impl RoleBuilder for BigQueryQueryBuilder {
+    fn prepare_role_create_statement(&self, _: &RoleCreateStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support role");
+    }
+
+    fn prepare_role_drop_statement(&self, _: &RoleDropStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support role");
+    }
+}
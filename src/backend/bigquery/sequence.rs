@@ -0,0 +1,15 @@
+use super::*;
+
+impl SequenceBuilder for BigQueryQueryBuilder {
+    fn prepare_sequence_create_statement(&self, _: &SequenceCreateStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support sequence");
+    }
+
+    fn prepare_sequence_alter_statement(&self, _: &SequenceAlterStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support sequence");
+    }
+
+    fn prepare_sequence_drop_statement(&self, _: &SequenceDropStatement, _: &mut SqlWriter) {
+        panic!("BigQuery not support sequence");
+    }
+}
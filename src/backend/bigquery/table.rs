@@ -0,0 +1,239 @@
+use super::*;
+
+impl TableBuilder for BigQueryQueryBuilder {
+    fn prepare_table_create_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(BigQueryQueryBuilder)).unwrap();
+    }
+
+    fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut SqlWriter) {
+        column_def.name.prepare(sql, '`');
+
+        if let Some(column_type) = &column_def.types {
+            write!(sql, " ").unwrap();
+            self.prepare_column_type(column_type, sql);
+        }
+
+        for column_spec in column_def.spec.iter() {
+            // Columns are nullable unless declared otherwise
+            if let ColumnSpec::Null = column_spec {
+                continue;
+            }
+            write!(sql, " ").unwrap();
+            self.prepare_column_spec(column_spec, sql);
+        }
+    }
+
+    fn prepare_column_spec(&self, column_spec: &ColumnSpec, sql: &mut SqlWriter) {
+        match column_spec {
+            ColumnSpec::Null => Ok(()),
+            ColumnSpec::NotNull => write!(sql, "NOT NULL"),
+            ColumnSpec::Default(value) => {
+                write!(sql, "DEFAULT ").unwrap();
+                self.prepare_column_default(value, sql);
+                Ok(())
+            }
+            ColumnSpec::AutoIncrement | ColumnSpec::Identity(_) => {
+                panic!("BigQuery not support auto increment")
+            }
+            ColumnSpec::UniqueKey => panic!("BigQuery not support unique key"),
+            ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY NOT ENFORCED"),
+            ColumnSpec::Check(_) => panic!("BigQuery not support check constraint"),
+            ColumnSpec::Generated(_, _) => panic!("BigQuery not support generated column"),
+            ColumnSpec::Extra(string) => write!(sql, "{}", string),
+        }
+        .unwrap()
+    }
+
+    fn prepare_check_constraint(&self, _check: &SimpleExpr, _sql: &mut SqlWriter) {
+        panic!("BigQuery not support check constraint");
+    }
+
+    fn prepare_table_opt(&self, table_opt: &TableOpt, _sql: &mut SqlWriter) {
+        panic!("BigQuery not support table option {:?}", table_opt);
+    }
+
+    fn prepare_table_partition(&self, table_partition: &TablePartition, sql: &mut SqlWriter) {
+        match table_partition {
+            TablePartition::By(_, columns) => match columns.as_slice() {
+                [column] => {
+                    write!(sql, "PARTITION BY ").unwrap();
+                    column.prepare(sql, '`');
+                }
+                _ => panic!("BigQuery partitions a table by a single column"),
+            },
+            TablePartition::Definition(..) => {
+                panic!("BigQuery not support partition definition")
+            }
+        }
+    }
+
+    fn prepare_partition_values(&self, _values: &PartitionValues, _sql: &mut SqlWriter) {
+        panic!("BigQuery not support partition definition");
+    }
+
+    fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
+        if alter.options.is_empty() {
+            panic!("No alter option found")
+        }
+        write!(sql, "ALTER TABLE ").unwrap();
+        if let Some(table) = &alter.table {
+            table.prepare(sql, '`');
+            write!(sql, " ").unwrap();
+        }
+        alter.options.iter().fold(true, |first, option| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_table_alter_option(option, sql);
+            false
+        });
+    }
+
+    fn prepare_table_alter_option(&self, alter_option: &TableAlterOption, sql: &mut SqlWriter) {
+        match alter_option {
+            TableAlterOption::AddColumn(column_def) => {
+                write!(sql, "ADD COLUMN ").unwrap();
+                self.prepare_column_def(column_def, sql);
+            }
+            TableAlterOption::ModifyColumn(column_def) => {
+                write!(sql, "ALTER COLUMN ").unwrap();
+                column_def.name.prepare(sql, '`');
+                match &column_def.types {
+                    Some(column_type) => {
+                        write!(sql, " SET DATA TYPE ").unwrap();
+                        self.prepare_column_type(column_type, sql);
+                    }
+                    None => panic!("BigQuery only support changing the type of a column"),
+                }
+            }
+            TableAlterOption::ModifyColumnUsing(_, _) => {
+                panic!("BigQuery not support USING on modifying column")
+            }
+            TableAlterOption::ChangeColumn(_, _) => {
+                panic!("BigQuery not support changing column, rename and modify it instead")
+            }
+            TableAlterOption::RenameColumn(from_name, to_name) => {
+                write!(sql, "RENAME COLUMN ").unwrap();
+                from_name.prepare(sql, '`');
+                write!(sql, " TO ").unwrap();
+                to_name.prepare(sql, '`');
+            }
+            TableAlterOption::DropColumn(column_name) => {
+                write!(sql, "DROP COLUMN ").unwrap();
+                column_name.prepare(sql, '`');
+            }
+            TableAlterOption::DropColumnDefault(column_name) => {
+                write!(sql, "ALTER COLUMN ").unwrap();
+                column_name.prepare(sql, '`');
+                write!(sql, " DROP DEFAULT").unwrap();
+            }
+            TableAlterOption::AddCheckConstraint(_, _) => {
+                panic!("BigQuery not support check constraint")
+            }
+            TableAlterOption::DropConstraint(name) => {
+                write!(sql, "DROP CONSTRAINT ").unwrap();
+                name.prepare(sql, '`');
+            }
+        }
+    }
+
+    fn prepare_table_drop_statement(&self, drop: &TableDropStatement, sql: &mut SqlWriter) {
+        if drop.tables.len() > 1 {
+            panic!("BigQuery not support dropping multiple tables");
+        }
+        if !drop.options.is_empty() {
+            panic!("BigQuery not support RESTRICT and CASCADE");
+        }
+
+        write!(sql, "DROP TABLE ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(table) = drop.tables.first() {
+            table.prepare(sql, '`');
+        }
+    }
+
+    fn prepare_table_truncate_statement(
+        &self,
+        truncate: &TableTruncateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if truncate.tables.len() > 1 {
+            panic!("BigQuery not support truncating multiple tables");
+        }
+        if truncate.identity.is_some() || !truncate.options.is_empty() {
+            panic!("BigQuery not support truncate options");
+        }
+
+        write!(sql, "TRUNCATE TABLE ").unwrap();
+
+        if let Some(table) = truncate.tables.first() {
+            table.prepare(sql, '`');
+        }
+    }
+
+    fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter) {
+        write!(sql, "ALTER TABLE ").unwrap();
+        if let Some(from_name) = &rename.from_name {
+            from_name.prepare(sql, '`');
+        }
+        write!(sql, " RENAME TO ").unwrap();
+        if let Some(to_name) = &rename.to_name {
+            to_name.prepare(sql, '`');
+        }
+    }
+}
+
+impl ColumnTypeBuilder for BigQueryQueryBuilder {
+    fn prepare_column_type(&self, column_type: &ColumnType, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            "{}",
+            match column_type {
+                ColumnType::Char(length) | ColumnType::String(length) => match length {
+                    Some(length) => format!("STRING({})", length),
+                    None => "STRING".into(),
+                },
+                ColumnType::Text => "STRING".into(),
+                ColumnType::TinyInteger(_)
+                | ColumnType::SmallInteger(_)
+                | ColumnType::Integer(_)
+                | ColumnType::BigInteger(_) => "INT64".into(),
+                ColumnType::Float(_) | ColumnType::Double(_) => "FLOAT64".into(),
+                ColumnType::Decimal(precision) | ColumnType::Money(precision) => {
+                    match precision {
+                        Some((precision, scale)) => format!("NUMERIC({}, {})", precision, scale),
+                        None => "NUMERIC".into(),
+                    }
+                }
+                ColumnType::DateTime(_) => "DATETIME".into(),
+                ColumnType::Timestamp(_) | ColumnType::TimestampWithTimeZone(_) => {
+                    "TIMESTAMP".into()
+                }
+                ColumnType::Time(_) => "TIME".into(),
+                ColumnType::Date => "DATE".into(),
+                ColumnType::Binary(length) => match length {
+                    Some(length) => format!("BYTES({})", length),
+                    None => "BYTES".into(),
+                },
+                ColumnType::Boolean => "BOOL".into(),
+                ColumnType::Json | ColumnType::JsonBinary => "JSON".into(),
+                ColumnType::Uuid
+                | ColumnType::Inet
+                | ColumnType::Cidr
+                | ColumnType::MacAddr
+                | ColumnType::Enum(_, _) => "STRING".into(),
+                ColumnType::Array(elem_type) => {
+                    let mut elem_sql = SqlWriter::new();
+                    self.prepare_column_type(elem_type, &mut elem_sql);
+                    format!("ARRAY<{}>", elem_sql.result())
+                }
+                ColumnType::Custom(iden) => iden.to_string(),
+            }
+        )
+        .unwrap()
+    }
+}
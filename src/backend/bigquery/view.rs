@@ -0,0 +1,20 @@
+use super::*;
+
+impl ViewBuilder for BigQueryQueryBuilder {
+    fn prepare_view_create_kind(&self, create: &ViewCreateStatement, sql: &mut SqlWriter) {
+        if create.or_replace {
+            write!(sql, "OR REPLACE ").unwrap();
+        }
+        if create.materialized {
+            write!(sql, "MATERIALIZED ").unwrap();
+        }
+    }
+
+    fn prepare_view_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(BigQueryQueryBuilder)).unwrap();
+    }
+
+    fn prepare_view_check_option(&self, _sql: &mut SqlWriter) {
+        panic!("BigQuery not support WITH CHECK OPTION");
+    }
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+impl DatabaseBuilder for ClickHouseQueryBuilder {
+    fn prepare_database_create_statement(
+        &self,
+        create: &DatabaseCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if create.owner.is_some() {
+            panic!("ClickHouse not support database owner");
+        }
+        if create.charset.is_some() || create.collate.is_some() {
+            panic!("ClickHouse not support database character set and collation");
+        }
+
+        write!(sql, "CREATE DATABASE ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, '`');
+        }
+    }
+
+    fn prepare_schema_create_statement(&self, _: &SchemaCreateStatement, _: &mut SqlWriter) {
+        panic!("ClickHouse not support schema");
+    }
+
+    fn prepare_schema_drop_statement(&self, _: &SchemaDropStatement, _: &mut SqlWriter) {
+        panic!("ClickHouse not support schema");
+    }
+}
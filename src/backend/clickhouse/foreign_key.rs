@@ -0,0 +1,16 @@
+use super::*;
+
+impl ForeignKeyBuilder for ClickHouseQueryBuilder {
+    fn prepare_foreign_key_drop_statement(&self, _: &ForeignKeyDropStatement, _: &mut SqlWriter) {
+        panic!("ClickHouse not support foreign key");
+    }
+
+    fn prepare_foreign_key_create_statement_internal(
+        &self,
+        _: &ForeignKeyCreateStatement,
+        _: &mut SqlWriter,
+        _: bool,
+    ) {
+        panic!("ClickHouse not support foreign key");
+    }
+}
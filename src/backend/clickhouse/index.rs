@@ -0,0 +1,38 @@
+use super::*;
+
+impl IndexBuilder for ClickHouseQueryBuilder {
+    fn prepare_table_index_expression(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
+        if !create.primary {
+            panic!("ClickHouse not support secondary index in table definition");
+        }
+        write!(sql, "PRIMARY KEY").unwrap();
+
+        self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include, sql);
+    }
+
+    fn prepare_index_create_statement(&self, _: &IndexCreateStatement, _: &mut SqlWriter) {
+        panic!("ClickHouse not support CREATE INDEX");
+    }
+
+    fn prepare_index_drop_statement(&self, _: &IndexDropStatement, _: &mut SqlWriter) {
+        panic!("ClickHouse not support DROP INDEX");
+    }
+
+    fn prepare_index_reindex_statement(&self, _: &IndexReindexStatement, _: &mut SqlWriter) {
+        panic!("ClickHouse not support REINDEX");
+    }
+
+    fn write_column_index_prefix(&self, col_prefix: &Option<u32>, _sql: &mut SqlWriter) {
+        if col_prefix.is_some() {
+            panic!("ClickHouse not support index prefix");
+        }
+    }
+
+    fn prepare_index_prefix(&self, _create: &IndexCreateStatement, _sql: &mut SqlWriter) {}
+
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter) {
+        self.prepare_simple_expr_inlined(expr, sql);
+    }
+}
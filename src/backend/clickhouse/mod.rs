@@ -0,0 +1,36 @@
+pub(crate) mod database;
+pub(crate) mod foreign_key;
+pub(crate) mod index;
+pub(crate) mod query;
+pub(crate) mod role;
+pub(crate) mod sequence;
+pub(crate) mod table;
+pub(crate) mod transaction;
+pub(crate) mod trigger;
+pub(crate) mod view;
+
+use super::*;
+
+/// ClickHouse query builder.
+#[derive(Debug)]
+pub struct ClickHouseQueryBuilder;
+
+impl Default for ClickHouseQueryBuilder {
+    fn default() -> Self {
+        Self
+    }
+}
+
+impl GenericBuilder for ClickHouseQueryBuilder {}
+
+impl SchemaBuilder for ClickHouseQueryBuilder {}
+
+impl PlaceholderBuilder for ClickHouseQueryBuilder {}
+
+impl PaginationBuilder for ClickHouseQueryBuilder {}
+
+impl QuotedBuilder for ClickHouseQueryBuilder {
+    fn quote(&self) -> char {
+        '`'
+    }
+}
@@ -0,0 +1,201 @@
+use super::*;
+
+impl QueryBuilder for ClickHouseQueryBuilder {
+    fn backend_name(&self) -> &'static str {
+        "ClickHouse"
+    }
+
+    fn on_conflict_supported(&self) -> bool {
+        false
+    }
+
+    fn returning_supported(&self) -> bool {
+        false
+    }
+
+    fn final_sample_supported(&self) -> bool {
+        true
+    }
+
+    fn prepare_select_final_sample(&self, select: &SelectStatement, sql: &mut SqlWriter) {
+        if select.r#final {
+            write!(sql, " FINAL").unwrap();
+        }
+        if let Some(sample) = &select.sample {
+            write!(sql, " SAMPLE {}", self.value_to_string(sample)).unwrap();
+        }
+    }
+
+    fn update_from_supported(&self) -> bool {
+        false
+    }
+
+    fn delete_using_supported(&self) -> bool {
+        false
+    }
+
+    fn update_delete_order_limit_supported(&self) -> bool {
+        false
+    }
+
+    fn prepare_update_statement(
+        &self,
+        update: &UpdateStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if !update.from.is_empty() {
+            panic!("ClickHouse not support UPDATE FROM");
+        }
+        if !update.orders.is_empty() || update.limit.is_some() {
+            panic!("ClickHouse not support ORDER BY and LIMIT in UPDATE");
+        }
+        if update.returning.is_some() {
+            panic!("ClickHouse not support RETURNING");
+        }
+
+        // Rows are updated by a mutation of the table
+        write!(sql, "ALTER TABLE ").unwrap();
+
+        if let Some(table) = &update.table {
+            self.prepare_table_ref(table, sql, collector);
+        }
+
+        write!(sql, " UPDATE ").unwrap();
+
+        update.values.iter().fold(true, |first, row| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            let (k, v) = row;
+            write!(sql, "{}{}{} = ", self.quote(), k, self.quote()).unwrap();
+            self.prepare_simple_expr(v, sql, collector);
+            false
+        });
+
+        self.prepare_update_delete_condition(&update.wherei, sql, collector);
+    }
+
+    fn prepare_delete_statement(
+        &self,
+        delete: &DeleteStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if !delete.using.is_empty() {
+            panic!("ClickHouse not support DELETE USING");
+        }
+        if !delete.orders.is_empty() || delete.limit.is_some() {
+            panic!("ClickHouse not support ORDER BY and LIMIT in DELETE");
+        }
+        if delete.returning.is_some() {
+            panic!("ClickHouse not support RETURNING");
+        }
+
+        // Rows are deleted by a mutation of the table
+        write!(sql, "ALTER TABLE ").unwrap();
+
+        if let Some(table) = &delete.table {
+            self.prepare_table_ref(table, sql, collector);
+        }
+
+        write!(sql, " DELETE").unwrap();
+
+        self.prepare_update_delete_condition(&delete.wherei, sql, collector);
+    }
+
+    fn prepare_update_delete_condition(
+        &self,
+        condition: &ConditionHolder,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        // The condition of a mutation is mandatory, even when all rows are affected
+        if condition.is_empty() {
+            write!(sql, " WHERE 1").unwrap();
+        } else {
+            self.prepare_condition(condition, "WHERE", sql, collector);
+        }
+    }
+
+    fn string_agg_order_by_supported(&self) -> bool {
+        false
+    }
+
+    fn multi_expr_string_agg_supported(&self) -> bool {
+        false
+    }
+
+    fn prepare_string_agg(
+        &self,
+        exprs: &[SimpleExpr],
+        separator: &str,
+        orders: &[OrderExpr],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if !orders.is_empty() {
+            panic!("ClickHouse not support ORDER BY in string aggregation");
+        }
+        write!(sql, "arrayStringConcat(groupArray(").unwrap();
+        match exprs {
+            [expr] => self.prepare_simple_expr(expr, sql, collector),
+            _ => panic!("ClickHouse not support string aggregation of multiple expressions"),
+        }
+        write!(sql, "), ").unwrap();
+        self.prepare_string_agg_separator(separator, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn full_text_search_supported(&self) -> bool {
+        false
+    }
+
+    fn prepare_full_text_match(
+        &self,
+        _columns: &[SimpleExpr],
+        _query: &SimpleExpr,
+        _mode: &SearchMode,
+        _sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        panic!("ClickHouse not support full text search");
+    }
+
+    fn lateral_join_supported(&self) -> bool {
+        false
+    }
+
+    fn prepare_on_conflict(
+        &self,
+        _on_conflict: &OnConflict,
+        _sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        panic!("ClickHouse not support ON CONFLICT");
+    }
+
+    fn prepare_returning(
+        &self,
+        returning: &Option<ReturningClause>,
+        _sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        if returning.is_some() {
+            panic!("ClickHouse not support RETURNING");
+        }
+    }
+
+    fn write_array(&self, elements: &[Value], buffer: &mut String) {
+        write!(
+            buffer,
+            "[{}]",
+            elements
+                .iter()
+                .map(|element| self.value_to_string(element))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+        .unwrap()
+    }
+}
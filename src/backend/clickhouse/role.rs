@@ -0,0 +1,12 @@
+use super::*;
+
+impl RoleBuilder for ClickHouseQueryBuilder {
+    fn prepare_role_option(&self, option: &RoleOption, sql: &mut SqlWriter) {
+        match option {
+            RoleOption::Password(password) => {
+                write!(sql, "IDENTIFIED BY '{}'", self.escape_string(password)).unwrap()
+            }
+            _ => panic!("ClickHouse not support role option {:?}", option),
+        }
+    }
+}
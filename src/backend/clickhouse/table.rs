@@ -0,0 +1,262 @@
+use super::*;
+
+impl TableBuilder for ClickHouseQueryBuilder {
+    fn prepare_table_create_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(ClickHouseQueryBuilder)).unwrap();
+    }
+
+    fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut SqlWriter) {
+        column_def.name.prepare(sql, '`');
+
+        if let Some(column_type) = &column_def.types {
+            write!(sql, " ").unwrap();
+            self.prepare_column_type(column_type, sql);
+        }
+
+        for column_spec in column_def.spec.iter() {
+            write!(sql, " ").unwrap();
+            self.prepare_column_spec(column_spec, sql);
+        }
+    }
+
+    fn prepare_column_spec(&self, column_spec: &ColumnSpec, sql: &mut SqlWriter) {
+        match column_spec {
+            ColumnSpec::Null => write!(sql, "NULL"),
+            ColumnSpec::NotNull => write!(sql, "NOT NULL"),
+            ColumnSpec::Default(value) => {
+                write!(sql, "DEFAULT ").unwrap();
+                self.prepare_column_default(value, sql);
+                Ok(())
+            }
+            ColumnSpec::AutoIncrement | ColumnSpec::Identity(_) => {
+                panic!("ClickHouse not support auto increment")
+            }
+            ColumnSpec::UniqueKey => panic!("ClickHouse not support unique key"),
+            ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY"),
+            ColumnSpec::Check(_) => panic!("ClickHouse not support column check constraint"),
+            ColumnSpec::Generated(expr, storage) => {
+                write!(
+                    sql,
+                    "{} ",
+                    match storage {
+                        GeneratedStorage::Stored => "MATERIALIZED",
+                        GeneratedStorage::Virtual => "ALIAS",
+                    }
+                )
+                .unwrap();
+                self.prepare_simple_expr_inlined(expr, sql);
+                Ok(())
+            }
+            ColumnSpec::Extra(string) => write!(sql, "{}", string),
+        }
+        .unwrap()
+    }
+
+    fn prepare_check_constraint(&self, check: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, "CHECK (").unwrap();
+        self.prepare_simple_expr_inlined(check, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_table_opt(&self, table_opt: &TableOpt, sql: &mut SqlWriter) {
+        match table_opt {
+            TableOpt::Engine(engine) => write!(sql, "ENGINE = {}", engine).unwrap(),
+            TableOpt::OrderBy(columns) if columns.is_empty() => {
+                write!(sql, "ORDER BY tuple()").unwrap()
+            }
+            TableOpt::OrderBy(columns) => {
+                write!(sql, "ORDER BY (").unwrap();
+                columns.iter().fold(true, |first, column| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    column.prepare(sql, '`');
+                    false
+                });
+                write!(sql, ")").unwrap();
+            }
+            TableOpt::Collate(_) | TableOpt::CharacterSet(_) => {
+                panic!("ClickHouse not support table character set and collation")
+            }
+        }
+    }
+
+    fn prepare_table_partition(&self, table_partition: &TablePartition, sql: &mut SqlWriter) {
+        match table_partition {
+            // ClickHouse partitions by the value of an expression, there is no partitioning method
+            TablePartition::By(_, columns) => {
+                write!(sql, "PARTITION BY (").unwrap();
+                columns.iter().fold(true, |first, column| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    column.prepare(sql, '`');
+                    false
+                });
+                write!(sql, ")").unwrap();
+            }
+            TablePartition::Definition(..) => {
+                panic!("ClickHouse not support partition definition")
+            }
+        }
+    }
+
+    fn prepare_partition_values(&self, _values: &PartitionValues, _sql: &mut SqlWriter) {
+        panic!("ClickHouse not support partition definition");
+    }
+
+    fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
+        if alter.options.is_empty() {
+            panic!("No alter option found")
+        }
+        write!(sql, "ALTER TABLE ").unwrap();
+        if let Some(table) = &alter.table {
+            table.prepare(sql, '`');
+            write!(sql, " ").unwrap();
+        }
+        alter.options.iter().fold(true, |first, option| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_table_alter_option(option, sql);
+            false
+        });
+    }
+
+    fn prepare_table_alter_option(&self, alter_option: &TableAlterOption, sql: &mut SqlWriter) {
+        match alter_option {
+            TableAlterOption::AddColumn(column_def) => {
+                write!(sql, "ADD COLUMN ").unwrap();
+                self.prepare_column_def(column_def, sql);
+            }
+            TableAlterOption::ModifyColumn(column_def) => {
+                write!(sql, "MODIFY COLUMN ").unwrap();
+                self.prepare_column_def(column_def, sql);
+            }
+            TableAlterOption::ModifyColumnUsing(_, _) => {
+                panic!("ClickHouse not support USING on modifying column")
+            }
+            TableAlterOption::ChangeColumn(_, _) => {
+                panic!("ClickHouse not support changing column, rename and modify it instead")
+            }
+            TableAlterOption::RenameColumn(from_name, to_name) => {
+                write!(sql, "RENAME COLUMN ").unwrap();
+                from_name.prepare(sql, '`');
+                write!(sql, " TO ").unwrap();
+                to_name.prepare(sql, '`');
+            }
+            TableAlterOption::DropColumn(column_name) => {
+                write!(sql, "DROP COLUMN ").unwrap();
+                column_name.prepare(sql, '`');
+            }
+            TableAlterOption::DropColumnDefault(column_name) => {
+                write!(sql, "MODIFY COLUMN ").unwrap();
+                column_name.prepare(sql, '`');
+                write!(sql, " REMOVE DEFAULT").unwrap();
+            }
+            TableAlterOption::AddCheckConstraint(name, check) => {
+                write!(sql, "ADD CONSTRAINT ").unwrap();
+                name.prepare(sql, '`');
+                write!(sql, " ").unwrap();
+                self.prepare_check_constraint(check, sql);
+            }
+            TableAlterOption::DropConstraint(name) => {
+                write!(sql, "DROP CONSTRAINT ").unwrap();
+                name.prepare(sql, '`');
+            }
+        }
+    }
+
+    fn prepare_table_drop_opt(&self, _drop_opt: &TableDropOpt, _sql: &mut SqlWriter) {
+        panic!("ClickHouse not support RESTRICT and CASCADE");
+    }
+
+    fn prepare_table_truncate_statement(
+        &self,
+        truncate: &TableTruncateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if truncate.tables.len() > 1 {
+            panic!("ClickHouse not support truncating multiple tables");
+        }
+        if truncate.identity.is_some() || !truncate.options.is_empty() {
+            panic!("ClickHouse not support truncate options");
+        }
+
+        write!(sql, "TRUNCATE TABLE ").unwrap();
+
+        if let Some(table) = truncate.tables.first() {
+            table.prepare(sql, '`');
+        }
+    }
+
+    fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter) {
+        write!(sql, "RENAME TABLE ").unwrap();
+        if let Some(from_name) = &rename.from_name {
+            from_name.prepare(sql, '`');
+        }
+        write!(sql, " TO ").unwrap();
+        if let Some(to_name) = &rename.to_name {
+            to_name.prepare(sql, '`');
+        }
+    }
+}
+
+impl ColumnTypeBuilder for ClickHouseQueryBuilder {
+    fn prepare_column_type(&self, column_type: &ColumnType, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            "{}",
+            match column_type {
+                ColumnType::Char(length) => match length {
+                    Some(length) => format!("FixedString({})", length),
+                    None => "String".into(),
+                },
+                ColumnType::String(_) | ColumnType::Text => "String".into(),
+                ColumnType::TinyInteger(_) => "Int8".into(),
+                ColumnType::SmallInteger(_) => "Int16".into(),
+                ColumnType::Integer(_) => "Int32".into(),
+                ColumnType::BigInteger(_) => "Int64".into(),
+                ColumnType::Float(_) => "Float32".into(),
+                ColumnType::Double(_) => "Float64".into(),
+                ColumnType::Decimal(precision) | ColumnType::Money(precision) => {
+                    match precision {
+                        Some((precision, scale)) => format!("Decimal({}, {})", precision, scale),
+                        None => "Decimal".into(),
+                    }
+                }
+                ColumnType::DateTime(precision)
+                | ColumnType::Timestamp(precision)
+                | ColumnType::TimestampWithTimeZone(precision) => match precision {
+                    Some(precision) => format!("DateTime64({})", precision),
+                    None => "DateTime".into(),
+                },
+                ColumnType::Time(_) => panic!("ClickHouse not support time column type"),
+                ColumnType::Date => "Date".into(),
+                ColumnType::Binary(length) => match length {
+                    Some(length) => format!("FixedString({})", length),
+                    None => "String".into(),
+                },
+                ColumnType::Boolean => "Bool".into(),
+                ColumnType::Json | ColumnType::JsonBinary => "JSON".into(),
+                ColumnType::Uuid => "UUID".into(),
+                ColumnType::Inet | ColumnType::Cidr | ColumnType::MacAddr => "String".into(),
+                ColumnType::Enum(_, variants) => format!(
+                    "Enum({})",
+                    variants
+                        .iter()
+                        .map(|variant| format!("'{}'", self.escape_string(&variant.to_string())))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                ColumnType::Array(elem_type) => {
+                    let mut elem_sql = SqlWriter::new();
+                    self.prepare_column_type(elem_type, &mut elem_sql);
+                    format!("Array({})", elem_sql.result())
+                }
+                ColumnType::Custom(iden) => iden.to_string(),
+            }
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+impl TransactionBuilder for ClickHouseQueryBuilder {
+    fn prepare_transaction_statement(&self, statement: &TransactionStatement, sql: &mut SqlWriter) {
+        match statement {
+            TransactionStatement::Begin(begin) => {
+                self.prepare_transaction_begin_statement(begin, sql)
+            }
+            TransactionStatement::Set(set) => self.prepare_transaction_set_statement(set, sql),
+            TransactionStatement::Commit => write!(sql, "COMMIT").unwrap(),
+            TransactionStatement::Rollback(None) => write!(sql, "ROLLBACK").unwrap(),
+            TransactionStatement::Rollback(Some(_))
+            | TransactionStatement::Savepoint(_)
+            | TransactionStatement::ReleaseSavepoint(_) => {
+                panic!("ClickHouse not support savepoint")
+            }
+        }
+    }
+
+    fn prepare_transaction_begin_statement(
+        &self,
+        begin: &TransactionBeginStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if begin.isolation_level.is_some() || begin.access_mode.is_some() {
+            panic!("ClickHouse not support transaction modes");
+        }
+        write!(sql, "BEGIN TRANSACTION").unwrap();
+    }
+
+    fn prepare_transaction_set_statement(&self, _: &TransactionSetStatement, _: &mut SqlWriter) {
+        panic!("ClickHouse not support SET TRANSACTION");
+    }
+}
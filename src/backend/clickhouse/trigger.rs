@@ -0,0 +1,19 @@
+use super::*;
+
+impl TriggerBuilder for ClickHouseQueryBuilder {
+    fn prepare_trigger_create_statement(&self, _: &TriggerCreateStatement, _: &mut SqlWriter) {
+        panic!("ClickHouse not support trigger");
+    }
+
+    fn prepare_trigger_drop_statement(&self, _: &TriggerDropStatement, _: &mut SqlWriter) {
+        panic!("ClickHouse not support trigger");
+    }
+
+    fn prepare_trigger_when(&self, _: &SimpleExpr, _: &mut SqlWriter) {
+        panic!("ClickHouse not support trigger");
+    }
+
+    fn prepare_trigger_action(&self, _: &TriggerAction, _: &mut SqlWriter) {
+        panic!("ClickHouse not support trigger");
+    }
+}
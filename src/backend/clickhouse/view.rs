@@ -0,0 +1,20 @@
+use super::*;
+
+impl ViewBuilder for ClickHouseQueryBuilder {
+    fn prepare_view_create_kind(&self, create: &ViewCreateStatement, sql: &mut SqlWriter) {
+        if create.materialized {
+            panic!("ClickHouse not support materialized view without engine");
+        }
+        if create.or_replace {
+            write!(sql, "OR REPLACE ").unwrap();
+        }
+    }
+
+    fn prepare_view_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(ClickHouseQueryBuilder)).unwrap();
+    }
+
+    fn prepare_view_check_option(&self, _sql: &mut SqlWriter) {
+        panic!("ClickHouse not support WITH CHECK OPTION");
+    }
+}
@@ -0,0 +1,53 @@
+use crate::*;
+
+pub trait ColumnTypeBuilder: QuotedBuilder {
+    /// Translate [`ColumnType`] into SQL statement.
+    fn prepare_column_type(&self, column_type: &ColumnType, sql: &mut SqlWriter) {
+        let quote = self.quote();
+        write!(
+            sql,
+            "{}",
+            match column_type {
+                ColumnType::Char(length) => match length {
+                    Some(length) => format!("char({})", length),
+                    None => "char".into(),
+                },
+                ColumnType::String(length) => match length {
+                    Some(length) => format!("varchar({})", length),
+                    None => "varchar".into(),
+                },
+                ColumnType::Text => "text".into(),
+                ColumnType::TinyInteger(_) | ColumnType::SmallInteger(_) => "smallint".into(),
+                ColumnType::Integer(_) => "integer".into(),
+                ColumnType::BigInteger(_) => "bigint".into(),
+                ColumnType::Float(_) => "real".into(),
+                ColumnType::Double(_) => "double precision".into(),
+                ColumnType::Decimal(precision) => match precision {
+                    Some((precision, scale)) => format!("decimal({}, {})", precision, scale),
+                    None => "decimal".into(),
+                },
+                ColumnType::DateTime(_) | ColumnType::Timestamp(_) => "timestamp".into(),
+                ColumnType::TimestampWithTimeZone(_) => "timestamp with time zone".into(),
+                ColumnType::Time(_) => "time".into(),
+                ColumnType::Date => "date".into(),
+                ColumnType::Binary(_) => "bytea".into(),
+                ColumnType::Boolean => "bool".into(),
+                ColumnType::Money(_) => "money".into(),
+                ColumnType::Json => "json".into(),
+                ColumnType::JsonBinary => "jsonb".into(),
+                ColumnType::Uuid => "uuid".into(),
+                ColumnType::Inet => "inet".into(),
+                ColumnType::Cidr => "cidr".into(),
+                ColumnType::MacAddr => "macaddr".into(),
+                ColumnType::Enum(name, _) => format!("{}{}{}", quote, name.quoted(quote), quote),
+                ColumnType::Array(elem_type) => {
+                    let mut elem_sql = SqlWriter::new();
+                    self.prepare_column_type(elem_type, &mut elem_sql);
+                    format!("{}[]", elem_sql.result())
+                }
+                ColumnType::Custom(iden) => iden.to_string(),
+            }
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,58 @@
+use crate::*;
+
+pub trait DatabaseBuilder: QuotedBuilder {
+    /// Translate [`DatabaseCreateStatement`] into SQL statement.
+    fn prepare_database_create_statement(
+        &self,
+        create: &DatabaseCreateStatement,
+        sql: &mut SqlWriter,
+    );
+
+    /// Translate [`DatabaseDropStatement`] into SQL statement.
+    fn prepare_database_drop_statement(&self, drop: &DatabaseDropStatement, sql: &mut SqlWriter) {
+        write!(sql, "DROP DATABASE ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &drop.name {
+            name.prepare(sql, self.quote());
+        }
+    }
+
+    /// Translate [`SchemaCreateStatement`] into SQL statement.
+    fn prepare_schema_create_statement(&self, create: &SchemaCreateStatement, sql: &mut SqlWriter) {
+        write!(sql, "CREATE SCHEMA ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if let Some(role) = &create.authorization {
+            write!(sql, " AUTHORIZATION ").unwrap();
+            role.prepare(sql, self.quote());
+        }
+    }
+
+    /// Translate [`SchemaDropStatement`] into SQL statement.
+    fn prepare_schema_drop_statement(&self, drop: &SchemaDropStatement, sql: &mut SqlWriter) {
+        write!(sql, "DROP SCHEMA ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &drop.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if drop.cascade {
+            write!(sql, " CASCADE").unwrap();
+        }
+    }
+}
@@ -30,6 +30,38 @@ pub trait ForeignKeyBuilder: QuotedBuilder {
         .unwrap()
     }
 
+    /// Translate [`ForeignKeyMatch`] into SQL statement.
+    fn prepare_foreign_key_match(&self, foreign_key_match: &ForeignKeyMatch, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            "{}",
+            match foreign_key_match {
+                ForeignKeyMatch::Full => "FULL",
+                ForeignKeyMatch::Partial => "PARTIAL",
+                ForeignKeyMatch::Simple => "SIMPLE",
+            }
+        )
+        .unwrap()
+    }
+
+    /// Translate [`ForeignKeyDeferrable`] into SQL statement.
+    fn prepare_foreign_key_deferrable(
+        &self,
+        foreign_key_deferrable: &ForeignKeyDeferrable,
+        sql: &mut SqlWriter,
+    ) {
+        write!(
+            sql,
+            "{}",
+            match foreign_key_deferrable {
+                ForeignKeyDeferrable::NotDeferrable => "NOT DEFERRABLE",
+                ForeignKeyDeferrable::InitiallyImmediate => "DEFERRABLE INITIALLY IMMEDIATE",
+                ForeignKeyDeferrable::InitiallyDeferred => "DEFERRABLE INITIALLY DEFERRED",
+            }
+        )
+        .unwrap()
+    }
+
     /// Translate [`ForeignKeyDropStatement`] into SQL statement.
     fn prepare_foreign_key_drop_statement(
         &self,
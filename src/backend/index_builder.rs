@@ -11,6 +11,8 @@ pub trait IndexBuilder: QuotedBuilder {
         self.prepare_index_type(&create.index_type, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include, sql);
     }
 
     /// Translate [`IndexCreateStatement`] into SQL statement.
@@ -19,6 +21,14 @@ pub trait IndexBuilder: QuotedBuilder {
         self.prepare_index_prefix(create, sql);
         write!(sql, "INDEX ").unwrap();
 
+        if create.concurrently {
+            write!(sql, "CONCURRENTLY ").unwrap();
+        }
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
         self.prepare_index_name(&create.index.name, sql);
 
         write!(sql, " ON ").unwrap();
@@ -29,11 +39,31 @@ pub trait IndexBuilder: QuotedBuilder {
         self.prepare_index_type(&create.index_type, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include, sql);
+
+        self.prepare_index_algorithm_lock(&create.algorithm, &create.lock, sql);
     }
 
     /// Translate [`IndexDropStatement`] into SQL statement.
     fn prepare_index_drop_statement(&self, drop: &IndexDropStatement, sql: &mut SqlWriter);
 
+    /// Translate [`IndexReindexStatement`] into SQL statement.
+    fn prepare_index_reindex_statement(&self, reindex: &IndexReindexStatement, sql: &mut SqlWriter);
+
+    #[doc(hidden)]
+    /// Write the algorithm and lock options building or dropping an index.
+    fn prepare_index_algorithm_lock(
+        &self,
+        algorithm: &Option<IndexAlgorithm>,
+        lock: &Option<IndexLock>,
+        _sql: &mut SqlWriter,
+    ) {
+        if algorithm.is_some() || lock.is_some() {
+            panic!("ALGORITHM and LOCK are not supported by this backend");
+        }
+    }
+
     #[doc(hidden)]
     /// Write the index type (Btree, hash, ...).
     fn prepare_index_type(&self, _col_index_type: &Option<IndexType>, _sql: &mut SqlWriter) {}
@@ -58,19 +88,58 @@ pub trait IndexBuilder: QuotedBuilder {
             if !first {
                 write!(sql, ", ").unwrap();
             }
-            col.name.prepare(sql, self.quote());
-            self.write_column_index_prefix(&col.prefix, sql);
+            match &col.expr {
+                IndexExpr::Column(name) => {
+                    name.prepare(sql, self.quote());
+                    self.write_column_index_prefix(&col.prefix, sql);
+                }
+                IndexExpr::Expr(expr) => {
+                    write!(sql, "(").unwrap();
+                    self.prepare_index_expr(expr, sql);
+                    write!(sql, ")").unwrap();
+                }
+            }
+            self.prepare_index_opclass(&col.opclass, sql);
             if let Some(order) = &col.order {
                 match order {
                     IndexOrder::Asc => write!(sql, " ASC").unwrap(),
                     IndexOrder::Desc => write!(sql, " DESC").unwrap(),
                 }
             }
+            self.prepare_index_nulls(&col.nulls, sql);
             false
         });
         write!(sql, ")").unwrap();
     }
 
+    #[doc(hidden)]
+    /// Write the non-key columns of a covering index.
+    fn prepare_index_include(&self, include: &[DynIden], _sql: &mut SqlWriter) {
+        if !include.is_empty() {
+            panic!("INCLUDE is not supported by this backend");
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write the operator class of an index column.
+    fn prepare_index_opclass(&self, opclass: &Option<DynIden>, _sql: &mut SqlWriter) {
+        if opclass.is_some() {
+            panic!("Operator class is not supported by this backend");
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write the ordering of nulls of an index column.
+    fn prepare_index_nulls(&self, nulls: &Option<NullOrdering>, _sql: &mut SqlWriter) {
+        if nulls.is_some() {
+            panic!("NULLS FIRST and NULLS LAST are not supported by this backend");
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write an indexed expression.
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter);
+
     #[doc(hidden)]
     /// Write index name.
     fn prepare_index_name(&self, name: &Option<String>, sql: &mut SqlWriter) {
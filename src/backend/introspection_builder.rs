@@ -0,0 +1,410 @@
+use crate::error::{Error, Result};
+use crate::*;
+
+/// Queries discovering the tables of a database, and parsing of their result rows back into
+/// [`TableCreateStatement`]s.
+///
+/// Every column the queries select is text, so that the rows can be read as
+/// `Vec<Option<String>>` with any driver. Tables are looked up in the current schema unless
+/// one is given.
+///
+/// Only what a [`TableCreateStatement`] can express is read back: column types, nullability,
+/// defaults and auto increment, the primary key, indexes over columns and foreign keys. A
+/// primary key over a single column is set on the column, a composite one on the table.
+/// Column types the database reports in a form sea-query has no variant for are kept as
+/// [`ColumnType::Custom`], expression indexes are left out.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// assert_eq!(
+///     SqliteQueryBuilder
+///         .columns_query(None, "font")
+///         .to_string(SqliteQueryBuilder),
+///     [
+///         r#"SELECT `name`, `type`, CAST(`notnull` AS text), `dflt_value`, CAST(`pk` AS text)"#,
+///         r#"FROM `pragma_table_info` WHERE `arg` = 'font' ORDER BY `cid` ASC"#,
+///     ]
+///     .join(" ")
+/// );
+///
+/// let row = |values: &[Option<&str>]| -> Vec<Option<String>> {
+///     values.iter().map(|value| value.map(str::to_owned)).collect()
+/// };
+/// let columns = vec![
+///     row(&[Some("id"), Some("integer"), Some("1"), None, Some("1")]),
+///     row(&[Some("name"), Some("varchar(255)"), Some("0"), Some("'Sans'"), Some("0")]),
+/// ];
+/// let indexes = vec![row(&[Some("idx-font-name"), Some("0"), Some("0"), Some("name")])];
+///
+/// let current = SqliteQueryBuilder
+///     .parse_table("font", &columns, &indexes, &[])
+///     .unwrap();
+///
+/// let desired = Table::create()
+///     .table(Alias::new("font"))
+///     .col(ColumnDef::new(Alias::new("id")).integer().not_null().primary_key())
+///     .col(
+///         ColumnDef::new(Alias::new("name"))
+///             .string_len(255)
+///             .default_expr(Expr::cust("'Sans'")),
+///     )
+///     .col(ColumnDef::new(Alias::new("variant")).string_len(255))
+///     .index(Index::create().name("idx-font-name").col(Alias::new("name")))
+///     .to_owned();
+///
+/// assert_eq!(
+///     current.diff(&desired).unwrap().build(SqliteQueryBuilder),
+///     vec!["ALTER TABLE `font` ADD COLUMN `variant` text(255)"]
+/// );
+/// ```
+pub trait IntrospectionBuilder {
+    /// Query the names of the tables, one per row.
+    fn tables_query(&self, schema: Option<&str>) -> SelectStatement;
+
+    /// Query the columns of a table, one per row in order. The layout of the rows depends on
+    /// the backend, see [`IntrospectionBuilder::parse_column`].
+    fn columns_query(&self, schema: Option<&str>, table: &str) -> SelectStatement;
+
+    /// Query the indexes of a table, one row per indexed column in order: index name, whether
+    /// the index is unique, whether it is the primary key, column name.
+    fn indexes_query(&self, schema: Option<&str>, table: &str) -> SelectStatement;
+
+    /// Query the foreign keys of a table, one row per column in order: constraint name, column
+    /// name, referenced table, referenced column, `ON UPDATE` action, `ON DELETE` action.
+    fn foreign_keys_query(&self, schema: Option<&str>, table: &str) -> SelectStatement;
+
+    /// Parse a row of [`IntrospectionBuilder::columns_query`] into a column definition.
+    fn parse_column(&self, row: &[Option<String>]) -> Result<ColumnDef>;
+
+    /// Position of the column in the primary key, for backends reporting the primary key with
+    /// the columns rather than as an index.
+    fn parse_column_primary_key(&self, _row: &[Option<String>]) -> Result<Option<u32>> {
+        Ok(None)
+    }
+
+    /// Name an index is created with again, `None` if the database generated it.
+    fn index_name(&self, name: &str) -> Option<String> {
+        Some(name.to_owned())
+    }
+
+    /// Name a foreign key is created with again, `None` if the database generated it.
+    fn foreign_key_name(&self, name: &str) -> Option<String> {
+        Some(name.to_owned())
+    }
+
+    /// Parse the rows of [`IntrospectionBuilder::tables_query`] into table names.
+    fn parse_tables(&self, rows: &[Vec<Option<String>>]) -> Result<Vec<String>> {
+        rows.iter()
+            .map(|row| required_text(row, 0, "table name").map(|name| name.to_owned()))
+            .collect()
+    }
+
+    /// Assemble the definition of a table from the rows of
+    /// [`IntrospectionBuilder::columns_query`], [`IntrospectionBuilder::indexes_query`] and
+    /// [`IntrospectionBuilder::foreign_keys_query`].
+    fn parse_table(
+        &self,
+        table: &str,
+        columns: &[Vec<Option<String>>],
+        indexes: &[Vec<Option<String>>],
+        foreign_keys: &[Vec<Option<String>>],
+    ) -> Result<TableCreateStatement> {
+        let mut create = Table::create();
+        create.table(Alias::new(table));
+
+        let mut column_primary_key = Vec::new();
+        for row in columns.iter() {
+            let mut column = self.parse_column(row)?;
+            if let Some(position) = self.parse_column_primary_key(row)? {
+                column_primary_key.push((position, column.get_column_name()));
+            }
+            create.col(&mut column);
+        }
+
+        let mut primary_key = None;
+        for rows in group_rows(indexes) {
+            let name = required_text(&rows[0], 0, "index name")?;
+            let unique = parse_flag(required_text(&rows[0], 1, "index uniqueness")?)?;
+            let primary = parse_flag(required_text(&rows[0], 2, "index primary")?)?;
+            let mut columns = Vec::new();
+            for row in rows.iter() {
+                columns.extend(column_text(row, 3)?.map(|column| column.to_owned()));
+            }
+            if columns.len() < rows.len() {
+                // Indexes over expressions cannot be expressed
+                continue;
+            }
+            if primary {
+                primary_key = Some(columns);
+                continue;
+            }
+            let mut index = Index::create();
+            if let Some(name) = self.index_name(name) {
+                index.name(&name);
+            }
+            for column in columns.iter() {
+                index.col(Alias::new(column));
+            }
+            if unique {
+                index.unique();
+            }
+            create.index(&mut index);
+        }
+
+        let primary_key = primary_key.unwrap_or_else(|| {
+            column_primary_key.sort();
+            column_primary_key
+                .into_iter()
+                .map(|(_, column)| column)
+                .collect()
+        });
+        match primary_key.as_slice() {
+            [] => {}
+            [name] => {
+                if let Some(column) = create
+                    .columns
+                    .iter_mut()
+                    .find(|column| &column.get_column_name() == name)
+                {
+                    column.primary_key();
+                }
+            }
+            columns => {
+                let mut index = Index::create();
+                for column in columns.iter() {
+                    index.col(Alias::new(column));
+                }
+                create.primary_key(&mut index);
+            }
+        }
+
+        for rows in group_rows(foreign_keys) {
+            let name = required_text(&rows[0], 0, "foreign key name")?;
+            let ref_table = required_text(&rows[0], 2, "referenced table")?;
+            let mut foreign_key = ForeignKey::create();
+            if let Some(name) = self.foreign_key_name(name) {
+                foreign_key.name(&name);
+            }
+            foreign_key
+                .from_tbl(Alias::new(table))
+                .to_tbl(Alias::new(ref_table));
+            for row in rows.iter() {
+                let column = required_text(row, 1, "foreign key column")?;
+                let ref_column = column_text(row, 3)?.ok_or_else(|| {
+                    Error::Introspection(format!(
+                        "foreign key {} references the primary key of {} implicitly",
+                        name, ref_table
+                    ))
+                })?;
+                foreign_key
+                    .from_col(Alias::new(column))
+                    .to_col(Alias::new(ref_column));
+            }
+            if let Some(action) = parse_foreign_key_action(column_text(&rows[0], 4)?)? {
+                foreign_key.on_update(action);
+            }
+            if let Some(action) = parse_foreign_key_action(column_text(&rows[0], 5)?)? {
+                foreign_key.on_delete(action);
+            }
+            create.foreign_key(&mut foreign_key);
+        }
+
+        Ok(create.take())
+    }
+}
+
+/// Split rows into runs sharing the value of their first column
+fn group_rows(rows: &[Vec<Option<String>>]) -> Vec<&[Vec<Option<String>>]> {
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for i in 1..=rows.len() {
+        if i == rows.len() || rows[i].first() != rows[start].first() {
+            groups.push(&rows[start..i]);
+            start = i;
+        }
+    }
+    groups
+}
+
+/// Value of a column of a row, `None` if it is null
+pub(crate) fn column_text(row: &[Option<String>], index: usize) -> Result<Option<&str>> {
+    match row.get(index) {
+        Some(value) => Ok(value.as_deref()),
+        None => Err(Error::Introspection(format!(
+            "expected at least {} columns, got {}",
+            index + 1,
+            row.len()
+        ))),
+    }
+}
+
+/// Value of a column of a row which cannot be null
+pub(crate) fn required_text<'a>(
+    row: &'a [Option<String>],
+    index: usize,
+    what: &str,
+) -> Result<&'a str> {
+    column_text(row, index)?.ok_or_else(|| Error::Introspection(format!("{} is null", what)))
+}
+
+/// Boolean as rendered to text by any of the backends
+pub(crate) fn parse_flag(value: &str) -> Result<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "t" | "true" | "yes" => Ok(true),
+        "0" | "f" | "false" | "no" => Ok(false),
+        _ => Err(Error::Introspection(format!("{} is not a boolean", value))),
+    }
+}
+
+/// Default expression as written in SQL, with the keywords sea-query has a variant for
+#[cfg(any(
+    feature = "backend-mysql",
+    feature = "backend-postgres",
+    feature = "backend-sqlite"
+))]
+pub(crate) fn parse_default_expr(default: &str) -> SimpleExpr {
+    match default.to_ascii_uppercase().as_str() {
+        "NULL" => SimpleExpr::Keyword(Keyword::Null),
+        "CURRENT_TIMESTAMP" => SimpleExpr::Keyword(Keyword::CurrentTimestamp),
+        "CURRENT_DATE" => SimpleExpr::Keyword(Keyword::CurrentDate),
+        "CURRENT_TIME" => SimpleExpr::Keyword(Keyword::CurrentTime),
+        _ => SimpleExpr::Custom(default.to_owned()),
+    }
+}
+
+/// Referential action, `None` for `NO ACTION` which is the default
+fn parse_foreign_key_action(value: Option<&str>) -> Result<Option<ForeignKeyAction>> {
+    let value = match value {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+    match value.to_ascii_uppercase().as_str() {
+        "NO ACTION" => Ok(None),
+        "RESTRICT" => Ok(Some(ForeignKeyAction::Restrict)),
+        "CASCADE" => Ok(Some(ForeignKeyAction::Cascade)),
+        "SET NULL" => Ok(Some(ForeignKeyAction::SetNull)),
+        "SET DEFAULT" => Ok(Some(ForeignKeyAction::SetDefault)),
+        _ => Err(Error::Introspection(format!(
+            "{} is not a referential action",
+            value
+        ))),
+    }
+}
+
+/// Column type from its name as reported by any of the backends, e.g. `varchar(255)` or
+/// `timestamp(3) without time zone`
+#[cfg(any(
+    feature = "backend-mysql",
+    feature = "backend-postgres",
+    feature = "backend-sqlite"
+))]
+pub(crate) fn parse_column_type(column_type: &str) -> ColumnType {
+    let custom = || ColumnType::Custom(SeaRc::new(Alias::new(column_type)));
+    if let Some(elem_type) = column_type.strip_suffix("[]") {
+        return ColumnType::Array(Box::new(parse_column_type(elem_type)));
+    }
+
+    let lower = column_type.to_ascii_lowercase();
+    let (name, args) = match (lower.find('('), lower.find(')')) {
+        (Some(open), Some(close)) if open < close => (
+            format!("{} {}", &lower[..open], &lower[close + 1..]),
+            lower[open + 1..close]
+                .split(',')
+                .map(|arg| arg.trim().parse::<u32>())
+                .collect::<std::result::Result<Vec<_>, _>>(),
+        ),
+        (None, None) => (lower.clone(), Ok(Vec::new())),
+        _ => return custom(),
+    };
+    let args = match args {
+        Ok(args) => args,
+        Err(_) => return custom(),
+    };
+    let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+    let length = args.first().copied();
+
+    match (name.as_str(), args.len()) {
+        ("char" | "character", 0 | 1) => ColumnType::Char(length),
+        ("varchar" | "character varying", 0 | 1) => ColumnType::String(length),
+        ("text", 0) => ColumnType::Text,
+        ("tinyint", 0 | 1) => ColumnType::TinyInteger(length),
+        ("smallint", 0 | 1) => ColumnType::SmallInteger(length),
+        ("int" | "integer", 0 | 1) => ColumnType::Integer(length),
+        ("bigint", 0 | 1) => ColumnType::BigInteger(length),
+        ("float" | "real", 0 | 1) => ColumnType::Float(length),
+        ("double" | "double precision", 0 | 1) => ColumnType::Double(length),
+        ("decimal" | "numeric", 0) => ColumnType::Decimal(None),
+        ("decimal" | "numeric", 1) => ColumnType::Decimal(Some((args[0], 0))),
+        ("decimal" | "numeric", 2) => ColumnType::Decimal(Some((args[0], args[1]))),
+        ("datetime", 0 | 1) => ColumnType::DateTime(length),
+        ("timestamp" | "timestamp without time zone", 0 | 1) => ColumnType::Timestamp(length),
+        ("timestamp with time zone", 0 | 1) => ColumnType::TimestampWithTimeZone(length),
+        ("time" | "time without time zone", 0 | 1) => ColumnType::Time(length),
+        ("date", 0) => ColumnType::Date,
+        ("binary", 0 | 1) => ColumnType::Binary(length),
+        ("bytea" | "blob", 0) => ColumnType::Binary(None),
+        ("bool" | "boolean", 0) => ColumnType::Boolean,
+        ("money", 0) => ColumnType::Money(None),
+        ("json", 0) => ColumnType::Json,
+        ("jsonb", 0) => ColumnType::JsonBinary,
+        ("uuid", 0) => ColumnType::Uuid,
+        ("inet", 0) => ColumnType::Inet,
+        ("cidr", 0) => ColumnType::Cidr,
+        ("macaddr", 0) => ColumnType::MacAddr,
+        _ => custom(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_column_type() {
+        assert!(matches!(
+            parse_column_type("character varying(255)"),
+            ColumnType::String(Some(255))
+        ));
+        assert!(matches!(
+            parse_column_type("timestamp(3) without time zone"),
+            ColumnType::Timestamp(Some(3))
+        ));
+        assert!(matches!(
+            parse_column_type("numeric(10,2)"),
+            ColumnType::Decimal(Some((10, 2)))
+        ));
+        assert!(matches!(
+            parse_column_type("INTEGER"),
+            ColumnType::Integer(None)
+        ));
+        assert!(matches!(
+            parse_column_type("integer[]"),
+            ColumnType::Array(elem_type) if matches!(*elem_type, ColumnType::Integer(None))
+        ));
+        assert!(matches!(
+            parse_column_type("int unsigned"),
+            ColumnType::Custom(name) if name.to_string() == "int unsigned"
+        ));
+        assert!(matches!(
+            parse_column_type("enum('a','b')"),
+            ColumnType::Custom(name) if name.to_string() == "enum('a','b')"
+        ));
+    }
+
+    #[test]
+    fn test_group_rows() {
+        let row = |name: &str| vec![Some(name.to_owned())];
+        let rows = vec![row("a"), row("a"), row("b"), row("a")];
+        assert_eq!(
+            group_rows(&rows)
+                .into_iter()
+                .map(|rows| rows.len())
+                .collect::<Vec<_>>(),
+            vec![2, 1, 1]
+        );
+        assert!(group_rows(&[]).is_empty());
+    }
+}
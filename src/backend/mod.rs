@@ -1,7 +1,91 @@
 //! Translating the SQL AST into engine-specific SQL statements.
+//!
+//! [`QueryBuilder`] is composed of small traits: [`QuotedBuilder`] for quoting,
+//! [`PlaceholderBuilder`] for parameter placeholders, [`PaginationBuilder`] for
+//! `LIMIT` and `OFFSET`, and [`ColumnTypeBuilder`] for column types. All of them come
+//! with defaults, so a custom dialect only overrides where it differs.
+//!
+//! ```
+//! use sea_query::{tests_cfg::*, *};
+//!
+//! struct FetchFirstQueryBuilder;
+//!
+//! impl QuotedBuilder for FetchFirstQueryBuilder {
+//!     fn quote(&self) -> char {
+//!         '"'
+//!     }
+//! }
+//!
+//! impl PlaceholderBuilder for FetchFirstQueryBuilder {
+//!     fn placeholder(&self) -> (&str, bool) {
+//!         (":", true)
+//!     }
+//! }
+//!
+//! impl PaginationBuilder for FetchFirstQueryBuilder {
+//!     fn prepare_limit_offset(
+//!         &self,
+//!         limit: &Option<Value>,
+//!         offset: &Option<Value>,
+//!         sql: &mut SqlWriter,
+//!         collector: &mut dyn FnMut(Value),
+//!     ) {
+//!         if let Some(offset) = offset {
+//!             write!(sql, " OFFSET ").unwrap();
+//!             self.prepare_param(offset, sql, collector);
+//!             write!(sql, " ROWS").unwrap();
+//!         }
+//!         if let Some(limit) = limit {
+//!             write!(sql, " FETCH FIRST ").unwrap();
+//!             self.prepare_param(limit, sql, collector);
+//!             write!(sql, " ROWS ONLY").unwrap();
+//!         }
+//!     }
+//! }
+//!
+//! impl ColumnTypeBuilder for FetchFirstQueryBuilder {}
+//!
+//! impl QueryBuilder for FetchFirstQueryBuilder {}
+//!
+//! let query = Query::select()
+//!     .column(Char::Character)
+//!     .from(Char::Table)
+//!     .expr(Expr::cast_as(Expr::col(Char::SizeW).into(), ColumnType::Integer(None)))
+//!     .limit(10)
+//!     .offset(20)
+//!     .to_owned();
+//!
+//! assert_eq!(
+//!     query.build(FetchFirstQueryBuilder),
+//!     (
+//!         r#"SELECT "character", CAST("size_w" AS integer) FROM "character" OFFSET :1 ROWS FETCH FIRST :2 ROWS ONLY"#
+//!             .to_owned(),
+//!         Values(vec![20u64.into(), 10u64.into()])
+//!     )
+//! );
+//! ```
 
 use crate::*;
 
+/// Implement a builder for both the query builder of a backend and its versioned counterpart.
+#[allow(unused_macros)]
+macro_rules! impl_versioned {
+    ( impl $trait: ident for $builder: ident, $versioned: ident { $( $body: tt )* } ) => {
+        impl $trait for $builder { $( $body )* }
+        impl $trait for $versioned { $( $body )* }
+    };
+    ( impl $builder: ident, $versioned: ident { $( $body: tt )* } ) => {
+        impl $builder { $( $body )* }
+        impl $versioned { $( $body )* }
+    };
+}
+
+#[cfg(feature = "backend-bigquery")]
+#[cfg_attr(docsrs, doc(cfg(feature = "backend-bigquery")))]
+mod bigquery;
+#[cfg(feature = "backend-clickhouse")]
+#[cfg_attr(docsrs, doc(cfg(feature = "backend-clickhouse")))]
+mod clickhouse;
 #[cfg(feature = "backend-mysql")]
 #[cfg_attr(docsrs, doc(cfg(feature = "backend-mysql")))]
 mod mysql;
@@ -12,6 +96,10 @@ mod postgres;
 #[cfg_attr(docsrs, doc(cfg(feature = "backend-sqlite")))]
 mod sqlite;
 
+#[cfg(feature = "backend-bigquery")]
+pub use bigquery::*;
+#[cfg(feature = "backend-clickhouse")]
+pub use clickhouse::*;
 #[cfg(feature = "backend-mysql")]
 pub use mysql::*;
 #[cfg(feature = "backend-postgres")]
@@ -19,21 +107,57 @@ pub use postgres::*;
 #[cfg(feature = "backend-sqlite")]
 pub use sqlite::*;
 
+mod column_type_builder;
+mod database_builder;
 mod foreign_key_builder;
 mod index_builder;
+mod introspection_builder;
+mod pagination_builder;
+mod placeholder_builder;
 mod query_builder;
+mod role_builder;
+mod sequence_builder;
 mod table_builder;
+mod transaction_builder;
+mod trigger_builder;
+mod view_builder;
 
+pub use self::column_type_builder::*;
+pub use self::database_builder::*;
 pub use self::foreign_key_builder::*;
 pub use self::index_builder::*;
+pub use self::introspection_builder::*;
+pub use self::pagination_builder::*;
+pub use self::placeholder_builder::*;
 pub use self::query_builder::*;
+pub use self::role_builder::*;
+pub use self::sequence_builder::*;
 pub use self::table_builder::*;
+pub use self::transaction_builder::*;
+pub use self::trigger_builder::*;
+pub use self::view_builder::*;
 
-pub trait GenericBuilder: QueryBuilder + SchemaBuilder {}
+pub trait GenericBuilder: QueryBuilder + SchemaBuilder + TransactionBuilder {}
 
-pub trait SchemaBuilder: TableBuilder + IndexBuilder + ForeignKeyBuilder {}
+pub trait SchemaBuilder:
+    TableBuilder
+    + IndexBuilder
+    + ForeignKeyBuilder
+    + ViewBuilder
+    + SequenceBuilder
+    + TriggerBuilder
+    + DatabaseBuilder
+    + RoleBuilder
+{
+}
 
 pub trait QuotedBuilder {
     /// The type of quote the builder uses.
     fn quote(&self) -> char;
+
+    /// Escape a string to be written between single quotes.
+    /// Backslash escapes by default, as in MySQL.
+    fn escape_string(&self, string: &str) -> String {
+        crate::value::escape_string(string)
+    }
 }
@@ -0,0 +1,65 @@
+use super::*;
+
+impl_versioned! {
+impl DatabaseBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
+    fn prepare_database_create_statement(
+        &self,
+        create: &DatabaseCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if create.owner.is_some() {
+            panic!("Mysql not support database owner");
+        }
+
+        write!(sql, "CREATE DATABASE ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, '`');
+        }
+
+        if let Some(charset) = &create.charset {
+            write!(sql, " CHARACTER SET {}", charset).unwrap();
+        }
+
+        if let Some(collate) = &create.collate {
+            write!(sql, " COLLATE {}", collate).unwrap();
+        }
+    }
+
+    fn prepare_schema_create_statement(&self, create: &SchemaCreateStatement, sql: &mut SqlWriter) {
+        if create.authorization.is_some() {
+            panic!("Mysql not support schema authorization");
+        }
+
+        write!(sql, "CREATE SCHEMA ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, '`');
+        }
+    }
+
+    fn prepare_schema_drop_statement(&self, drop: &SchemaDropStatement, sql: &mut SqlWriter) {
+        if drop.cascade {
+            panic!("Mysql not support CASCADE on schema");
+        }
+
+        write!(sql, "DROP SCHEMA ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &drop.name {
+            name.prepare(sql, '`');
+        }
+    }
+}
+}
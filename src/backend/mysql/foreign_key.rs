@@ -1,6 +1,7 @@
 use super::*;
 
-impl ForeignKeyBuilder for MysqlQueryBuilder {
+impl_versioned! {
+impl ForeignKeyBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
     fn prepare_foreign_key_drop_statement(
         &self,
         drop: &ForeignKeyDropStatement,
@@ -11,12 +12,19 @@ impl ForeignKeyBuilder for MysqlQueryBuilder {
             table.prepare(sql, '`');
         }
 
+        if drop.if_exists {
+            panic!("Mysql not support IF EXISTS on foreign key");
+        }
         write!(sql, " DROP FOREIGN KEY ").unwrap();
         if let Some(name) = &drop.foreign_key.name {
             write!(sql, "`{}`", name).unwrap();
         }
     }
 
+    fn prepare_foreign_key_deferrable(&self, _: &ForeignKeyDeferrable, _: &mut SqlWriter) {
+        panic!("Mysql not support deferrable foreign key");
+    }
+
     fn prepare_foreign_key_create_statement_internal(
         &self,
         create: &ForeignKeyCreateStatement,
@@ -67,6 +75,11 @@ impl ForeignKeyBuilder for MysqlQueryBuilder {
             });
         write!(sql, ")").unwrap();
 
+        if let Some(foreign_key_match) = &create.foreign_key.match_type {
+            write!(sql, " MATCH ").unwrap();
+            self.prepare_foreign_key_match(foreign_key_match, sql);
+        }
+
         if let Some(foreign_key_action) = &create.foreign_key.on_delete {
             write!(sql, " ON DELETE ").unwrap();
             self.prepare_foreign_key_action(foreign_key_action, sql);
@@ -76,5 +89,11 @@ impl ForeignKeyBuilder for MysqlQueryBuilder {
             write!(sql, " ON UPDATE ").unwrap();
             self.prepare_foreign_key_action(foreign_key_action, sql);
         }
+
+        if let Some(foreign_key_deferrable) = &create.foreign_key.deferrable {
+            write!(sql, " ").unwrap();
+            self.prepare_foreign_key_deferrable(foreign_key_deferrable, sql);
+        }
     }
 }
+}
@@ -1,6 +1,7 @@
 use super::*;
 
-impl IndexBuilder for MysqlQueryBuilder {
+impl_versioned! {
+impl IndexBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
     fn prepare_table_index_expression(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
         self.prepare_index_prefix(create, sql);
         write!(sql, "KEY ").unwrap();
@@ -10,13 +11,29 @@ impl IndexBuilder for MysqlQueryBuilder {
         self.prepare_index_type(&create.index_type, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include, sql);
+
+        self.prepare_index_algorithm_lock(&create.algorithm, &create.lock, sql);
     }
 
     fn prepare_index_create_statement(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
+        if !create.r#where.is_empty() {
+            panic!("Mysql not support partial index");
+        }
+
         write!(sql, "CREATE ").unwrap();
         self.prepare_index_prefix(create, sql);
         write!(sql, "INDEX ").unwrap();
 
+        if create.concurrently {
+            panic!("Mysql not support CONCURRENTLY on index");
+        }
+
+        if create.if_not_exists {
+            panic!("Mysql not support IF NOT EXISTS on index");
+        }
+
         self.prepare_index_name(&create.index.name, sql);
 
         write!(sql, " ON ").unwrap();
@@ -27,10 +44,20 @@ impl IndexBuilder for MysqlQueryBuilder {
         self.prepare_index_type(&create.index_type, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include, sql);
+
+        self.prepare_index_algorithm_lock(&create.algorithm, &create.lock, sql);
     }
 
     fn prepare_index_drop_statement(&self, drop: &IndexDropStatement, sql: &mut SqlWriter) {
         write!(sql, "DROP INDEX ").unwrap();
+        if drop.concurrently {
+            panic!("Mysql not support CONCURRENTLY on index");
+        }
+        if drop.if_exists {
+            panic!("Mysql not support IF EXISTS on index");
+        }
         if let Some(name) = &drop.index.name {
             write!(sql, "`{}`", name).unwrap();
         }
@@ -39,17 +66,59 @@ impl IndexBuilder for MysqlQueryBuilder {
         if let Some(table) = &drop.table {
             table.prepare(sql, '`');
         }
+
+        self.prepare_index_algorithm_lock(&drop.algorithm, &drop.lock, sql);
+    }
+
+    fn prepare_index_reindex_statement(&self, _: &IndexReindexStatement, _: &mut SqlWriter) {
+        panic!("Mysql not support REINDEX");
+    }
+
+    fn prepare_index_algorithm_lock(
+        &self,
+        algorithm: &Option<IndexAlgorithm>,
+        lock: &Option<IndexLock>,
+        sql: &mut SqlWriter,
+    ) {
+        if let Some(algorithm) = algorithm {
+            write!(
+                sql,
+                " ALGORITHM={}",
+                match algorithm {
+                    IndexAlgorithm::Default => "DEFAULT",
+                    IndexAlgorithm::Inplace => "INPLACE",
+                    IndexAlgorithm::Copy => "COPY",
+                }
+            )
+            .unwrap();
+        }
+        if let Some(lock) = lock {
+            write!(
+                sql,
+                " LOCK={}",
+                match lock {
+                    IndexLock::Default => "DEFAULT",
+                    IndexLock::None => "NONE",
+                    IndexLock::Shared => "SHARED",
+                    IndexLock::Exclusive => "EXCLUSIVE",
+                }
+            )
+            .unwrap();
+        }
     }
     fn prepare_index_type(&self, col_index_type: &Option<IndexType>, sql: &mut SqlWriter) {
         if let Some(index_type) = col_index_type {
-            if !matches!(index_type, IndexType::FullText) {
+            if !matches!(index_type, IndexType::FullText | IndexType::Spatial) {
                 write!(
                     sql,
                     " USING {}",
                     match index_type {
                         IndexType::BTree => "BTREE".to_owned(),
-                        IndexType::FullText => unreachable!(),
+                        IndexType::FullText | IndexType::Spatial => unreachable!(),
                         IndexType::Hash => "HASH".to_owned(),
+                        IndexType::Gin => panic!("Mysql not support GIN index"),
+                        IndexType::Gist => panic!("Mysql not support GIST index"),
+                        IndexType::Brin => panic!("Mysql not support BRIN index"),
                         IndexType::Custom(custom) => custom.to_string(),
                     }
                 )
@@ -68,5 +137,13 @@ impl IndexBuilder for MysqlQueryBuilder {
         if matches!(create.index_type, Some(IndexType::FullText)) {
             write!(sql, "FULLTEXT ").unwrap();
         }
+        if matches!(create.index_type, Some(IndexType::Spatial)) {
+            write!(sql, "SPATIAL ").unwrap();
+        }
     }
+
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter) {
+        self.prepare_simple_expr_inlined(expr, sql);
+    }
+}
 }
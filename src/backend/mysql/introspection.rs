@@ -0,0 +1,146 @@
+use super::*;
+use crate::backend::introspection_builder::{
+    column_text, parse_column_type, parse_default_expr, parse_flag, required_text,
+};
+use crate::error::Result;
+
+impl_versioned! {
+impl IntrospectionBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
+    fn tables_query(&self, schema: Option<&str>) -> SelectStatement {
+        Query::select()
+            .column(Alias::new("table_name"))
+            .from(information_schema("tables"))
+            .and_where(
+                Expr::col(Alias::new("table_schema"))
+                    .into_simple_expr()
+                    .equals(database(schema)),
+            )
+            .and_where(Expr::col(Alias::new("table_type")).eq("BASE TABLE"))
+            .order_by(Alias::new("table_name"), Order::Asc)
+            .take()
+    }
+
+    fn columns_query(&self, schema: Option<&str>, table: &str) -> SelectStatement {
+        Query::select()
+            .column(Alias::new("column_name"))
+            .column(Alias::new("column_type"))
+            .column(Alias::new("is_nullable"))
+            .column(Alias::new("column_default"))
+            .column(Alias::new("extra"))
+            .from(information_schema("columns"))
+            .and_where(
+                Expr::col(Alias::new("table_schema"))
+                    .into_simple_expr()
+                    .equals(database(schema)),
+            )
+            .and_where(Expr::col(Alias::new("table_name")).eq(table))
+            .order_by(Alias::new("ordinal_position"), Order::Asc)
+            .take()
+    }
+
+    fn indexes_query(&self, schema: Option<&str>, table: &str) -> SelectStatement {
+        Query::select()
+            .column(Alias::new("index_name"))
+            .expr(text(Expr::col(Alias::new("non_unique")).eq(0)))
+            .expr(text(Expr::col(Alias::new("index_name")).eq("PRIMARY")))
+            .column(Alias::new("column_name"))
+            .from(information_schema("statistics"))
+            .and_where(
+                Expr::col(Alias::new("table_schema"))
+                    .into_simple_expr()
+                    .equals(database(schema)),
+            )
+            .and_where(Expr::col(Alias::new("table_name")).eq(table))
+            .order_by(Alias::new("index_name"), Order::Asc)
+            .order_by(Alias::new("seq_in_index"), Order::Asc)
+            .take()
+    }
+
+    fn foreign_keys_query(&self, schema: Option<&str>, table: &str) -> SelectStatement {
+        let (kcu, rc) = (Alias::new("kcu"), Alias::new("rc"));
+        let same = |column: &str| {
+            Expr::tbl(rc.clone(), Alias::new(column)).equals(kcu.clone(), Alias::new(column))
+        };
+        Query::select()
+            .column((kcu.clone(), Alias::new("constraint_name")))
+            .column((kcu.clone(), Alias::new("column_name")))
+            .column((kcu.clone(), Alias::new("referenced_table_name")))
+            .column((kcu.clone(), Alias::new("referenced_column_name")))
+            .column((rc.clone(), Alias::new("update_rule")))
+            .column((rc.clone(), Alias::new("delete_rule")))
+            .from_as(information_schema("key_column_usage"), kcu.clone())
+            .join_as(
+                JoinType::InnerJoin,
+                information_schema("referential_constraints"),
+                rc.clone(),
+                same("constraint_schema")
+                    .and(same("constraint_name"))
+                    .and(same("table_name")),
+            )
+            .and_where(
+                Expr::tbl(kcu.clone(), Alias::new("table_schema"))
+                    .into_simple_expr()
+                    .equals(database(schema)),
+            )
+            .and_where(Expr::tbl(kcu.clone(), Alias::new("table_name")).eq(table))
+            .order_by((kcu.clone(), Alias::new("constraint_name")), Order::Asc)
+            .order_by((kcu, Alias::new("ordinal_position")), Order::Asc)
+            .take()
+    }
+
+    /// The row holds the name, the column type, `YES` if the column is nullable, the default
+    /// and the extra information, e.g. `auto_increment`.
+    fn parse_column(&self, row: &[Option<String>]) -> Result<ColumnDef> {
+        let mut column = ColumnDef::new(Alias::new(required_text(row, 0, "column name")?));
+        let column_type = required_text(row, 1, "column type")?;
+        column.types = Some(if column_type.eq_ignore_ascii_case("tinyint(1)") {
+            ColumnType::Boolean
+        } else {
+            parse_column_type(column_type)
+        });
+        if !parse_flag(required_text(row, 2, "column nullability")?)? {
+            column.not_null();
+        }
+        let extra = column_text(row, 4)?.unwrap_or_default();
+        let lower = extra.to_ascii_lowercase();
+        if let Some(default) = column_text(row, 3)? {
+            // Expressions are flagged since MySQL 8.0.13, earlier only `CURRENT_TIMESTAMP` is one
+            if lower.contains("default_generated")
+                || default
+                    .to_ascii_uppercase()
+                    .starts_with("CURRENT_TIMESTAMP")
+            {
+                column.default_expr(parse_default_expr(default));
+            } else {
+                column.default(default);
+            }
+        }
+        if lower.contains("auto_increment") {
+            column.auto_increment();
+        }
+        if let Some(position) = lower.find("on update ") {
+            column.extra(format!(
+                "ON UPDATE {}",
+                &extra[position + "on update ".len()..]
+            ));
+        }
+        Ok(column)
+    }
+}
+}
+
+fn information_schema(table: &str) -> (Alias, Alias) {
+    (Alias::new("information_schema"), Alias::new(table))
+}
+
+/// The given schema, or the current database
+fn database(schema: Option<&str>) -> SimpleExpr {
+    match schema {
+        Some(schema) => Expr::val(schema).into(),
+        None => Func::cust(Alias::new("DATABASE")).args(Vec::<SimpleExpr>::new()),
+    }
+}
+
+fn text(expr: SimpleExpr) -> SimpleExpr {
+    Expr::cast_as(expr, ColumnType::Text).into()
+}
@@ -1,11 +1,20 @@
+pub(crate) mod database;
 pub(crate) mod foreign_key;
 pub(crate) mod index;
+pub(crate) mod introspection;
 pub(crate) mod query;
+pub(crate) mod role;
+pub(crate) mod sequence;
 pub(crate) mod table;
+pub(crate) mod transaction;
+pub(crate) mod trigger;
+pub(crate) mod view;
 
 use super::*;
 
 /// Mysql query builder.
+///
+/// Renders for the latest MySQL, use [`MysqlQueryBuilder::version`] to target an older version.
 #[derive(Debug)]
 pub struct MysqlQueryBuilder;
 
@@ -17,12 +26,56 @@ impl Default for MysqlQueryBuilder {
     }
 }
 
-impl GenericBuilder for MysqlQueryBuilder {}
+/// Mysql query builder targeting a specific MySQL version.
+///
+/// Constructed with [`MysqlQueryBuilder::version`].
+#[derive(Debug, Clone, Copy)]
+pub struct VersionedMysqlQueryBuilder {
+    version: (u32, u32),
+}
+
+impl MysqlQueryBuilder {
+    /// Target a specific MySQL version, given as major and minor version.
+    /// Features introduced in later versions are rejected: `try_build` returns
+    /// [`Error::Unsupported`](crate::error::Error::Unsupported), while `build` panics.
+    pub fn version(self, major: u32, minor: u32) -> VersionedMysqlQueryBuilder {
+        VersionedMysqlQueryBuilder {
+            version: (major, minor),
+        }
+    }
 
-impl SchemaBuilder for MysqlQueryBuilder {}
+    pub(crate) fn version_at_least(&self, _major: u32, _minor: u32) -> bool {
+        true
+    }
+}
 
-impl QuotedBuilder for MysqlQueryBuilder {
+impl VersionedMysqlQueryBuilder {
+    /// Whether the targeted version is at least the given one.
+    pub(crate) fn version_at_least(&self, major: u32, minor: u32) -> bool {
+        self.version >= (major, minor)
+    }
+}
+
+impl_versioned! {
+impl GenericBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {}
+}
+
+impl_versioned! {
+impl SchemaBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {}
+}
+
+impl_versioned! {
+impl PlaceholderBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {}
+}
+
+impl_versioned! {
+impl PaginationBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {}
+}
+
+impl_versioned! {
+impl QuotedBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
     fn quote(&self) -> char {
         '`'
     }
 }
+}
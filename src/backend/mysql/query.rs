@@ -1,3 +1,445 @@
 use super::*;
 
-impl QueryBuilder for MysqlQueryBuilder {}
+impl_versioned! {
+impl QueryBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
+    fn backend_name(&self) -> &'static str {
+        "MySQL"
+    }
+
+    fn returning_supported(&self) -> bool {
+        false
+    }
+
+    fn typed_date_time_literal(&self) -> bool {
+        true
+    }
+
+    fn distinct_on_supported(&self) -> bool {
+        false
+    }
+
+    fn full_outer_join_supported(&self) -> bool {
+        false
+    }
+
+    fn with_clause_supported(&self) -> bool {
+        self.version_at_least(8, 0)
+    }
+
+    fn window_function_supported(&self) -> bool {
+        self.version_at_least(8, 0)
+    }
+
+    fn array_value_supported(&self) -> bool {
+        false
+    }
+
+    /// MySQL takes the `WITH` clause of an insert after the columns, before the select
+    fn prepare_with_query(
+        &self,
+        with_query: &WithQuery,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        match with_query.query.as_ref() {
+            QueryStatement::Insert(insert)
+                if matches!(insert.source, Some(InsertValueSource::Select(_))) =>
+            {
+                self.prepare_insert_with_clause(
+                    insert,
+                    Some(&with_query.with_clause),
+                    sql,
+                    collector,
+                );
+            }
+            query => {
+                self.prepare_with_clause(&with_query.with_clause, sql, collector);
+                self.prepare_query_statement(query, sql, collector);
+            }
+        }
+    }
+
+    fn prepare_update_join(
+        &self,
+        from: &[TableRef],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        for table_ref in from.iter() {
+            write!(sql, " JOIN ").unwrap();
+            self.prepare_table_ref(table_ref, sql, collector);
+        }
+    }
+
+    fn prepare_update_from(
+        &self,
+        _from: &[TableRef],
+        _sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+    }
+
+    fn prepare_delete_from(
+        &self,
+        table: &TableRef,
+        using: &[TableRef],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if !using.is_empty() {
+            match table {
+                TableRef::Table(table) => self.prepare_table_name(&[table], sql),
+                TableRef::SchemaTable(schema, table) => {
+                    self.prepare_table_name(&[schema, table], sql)
+                }
+                TableRef::DatabaseSchemaTable(database, schema, table) => {
+                    self.prepare_table_name(&[database, schema, table], sql)
+                }
+                TableRef::TableAlias(_, alias)
+                | TableRef::SchemaTableAlias(_, _, alias)
+                | TableRef::DatabaseSchemaTableAlias(_, _, _, alias)
+                | TableRef::SubQuery(_, alias)
+                | TableRef::ValuesList(_, alias, _) => alias.prepare(sql, self.quote()),
+            }
+            write!(sql, " ").unwrap();
+        }
+        write!(sql, "FROM ").unwrap();
+        self.prepare_table_ref(table, sql, collector);
+        for table_ref in using.iter() {
+            write!(sql, " JOIN ").unwrap();
+            self.prepare_table_ref(table_ref, sql, collector);
+        }
+    }
+
+    fn prepare_order_expr(
+        &self,
+        order_expr: &OrderExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if let Some(nulls) = &order_expr.nulls {
+            self.prepare_simple_expr(&order_expr.expr, sql, collector);
+            match nulls {
+                NullOrdering::First => write!(sql, " IS NULL DESC, ").unwrap(),
+                NullOrdering::Last => write!(sql, " IS NULL ASC, ").unwrap(),
+            }
+        }
+        match &order_expr.field {
+            Some(values) => self.prepare_field_order(&order_expr.expr, values, sql, collector),
+            None => {
+                self.prepare_simple_expr(&order_expr.expr, sql, collector);
+                write!(sql, " ").unwrap();
+                self.prepare_order(&order_expr.order, sql, collector);
+            }
+        }
+    }
+
+    fn prepare_field_order(
+        &self,
+        expr: &SimpleExpr,
+        values: &Values,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        // `FIELD` returns 0 for unlisted values; list in reverse and sort descending
+        // so that they come last, as with the `CASE` translation
+        write!(sql, "FIELD(").unwrap();
+        self.prepare_simple_expr(expr, sql, collector);
+        for value in values.0.iter().rev() {
+            write!(sql, ", ").unwrap();
+            self.prepare_value(value, sql, collector);
+        }
+        write!(sql, ") DESC").unwrap();
+    }
+
+    fn prepare_values_row(
+        &self,
+        row: &Values,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "ROW(").unwrap();
+        row.0.iter().fold(true, |first, value| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_value(value, sql, collector);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_cast_type(
+        &self,
+        cast_type: &CastType,
+        sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        let column_type = match cast_type {
+            CastType::Column(column_type) => column_type,
+            CastType::Custom(iden) => return iden.unquoted(sql),
+        };
+        // `CAST` only accepts a subset of the column types
+        write!(
+            sql,
+            "{}",
+            match column_type {
+                ColumnType::Char(length) | ColumnType::String(length) => match length {
+                    Some(length) => format!("char({})", length),
+                    None => "char".into(),
+                },
+                ColumnType::Text => "char".into(),
+                ColumnType::TinyInteger(_)
+                | ColumnType::SmallInteger(_)
+                | ColumnType::Integer(_)
+                | ColumnType::BigInteger(_)
+                | ColumnType::Boolean => "signed".into(),
+                ColumnType::Float(_) => "float".into(),
+                ColumnType::Double(_) => "double".into(),
+                ColumnType::Decimal(precision) | ColumnType::Money(precision) => match precision {
+                    Some((precision, scale)) => format!("decimal({}, {})", precision, scale),
+                    None => "decimal".into(),
+                },
+                ColumnType::DateTime(precision)
+                | ColumnType::Timestamp(precision)
+                | ColumnType::TimestampWithTimeZone(precision) => match precision {
+                    Some(precision) => format!("datetime({})", precision),
+                    None => "datetime".into(),
+                },
+                ColumnType::Time(precision) => match precision {
+                    Some(precision) => format!("time({})", precision),
+                    None => "time".into(),
+                },
+                ColumnType::Date => "date".into(),
+                ColumnType::Binary(length) => match length {
+                    Some(length) => format!("binary({})", length),
+                    None => "binary".into(),
+                },
+                ColumnType::Json | ColumnType::JsonBinary => "json".into(),
+                ColumnType::Uuid => "binary(16)".into(),
+                ColumnType::Inet
+                | ColumnType::Cidr
+                | ColumnType::MacAddr
+                | ColumnType::Enum(_, _) => "char".into(),
+                ColumnType::Array(_) => panic!("Mysql not support array column type"),
+                ColumnType::Custom(iden) => iden.to_string(),
+            }
+        )
+        .unwrap()
+    }
+
+    fn grouping_supported(&self, grouping: &Grouping) -> bool {
+        matches!(grouping, Grouping::Rollup)
+    }
+
+    fn prepare_group_by(
+        &self,
+        groups: &[SimpleExpr],
+        grouping: &Option<Grouping>,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if matches!(grouping, Some(Grouping::Cube) | Some(Grouping::Sets(_))) {
+            panic!("CUBE and GROUPING SETS are not supported by this backend");
+        }
+        if groups.is_empty() {
+            return;
+        }
+        write!(sql, " GROUP BY ").unwrap();
+        groups.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_simple_expr(expr, sql, collector);
+            false
+        });
+        if grouping.is_some() {
+            write!(sql, " WITH ROLLUP").unwrap();
+        }
+    }
+
+    fn prepare_string_agg(
+        &self,
+        exprs: &[SimpleExpr],
+        separator: &str,
+        orders: &[OrderExpr],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "GROUP_CONCAT(").unwrap();
+        exprs.iter().for_each(|expr| {
+            self.prepare_simple_expr(expr, sql, collector);
+        });
+        self.prepare_string_agg_order_by(orders, sql, collector);
+        write!(sql, " SEPARATOR ").unwrap();
+        self.prepare_string_agg_separator(separator, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_bin_oper(
+        &self,
+        bin_oper: &BinOper,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        match bin_oper {
+            BinOper::IsNotDistinctFrom => write!(sql, "<=>").unwrap(),
+            _ => self.prepare_bin_oper_common(bin_oper, sql, collector),
+        }
+    }
+
+    fn prepare_is_distinct_from(
+        &self,
+        left: &SimpleExpr,
+        right: &SimpleExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "NOT (").unwrap();
+        self.binary_expr(left, &BinOper::IsNotDistinctFrom, right, sql, collector);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_concat(
+        &self,
+        left: &SimpleExpr,
+        right: &SimpleExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        // `||` is logical OR in MySQL; nested concatenations become arguments of one `CONCAT`
+        fn operands<'a>(expr: &'a SimpleExpr, list: &mut Vec<&'a SimpleExpr>) {
+            match expr {
+                SimpleExpr::Binary(left, BinOper::Concat, right) => {
+                    operands(left, list);
+                    operands(right, list);
+                }
+                _ => list.push(expr),
+            }
+        }
+        let mut list = Vec::new();
+        operands(left, &mut list);
+        operands(right, &mut list);
+        write!(sql, "CONCAT(").unwrap();
+        list.into_iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_simple_expr(expr, sql, collector);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_case_insensitive_regexp(
+        &self,
+        left: &SimpleExpr,
+        op: &BinOper,
+        right: &SimpleExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if *op == BinOper::NotIRegexp {
+            write!(sql, "NOT ").unwrap();
+        }
+        write!(sql, "REGEXP_LIKE(").unwrap();
+        self.prepare_simple_expr(left, sql, collector);
+        write!(sql, ", ").unwrap();
+        self.prepare_simple_expr(right, sql, collector);
+        write!(sql, ", 'i')").unwrap();
+    }
+
+    fn prepare_full_text_match(
+        &self,
+        columns: &[SimpleExpr],
+        query: &SimpleExpr,
+        mode: &SearchMode,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "MATCH (").unwrap();
+        columns.iter().fold(true, |first, column| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_simple_expr(column, sql, collector);
+            false
+        });
+        write!(sql, ") AGAINST (").unwrap();
+        self.prepare_simple_expr(query, sql, collector);
+        write!(
+            sql,
+            " IN {} MODE)",
+            match mode {
+                SearchMode::NaturalLanguage => "NATURAL LANGUAGE",
+                SearchMode::Boolean => "BOOLEAN",
+            }
+        )
+        .unwrap();
+    }
+
+    fn insert_default_values(&self, sql: &mut SqlWriter) {
+        write!(sql, "() VALUES ()").unwrap();
+    }
+
+    fn prepare_on_conflict(
+        &self,
+        on_conflict: &OnConflict,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        match &on_conflict.action {
+            Some(OnConflictAction::Update(updates)) => {
+                write!(sql, " ON DUPLICATE KEY UPDATE ").unwrap();
+                updates.iter().fold(true, |first, update| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_on_conflict_update(update, sql, collector);
+                    false
+                });
+            }
+            Some(OnConflictAction::DoNothing) | None => {
+                if let Some(OnConflictTarget::Columns(columns)) = &on_conflict.target {
+                    if let Some(col) = columns.first() {
+                        write!(sql, " ON DUPLICATE KEY UPDATE ").unwrap();
+                        col.prepare(sql, self.quote());
+                        write!(sql, " = ").unwrap();
+                        col.prepare(sql, self.quote());
+                    }
+                }
+            }
+        }
+    }
+
+    /// `ON DUPLICATE KEY UPDATE` has no `WHERE` clauses, and ignoring a conflict is done by
+    /// assigning a target column to itself
+    fn check_on_conflict(&self, on_conflict: &OnConflict) -> error::Result<()> {
+        if !on_conflict.target_where.is_empty() || !on_conflict.action_where.is_empty() {
+            self.check_supported(false, "WHERE in ON CONFLICT")?;
+        }
+        let has_target_column = matches!(
+            &on_conflict.target,
+            Some(OnConflictTarget::Columns(columns)) if !columns.is_empty()
+        );
+        if !matches!(on_conflict.action, Some(OnConflictAction::Update(_))) && !has_target_column {
+            self.check_supported(false, "ON CONFLICT DO NOTHING without target columns")?;
+        }
+        Ok(())
+    }
+
+    fn prepare_on_conflict_excluded(&self, col: &DynIden, sql: &mut SqlWriter) {
+        write!(sql, "VALUES(").unwrap();
+        col.prepare(sql, self.quote());
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_returning(
+        &self,
+        _returning: &Option<ReturningClause>,
+        _sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+    }
+}
+}
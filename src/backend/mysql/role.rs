@@ -0,0 +1,27 @@
+use super::*;
+
+impl_versioned! {
+impl RoleBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
+    fn prepare_role_host(&self, host: &str, sql: &mut SqlWriter) {
+        write!(sql, "@'{}'", self.escape_string(host)).unwrap();
+    }
+
+    fn prepare_role_options(&self, options: &[RoleOption], sql: &mut SqlWriter) {
+        // `IDENTIFIED BY` has to precede the resource limits of `WITH`
+        for option in options.iter() {
+            match option {
+                RoleOption::Password(password) => {
+                    write!(sql, " IDENTIFIED BY '{}'", self.escape_string(password)).unwrap()
+                }
+                RoleOption::ConnectionLimit(_) => {}
+                _ => panic!("Mysql not support role option {:?}", option),
+            }
+        }
+        for option in options.iter() {
+            if let RoleOption::ConnectionLimit(limit) = option {
+                write!(sql, " WITH MAX_USER_CONNECTIONS {}", limit).unwrap();
+            }
+        }
+    }
+}
+}
@@ -1,12 +1,17 @@
 use super::*;
 
-impl TableBuilder for MysqlQueryBuilder {
+impl_versioned! {
+impl TableBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
     fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut SqlWriter) {
         column_def.name.prepare(sql, '`');
 
         if let Some(column_type) = &column_def.types {
             write!(sql, " ").unwrap();
             self.prepare_column_type(column_type, sql);
+            if let ColumnType::Enum(_, variants) = column_type {
+                write!(sql, " ").unwrap();
+                self.prepare_enum_check(&column_def.name, variants, sql);
+            }
         }
 
         for column_spec in column_def.spec.iter() {
@@ -15,6 +20,230 @@ impl TableBuilder for MysqlQueryBuilder {
         }
     }
 
+    fn prepare_column_spec(&self, column_spec: &ColumnSpec, sql: &mut SqlWriter) {
+        match column_spec {
+            ColumnSpec::Null => write!(sql, "NULL"),
+            ColumnSpec::NotNull => write!(sql, "NOT NULL"),
+            ColumnSpec::Default(value) => {
+                write!(sql, "DEFAULT ").unwrap();
+                self.prepare_column_default(value, sql);
+                Ok(())
+            }
+            ColumnSpec::AutoIncrement => write!(sql, "AUTO_INCREMENT"),
+            ColumnSpec::Identity(identity) => {
+                if identity.increment.is_some() {
+                    panic!("Mysql not support identity increment");
+                }
+                write!(sql, "AUTO_INCREMENT")
+            }
+            ColumnSpec::UniqueKey => write!(sql, "UNIQUE"),
+            ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY"),
+            ColumnSpec::Check(check) => {
+                self.prepare_check_constraint(check, sql);
+                Ok(())
+            }
+            ColumnSpec::Generated(expr, storage) => {
+                write!(sql, "GENERATED ALWAYS AS (").unwrap();
+                self.prepare_simple_expr_inlined(expr, sql);
+                write!(
+                    sql,
+                    ") {}",
+                    match storage {
+                        GeneratedStorage::Stored => "STORED",
+                        GeneratedStorage::Virtual => "VIRTUAL",
+                    }
+                )
+            }
+            ColumnSpec::Extra(string) => write!(sql, "{}", string),
+        }
+        .unwrap()
+    }
+
+    fn prepare_table_create_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(MysqlQueryBuilder)).unwrap();
+    }
+
+    fn prepare_check_constraint(&self, check: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, "CHECK (").unwrap();
+        self.prepare_simple_expr_inlined(check, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_table_identity_opt(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        let start = create.columns.iter().find_map(|column_def| {
+            column_def
+                .spec
+                .iter()
+                .find_map(|column_spec| match column_spec {
+                    ColumnSpec::Identity(identity) => identity.start,
+                    _ => None,
+                })
+        });
+        if let Some(start) = start {
+            write!(sql, " AUTO_INCREMENT={}", start).unwrap();
+        }
+    }
+
+    fn prepare_table_partition(&self, table_partition: &TablePartition, sql: &mut SqlWriter) {
+        match table_partition {
+            TablePartition::By(r#type, columns) => self.prepare_partition_by(r#type, columns, sql),
+            TablePartition::Definition(name, values) => {
+                write!(sql, "PARTITION ").unwrap();
+                name.prepare(sql, '`');
+                write!(sql, " ").unwrap();
+                self.prepare_partition_values(values, sql);
+            }
+        }
+    }
+
+    fn prepare_partition_by(
+        &self,
+        r#type: &PartitionType,
+        columns: &[DynIden],
+        sql: &mut SqlWriter,
+    ) {
+        // Partitioning by plain columns rather than expressions
+        write!(
+            sql,
+            "PARTITION BY {} (",
+            match r#type {
+                PartitionType::Range => "RANGE COLUMNS",
+                PartitionType::List => "LIST COLUMNS",
+                PartitionType::Hash => "HASH",
+            }
+        )
+        .unwrap();
+        columns.iter().fold(true, |first, column| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            column.prepare(sql, '`');
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_partition_values(&self, values: &PartitionValues, sql: &mut SqlWriter) {
+        let exprs = match values {
+            PartitionValues::LessThan(exprs) => {
+                write!(sql, "VALUES LESS THAN (").unwrap();
+                exprs
+            }
+            PartitionValues::In(exprs) => {
+                write!(sql, "VALUES IN (").unwrap();
+                exprs
+            }
+            PartitionValues::FromTo(..) => panic!("Mysql not support FROM ... TO partition bound"),
+            PartitionValues::Modulus(..) => panic!("Mysql not support MODULUS partition bound"),
+            PartitionValues::Default => panic!("Mysql not support DEFAULT partition"),
+        };
+        exprs.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_simple_expr_inlined(expr, sql);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
+        if alter.options.is_empty() {
+            panic!("No alter option found")
+        }
+        write!(sql, "ALTER TABLE ").unwrap();
+        if let Some(table) = &alter.table {
+            table.prepare(sql, '`');
+            write!(sql, " ").unwrap();
+        }
+        alter.options.iter().fold(true, |first, option| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_table_alter_option(option, sql);
+            false
+        });
+    }
+
+    fn prepare_table_alter_option(&self, alter_option: &TableAlterOption, sql: &mut SqlWriter) {
+        match alter_option {
+            TableAlterOption::AddColumn(column_def) => {
+                write!(sql, "ADD COLUMN ").unwrap();
+                self.prepare_column_def(column_def, sql);
+            }
+            TableAlterOption::ModifyColumn(column_def)
+            | TableAlterOption::ModifyColumnUsing(column_def, _) => {
+                write!(sql, "MODIFY COLUMN ").unwrap();
+                self.prepare_column_def(column_def, sql);
+            }
+            TableAlterOption::ChangeColumn(from_name, column_def) => {
+                write!(sql, "CHANGE COLUMN ").unwrap();
+                from_name.prepare(sql, '`');
+                write!(sql, " ").unwrap();
+                self.prepare_column_def(column_def, sql);
+            }
+            TableAlterOption::RenameColumn(from_name, to_name) => {
+                write!(sql, "RENAME COLUMN ").unwrap();
+                from_name.prepare(sql, '`');
+                write!(sql, " TO ").unwrap();
+                to_name.prepare(sql, '`');
+            }
+            TableAlterOption::DropColumn(column_name) => {
+                write!(sql, "DROP COLUMN ").unwrap();
+                column_name.prepare(sql, '`');
+            }
+            TableAlterOption::DropColumnDefault(column_name) => {
+                write!(sql, "ALTER COLUMN ").unwrap();
+                column_name.prepare(sql, '`');
+                write!(sql, " DROP DEFAULT").unwrap();
+            }
+            TableAlterOption::AddCheckConstraint(name, check) => {
+                write!(sql, "ADD CONSTRAINT ").unwrap();
+                name.prepare(sql, '`');
+                write!(sql, " ").unwrap();
+                self.prepare_check_constraint(check, sql);
+            }
+            TableAlterOption::DropConstraint(name) => {
+                write!(sql, "DROP CONSTRAINT ").unwrap();
+                name.prepare(sql, '`');
+            }
+        }
+    }
+
+    fn prepare_table_truncate_statement(
+        &self,
+        truncate: &TableTruncateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if truncate.tables.len() > 1 {
+            panic!("Mysql not support truncating multiple tables");
+        }
+        if truncate.identity.is_some() || !truncate.options.is_empty() {
+            panic!("Mysql not support truncate options");
+        }
+
+        write!(sql, "TRUNCATE TABLE ").unwrap();
+
+        if let Some(table) = truncate.tables.first() {
+            table.prepare(sql, '`');
+        }
+    }
+
+    fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter) {
+        write!(sql, "RENAME TABLE ").unwrap();
+        if let Some(from_name) = &rename.from_name {
+            from_name.prepare(sql, '`');
+        }
+        write!(sql, " TO ").unwrap();
+        if let Some(to_name) = &rename.to_name {
+            to_name.prepare(sql, '`');
+        }
+    }
+}
+}
+
+impl_versioned! {
+impl ColumnTypeBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
     fn prepare_column_type(&self, column_type: &ColumnType, sql: &mut SqlWriter) {
         write!(
             sql,
@@ -86,69 +315,15 @@ impl TableBuilder for MysqlQueryBuilder {
                 ColumnType::Json => "json".into(),
                 ColumnType::JsonBinary => "json".into(),
                 ColumnType::Uuid => "binary(16)".into(),
+                ColumnType::Inet => "varchar(43)".into(),
+                ColumnType::Cidr => "varchar(43)".into(),
+                ColumnType::MacAddr => "varchar(17)".into(),
+                ColumnType::Enum(_, _) => "varchar(255)".into(),
+                ColumnType::Array(_) => panic!("Mysql not support array column type"),
                 ColumnType::Custom(iden) => iden.to_string(),
             }
         )
         .unwrap()
     }
-
-    fn prepare_column_spec(&self, column_spec: &ColumnSpec, sql: &mut SqlWriter) {
-        match column_spec {
-            ColumnSpec::Null => write!(sql, "NULL"),
-            ColumnSpec::NotNull => write!(sql, "NOT NULL"),
-            ColumnSpec::Default(value) => write!(sql, "DEFAULT {}", self.value_to_string(value)),
-            ColumnSpec::AutoIncrement => write!(sql, "AUTO_INCREMENT"),
-            ColumnSpec::UniqueKey => write!(sql, "UNIQUE"),
-            ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY"),
-            ColumnSpec::Extra(string) => write!(sql, "{}", string),
-        }
-        .unwrap()
-    }
-
-    fn prepare_table_partition(&self, _table_partition: &TablePartition, _sql: &mut SqlWriter) {
-        panic!("unsupported");
-    }
-
-    fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
-        let alter_option = match &alter.alter_option {
-            Some(alter_option) => alter_option,
-            None => panic!("No alter option found"),
-        };
-        write!(sql, "ALTER TABLE ").unwrap();
-        if let Some(table) = &alter.table {
-            table.prepare(sql, '`');
-            write!(sql, " ").unwrap();
-        }
-        match alter_option {
-            TableAlterOption::AddColumn(column_def) => {
-                write!(sql, "ADD COLUMN ").unwrap();
-                self.prepare_column_def(column_def, sql);
-            }
-            TableAlterOption::ModifyColumn(column_def) => {
-                write!(sql, "MODIFY COLUMN ").unwrap();
-                self.prepare_column_def(column_def, sql);
-            }
-            TableAlterOption::RenameColumn(from_name, to_name) => {
-                write!(sql, "RENAME COLUMN ").unwrap();
-                from_name.prepare(sql, '`');
-                write!(sql, " TO ").unwrap();
-                to_name.prepare(sql, '`');
-            }
-            TableAlterOption::DropColumn(column_name) => {
-                write!(sql, "DROP COLUMN ").unwrap();
-                column_name.prepare(sql, '`');
-            }
-        }
-    }
-
-    fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter) {
-        write!(sql, "RENAME TABLE ").unwrap();
-        if let Some(from_name) = &rename.from_name {
-            from_name.prepare(sql, '`');
-        }
-        write!(sql, " TO ").unwrap();
-        if let Some(to_name) = &rename.to_name {
-            to_name.prepare(sql, '`');
-        }
-    }
+}
 }
@@ -0,0 +1,17 @@
+use super::*;
+
+impl_versioned! {
+impl TransactionBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
+    fn prepare_transaction_begin_statement(
+        &self,
+        begin: &TransactionBeginStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if begin.isolation_level.is_some() {
+            panic!("Mysql not support isolation level on START TRANSACTION");
+        }
+        write!(sql, "START TRANSACTION").unwrap();
+        self.prepare_transaction_modes(&None, &begin.access_mode, sql);
+    }
+}
+}
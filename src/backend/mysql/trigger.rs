@@ -0,0 +1,55 @@
+use super::*;
+
+impl_versioned! {
+impl TriggerBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
+    fn prepare_trigger_timing(&self, timing: &TriggerTiming, sql: &mut SqlWriter) {
+        match timing {
+            TriggerTiming::Before => write!(sql, "BEFORE").unwrap(),
+            TriggerTiming::After => write!(sql, "AFTER").unwrap(),
+            TriggerTiming::InsteadOf => panic!("Mysql not support INSTEAD OF trigger"),
+        }
+    }
+
+    fn prepare_trigger_events(&self, events: &[TriggerEvent], sql: &mut SqlWriter) {
+        if events.len() > 1 {
+            panic!("Mysql not support multiple trigger events");
+        }
+        match &events[0] {
+            TriggerEvent::Update(columns) if !columns.is_empty() => {
+                panic!("Mysql not support UPDATE OF trigger")
+            }
+            TriggerEvent::Truncate => panic!("Mysql not support TRUNCATE trigger"),
+            event => self.prepare_trigger_event(event, sql),
+        }
+    }
+
+    fn prepare_trigger_scope(&self, scope: &Option<TriggerScope>, sql: &mut SqlWriter) {
+        if let Some(TriggerScope::Statement) = scope {
+            panic!("Mysql not support FOR EACH STATEMENT trigger");
+        }
+        write!(sql, " FOR EACH ROW").unwrap();
+    }
+
+    fn prepare_trigger_when(&self, _: &SimpleExpr, _: &mut SqlWriter) {
+        panic!("Mysql not support WHEN on trigger");
+    }
+
+    fn prepare_trigger_action(&self, action: &TriggerAction, sql: &mut SqlWriter) {
+        match action {
+            TriggerAction::Function(_) => panic!("Mysql not support trigger function"),
+            TriggerAction::Body(statements) if statements.len() == 1 => {
+                write!(sql, " ").unwrap();
+                self.prepare_query_statement_inlined(&statements[0], sql);
+            }
+            TriggerAction::Body(statements) => {
+                write!(sql, " BEGIN ").unwrap();
+                for statement in statements.iter() {
+                    self.prepare_query_statement_inlined(statement, sql);
+                    write!(sql, "; ").unwrap();
+                }
+                write!(sql, "END").unwrap();
+            }
+        }
+    }
+}
+}
@@ -0,0 +1,9 @@
+use super::*;
+
+impl_versioned! {
+impl ViewBuilder for MysqlQueryBuilder, VersionedMysqlQueryBuilder {
+    fn prepare_view_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(MysqlQueryBuilder)).unwrap();
+    }
+}
+}
@@ -0,0 +1,22 @@
+use crate::*;
+
+pub trait PaginationBuilder: PlaceholderBuilder {
+    /// Translate the limit and offset of a [`SelectStatement`] into SQL statement.
+    fn prepare_limit_offset(
+        &self,
+        limit: &Option<Value>,
+        offset: &Option<Value>,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if let Some(limit) = limit {
+            write!(sql, " LIMIT ").unwrap();
+            self.prepare_param(limit, sql, collector);
+        }
+
+        if let Some(offset) = offset {
+            write!(sql, " OFFSET ").unwrap();
+            self.prepare_param(offset, sql, collector);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::*;
+
+pub trait PlaceholderBuilder {
+    /// The type of placeholder the builder uses for values, and whether it is numbered.
+    fn placeholder(&self) -> (&str, bool) {
+        ("?", false)
+    }
+
+    /// Write a placeholder for `value` and hand the value over to the collector.
+    fn prepare_param(&self, value: &Value, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        let (placeholder, numbered) = self.placeholder();
+        sql.push_param(placeholder, numbered);
+        collector(value.clone());
+    }
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+impl_versioned! {
+impl DatabaseBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
+    fn prepare_database_create_statement(
+        &self,
+        create: &DatabaseCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if create.if_not_exists {
+            panic!("Postgres not support IF NOT EXISTS on database");
+        }
+
+        write!(sql, "CREATE DATABASE ").unwrap();
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, '"');
+        }
+
+        if let Some(owner) = &create.owner {
+            write!(sql, " OWNER ").unwrap();
+            owner.prepare(sql, '"');
+        }
+
+        if let Some(charset) = &create.charset {
+            write!(sql, " ENCODING '{}'", self.escape_string(charset)).unwrap();
+        }
+
+        if let Some(collate) = &create.collate {
+            write!(sql, " LC_COLLATE '{}'", self.escape_string(collate)).unwrap();
+        }
+    }
+}
+}
@@ -0,0 +1,55 @@
+use super::*;
+use crate::extension::postgres::*;
+
+impl_versioned! {
+impl ExtensionBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
+    fn prepare_extension_create_statement(
+        &self,
+        create: &ExtensionCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "CREATE EXTENSION ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, '"');
+        }
+
+        if let Some(schema) = &create.schema {
+            write!(sql, " WITH SCHEMA ").unwrap();
+            schema.prepare(sql, '"');
+        }
+
+        if let Some(version) = &create.version {
+            write!(sql, " VERSION '{}'", self.escape_string(version)).unwrap();
+        }
+
+        if create.cascade {
+            write!(sql, " CASCADE").unwrap();
+        }
+    }
+
+    fn prepare_extension_drop_statement(&self, drop: &ExtensionDropStatement, sql: &mut SqlWriter) {
+        write!(sql, "DROP EXTENSION ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        drop.names.iter().fold(true, |first, name| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            name.prepare(sql, '"');
+            false
+        });
+
+        if drop.cascade {
+            write!(sql, " CASCADE").unwrap();
+        }
+    }
+}
+}
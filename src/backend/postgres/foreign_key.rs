@@ -1,6 +1,7 @@
 use super::*;
 
-impl ForeignKeyBuilder for PostgresQueryBuilder {
+impl_versioned! {
+impl ForeignKeyBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
     fn prepare_foreign_key_drop_statement(
         &self,
         drop: &ForeignKeyDropStatement,
@@ -12,6 +13,9 @@ impl ForeignKeyBuilder for PostgresQueryBuilder {
         }
 
         write!(sql, " DROP CONSTRAINT ").unwrap();
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
         if let Some(name) = &drop.foreign_key.name {
             write!(sql, "\"{}\"", name).unwrap();
         }
@@ -66,6 +70,11 @@ impl ForeignKeyBuilder for PostgresQueryBuilder {
             });
         write!(sql, ")").unwrap();
 
+        if let Some(foreign_key_match) = &create.foreign_key.match_type {
+            write!(sql, " MATCH ").unwrap();
+            self.prepare_foreign_key_match(foreign_key_match, sql);
+        }
+
         if let Some(foreign_key_action) = &create.foreign_key.on_delete {
             write!(sql, " ON DELETE ").unwrap();
             self.prepare_foreign_key_action(foreign_key_action, sql);
@@ -75,5 +84,11 @@ impl ForeignKeyBuilder for PostgresQueryBuilder {
             write!(sql, " ON UPDATE ").unwrap();
             self.prepare_foreign_key_action(foreign_key_action, sql);
         }
+
+        if let Some(foreign_key_deferrable) = &create.foreign_key.deferrable {
+            write!(sql, " ").unwrap();
+            self.prepare_foreign_key_deferrable(foreign_key_deferrable, sql);
+        }
     }
 }
+}
@@ -1,6 +1,7 @@
 use super::*;
 
-impl IndexBuilder for PostgresQueryBuilder {
+impl_versioned! {
+impl IndexBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
     fn prepare_table_index_expression(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
         if create.index.name.is_some() {
             write!(sql, "CONSTRAINT ").unwrap();
@@ -11,6 +12,8 @@ impl IndexBuilder for PostgresQueryBuilder {
         self.prepare_index_prefix(create, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include, sql);
     }
 
     fn prepare_index_create_statement(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
@@ -18,6 +21,14 @@ impl IndexBuilder for PostgresQueryBuilder {
         self.prepare_index_prefix(create, sql);
         write!(sql, "INDEX ").unwrap();
 
+        if create.concurrently {
+            write!(sql, "CONCURRENTLY ").unwrap();
+        }
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
         self.prepare_index_name(&create.index.name, sql);
 
         write!(sql, " ON ").unwrap();
@@ -28,13 +39,48 @@ impl IndexBuilder for PostgresQueryBuilder {
         self.prepare_index_type(&create.index_type, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include, sql);
+
+        self.prepare_condition_inlined(&create.r#where, "WHERE", sql);
+
+        self.prepare_index_algorithm_lock(&create.algorithm, &create.lock, sql);
     }
 
     fn prepare_index_drop_statement(&self, drop: &IndexDropStatement, sql: &mut SqlWriter) {
         write!(sql, "DROP INDEX ").unwrap();
+        if drop.concurrently {
+            write!(sql, "CONCURRENTLY ").unwrap();
+        }
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
         if let Some(name) = &drop.index.name {
             write!(sql, "\"{}\"", name).unwrap();
         }
+
+        self.prepare_index_algorithm_lock(&drop.algorithm, &drop.lock, sql);
+    }
+
+    fn prepare_index_reindex_statement(
+        &self,
+        reindex: &IndexReindexStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "REINDEX ").unwrap();
+        if reindex.index.name.is_some() {
+            write!(sql, "INDEX ").unwrap();
+        } else {
+            write!(sql, "TABLE ").unwrap();
+        }
+        if reindex.concurrently {
+            write!(sql, "CONCURRENTLY ").unwrap();
+        }
+        if let Some(name) = &reindex.index.name {
+            write!(sql, "\"{}\"", name).unwrap();
+        } else if let Some(table) = &reindex.table {
+            table.prepare(sql, '"');
+        }
     }
 
     fn prepare_index_type(&self, col_index_type: &Option<IndexType>, sql: &mut SqlWriter) {
@@ -46,6 +92,10 @@ impl IndexBuilder for PostgresQueryBuilder {
                     IndexType::BTree => "BTREE".to_owned(),
                     IndexType::FullText => "GIN".to_owned(),
                     IndexType::Hash => "HASH".to_owned(),
+                    IndexType::Gin => "GIN".to_owned(),
+                    IndexType::Gist => "GIST".to_owned(),
+                    IndexType::Brin => "BRIN".to_owned(),
+                    IndexType::Spatial => "GIST".to_owned(),
                     IndexType::Custom(custom) => custom.to_string(),
                 }
             )
@@ -61,4 +111,45 @@ impl IndexBuilder for PostgresQueryBuilder {
             write!(sql, "UNIQUE ").unwrap();
         }
     }
+
+    fn prepare_index_include(&self, include: &[DynIden], sql: &mut SqlWriter) {
+        if include.is_empty() {
+            return;
+        }
+        write!(sql, " INCLUDE (").unwrap();
+        include.iter().fold(true, |first, col| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            col.prepare(sql, '"');
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_index_opclass(&self, opclass: &Option<DynIden>, sql: &mut SqlWriter) {
+        if let Some(opclass) = opclass {
+            write!(sql, " ").unwrap();
+            opclass.unquoted(sql);
+        }
+    }
+
+    fn prepare_index_nulls(&self, nulls: &Option<NullOrdering>, sql: &mut SqlWriter) {
+        if let Some(nulls) = nulls {
+            write!(
+                sql,
+                " {}",
+                match nulls {
+                    NullOrdering::First => "NULLS FIRST",
+                    NullOrdering::Last => "NULLS LAST",
+                }
+            )
+            .unwrap();
+        }
+    }
+
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter) {
+        self.prepare_simple_expr_inlined(expr, sql);
+    }
+}
 }
@@ -0,0 +1,244 @@
+use super::*;
+use crate::backend::introspection_builder::{
+    column_text, parse_column_type, parse_default_expr, parse_flag, required_text,
+};
+use crate::error::Result;
+
+impl_versioned! {
+impl IntrospectionBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
+    fn tables_query(&self, schema: Option<&str>) -> SelectStatement {
+        Query::select()
+            .expr(text(Expr::col(Alias::new("table_name"))))
+            .from((Alias::new("information_schema"), Alias::new("tables")))
+            .and_where(
+                Expr::col(Alias::new("table_schema"))
+                    .into_simple_expr()
+                    .equals(current_schema(schema)),
+            )
+            .and_where(Expr::col(Alias::new("table_type")).eq("BASE TABLE"))
+            .order_by(Alias::new("table_name"), Order::Asc)
+            .take()
+    }
+
+    fn columns_query(&self, schema: Option<&str>, table: &str) -> SelectStatement {
+        let (a, c, n, d) = (
+            Alias::new("a"),
+            Alias::new("c"),
+            Alias::new("n"),
+            Alias::new("d"),
+        );
+        Query::select()
+            .expr(text(Expr::tbl(a.clone(), Alias::new("attname"))))
+            .expr(text(Func::cust(Alias::new("format_type")).args(vec![
+                Expr::tbl(a.clone(), Alias::new("atttypid")),
+                Expr::tbl(a.clone(), Alias::new("atttypmod")),
+            ])))
+            .expr(text(Expr::tbl(a.clone(), Alias::new("attnotnull"))))
+            .expr(text(Func::cust(Alias::new("pg_get_expr")).args(vec![
+                Expr::tbl(d.clone(), Alias::new("adbin")),
+                Expr::tbl(d.clone(), Alias::new("adrelid")),
+            ])))
+            .expr(text(Expr::tbl(a.clone(), Alias::new("attidentity"))))
+            .from_as(pg_catalog("pg_attribute"), a.clone())
+            .join_as(
+                JoinType::InnerJoin,
+                pg_catalog("pg_class"),
+                c.clone(),
+                Expr::tbl(c.clone(), Alias::new("oid")).equals(a.clone(), Alias::new("attrelid")),
+            )
+            .join_as(
+                JoinType::InnerJoin,
+                pg_catalog("pg_namespace"),
+                n.clone(),
+                Expr::tbl(n.clone(), Alias::new("oid"))
+                    .equals(c.clone(), Alias::new("relnamespace")),
+            )
+            .join_as(
+                JoinType::LeftJoin,
+                pg_catalog("pg_attrdef"),
+                d.clone(),
+                Expr::tbl(d.clone(), Alias::new("adrelid"))
+                    .equals(a.clone(), Alias::new("attrelid"))
+                    .and(Expr::tbl(d, Alias::new("adnum")).equals(a.clone(), Alias::new("attnum"))),
+            )
+            .and_where(
+                Expr::tbl(n, Alias::new("nspname"))
+                    .into_simple_expr()
+                    .equals(current_schema(schema)),
+            )
+            .and_where(Expr::tbl(c, Alias::new("relname")).eq(table))
+            .and_where(Expr::tbl(a.clone(), Alias::new("attnum")).gt(0))
+            .and_where(Expr::tbl(a.clone(), Alias::new("attisdropped")).not())
+            .order_by((a, Alias::new("attnum")), Order::Asc)
+            .take()
+    }
+
+    fn indexes_query(&self, schema: Option<&str>, table: &str) -> SelectStatement {
+        let (ix, t, i, n, a) = (
+            Alias::new("ix"),
+            Alias::new("t"),
+            Alias::new("i"),
+            Alias::new("n"),
+            Alias::new("a"),
+        );
+        Query::select()
+            .expr(text(Expr::tbl(i.clone(), Alias::new("relname"))))
+            .expr(text(Expr::tbl(ix.clone(), Alias::new("indisunique"))))
+            .expr(text(Expr::tbl(ix.clone(), Alias::new("indisprimary"))))
+            .expr(text(Expr::tbl(a.clone(), Alias::new("attname"))))
+            .from_as(pg_catalog("pg_index"), ix.clone())
+            .join_as(
+                JoinType::InnerJoin,
+                pg_catalog("pg_class"),
+                t.clone(),
+                Expr::tbl(t.clone(), Alias::new("oid")).equals(ix.clone(), Alias::new("indrelid")),
+            )
+            .join_as(
+                JoinType::InnerJoin,
+                pg_catalog("pg_class"),
+                i.clone(),
+                Expr::tbl(i.clone(), Alias::new("oid"))
+                    .equals(ix.clone(), Alias::new("indexrelid")),
+            )
+            .join_as(
+                JoinType::InnerJoin,
+                pg_catalog("pg_namespace"),
+                n.clone(),
+                Expr::tbl(n.clone(), Alias::new("oid"))
+                    .equals(t.clone(), Alias::new("relnamespace")),
+            )
+            .join_as(
+                JoinType::InnerJoin,
+                pg_catalog("pg_attribute"),
+                a.clone(),
+                Expr::tbl(a.clone(), Alias::new("attrelid"))
+                    .equals(t.clone(), Alias::new("oid"))
+                    .and(
+                        Expr::tbl(a.clone(), Alias::new("attnum"))
+                            .into_simple_expr()
+                            .equals(
+                                Func::cust(Alias::new("ANY"))
+                                    .arg(Expr::tbl(ix.clone(), Alias::new("indkey"))),
+                            ),
+                    ),
+            )
+            .and_where(
+                Expr::tbl(n, Alias::new("nspname"))
+                    .into_simple_expr()
+                    .equals(current_schema(schema)),
+            )
+            .and_where(Expr::tbl(t, Alias::new("relname")).eq(table))
+            .and_where(Expr::tbl(ix.clone(), Alias::new("indexprs")).is_null())
+            .and_where(Expr::tbl(ix.clone(), Alias::new("indpred")).is_null())
+            .order_by((i, Alias::new("relname")), Order::Asc)
+            .order_by_expr(
+                Func::cust(Alias::new("array_position")).args(vec![
+                    Expr::cast_as(
+                        Expr::tbl(ix, Alias::new("indkey")).into(),
+                        Alias::new("int2[]"),
+                    ),
+                    Expr::tbl(a, Alias::new("attnum")),
+                ]),
+                Order::Asc,
+            )
+            .take()
+    }
+
+    fn foreign_keys_query(&self, schema: Option<&str>, table: &str) -> SelectStatement {
+        let (rc, kcu, r) = (Alias::new("rc"), Alias::new("kcu"), Alias::new("r"));
+        Query::select()
+            .expr(text(Expr::tbl(rc.clone(), Alias::new("constraint_name"))))
+            .expr(text(Expr::tbl(kcu.clone(), Alias::new("column_name"))))
+            .expr(text(Expr::tbl(r.clone(), Alias::new("table_name"))))
+            .expr(text(Expr::tbl(r.clone(), Alias::new("column_name"))))
+            .expr(text(Expr::tbl(rc.clone(), Alias::new("update_rule"))))
+            .expr(text(Expr::tbl(rc.clone(), Alias::new("delete_rule"))))
+            .from_as(information_schema("referential_constraints"), rc.clone())
+            .join_as(
+                JoinType::InnerJoin,
+                information_schema("key_column_usage"),
+                kcu.clone(),
+                Expr::tbl(kcu.clone(), Alias::new("constraint_schema"))
+                    .equals(rc.clone(), Alias::new("constraint_schema"))
+                    .and(
+                        Expr::tbl(kcu.clone(), Alias::new("constraint_name"))
+                            .equals(rc.clone(), Alias::new("constraint_name")),
+                    ),
+            )
+            .join_as(
+                JoinType::InnerJoin,
+                information_schema("key_column_usage"),
+                r.clone(),
+                Expr::tbl(r.clone(), Alias::new("constraint_schema"))
+                    .equals(rc.clone(), Alias::new("unique_constraint_schema"))
+                    .and(
+                        Expr::tbl(r.clone(), Alias::new("constraint_name"))
+                            .equals(rc.clone(), Alias::new("unique_constraint_name")),
+                    )
+                    .and(
+                        Expr::tbl(r, Alias::new("ordinal_position"))
+                            .equals(kcu.clone(), Alias::new("position_in_unique_constraint")),
+                    ),
+            )
+            .and_where(
+                Expr::tbl(kcu.clone(), Alias::new("table_schema"))
+                    .into_simple_expr()
+                    .equals(current_schema(schema)),
+            )
+            .and_where(Expr::tbl(kcu.clone(), Alias::new("table_name")).eq(table))
+            .order_by((rc, Alias::new("constraint_name")), Order::Asc)
+            .order_by((kcu, Alias::new("ordinal_position")), Order::Asc)
+            .take()
+    }
+
+    /// The row holds the name, the column type, whether the column is `NOT NULL`, the default
+    /// expression and the kind of identity column, `a` for always and `d` for by default.
+    /// A default drawing from a sequence is read back as auto increment, i.e. `serial`.
+    fn parse_column(&self, row: &[Option<String>]) -> Result<ColumnDef> {
+        let mut column = ColumnDef::new(Alias::new(required_text(row, 0, "column name")?));
+        column.types = Some(parse_column_type(required_text(row, 1, "column type")?));
+        if parse_flag(required_text(row, 2, "column nullability")?)? {
+            column.not_null();
+        }
+        match column_text(row, 3)? {
+            Some(default) if default.starts_with("nextval(") => {
+                column.auto_increment();
+            }
+            Some(default) => {
+                column.default_expr(parse_default_expr(default));
+            }
+            None => {}
+        }
+        match column_text(row, 4)? {
+            Some("a") => {
+                column.identity(Identity::always());
+            }
+            Some("d") => {
+                column.identity(Identity::by_default());
+            }
+            _ => {}
+        }
+        Ok(column)
+    }
+}
+}
+
+fn information_schema(table: &str) -> (Alias, Alias) {
+    (Alias::new("information_schema"), Alias::new(table))
+}
+
+fn pg_catalog(table: &str) -> (Alias, Alias) {
+    (Alias::new("pg_catalog"), Alias::new(table))
+}
+
+/// The given schema, or the current one
+fn current_schema(schema: Option<&str>) -> SimpleExpr {
+    match schema {
+        Some(schema) => Expr::val(schema).into(),
+        None => Func::cust(Alias::new("current_schema")).args(Vec::<SimpleExpr>::new()),
+    }
+}
+
+fn text(expr: impl Into<SimpleExpr>) -> SimpleExpr {
+    Expr::cast_as(expr.into(), ColumnType::Text).into()
+}
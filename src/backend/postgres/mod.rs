@@ -1,12 +1,22 @@
+pub(crate) mod database;
+pub(crate) mod extension;
 pub(crate) mod foreign_key;
 pub(crate) mod index;
+pub(crate) mod introspection;
 pub(crate) mod query;
+pub(crate) mod role;
+pub(crate) mod sequence;
 pub(crate) mod table;
+pub(crate) mod transaction;
+pub(crate) mod trigger;
 pub(crate) mod types;
+pub(crate) mod view;
 
 use super::*;
 
 /// Postgres query builder.
+///
+/// Renders for the latest Postgres, use [`PostgresQueryBuilder::version`] to target an older version.
 #[derive(Debug)]
 pub struct PostgresQueryBuilder;
 
@@ -16,12 +26,57 @@ impl Default for PostgresQueryBuilder {
     }
 }
 
-impl GenericBuilder for PostgresQueryBuilder {}
+/// Postgres query builder targeting a specific Postgres version.
+///
+/// Constructed with [`PostgresQueryBuilder::version`].
+#[derive(Debug, Clone, Copy)]
+pub struct VersionedPostgresQueryBuilder {
+    version: (u32, u32),
+}
+
+impl PostgresQueryBuilder {
+    /// Target a specific Postgres version, given as major and minor version.
+    /// Features introduced in later versions are rejected: `try_build` returns
+    /// [`Error::Unsupported`](crate::error::Error::Unsupported), while `build` panics.
+    pub fn version(self, major: u32, minor: u32) -> VersionedPostgresQueryBuilder {
+        VersionedPostgresQueryBuilder {
+            version: (major, minor),
+        }
+    }
 
-impl SchemaBuilder for PostgresQueryBuilder {}
+    pub(crate) fn version_at_least(&self, _major: u32, _minor: u32) -> bool {
+        true
+    }
+}
 
-impl QuotedBuilder for PostgresQueryBuilder {
+impl VersionedPostgresQueryBuilder {
+    /// Whether the targeted version is at least the given one.
+    pub(crate) fn version_at_least(&self, major: u32, minor: u32) -> bool {
+        self.version >= (major, minor)
+    }
+}
+
+impl_versioned! {
+impl GenericBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {}
+}
+
+impl_versioned! {
+impl SchemaBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {}
+}
+
+impl_versioned! {
+impl PaginationBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {}
+}
+
+impl_versioned! {
+impl QuotedBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
     fn quote(&self) -> char {
         '"'
     }
+
+    fn escape_string(&self, string: &str) -> String {
+        // Standard conforming strings keep backslashes literal, a quote is escaped by doubling it
+        string.replace('\'', "''")
+    }
+}
 }
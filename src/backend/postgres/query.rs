@@ -1,41 +1,124 @@
 use super::*;
 use crate::extension::postgres::*;
 
-impl QueryBuilder for PostgresQueryBuilder {
+impl_versioned! {
+impl PlaceholderBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
     fn placeholder(&self) -> (&str, bool) {
         ("$", true)
     }
+}
+}
+
+impl_versioned! {
+impl QueryBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
+    fn backend_name(&self) -> &'static str {
+        "Postgres"
+    }
 
-    fn prepare_returning(
+    fn prepare_cast_type(
         &self,
-        returning: &Vec<SelectExpr>,
+        cast_type: &CastType,
         sql: &mut SqlWriter,
-        collector: &mut dyn FnMut(Value),
+        _collector: &mut dyn FnMut(Value),
     ) {
-        if !returning.is_empty() {
-            write!(sql, " RETURNING ").unwrap();
-            returning.iter().fold(true, |first, expr| {
-                if !first {
-                    write!(sql, ", ").unwrap()
+        let column_type = match cast_type {
+            CastType::Column(column_type) => column_type,
+            CastType::Custom(iden) => return iden.unquoted(sql),
+        };
+        write!(
+            sql,
+            "{}",
+            match column_type {
+                ColumnType::Char(length) => match length {
+                    Some(length) => format!("char({})", length),
+                    None => "char".into(),
+                },
+                ColumnType::String(length) => match length {
+                    Some(length) => format!("varchar({})", length),
+                    None => "varchar".into(),
+                },
+                ColumnType::Text => "text".into(),
+                ColumnType::TinyInteger(_) | ColumnType::SmallInteger(_) => "smallint".into(),
+                ColumnType::Integer(_) => "integer".into(),
+                ColumnType::BigInteger(_) => "bigint".into(),
+                ColumnType::Float(_) => "real".into(),
+                ColumnType::Double(_) => "double precision".into(),
+                ColumnType::Decimal(precision) => match precision {
+                    Some((precision, scale)) => format!("decimal({}, {})", precision, scale),
+                    None => "decimal".into(),
+                },
+                ColumnType::DateTime(_) | ColumnType::Timestamp(_) => "timestamp".into(),
+                ColumnType::TimestampWithTimeZone(_) => "timestamp with time zone".into(),
+                ColumnType::Time(_) => "time".into(),
+                ColumnType::Date => "date".into(),
+                ColumnType::Binary(_) => "bytea".into(),
+                ColumnType::Boolean => "bool".into(),
+                ColumnType::Money(_) => "money".into(),
+                ColumnType::Json => "json".into(),
+                ColumnType::JsonBinary => "jsonb".into(),
+                ColumnType::Uuid => "uuid".into(),
+                ColumnType::Inet => "inet".into(),
+                ColumnType::Cidr => "cidr".into(),
+                ColumnType::MacAddr => "macaddr".into(),
+                ColumnType::Enum(name, _) => format!("\"{}\"", name.quoted('"')),
+                ColumnType::Array(elem_type) => {
+                    let mut elem_sql = SqlWriter::new();
+                    self.prepare_cast_type(
+                        &CastType::Column(elem_type.as_ref().clone()),
+                        &mut elem_sql,
+                        _collector,
+                    );
+                    format!("{}[]", elem_sql.result())
                 }
-                self.prepare_select_expr(expr, sql, collector);
-                false
-            });
-        }
+                ColumnType::Custom(iden) => iden.to_string(),
+            }
+        )
+        .unwrap()
+    }
+
+    fn update_delete_order_limit_supported(&self) -> bool {
+        false
+    }
+
+    fn postgres_syntax_supported(&self) -> bool {
+        true
+    }
+
+    fn on_conflict_supported(&self) -> bool {
+        self.version_at_least(9, 5)
     }
 
     fn if_null_function(&self) -> &str {
         "COALESCE"
     }
 
-    fn write_string_quoted(&self, string: &str, buffer: &mut String) {
-        let escaped = escape_string(string);
-        let string = if escaped.find('\\').is_some() {
-            "E'".to_owned() + &escaped + "'"
-        } else {
-            "'".to_owned() + &escaped + "'"
-        };
-        write!(buffer, "{}", string).unwrap()
+    fn json_build_object_function(&self) -> &str {
+        "JSON_BUILD_OBJECT"
+    }
+
+    fn json_set_function(&self) -> &str {
+        "JSONB_SET"
+    }
+
+    fn sequence_function(&self, function: &Function) -> &str {
+        match function {
+            Function::NextVal => "NEXTVAL",
+            Function::CurrVal => "CURRVAL",
+            Function::SetVal => "SETVAL",
+            _ => unreachable!(),
+        }
+    }
+
+    fn typed_date_time_literal(&self) -> bool {
+        true
+    }
+
+    fn write_bytes(&self, bytes: &[u8], buffer: &mut String) {
+        write!(buffer, "'\\x").unwrap();
+        for b in bytes {
+            write!(buffer, "{:02X}", b).unwrap();
+        }
+        write!(buffer, "'::bytea").unwrap();
     }
 
     fn prepare_bin_oper(
@@ -48,6 +131,17 @@ impl QueryBuilder for PostgresQueryBuilder {
             BinOper::Matches => write!(sql, "@@").unwrap(),
             BinOper::Contains => write!(sql, "@>").unwrap(),
             BinOper::Contained => write!(sql, "<@").unwrap(),
+            BinOper::GetJsonPath => write!(sql, "#>").unwrap(),
+            BinOper::CastJsonPath => write!(sql, "#>>").unwrap(),
+            BinOper::HasKey => write!(sql, "?").unwrap(),
+            BinOper::Overlap => write!(sql, "&&").unwrap(),
+            BinOper::BitXor => write!(sql, "#").unwrap(),
+            BinOper::Regexp => write!(sql, "~").unwrap(),
+            BinOper::NotRegexp => write!(sql, "!~").unwrap(),
+            BinOper::IRegexp => write!(sql, "~*").unwrap(),
+            BinOper::NotIRegexp => write!(sql, "!~*").unwrap(),
+            BinOper::ILike => write!(sql, "ILIKE").unwrap(),
+            BinOper::NotILike => write!(sql, "NOT ILIKE").unwrap(),
             _ => self.prepare_bin_oper_common(bin_oper, sql, collector),
         }
     }
@@ -70,10 +164,66 @@ impl QueryBuilder for PostgresQueryBuilder {
                     PgFunction::WebsearchToTsquery => "WEBSEARCH_TO_TSQUERY",
                     PgFunction::TsRank => "TS_RANK",
                     PgFunction::TsRankCd => "TS_RANK_CD",
+                    PgFunction::ArrayAgg => "ARRAY_AGG",
+                    PgFunction::Unnest => "UNNEST",
                 }
             )
             .unwrap(),
             _ => self.prepare_function_common(function, sql, collector),
         }
     }
+
+    fn prepare_json_extract(
+        &self,
+        exprs: &[SimpleExpr],
+        keys: &[String],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "JSONB_EXTRACT_PATH(").unwrap();
+        exprs.iter().for_each(|expr| {
+            self.prepare_simple_expr(expr, sql, collector);
+        });
+        keys.iter().for_each(|key| {
+            write!(sql, ", ").unwrap();
+            self.prepare_value(&key.as_str().into(), sql, collector);
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_json_path(
+        &self,
+        keys: &[String],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        // Bound as a `text[]` array, the type of the path parameter of `JSONB_SET`
+        let path: Vec<Value> = keys.iter().map(|key| key.as_str().into()).collect();
+        self.prepare_value(&Value::Array(Some(Box::new(path))), sql, collector);
+    }
+
+    fn prepare_subscript(
+        &self,
+        expr: &SimpleExpr,
+        index: &SimpleExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        let paren = !matches!(expr, SimpleExpr::Column(_) | SimpleExpr::Subscript(_, _));
+        if paren {
+            write!(sql, "(").unwrap();
+        }
+        self.prepare_simple_expr(expr, sql, collector);
+        if paren {
+            write!(sql, ")").unwrap();
+        }
+        write!(sql, "[").unwrap();
+        match index {
+            // Brackets read as a quoted identifier when injecting parameters, so write the index inline
+            SimpleExpr::Value(value) => write!(sql, "{}", self.value_to_string(value)).unwrap(),
+            _ => self.prepare_simple_expr(index, sql, collector),
+        }
+        write!(sql, "]").unwrap();
+    }
+}
 }
@@ -0,0 +1,9 @@
+use super::*;
+
+impl_versioned! {
+impl RoleBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
+    fn prepare_role_if_not_exists(&self, _: &mut SqlWriter) {
+        panic!("Postgres not support IF NOT EXISTS on role");
+    }
+}
+}
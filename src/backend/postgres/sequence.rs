@@ -0,0 +1,5 @@
+use super::*;
+
+impl_versioned! {
+impl SequenceBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {}
+}
@@ -1,6 +1,7 @@
 use super::*;
 
-impl TableBuilder for PostgresQueryBuilder {
+impl_versioned! {
+impl TableBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
     fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut SqlWriter) {
         column_def.name.prepare(sql, '"');
 
@@ -15,6 +16,281 @@ impl TableBuilder for PostgresQueryBuilder {
         }
     }
 
+    fn check_column_def(&self, column_def: &ColumnDef) -> error::Result<()> {
+        for column_spec in column_def.spec.iter() {
+            match column_spec {
+                ColumnSpec::Identity(_) => {
+                    self.check_supported(self.version_at_least(10, 0), "Identity columns")?
+                }
+                ColumnSpec::Generated(_, storage) => {
+                    self.check_supported(self.version_at_least(12, 0), "Generated columns")?;
+                    self.check_supported(
+                        *storage != GeneratedStorage::Virtual,
+                        "Virtual generated columns",
+                    )?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn prepare_column_spec(&self, column_spec: &ColumnSpec, sql: &mut SqlWriter) {
+        match column_spec {
+            ColumnSpec::Null => write!(sql, "NULL"),
+            ColumnSpec::NotNull => write!(sql, "NOT NULL"),
+            ColumnSpec::Default(value) => {
+                write!(sql, "DEFAULT ").unwrap();
+                self.prepare_column_default(value, sql);
+                Ok(())
+            }
+            ColumnSpec::AutoIncrement => write!(sql, ""),
+            ColumnSpec::Identity(identity) => {
+                if !self.version_at_least(10, 0) {
+                    panic!("Postgres does not support identity columns before 10");
+                }
+                write!(
+                    sql,
+                    "GENERATED {} AS IDENTITY",
+                    if identity.always {
+                        "ALWAYS"
+                    } else {
+                        "BY DEFAULT"
+                    }
+                )
+                .unwrap();
+                let mut options = Vec::new();
+                if let Some(start) = identity.start {
+                    options.push(format!("START WITH {}", start));
+                }
+                if let Some(increment) = identity.increment {
+                    options.push(format!("INCREMENT BY {}", increment));
+                }
+                if !options.is_empty() {
+                    write!(sql, " ({})", options.join(" ")).unwrap();
+                }
+                Ok(())
+            }
+            ColumnSpec::UniqueKey => write!(sql, "UNIQUE"),
+            ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY"),
+            ColumnSpec::Check(check) => {
+                self.prepare_check_constraint(check, sql);
+                Ok(())
+            }
+            ColumnSpec::Generated(expr, storage) => {
+                if !self.version_at_least(12, 0) {
+                    panic!("Postgres does not support generated columns before 12");
+                }
+                write!(sql, "GENERATED ALWAYS AS (").unwrap();
+                self.prepare_simple_expr_inlined(expr, sql);
+                write!(
+                    sql,
+                    ") {}",
+                    match storage {
+                        GeneratedStorage::Stored => "STORED",
+                        GeneratedStorage::Virtual => {
+                            panic!("Postgres not support virtual generated column")
+                        }
+                    }
+                )
+            }
+            ColumnSpec::Extra(string) => write!(sql, "{}", string),
+        }
+        .unwrap()
+    }
+
+    fn prepare_table_create_kind(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        if create.temporary {
+            write!(sql, "TEMPORARY ").unwrap();
+        }
+        if create.unlogged {
+            write!(sql, "UNLOGGED ").unwrap();
+        }
+    }
+
+    fn prepare_table_create_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(PostgresQueryBuilder)).unwrap();
+    }
+
+    fn prepare_table_create_no_data(&self, sql: &mut SqlWriter) {
+        write!(sql, " WITH NO DATA").unwrap();
+    }
+
+    fn prepare_check_constraint(&self, check: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, "CHECK (").unwrap();
+        self.prepare_simple_expr_inlined(check, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_table_partition(&self, table_partition: &TablePartition, sql: &mut SqlWriter) {
+        match table_partition {
+            TablePartition::By(r#type, columns) => self.prepare_partition_by(r#type, columns, sql),
+            TablePartition::Definition(..) => {
+                panic!("Postgres not support partition definition, use partition_of")
+            }
+        }
+    }
+
+    fn prepare_partition_values(&self, values: &PartitionValues, sql: &mut SqlWriter) {
+        match values {
+            PartitionValues::FromTo(from, to) => {
+                write!(sql, "FOR VALUES FROM (").unwrap();
+                self.prepare_partition_exprs(from, sql);
+                write!(sql, ") TO (").unwrap();
+                self.prepare_partition_exprs(to, sql);
+                write!(sql, ")").unwrap();
+            }
+            PartitionValues::In(exprs) => {
+                write!(sql, "FOR VALUES IN (").unwrap();
+                self.prepare_partition_exprs(exprs, sql);
+                write!(sql, ")").unwrap();
+            }
+            PartitionValues::Modulus(modulus, remainder) => write!(
+                sql,
+                "FOR VALUES WITH (MODULUS {}, REMAINDER {})",
+                modulus, remainder
+            )
+            .unwrap(),
+            PartitionValues::Default => write!(sql, "DEFAULT").unwrap(),
+            PartitionValues::LessThan(..) => {
+                panic!("Postgres not support LESS THAN partition bound")
+            }
+        }
+    }
+
+    fn prepare_table_partition_of(&self, parent: &DynIden, sql: &mut SqlWriter) {
+        write!(sql, " PARTITION OF ").unwrap();
+        parent.prepare(sql, '"');
+    }
+
+    fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
+        if alter.options.is_empty() {
+            panic!("No alter option found")
+        }
+        // Renaming a column cannot be combined with other alterations
+        let (renames, others): (Vec<_>, Vec<_>) = alter.options.iter().partition(|option| {
+            matches!(
+                option,
+                TableAlterOption::RenameColumn(_, _) | TableAlterOption::ChangeColumn(_, _)
+            )
+        });
+        let mut statements = Vec::new();
+        if !others.is_empty() {
+            statements.push(others);
+        }
+        statements.extend(renames.into_iter().map(|rename| vec![rename]));
+        statements.into_iter().fold(true, |first, options| {
+            if !first {
+                write!(sql, "; ").unwrap();
+            }
+            write!(sql, "ALTER TABLE ").unwrap();
+            if let Some(table) = &alter.table {
+                table.prepare(sql, '"');
+                write!(sql, " ").unwrap();
+            }
+            options.into_iter().fold(true, |first, option| {
+                if !first {
+                    write!(sql, ", ").unwrap();
+                }
+                self.prepare_table_alter_option(option, sql);
+                false
+            });
+            false
+        });
+    }
+
+    fn prepare_table_alter_option(&self, alter_option: &TableAlterOption, sql: &mut SqlWriter) {
+        match alter_option {
+            TableAlterOption::AddColumn(column_def) => {
+                write!(sql, "ADD COLUMN ").unwrap();
+                self.prepare_column_def(column_def, sql);
+            }
+            TableAlterOption::ModifyColumn(column_def)
+            | TableAlterOption::ModifyColumnUsing(column_def, _) => {
+                write!(sql, "ALTER COLUMN ").unwrap();
+                column_def.name.prepare(sql, '"');
+                write!(sql, " TYPE").unwrap();
+                self.prepare_column_type_check_auto_increment(column_def, sql);
+                if let TableAlterOption::ModifyColumnUsing(_, using) = alter_option {
+                    write!(sql, " USING ").unwrap();
+                    self.prepare_simple_expr_inlined(using, sql);
+                }
+                for column_spec in column_def.spec.iter() {
+                    if let ColumnSpec::AutoIncrement = column_spec {
+                        continue;
+                    }
+                    write!(sql, ", ").unwrap();
+                    if let ColumnSpec::Generated(_, _) = column_spec {
+                        panic!("Postgres not support modifying generated column");
+                    }
+                    if let ColumnSpec::Identity(_) = column_spec {
+                        write!(sql, "ALTER COLUMN ").unwrap();
+                        column_def.name.prepare(sql, '"');
+                        write!(sql, " ADD ").unwrap();
+                        self.prepare_column_spec(column_spec, sql);
+                        continue;
+                    }
+                    if let ColumnSpec::Check(check) = column_spec {
+                        write!(sql, "ADD ").unwrap();
+                        self.prepare_check_constraint(check, sql);
+                        continue;
+                    }
+                    if let ColumnSpec::Null = column_spec {
+                        write!(sql, "ALTER COLUMN ").unwrap();
+                        column_def.name.prepare(sql, '"');
+                        write!(sql, " DROP NOT NULL").unwrap();
+                        continue;
+                    }
+                    write!(sql, "ALTER COLUMN ").unwrap();
+                    column_def.name.prepare(sql, '"');
+                    write!(sql, " SET ").unwrap();
+                    self.prepare_column_spec(column_spec, sql);
+                }
+            }
+            TableAlterOption::RenameColumn(from_name, to_name)
+            | TableAlterOption::ChangeColumn(from_name, ColumnDef { name: to_name, .. }) => {
+                write!(sql, "RENAME COLUMN ").unwrap();
+                from_name.prepare(sql, '"');
+                write!(sql, " TO ").unwrap();
+                to_name.prepare(sql, '"');
+            }
+            TableAlterOption::DropColumn(column_name) => {
+                write!(sql, "DROP COLUMN ").unwrap();
+                column_name.prepare(sql, '"');
+            }
+            TableAlterOption::DropColumnDefault(column_name) => {
+                write!(sql, "ALTER COLUMN ").unwrap();
+                column_name.prepare(sql, '"');
+                write!(sql, " DROP DEFAULT").unwrap();
+            }
+            TableAlterOption::AddCheckConstraint(name, check) => {
+                write!(sql, "ADD CONSTRAINT ").unwrap();
+                name.prepare(sql, '"');
+                write!(sql, " ").unwrap();
+                self.prepare_check_constraint(check, sql);
+            }
+            TableAlterOption::DropConstraint(name) => {
+                write!(sql, "DROP CONSTRAINT ").unwrap();
+                name.prepare(sql, '"');
+            }
+        }
+    }
+
+    fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter) {
+        write!(sql, "ALTER TABLE ").unwrap();
+        if let Some(from_name) = &rename.from_name {
+            from_name.prepare(sql, '"');
+        }
+        write!(sql, " RENAME TO ").unwrap();
+        if let Some(to_name) = &rename.to_name {
+            to_name.prepare(sql, '"');
+        }
+    }
+}
+}
+
+impl_versioned! {
+impl ColumnTypeBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
     fn prepare_column_type(&self, column_type: &ColumnType, sql: &mut SqlWriter) {
         write!(
             sql,
@@ -85,84 +361,25 @@ impl TableBuilder for PostgresQueryBuilder {
                 ColumnType::Json => "json".into(),
                 ColumnType::JsonBinary => "jsonb".into(),
                 ColumnType::Uuid => "uuid".into(),
+                ColumnType::Inet => "inet".into(),
+                ColumnType::Cidr => "cidr".into(),
+                ColumnType::MacAddr => "macaddr".into(),
+                ColumnType::Enum(name, _) => format!("\"{}\"", name.quoted('"')),
+                ColumnType::Array(elem_type) => {
+                    let mut elem_sql = SqlWriter::new();
+                    self.prepare_column_type(elem_type, &mut elem_sql);
+                    format!("{}[]", elem_sql.result())
+                }
                 ColumnType::Custom(iden) => iden.to_string(),
             }
         )
         .unwrap()
     }
-
-    fn prepare_column_spec(&self, column_spec: &ColumnSpec, sql: &mut SqlWriter) {
-        match column_spec {
-            ColumnSpec::Null => write!(sql, "NULL"),
-            ColumnSpec::NotNull => write!(sql, "NOT NULL"),
-            ColumnSpec::Default(value) => write!(sql, "DEFAULT {}", self.value_to_string(value)),
-            ColumnSpec::AutoIncrement => write!(sql, ""),
-            ColumnSpec::UniqueKey => write!(sql, "UNIQUE"),
-            ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY"),
-            ColumnSpec::Extra(string) => write!(sql, "{}", string),
-        }
-        .unwrap()
-    }
-
-    fn prepare_table_partition(&self, _table_partition: &TablePartition, _sql: &mut SqlWriter) {}
-
-    fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
-        let alter_option = match &alter.alter_option {
-            Some(alter_option) => alter_option,
-            None => panic!("No alter option found"),
-        };
-        write!(sql, "ALTER TABLE ").unwrap();
-        if let Some(table) = &alter.table {
-            table.prepare(sql, '"');
-            write!(sql, " ").unwrap();
-        }
-        match alter_option {
-            TableAlterOption::AddColumn(column_def) => {
-                write!(sql, "ADD COLUMN ").unwrap();
-                self.prepare_column_def(column_def, sql);
-            }
-            TableAlterOption::ModifyColumn(column_def) => {
-                write!(sql, "ALTER COLUMN ").unwrap();
-                column_def.name.prepare(sql, '"');
-                write!(sql, " TYPE").unwrap();
-                self.prepare_column_type_check_auto_increment(column_def, sql);
-                for column_spec in column_def.spec.iter() {
-                    if let ColumnSpec::AutoIncrement = column_spec {
-                        continue;
-                    }
-                    write!(sql, ", ").unwrap();
-                    write!(sql, "ALTER COLUMN ").unwrap();
-                    column_def.name.prepare(sql, '"');
-                    write!(sql, " SET ").unwrap();
-                    self.prepare_column_spec(column_spec, sql);
-                }
-            }
-            TableAlterOption::RenameColumn(from_name, to_name) => {
-                write!(sql, "RENAME COLUMN ").unwrap();
-                from_name.prepare(sql, '"');
-                write!(sql, " TO ").unwrap();
-                to_name.prepare(sql, '"');
-            }
-            TableAlterOption::DropColumn(column_name) => {
-                write!(sql, "DROP COLUMN ").unwrap();
-                column_name.prepare(sql, '"');
-            }
-        }
-    }
-
-    fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter) {
-        write!(sql, "ALTER TABLE ").unwrap();
-        if let Some(from_name) = &rename.from_name {
-            from_name.prepare(sql, '"');
-        }
-        write!(sql, " RENAME TO ").unwrap();
-        if let Some(to_name) = &rename.to_name {
-            to_name.prepare(sql, '"');
-        }
-    }
+}
 }
 
-impl PostgresQueryBuilder {
+impl_versioned! {
+impl PostgresQueryBuilder, VersionedPostgresQueryBuilder {
     fn prepare_column_type_check_auto_increment(
         &self,
         column_def: &ColumnDef,
@@ -186,4 +403,15 @@ impl PostgresQueryBuilder {
             }
         }
     }
+
+    fn prepare_partition_exprs(&self, exprs: &[SimpleExpr], sql: &mut SqlWriter) {
+        exprs.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_simple_expr_inlined(expr, sql);
+            false
+        });
+    }
+}
 }
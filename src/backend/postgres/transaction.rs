@@ -0,0 +1,14 @@
+use super::*;
+
+impl_versioned! {
+impl TransactionBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
+    fn prepare_transaction_begin_statement(
+        &self,
+        begin: &TransactionBeginStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "BEGIN").unwrap();
+        self.prepare_transaction_modes(&begin.isolation_level, &begin.access_mode, sql);
+    }
+}
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+impl_versioned! {
+impl TriggerBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
+    fn prepare_trigger_drop_statement(&self, drop: &TriggerDropStatement, sql: &mut SqlWriter) {
+        write!(sql, "DROP TRIGGER ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &drop.name {
+            name.prepare(sql, '"');
+        }
+
+        write!(sql, " ON ").unwrap();
+        if let Some(table) = &drop.table {
+            table.prepare(sql, '"');
+        }
+    }
+
+    fn prepare_trigger_when(&self, condition: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, " WHEN (").unwrap();
+        self.prepare_simple_expr_inlined(condition, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_trigger_action(&self, action: &TriggerAction, sql: &mut SqlWriter) {
+        match action {
+            TriggerAction::Function(function) => {
+                write!(sql, " EXECUTE FUNCTION ").unwrap();
+                function.prepare(sql, '"');
+                write!(sql, "()").unwrap();
+            }
+            TriggerAction::Body(_) => panic!("Postgres not support trigger body"),
+        }
+    }
+}
+}
@@ -1,7 +1,8 @@
 use super::*;
 use crate::extension::postgres::*;
 
-impl TypeBuilder for PostgresQueryBuilder {
+impl_versioned! {
+impl TypeBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
     fn prepare_type_create_statement(
         &self,
         create: &TypeCreateStatement,
@@ -45,9 +46,13 @@ impl TypeBuilder for PostgresQueryBuilder {
             write!(sql, "IF EXISTS ").unwrap();
         }
 
-        for name in drop.names.iter() {
+        drop.names.iter().fold(true, |first, name| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
             name.prepare(sql, '"');
-        }
+            false
+        });
 
         if let Some(option) = &drop.option {
             write!(sql, " ").unwrap();
@@ -72,8 +77,10 @@ impl TypeBuilder for PostgresQueryBuilder {
         }
     }
 }
+}
 
-impl PostgresQueryBuilder {
+impl_versioned! {
+impl PostgresQueryBuilder, VersionedPostgresQueryBuilder {
     fn prepare_create_as_type(&self, as_type: &TypeAs, sql: &mut SqlWriter) {
         write!(
             sql,
@@ -135,3 +142,4 @@ impl PostgresQueryBuilder {
         }
     }
 }
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+impl_versioned! {
+impl ViewBuilder for PostgresQueryBuilder, VersionedPostgresQueryBuilder {
+    fn prepare_view_refresh_statement(&self, refresh: &ViewRefreshStatement, sql: &mut SqlWriter) {
+        write!(sql, "REFRESH MATERIALIZED VIEW ").unwrap();
+        if refresh.concurrently {
+            write!(sql, "CONCURRENTLY ").unwrap();
+        }
+        if let Some(view) = &refresh.view {
+            view.prepare(sql, '"');
+        }
+    }
+
+    fn prepare_view_create_kind(&self, create: &ViewCreateStatement, sql: &mut SqlWriter) {
+        if create.materialized {
+            if create.or_replace {
+                panic!("Postgres not support OR REPLACE on materialized view");
+            }
+            if create.check_option {
+                panic!("Postgres not support WITH CHECK OPTION on materialized view");
+            }
+            write!(sql, "MATERIALIZED ").unwrap();
+        }
+        if create.or_replace {
+            write!(sql, "OR REPLACE ").unwrap();
+        }
+    }
+
+    fn prepare_view_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(PostgresQueryBuilder)).unwrap();
+    }
+}
+}
@@ -1,17 +1,27 @@
 use crate::*;
 
-pub trait QueryBuilder: QuotedBuilder {
-    /// The type of placeholder the builder uses for values, and whether it is numbered.
-    fn placeholder(&self) -> (&str, bool) {
-        ("?", false)
-    }
-
+pub trait QueryBuilder:
+    QuotedBuilder + PlaceholderBuilder + PaginationBuilder + ColumnTypeBuilder
+{
     /// Translate [`InsertStatement`] into SQL statement.
     fn prepare_insert_statement(
         &self,
         insert: &InsertStatement,
         sql: &mut SqlWriter,
         collector: &mut dyn FnMut(Value),
+    ) {
+        self.prepare_insert_with_clause(insert, None, sql, collector);
+    }
+
+    #[doc(hidden)]
+    /// Translate [`InsertStatement`] into SQL statement, writing the `WITH` clause, if any,
+    /// right before the select the rows are inserted from.
+    fn prepare_insert_with_clause(
+        &self,
+        insert: &InsertStatement,
+        with_clause: Option<&WithClause>,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
     ) {
         write!(sql, "INSERT").unwrap();
 
@@ -21,36 +31,73 @@ pub trait QueryBuilder: QuotedBuilder {
             write!(sql, " ").unwrap();
         }
 
-        write!(sql, "(").unwrap();
-        insert.columns.iter().fold(true, |first, col| {
-            if !first {
-                write!(sql, ", ").unwrap()
-            }
-            col.prepare(sql, self.quote());
-            false
-        });
-        write!(sql, ")").unwrap();
-
-        write!(sql, " VALUES ").unwrap();
-        insert.values.iter().fold(true, |first, row| {
-            if !first {
-                write!(sql, ", ").unwrap()
-            }
+        if insert.source.is_none() && insert.default_values {
+            self.insert_default_values(sql);
+        } else {
             write!(sql, "(").unwrap();
-            row.iter().fold(true, |first, col| {
+            insert.columns.iter().fold(true, |first, col| {
                 if !first {
                     write!(sql, ", ").unwrap()
                 }
-                self.prepare_value(col, sql, collector);
+                col.prepare(sql, self.quote());
                 false
             });
             write!(sql, ")").unwrap();
-            false
-        });
+
+            match &insert.source {
+                Some(InsertValueSource::Select(select)) => {
+                    write!(sql, " ").unwrap();
+                    if let Some(with_clause) = with_clause {
+                        self.prepare_with_clause(with_clause, sql, collector);
+                    }
+                    self.prepare_select_statement(select, sql, collector);
+                }
+                Some(InsertValueSource::Values(rows)) => {
+                    write!(sql, " VALUES ").unwrap();
+                    rows.iter().fold(true, |first, row| {
+                        if !first {
+                            write!(sql, ", ").unwrap()
+                        }
+                        write!(sql, "(").unwrap();
+                        row.iter().fold(true, |first, col| {
+                            if !first {
+                                write!(sql, ", ").unwrap()
+                            }
+                            if matches!(col, SimpleExpr::Keyword(Keyword::Default))
+                                && !self.insert_default_keyword_supported()
+                            {
+                                panic!("DEFAULT in VALUES is not supported by this backend");
+                            }
+                            self.prepare_simple_expr(col, sql, collector);
+                            false
+                        });
+                        write!(sql, ")").unwrap();
+                        false
+                    });
+                }
+                None => write!(sql, " VALUES ").unwrap(),
+            }
+        }
+
+        if let Some(on_conflict) = &insert.on_conflict {
+            self.prepare_on_conflict(on_conflict, sql, collector);
+        }
 
         self.prepare_returning(&insert.returning, sql, collector);
     }
 
+    #[doc(hidden)]
+    /// Whether `DEFAULT` is accepted in the rows of the values clause of an insert statement.
+    fn insert_default_keyword_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Write the values clause of an insert statement consisting of default values only.
+    fn insert_default_values(&self, sql: &mut SqlWriter) {
+        write!(sql, "DEFAULT VALUES").unwrap();
+    }
+
     /// Translate [`SelectStatement`] into SQL statement.
     fn prepare_select_statement(
         &self,
@@ -61,11 +108,15 @@ pub trait QueryBuilder: QuotedBuilder {
         write!(sql, "SELECT ").unwrap();
 
         if let Some(distinct) = &select.distinct {
-            write!(sql, " ").unwrap();
             self.prepare_select_distinct(distinct, sql, collector);
             write!(sql, " ").unwrap();
         }
 
+        if !select.distinct_on.is_empty() {
+            self.prepare_select_distinct_on(&select.distinct_on, sql, collector);
+            write!(sql, " ").unwrap();
+        }
+
         select.selects.iter().fold(true, |first, expr| {
             if !first {
                 write!(sql, ", ").unwrap()
@@ -77,6 +128,7 @@ pub trait QueryBuilder: QuotedBuilder {
         if let Some(from) = &select.from {
             write!(sql, " FROM ").unwrap();
             self.prepare_table_ref(from, sql, collector);
+            self.prepare_select_final_sample(select, sql);
         }
 
         if !select.join.is_empty() {
@@ -88,18 +140,29 @@ pub trait QueryBuilder: QuotedBuilder {
 
         self.prepare_condition(&select.wherei, "WHERE", sql, collector);
 
-        if !select.groups.is_empty() {
-            write!(sql, " GROUP BY ").unwrap();
-            select.groups.iter().fold(true, |first, expr| {
+        self.prepare_group_by(&select.groups, &select.grouping, sql, collector);
+
+        self.prepare_condition(&select.having, "HAVING", sql, collector);
+
+        if !select.windows.is_empty() {
+            if !self.window_function_supported() {
+                panic!("Window functions are not supported by this backend");
+            }
+            write!(sql, " WINDOW ").unwrap();
+            select.windows.iter().fold(true, |first, (name, window)| {
                 if !first {
-                    write!(sql, ", ").unwrap()
+                    write!(sql, ", ").unwrap();
                 }
-                self.prepare_simple_expr(expr, sql, collector);
+                name.prepare(sql, self.quote());
+                write!(sql, " AS ").unwrap();
+                self.prepare_window_statement(window, sql, collector);
                 false
             });
         }
 
-        self.prepare_condition(&select.having, "HAVING", sql, collector);
+        for (union_type, query) in select.unions.iter() {
+            self.prepare_union_statement(union_type, query, sql, collector);
+        }
 
         if !select.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
@@ -112,14 +175,144 @@ pub trait QueryBuilder: QuotedBuilder {
             });
         }
 
-        if let Some(limit) = &select.limit {
-            write!(sql, " LIMIT ").unwrap();
-            self.prepare_value(limit, sql, collector);
+        self.prepare_limit_offset(&select.limit, &select.offset, sql, collector);
+    }
+
+    #[doc(hidden)]
+    /// Write the `FINAL` and `SAMPLE` modifiers of the table a select statement reads from.
+    fn prepare_select_final_sample(&self, select: &SelectStatement, _sql: &mut SqlWriter) {
+        if select.r#final || select.sample.is_some() {
+            panic!("FINAL and SAMPLE are not supported by this backend");
+        }
+    }
+
+    #[doc(hidden)]
+    /// Whether the `FINAL` and `SAMPLE` modifiers of [`SelectStatement`] are supported.
+    fn final_sample_supported(&self) -> bool {
+        false
+    }
+
+    /// Translate [`WithQuery`] into SQL statement.
+    fn prepare_with_query(
+        &self,
+        with_query: &WithQuery,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        self.prepare_with_clause(&with_query.with_clause, sql, collector);
+        self.prepare_query_statement(&with_query.query, sql, collector);
+    }
+
+    /// Translate [`WithClause`] into SQL statement.
+    fn prepare_with_clause(
+        &self,
+        with_clause: &WithClause,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if with_clause.cte_expressions.is_empty() {
+            return;
+        }
+        if !self.with_clause_supported() {
+            panic!("Common table expressions are not supported by this backend");
+        }
+        write!(sql, "WITH ").unwrap();
+        if with_clause.recursive {
+            write!(sql, "RECURSIVE ").unwrap();
+        }
+        with_clause.cte_expressions.iter().fold(true, |first, cte| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_common_table_expression(cte, sql, collector);
+            false
+        });
+        write!(sql, " ").unwrap();
+    }
+
+    /// Translate [`CommonTableExpression`] into SQL statement.
+    fn prepare_common_table_expression(
+        &self,
+        cte: &CommonTableExpression,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if let Some(table_name) = &cte.table_name {
+            table_name.prepare(sql, self.quote());
+            write!(sql, " ").unwrap();
+        }
+        if !cte.cols.is_empty() {
+            write!(sql, "(").unwrap();
+            cte.cols.iter().fold(true, |first, col| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                col.prepare(sql, self.quote());
+                false
+            });
+            write!(sql, ") ").unwrap();
+        }
+        write!(sql, "AS (").unwrap();
+        if let Some(query) = &cte.query {
+            self.prepare_query_statement(query, sql, collector);
+        }
+        if let Some((union_type, recursive)) = &cte.recursive_query {
+            write!(sql, " ").unwrap();
+            self.prepare_union_type(union_type, sql, collector);
+            write!(sql, " ").unwrap();
+            self.prepare_select_statement(recursive, sql, collector);
         }
+        write!(sql, ")").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write a select statement combined with a [`UnionType`], parenthesized by default.
+    fn prepare_union_statement(
+        &self,
+        union_type: &UnionType,
+        select: &SelectStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, " ").unwrap();
+        self.prepare_union_type(union_type, sql, collector);
+        write!(sql, " (").unwrap();
+        self.prepare_select_statement(select, sql, collector);
+        write!(sql, ")").unwrap();
+    }
+
+    /// Translate [`UnionType`] into SQL statement.
+    fn prepare_union_type(
+        &self,
+        union_type: &UnionType,
+        sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        write!(
+            sql,
+            "{}",
+            match union_type {
+                UnionType::Distinct => "UNION",
+                UnionType::All => "UNION ALL",
+                UnionType::Intersect => "INTERSECT",
+                UnionType::Except => "EXCEPT",
+            }
+        )
+        .unwrap();
+    }
 
-        if let Some(offset) = &select.offset {
-            write!(sql, " OFFSET ").unwrap();
-            self.prepare_value(offset, sql, collector);
+    /// Translate [`QueryStatement`] into SQL statement.
+    fn prepare_query_statement(
+        &self,
+        query: &QueryStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        match query {
+            QueryStatement::Select(select) => self.prepare_select_statement(select, sql, collector),
+            QueryStatement::Insert(insert) => self.prepare_insert_statement(insert, sql, collector),
+            QueryStatement::Update(update) => self.prepare_update_statement(update, sql, collector),
+            QueryStatement::Delete(delete) => self.prepare_delete_statement(delete, sql, collector),
         }
     }
 
@@ -136,6 +329,8 @@ pub trait QueryBuilder: QuotedBuilder {
             self.prepare_table_ref(table, sql, collector);
         }
 
+        self.prepare_update_join(&update.from, sql, collector);
+
         write!(sql, " SET ").unwrap();
 
         update.values.iter().fold(true, |first, row| {
@@ -148,7 +343,19 @@ pub trait QueryBuilder: QuotedBuilder {
             false
         });
 
-        self.prepare_condition(&update.wherei, "WHERE", sql, collector);
+        self.prepare_update_from(&update.from, sql, collector);
+
+        self.prepare_update_delete_condition(&update.wherei, sql, collector);
+
+        // `RETURNING` precedes `ORDER BY` and `LIMIT` in SQLite, no other backend supports
+        // both
+        self.prepare_returning(&update.returning, sql, collector);
+
+        if (!update.orders.is_empty() || update.limit.is_some())
+            && !self.update_delete_order_limit_supported()
+        {
+            panic!("ORDER BY and LIMIT in UPDATE and DELETE statements are not supported by this backend");
+        }
 
         if !update.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
@@ -165,8 +372,66 @@ pub trait QueryBuilder: QuotedBuilder {
             write!(sql, " LIMIT ").unwrap();
             self.prepare_value(limit, sql, collector);
         }
+    }
 
-        self.prepare_returning(&update.returning, sql, collector);
+    #[doc(hidden)]
+    /// Write the `WHERE` clause of an update or delete statement.
+    fn prepare_update_delete_condition(
+        &self,
+        condition: &ConditionHolder,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        self.prepare_condition(condition, "WHERE", sql, collector);
+    }
+
+    #[doc(hidden)]
+    /// Whether `ORDER BY` and `LIMIT` are supported in `UPDATE` and `DELETE` statements.
+    fn update_delete_order_limit_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether update statements can read from other tables, i.e. `UPDATE ... FROM`.
+    fn update_from_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether delete statements can read from other tables, i.e. `DELETE ... USING`.
+    fn delete_using_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Hook to write the tables an update statement reads from before the `SET` clause.
+    fn prepare_update_join(
+        &self,
+        _from: &[TableRef],
+        _sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+    }
+
+    #[doc(hidden)]
+    /// Hook to write the tables an update statement reads from after the `SET` clause.
+    fn prepare_update_from(
+        &self,
+        from: &[TableRef],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if from.is_empty() {
+            return;
+        }
+        write!(sql, " FROM ").unwrap();
+        from.iter().fold(true, |first, table_ref| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_table_ref(table_ref, sql, collector);
+            false
+        });
     }
 
     /// Translate [`DeleteStatement`] into SQL statement.
@@ -179,11 +444,20 @@ pub trait QueryBuilder: QuotedBuilder {
         write!(sql, "DELETE ").unwrap();
 
         if let Some(table) = &delete.table {
-            write!(sql, "FROM ").unwrap();
-            self.prepare_table_ref(table, sql, collector);
+            self.prepare_delete_from(table, &delete.using, sql, collector);
         }
 
-        self.prepare_condition(&delete.wherei, "WHERE", sql, collector);
+        self.prepare_update_delete_condition(&delete.wherei, sql, collector);
+
+        // `RETURNING` precedes `ORDER BY` and `LIMIT` in SQLite, no other backend supports
+        // both
+        self.prepare_returning(&delete.returning, sql, collector);
+
+        if (!delete.orders.is_empty() || delete.limit.is_some())
+            && !self.update_delete_order_limit_supported()
+        {
+            panic!("ORDER BY and LIMIT in UPDATE and DELETE statements are not supported by this backend");
+        }
 
         if !delete.orders.is_empty() {
             write!(sql, " ORDER BY ").unwrap();
@@ -202,6 +476,29 @@ pub trait QueryBuilder: QuotedBuilder {
         }
     }
 
+    #[doc(hidden)]
+    /// Write the table to delete from, along with other tables the condition depends on.
+    fn prepare_delete_from(
+        &self,
+        table: &TableRef,
+        using: &[TableRef],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "FROM ").unwrap();
+        self.prepare_table_ref(table, sql, collector);
+        if !using.is_empty() {
+            write!(sql, " USING ").unwrap();
+            using.iter().fold(true, |first, table_ref| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_table_ref(table_ref, sql, collector);
+                false
+            });
+        }
+    }
+
     /// Translate [`SimpleExpr`] into SQL statement.
     fn prepare_simple_expr(
         &self,
@@ -221,9 +518,31 @@ pub trait QueryBuilder: QuotedBuilder {
                 };
             }
             SimpleExpr::Unary(op, expr) => {
+                if matches!(op, UnOper::Any | UnOper::All)
+                    && !self.quantified_comparison_supported()
+                {
+                    panic!("ANY and ALL comparisons are not supported by this backend");
+                }
                 self.prepare_un_oper(op, sql, collector);
-                write!(sql, " ").unwrap();
-                self.prepare_simple_expr(expr, sql, collector);
+                if matches!(op, UnOper::Any | UnOper::All) && !expr.is_sub_query()
+                    || *op == UnOper::BitNot && expr.is_binary()
+                {
+                    write!(sql, "(").unwrap();
+                    self.prepare_simple_expr(expr, sql, collector);
+                    write!(sql, ")").unwrap();
+                } else {
+                    write!(sql, " ").unwrap();
+                    self.prepare_simple_expr(expr, sql, collector);
+                }
+            }
+            SimpleExpr::FunctionCall(Function::JsonExtract(keys), exprs) => {
+                self.prepare_json_extract(exprs, keys, sql, collector);
+            }
+            SimpleExpr::FunctionCall(Function::JsonSet(keys), exprs) => {
+                self.prepare_json_set(exprs, keys, sql, collector);
+            }
+            SimpleExpr::FunctionCall(Function::StringAgg(separator, orders), exprs) => {
+                self.prepare_string_agg(exprs, separator, orders, sql, collector);
             }
             SimpleExpr::FunctionCall(func, exprs) => {
                 self.prepare_function(func, sql, collector);
@@ -257,6 +576,14 @@ pub trait QueryBuilder: QuotedBuilder {
                         sql,
                         collector,
                     );
+                } else if *op == BinOper::Concat {
+                    self.prepare_concat(left, right, sql, collector);
+                } else if *op == BinOper::BitXor {
+                    self.prepare_bit_xor(left, right, sql, collector);
+                } else if *op == BinOper::IsDistinctFrom {
+                    self.prepare_is_distinct_from(left, right, sql, collector);
+                } else if matches!(op, BinOper::IRegexp | BinOper::NotIRegexp) {
+                    self.prepare_case_insensitive_regexp(left, op, right, sql, collector);
                 } else {
                     self.binary_expr(left, op, right, sql, collector);
                 }
@@ -280,6 +607,34 @@ pub trait QueryBuilder: QuotedBuilder {
                 });
                 write!(sql, ")").unwrap();
             }
+            SimpleExpr::Tuple(exprs) => {
+                write!(sql, "(").unwrap();
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
+            }
+            SimpleExpr::Cast(expr, cast_type) => {
+                write!(sql, "CAST(").unwrap();
+                self.prepare_simple_expr(expr, sql, collector);
+                write!(sql, " AS ").unwrap();
+                self.prepare_cast_type(cast_type, sql, collector);
+                write!(sql, ")").unwrap();
+            }
+            SimpleExpr::Excluded(col) => {
+                self.prepare_on_conflict_excluded(col, sql);
+            }
+            #[cfg(feature = "backend-postgres")]
+            SimpleExpr::Subscript(expr, index) => {
+                self.prepare_subscript(expr, index, sql, collector);
+            }
+            SimpleExpr::FullTextMatch(columns, query, mode) => {
+                self.prepare_full_text_match(columns, query, mode, sql, collector);
+            }
             SimpleExpr::Custom(s) => {
                 write!(sql, "{}", s).unwrap();
             }
@@ -319,6 +674,51 @@ pub trait QueryBuilder: QuotedBuilder {
         }
     }
 
+    #[doc(hidden)]
+    /// Write the `GROUP BY` clause of a select statement.
+    fn prepare_group_by(
+        &self,
+        groups: &[SimpleExpr],
+        grouping: &Option<Grouping>,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if groups.is_empty() && grouping.is_none() {
+            return;
+        }
+        write!(sql, " GROUP BY ").unwrap();
+        match grouping {
+            Some(Grouping::Rollup) => write!(sql, "ROLLUP (").unwrap(),
+            Some(Grouping::Cube) => write!(sql, "CUBE (").unwrap(),
+            _ => {}
+        }
+        groups.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_simple_expr(expr, sql, collector);
+            false
+        });
+        match grouping {
+            Some(Grouping::Rollup) | Some(Grouping::Cube) => write!(sql, ")").unwrap(),
+            Some(Grouping::Sets(sets)) => {
+                if !groups.is_empty() {
+                    write!(sql, ", ").unwrap();
+                }
+                write!(sql, "GROUPING SETS (").unwrap();
+                sets.iter().fold(true, |first, set| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_simple_expr(&SimpleExpr::Tuple(set.clone()), sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
+            }
+            None => {}
+        }
+    }
+
     /// Translate [`SelectDistinct`] into SQL statement.
     fn prepare_select_distinct(
         &self,
@@ -326,26 +726,56 @@ pub trait QueryBuilder: QuotedBuilder {
         sql: &mut SqlWriter,
         _collector: &mut dyn FnMut(Value),
     ) {
-        write!(
-            sql,
-            "{}",
-            match select_distinct {
-                SelectDistinct::All => "ALL",
-                SelectDistinct::Distinct => "DISTINCT",
-                SelectDistinct::DistinctRow => "DISTINCTROW",
-            }
-        )
-        .unwrap();
+        match select_distinct {
+            SelectDistinct::All => write!(sql, "ALL").unwrap(),
+            SelectDistinct::Distinct => write!(sql, "DISTINCT").unwrap(),
+            SelectDistinct::DistinctRow => write!(sql, "DISTINCTROW").unwrap(),
+        }
     }
 
-    /// Translate [`SelectExpr`] into SQL statement.
-    fn prepare_select_expr(
+    /// Translate the `DISTINCT ON` expressions of [`SelectStatement`] into SQL statement.
+    fn prepare_select_distinct_on(
         &self,
-        select_expr: &SelectExpr,
+        exprs: &[SimpleExpr],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if !self.distinct_on_supported() {
+            panic!("DISTINCT ON is not supported by this backend");
+        }
+        write!(sql, "DISTINCT ON (").unwrap();
+        exprs.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_simple_expr(expr, sql, collector);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    /// Translate [`SelectExpr`] into SQL statement.
+    fn prepare_select_expr(
+        &self,
+        select_expr: &SelectExpr,
         sql: &mut SqlWriter,
         collector: &mut dyn FnMut(Value),
     ) {
         self.prepare_simple_expr(&select_expr.expr, sql, collector);
+        if select_expr.window.is_some() && !self.window_function_supported() {
+            panic!("Window functions are not supported by this backend");
+        }
+        match &select_expr.window {
+            Some(WindowSelectType::Name(name)) => {
+                write!(sql, " OVER ").unwrap();
+                name.prepare(sql, self.quote());
+            }
+            Some(WindowSelectType::Query(window)) => {
+                write!(sql, " OVER ").unwrap();
+                self.prepare_window_statement(window, sql, collector);
+            }
+            None => {}
+        }
         match &select_expr.alias {
             Some(alias) => {
                 write!(sql, " AS ").unwrap();
@@ -362,8 +792,17 @@ pub trait QueryBuilder: QuotedBuilder {
         sql: &mut SqlWriter,
         collector: &mut dyn FnMut(Value),
     ) {
+        if join_expr.join == JoinType::FullOuterJoin && !self.full_outer_join_supported() {
+            panic!("FULL OUTER JOIN is not supported by this backend");
+        }
+        if join_expr.lateral && !self.lateral_join_supported() {
+            panic!("LATERAL joins are not supported by this backend");
+        }
         self.prepare_join_type(&join_expr.join, sql, collector);
         write!(sql, " ").unwrap();
+        if join_expr.lateral {
+            write!(sql, "LATERAL ").unwrap();
+        }
         self.prepare_table_ref(&join_expr.table, sql, collector);
         if let Some(on) = &join_expr.on {
             write!(sql, " ").unwrap();
@@ -371,6 +810,304 @@ pub trait QueryBuilder: QuotedBuilder {
         }
     }
 
+    #[doc(hidden)]
+    /// Whether `DISTINCT ON` is supported.
+    fn distinct_on_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether `FULL OUTER JOIN` is supported.
+    fn full_outer_join_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether `LATERAL` joins are supported.
+    fn lateral_join_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether common table expressions, i.e. `WITH` clauses, are supported.
+    fn with_clause_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether window functions and `WINDOW` clauses are supported.
+    fn window_function_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether `ON CONFLICT` is supported.
+    fn on_conflict_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether `RETURNING` is supported.
+    fn returning_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether the [`Grouping`] of group by expressions is supported.
+    fn grouping_supported(&self, _grouping: &Grouping) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether full text search is supported.
+    fn full_text_search_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether full text search can match several columns at once.
+    fn multi_column_full_text_search_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether a string aggregation can order the values it concatenates.
+    fn string_agg_order_by_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether a string aggregation can concatenate several expressions per row.
+    fn multi_expr_string_agg_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Whether the syntax only Postgres understands is supported, i.e. Postgres operators,
+    /// functions and array subscripts, and sequence functions.
+    fn postgres_syntax_supported(&self) -> bool {
+        false
+    }
+
+    #[doc(hidden)]
+    /// Name of the backend, as reported by [`error::Error::Unsupported`].
+    fn backend_name(&self) -> &'static str {
+        "this backend"
+    }
+
+    #[doc(hidden)]
+    /// Fail with [`error::Error::Unsupported`] unless the feature is supported.
+    fn check_supported(&self, supported: bool, feature: &'static str) -> error::Result<()> {
+        if supported {
+            Ok(())
+        } else {
+            Err(error::Error::Unsupported {
+                backend: self.backend_name(),
+                feature,
+            })
+        }
+    }
+
+    #[doc(hidden)]
+    /// Check the clauses of [`SelectStatement`] for features not supported by the backend,
+    /// sub-queries and expressions are checked on their own by [`SupportCheck`].
+    fn check_select_statement(&self, select: &SelectStatement) -> error::Result<()> {
+        if !select.distinct_on.is_empty() {
+            self.check_supported(self.distinct_on_supported(), "DISTINCT ON")?;
+        }
+        if select.r#final || select.sample.is_some() {
+            self.check_supported(self.final_sample_supported(), "FINAL and SAMPLE")?;
+        }
+        if !select.windows.is_empty() || select.selects.iter().any(|expr| expr.window.is_some()) {
+            self.check_supported(self.window_function_supported(), "Window functions")?;
+        }
+        if let Some(grouping) = &select.grouping {
+            self.check_supported(
+                self.grouping_supported(grouping),
+                match grouping {
+                    Grouping::Rollup => "ROLLUP",
+                    Grouping::Cube => "CUBE",
+                    Grouping::Sets(_) => "GROUPING SETS",
+                },
+            )?;
+        }
+        for join_expr in select.join.iter() {
+            if join_expr.join == JoinType::FullOuterJoin {
+                self.check_supported(self.full_outer_join_supported(), "FULL OUTER JOIN")?;
+            }
+            if join_expr.lateral {
+                self.check_supported(self.lateral_join_supported(), "LATERAL joins")?;
+            }
+        }
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Check the clauses of [`InsertStatement`] for features not supported by the backend.
+    fn check_insert_statement(&self, insert: &InsertStatement) -> error::Result<()> {
+        if let Some(InsertValueSource::Values(rows)) = &insert.source {
+            if rows
+                .iter()
+                .flatten()
+                .any(|expr| matches!(expr, SimpleExpr::Keyword(Keyword::Default)))
+            {
+                self.check_supported(self.insert_default_keyword_supported(), "DEFAULT in VALUES")?;
+            }
+        }
+        if let Some(on_conflict) = &insert.on_conflict {
+            self.check_supported(self.on_conflict_supported(), "ON CONFLICT")?;
+            self.check_on_conflict(on_conflict)?;
+        }
+        if insert.returning.is_some() {
+            self.check_supported(self.returning_supported(), "RETURNING")?;
+        }
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Check [`OnConflict`] for features not supported by the backend.
+    fn check_on_conflict(&self, _on_conflict: &OnConflict) -> error::Result<()> {
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Check the clauses of [`UpdateStatement`] for features not supported by the backend.
+    fn check_update_statement(&self, update: &UpdateStatement) -> error::Result<()> {
+        if !update.from.is_empty() {
+            self.check_supported(self.update_from_supported(), "UPDATE FROM")?;
+        }
+        if !update.orders.is_empty() || update.limit.is_some() {
+            self.check_supported(
+                self.update_delete_order_limit_supported(),
+                "ORDER BY and LIMIT in UPDATE",
+            )?;
+        }
+        if update.returning.is_some() {
+            self.check_supported(self.returning_supported(), "RETURNING")?;
+        }
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Check the clauses of [`DeleteStatement`] for features not supported by the backend.
+    fn check_delete_statement(&self, delete: &DeleteStatement) -> error::Result<()> {
+        if !delete.using.is_empty() {
+            self.check_supported(self.delete_using_supported(), "DELETE USING")?;
+        }
+        if !delete.orders.is_empty() || delete.limit.is_some() {
+            self.check_supported(
+                self.update_delete_order_limit_supported(),
+                "ORDER BY and LIMIT in DELETE",
+            )?;
+        }
+        if delete.returning.is_some() {
+            self.check_supported(self.returning_supported(), "RETURNING")?;
+        }
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Check the `WITH` clause of [`WithQuery`] for features not supported by the backend.
+    fn check_with_query(&self, with_query: &WithQuery) -> error::Result<()> {
+        if !with_query.with_clause.cte_expressions.is_empty() {
+            self.check_supported(self.with_clause_supported(), "Common table expressions")?;
+        }
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Check [`SimpleExpr`] for features not supported by the backend, not including the
+    /// expressions it is made of.
+    fn check_expr(&self, expr: &SimpleExpr) -> error::Result<()> {
+        match expr {
+            SimpleExpr::Unary(UnOper::Any | UnOper::All, _) => {
+                self.check_supported(
+                    self.quantified_comparison_supported(),
+                    "ANY and ALL comparisons",
+                )?;
+            }
+            #[cfg(feature = "backend-postgres")]
+            SimpleExpr::Binary(
+                _,
+                BinOper::Matches
+                | BinOper::Contains
+                | BinOper::Contained
+                | BinOper::GetJsonPath
+                | BinOper::CastJsonPath
+                | BinOper::HasKey
+                | BinOper::Overlap
+                | BinOper::ILike
+                | BinOper::NotILike,
+                _,
+            ) => {
+                self.check_supported(self.postgres_syntax_supported(), "Postgres operators")?;
+            }
+            #[cfg(feature = "backend-postgres")]
+            SimpleExpr::FunctionCall(Function::PgFunction(_), _) => {
+                self.check_supported(self.postgres_syntax_supported(), "Postgres functions")?;
+            }
+            #[cfg(feature = "backend-postgres")]
+            SimpleExpr::Subscript(_, _) => {
+                self.check_supported(self.postgres_syntax_supported(), "Array subscripts")?;
+            }
+            SimpleExpr::FunctionCall(
+                Function::NextVal | Function::CurrVal | Function::SetVal,
+                _,
+            ) => {
+                self.check_supported(self.postgres_syntax_supported(), "Sequence functions")?;
+            }
+            SimpleExpr::FunctionCall(Function::StringAgg(_, orders), exprs) => {
+                if !orders.is_empty() {
+                    self.check_supported(
+                        self.string_agg_order_by_supported(),
+                        "ORDER BY in string aggregation",
+                    )?;
+                }
+                if exprs.len() > 1 {
+                    self.check_supported(
+                        self.multi_expr_string_agg_supported(),
+                        "String aggregation of multiple expressions",
+                    )?;
+                }
+            }
+            SimpleExpr::FullTextMatch(columns, _, _) => {
+                self.check_supported(self.full_text_search_supported(), "Full text search")?;
+                if columns.len() > 1 {
+                    self.check_supported(
+                        self.multi_column_full_text_search_supported(),
+                        "Full text search of multiple columns",
+                    )?;
+                }
+            }
+            SimpleExpr::Cast(_, CastType::Column(ColumnType::Array(_))) => {
+                self.check_supported(self.array_value_supported(), "Array casts")?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Whether `ANY` and `ALL` quantified comparisons are supported.
+    fn quantified_comparison_supported(&self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    /// Check [`Value`] for features not supported by the backend.
+    fn check_value(&self, value: &Value) -> error::Result<()> {
+        if value.is_array() {
+            self.check_supported(self.array_value_supported(), "Array values")?;
+        }
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Whether array values are supported.
+    fn array_value_supported(&self) -> bool {
+        true
+    }
+
     /// Translate [`TableRef`] into SQL statement.
     fn prepare_table_ref(
         &self,
@@ -380,22 +1117,26 @@ pub trait QueryBuilder: QuotedBuilder {
     ) {
         match table_ref {
             TableRef::Table(iden) => {
-                iden.prepare(sql, self.quote());
+                self.prepare_table_name(&[iden], sql);
             }
             TableRef::SchemaTable(schema, table) => {
-                schema.prepare(sql, self.quote());
-                write!(sql, ".").unwrap();
-                table.prepare(sql, self.quote());
+                self.prepare_table_name(&[schema, table], sql);
+            }
+            TableRef::DatabaseSchemaTable(database, schema, table) => {
+                self.prepare_table_name(&[database, schema, table], sql);
             }
             TableRef::TableAlias(iden, alias) => {
-                iden.prepare(sql, self.quote());
+                self.prepare_table_name(&[iden], sql);
                 write!(sql, " AS ").unwrap();
                 alias.prepare(sql, self.quote());
             }
             TableRef::SchemaTableAlias(schema, table, alias) => {
-                schema.prepare(sql, self.quote());
-                write!(sql, ".").unwrap();
-                table.prepare(sql, self.quote());
+                self.prepare_table_name(&[schema, table], sql);
+                write!(sql, " AS ").unwrap();
+                alias.prepare(sql, self.quote());
+            }
+            TableRef::DatabaseSchemaTableAlias(database, schema, table, alias) => {
+                self.prepare_table_name(&[database, schema, table], sql);
                 write!(sql, " AS ").unwrap();
                 alias.prepare(sql, self.quote());
             }
@@ -406,9 +1147,76 @@ pub trait QueryBuilder: QuotedBuilder {
                 write!(sql, " AS ").unwrap();
                 alias.prepare(sql, self.quote());
             }
+            TableRef::ValuesList(rows, alias, cols) => {
+                self.prepare_values_list(rows, alias, cols, sql, collector);
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write a table name, qualified by the given parts, e.g. schema and table.
+    fn prepare_table_name(&self, parts: &[&DynIden], sql: &mut SqlWriter) {
+        parts.iter().fold(true, |first, part| {
+            if !first {
+                write!(sql, ".").unwrap();
+            }
+            part.prepare(sql, self.quote());
+            false
+        });
+    }
+
+    #[doc(hidden)]
+    /// Translate [`TableRef::ValuesList`] into SQL statement.
+    fn prepare_values_list(
+        &self,
+        rows: &[Values],
+        alias: &DynIden,
+        cols: &[DynIden],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "(VALUES ").unwrap();
+        rows.iter().fold(true, |first, row| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_values_row(row, sql, collector);
+            false
+        });
+        write!(sql, ") AS ").unwrap();
+        alias.prepare(sql, self.quote());
+        if !cols.is_empty() {
+            write!(sql, " (").unwrap();
+            cols.iter().fold(true, |first, col| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                col.prepare(sql, self.quote());
+                false
+            });
+            write!(sql, ")").unwrap();
         }
     }
 
+    #[doc(hidden)]
+    /// Write a row of a [`TableRef::ValuesList`].
+    fn prepare_values_row(
+        &self,
+        row: &Values,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "(").unwrap();
+        row.0.iter().fold(true, |first, value| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_value(value, sql, collector);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
     /// Translate [`UnOper`] into SQL statement.
     fn prepare_un_oper(
         &self,
@@ -421,6 +1229,11 @@ pub trait QueryBuilder: QuotedBuilder {
             "{}",
             match un_oper {
                 UnOper::Not => "NOT",
+                UnOper::Exists => "EXISTS",
+                UnOper::Any => "ANY",
+                UnOper::All => "ALL",
+                UnOper::Distinct => "DISTINCT",
+                UnOper::BitNot => "~",
             }
         )
         .unwrap();
@@ -442,6 +1255,8 @@ pub trait QueryBuilder: QuotedBuilder {
                 BinOper::NotLike => "NOT LIKE",
                 BinOper::Is => "IS",
                 BinOper::IsNot => "IS NOT",
+                BinOper::IsDistinctFrom => "IS DISTINCT FROM",
+                BinOper::IsNotDistinctFrom => "IS NOT DISTINCT FROM",
                 BinOper::In => "IN",
                 BinOper::NotIn => "NOT IN",
                 BinOper::Between => "BETWEEN",
@@ -456,7 +1271,19 @@ pub trait QueryBuilder: QuotedBuilder {
                 BinOper::Sub => "-",
                 BinOper::Mul => "*",
                 BinOper::Div => "/",
-                _ => unimplemented!(),
+                BinOper::BitAnd => "&",
+                BinOper::BitOr => "|",
+                BinOper::BitXor => "^",
+                BinOper::LShift => "<<",
+                BinOper::RShift => ">>",
+                BinOper::Concat => "||",
+                BinOper::Escape => "ESCAPE",
+                BinOper::Regexp | BinOper::IRegexp => "REGEXP",
+                BinOper::NotRegexp | BinOper::NotIRegexp => "NOT REGEXP",
+                BinOper::GetJsonField => "->",
+                BinOper::CastJsonField => "->>",
+                #[cfg(feature = "backend-postgres")]
+                _ => panic!("{:?} operator is only supported by Postgres", bin_oper),
             }
         )
         .unwrap();
@@ -524,23 +1351,256 @@ pub trait QueryBuilder: QuotedBuilder {
                     Function::Avg => "AVG",
                     Function::Count => "COUNT",
                     Function::IfNull => self.if_null_function(),
+                    Function::Coalesce => "COALESCE",
+                    Function::NullIf => "NULLIF",
+                    Function::Greatest => self.greatest_function(),
+                    Function::Least => self.least_function(),
+                    Function::StringAgg(_, _) => "STRING_AGG",
+                    Function::JsonExtract(_) => "JSON_EXTRACT",
+                    Function::JsonBuildObject => self.json_build_object_function(),
+                    Function::JsonSet(_) => "JSON_SET",
                     Function::CharLength => self.char_length_function(),
+                    Function::NextVal | Function::CurrVal | Function::SetVal =>
+                        self.sequence_function(function),
                     Function::Custom(_) => "",
                     #[cfg(feature = "backend-postgres")]
-                    Function::PgFunction(_) => unimplemented!(),
+                    Function::PgFunction(function) =>
+                        panic!("{:?} function is only supported by Postgres", function),
                 }
             )
             .unwrap();
         }
     }
 
-    fn prepare_function(
+    fn prepare_function(
+        &self,
+        function: &Function,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        self.prepare_function_common(function, sql, collector)
+    }
+
+    #[doc(hidden)]
+    /// Write a string aggregation, i.e. [`Function::StringAgg`].
+    fn prepare_string_agg(
+        &self,
+        exprs: &[SimpleExpr],
+        separator: &str,
+        orders: &[OrderExpr],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "STRING_AGG(").unwrap();
+        exprs.iter().for_each(|expr| {
+            self.prepare_simple_expr(expr, sql, collector);
+            write!(sql, ", ").unwrap();
+        });
+        self.prepare_string_agg_separator(separator, sql);
+        self.prepare_string_agg_order_by(orders, sql, collector);
+        write!(sql, ")").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write the separator of a string aggregation as a literal, since MySQL does not accept a parameter.
+    fn prepare_string_agg_separator(&self, separator: &str, sql: &mut SqlWriter) {
+        let separator = Value::String(Some(Box::new(separator.to_owned())));
+        write!(sql, "{}", self.value_to_string(&separator)).unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write the `ORDER BY` of a string aggregation.
+    fn prepare_string_agg_order_by(
+        &self,
+        orders: &[OrderExpr],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if !orders.is_empty() {
+            write!(sql, " ORDER BY ").unwrap();
+            orders.iter().fold(true, |first, expr| {
+                if !first {
+                    write!(sql, ", ").unwrap();
+                }
+                self.prepare_order_expr(expr, sql, collector);
+                false
+            });
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write a JSON extraction, i.e. [`Function::JsonExtract`].
+    fn prepare_json_extract(
+        &self,
+        exprs: &[SimpleExpr],
+        keys: &[String],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "JSON_EXTRACT(").unwrap();
+        exprs.iter().for_each(|expr| {
+            self.prepare_simple_expr(expr, sql, collector);
+            write!(sql, ", ").unwrap();
+        });
+        self.prepare_json_path(keys, sql, collector);
+        write!(sql, ")").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write a JSON modification, i.e. [`Function::JsonSet`].
+    fn prepare_json_set(
+        &self,
+        exprs: &[SimpleExpr],
+        keys: &[String],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "{}(", self.json_set_function()).unwrap();
+        let (document, values) = exprs.split_first().expect("JSON document is missing");
+        self.prepare_simple_expr(document, sql, collector);
+        write!(sql, ", ").unwrap();
+        self.prepare_json_path(keys, sql, collector);
+        values.iter().for_each(|expr| {
+            write!(sql, ", ").unwrap();
+            self.prepare_simple_expr(expr, sql, collector);
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write the path of nested object keys, e.g. `$.a.b`.
+    fn prepare_json_path(
+        &self,
+        keys: &[String],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        let path = keys.iter().fold("$".to_owned(), |mut path, key| {
+            if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                write!(path, ".{}", key).unwrap();
+            } else {
+                write!(
+                    path,
+                    ".\"{}\"",
+                    key.replace('\\', "\\\\").replace('"', "\\\"")
+                )
+                .unwrap();
+            }
+            path
+        });
+        self.prepare_value(&path.into(), sql, collector);
+    }
+
+    #[doc(hidden)]
+    /// Write a string concatenation, i.e. [`BinOper::Concat`].
+    fn prepare_concat(
+        &self,
+        left: &SimpleExpr,
+        right: &SimpleExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        self.binary_expr(left, &BinOper::Concat, right, sql, collector);
+    }
+
+    #[doc(hidden)]
+    /// Write a bitwise exclusive or, i.e. [`BinOper::BitXor`].
+    fn prepare_bit_xor(
+        &self,
+        left: &SimpleExpr,
+        right: &SimpleExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        self.binary_expr(left, &BinOper::BitXor, right, sql, collector);
+    }
+
+    #[doc(hidden)]
+    /// Write a null-safe inequality, i.e. [`BinOper::IsDistinctFrom`].
+    fn prepare_is_distinct_from(
+        &self,
+        left: &SimpleExpr,
+        right: &SimpleExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        self.binary_expr(left, &BinOper::IsDistinctFrom, right, sql, collector);
+    }
+
+    #[doc(hidden)]
+    /// Write a case insensitive regular expression match, i.e. [`BinOper::IRegexp`].
+    fn prepare_case_insensitive_regexp(
+        &self,
+        left: &SimpleExpr,
+        op: &BinOper,
+        right: &SimpleExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        self.binary_expr(left, op, right, sql, collector);
+    }
+
+    #[doc(hidden)]
+    /// Write a full text search, i.e. [`SimpleExpr::FullTextMatch`].
+    fn prepare_full_text_match(
+        &self,
+        columns: &[SimpleExpr],
+        query: &SimpleExpr,
+        mode: &SearchMode,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "TO_TSVECTOR(").unwrap();
+        if columns.len() > 1 {
+            write!(sql, "CONCAT_WS(' ', ").unwrap();
+        }
+        columns.iter().fold(true, |first, column| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_simple_expr(column, sql, collector);
+            false
+        });
+        if columns.len() > 1 {
+            write!(sql, ")").unwrap();
+        }
+        write!(
+            sql,
+            ") @@ {}(",
+            match mode {
+                SearchMode::NaturalLanguage => "PLAINTO_TSQUERY",
+                SearchMode::Boolean => "TO_TSQUERY",
+            }
+        )
+        .unwrap();
+        self.prepare_simple_expr(query, sql, collector);
+        write!(sql, ")").unwrap();
+    }
+
+    #[doc(hidden)]
+    #[cfg(feature = "backend-postgres")]
+    /// Write an array subscript, i.e. [`SimpleExpr::Subscript`].
+    fn prepare_subscript(
+        &self,
+        _expr: &SimpleExpr,
+        _index: &SimpleExpr,
+        _sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        panic!("Array subscripts are only supported by Postgres");
+    }
+
+    /// Translate [`CastType`] into SQL statement.
+    fn prepare_cast_type(
         &self,
-        function: &Function,
+        cast_type: &CastType,
         sql: &mut SqlWriter,
-        collector: &mut dyn FnMut(Value),
+        _collector: &mut dyn FnMut(Value),
     ) {
-        self.prepare_function_common(function, sql, collector)
+        match cast_type {
+            CastType::Column(column_type) => self.prepare_column_type(column_type, sql),
+            CastType::Custom(iden) => iden.unquoted(sql),
+        }
     }
 
     /// Translate [`JoinType`] into SQL statement.
@@ -555,9 +1615,11 @@ pub trait QueryBuilder: QuotedBuilder {
             "{}",
             match join_type {
                 JoinType::Join => "JOIN",
+                JoinType::CrossJoin => "CROSS JOIN",
                 JoinType::InnerJoin => "INNER JOIN",
                 JoinType::LeftJoin => "LEFT JOIN",
                 JoinType::RightJoin => "RIGHT JOIN",
+                JoinType::FullOuterJoin => "FULL OUTER JOIN",
             }
         )
         .unwrap()
@@ -570,9 +1632,91 @@ pub trait QueryBuilder: QuotedBuilder {
         sql: &mut SqlWriter,
         collector: &mut dyn FnMut(Value),
     ) {
-        self.prepare_simple_expr(&order_expr.expr, sql, collector);
-        write!(sql, " ").unwrap();
-        self.prepare_order(&order_expr.order, sql, collector);
+        match &order_expr.field {
+            Some(values) => self.prepare_field_order(&order_expr.expr, values, sql, collector),
+            None => {
+                self.prepare_simple_expr(&order_expr.expr, sql, collector);
+                write!(sql, " ").unwrap();
+                self.prepare_order(&order_expr.order, sql, collector);
+            }
+        }
+        if let Some(nulls) = &order_expr.nulls {
+            write!(sql, " ").unwrap();
+            self.prepare_null_ordering(nulls, sql, collector);
+        }
+    }
+
+    /// Translate [`WindowStatement`] into SQL statement.
+    fn prepare_window_statement(
+        &self,
+        window: &WindowStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "(").unwrap();
+        if !window.partition_by.is_empty() {
+            write!(sql, "PARTITION BY ").unwrap();
+            window.partition_by.iter().fold(true, |first, expr| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_simple_expr(expr, sql, collector);
+                false
+            });
+        }
+        if !window.order_by.is_empty() {
+            if !window.partition_by.is_empty() {
+                write!(sql, " ").unwrap();
+            }
+            write!(sql, "ORDER BY ").unwrap();
+            window.order_by.iter().fold(true, |first, expr| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_order_expr(expr, sql, collector);
+                false
+            });
+        }
+        if let Some(frame) = &window.frame {
+            if !window.partition_by.is_empty() || !window.order_by.is_empty() {
+                write!(sql, " ").unwrap();
+            }
+            self.prepare_frame_clause(frame, sql, collector);
+        }
+        write!(sql, ")").unwrap();
+    }
+
+    /// Translate [`FrameClause`] into SQL statement.
+    fn prepare_frame_clause(
+        &self,
+        frame: &FrameClause,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        match frame.r#type {
+            FrameType::Range => write!(sql, "RANGE ").unwrap(),
+            FrameType::Rows => write!(sql, "ROWS ").unwrap(),
+        }
+        match &frame.end {
+            Some(end) => {
+                write!(sql, "BETWEEN ").unwrap();
+                self.prepare_frame(&frame.start, sql, collector);
+                write!(sql, " AND ").unwrap();
+                self.prepare_frame(end, sql, collector);
+            }
+            None => self.prepare_frame(&frame.start, sql, collector),
+        }
+    }
+
+    /// Translate [`Frame`] into SQL statement.
+    fn prepare_frame(&self, frame: &Frame, sql: &mut SqlWriter, _collector: &mut dyn FnMut(Value)) {
+        match frame {
+            Frame::UnboundedPreceding => write!(sql, "UNBOUNDED PRECEDING").unwrap(),
+            Frame::Preceding(v) => write!(sql, "{} PRECEDING", v).unwrap(),
+            Frame::CurrentRow => write!(sql, "CURRENT ROW").unwrap(),
+            Frame::Following(v) => write!(sql, "{} FOLLOWING", v).unwrap(),
+            Frame::UnboundedFollowing => write!(sql, "UNBOUNDED FOLLOWING").unwrap(),
+        }
     }
 
     /// Translate [`JoinOn`] into SQL statement.
@@ -585,9 +1729,23 @@ pub trait QueryBuilder: QuotedBuilder {
         match join_on {
             JoinOn::Condition(c) => {
                 write!(sql, "ON ").unwrap();
-                self.prepare_simple_expr(c, sql, collector);
+                if c.conditions.is_empty() {
+                    write!(sql, "TRUE").unwrap();
+                } else {
+                    self.prepare_condition_where(c, sql, collector);
+                }
+            }
+            JoinOn::Columns(c) => {
+                write!(sql, "USING (").unwrap();
+                c.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_simple_expr(expr, sql, collector);
+                    false
+                });
+                write!(sql, ")").unwrap();
             }
-            JoinOn::Columns(_c) => unimplemented!(),
         }
     }
 
@@ -599,11 +1757,119 @@ pub trait QueryBuilder: QuotedBuilder {
         }
     }
 
+    /// Translate the value list of an order by field expression into SQL statement.
+    fn prepare_field_order(
+        &self,
+        expr: &SimpleExpr,
+        values: &Values,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "CASE").unwrap();
+        for (i, value) in values.0.iter().enumerate() {
+            write!(sql, " WHEN ").unwrap();
+            self.prepare_simple_expr(expr, sql, collector);
+            write!(sql, " = ").unwrap();
+            self.prepare_value(value, sql, collector);
+            write!(sql, " THEN {}", i).unwrap();
+        }
+        write!(sql, " ELSE {} END ASC", values.0.len()).unwrap();
+    }
+
+    /// Translate [`NullOrdering`] into SQL statement.
+    fn prepare_null_ordering(
+        &self,
+        nulls: &NullOrdering,
+        sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        match nulls {
+            NullOrdering::First => write!(sql, "NULLS FIRST").unwrap(),
+            NullOrdering::Last => write!(sql, "NULLS LAST").unwrap(),
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write a condition with its values inlined, for statements taking no parameters,
+    /// e.g. the `WHERE` of a partial index.
+    fn prepare_condition_inlined(
+        &self,
+        condition: &ConditionHolder,
+        keyword: &str,
+        sql: &mut SqlWriter,
+    ) where
+        Self: Sized,
+    {
+        let mut values = Vec::new();
+        let mut condition_sql = SqlWriter::new();
+        self.prepare_condition(condition, keyword, &mut condition_sql, &mut |v| {
+            values.push(v)
+        });
+        write!(
+            sql,
+            "{}",
+            inject_parameters(&condition_sql.result(), values, self)
+        )
+        .unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write a [`SimpleExpr`] with its values inlined, for statements taking no parameters,
+    /// e.g. a `CHECK` constraint in table DDL.
+    fn prepare_simple_expr_inlined(&self, simple_expr: &SimpleExpr, sql: &mut SqlWriter)
+    where
+        Self: Sized,
+    {
+        let mut values = Vec::new();
+        let mut expr_sql = SqlWriter::new();
+        self.prepare_simple_expr(simple_expr, &mut expr_sql, &mut |v| values.push(v));
+        write!(
+            sql,
+            "{}",
+            inject_parameters(&expr_sql.result(), values, self)
+        )
+        .unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write a [`QueryStatement`] with its values inlined, e.g. in the body of a trigger.
+    fn prepare_query_statement_inlined(&self, query: &QueryStatement, sql: &mut SqlWriter)
+    where
+        Self: Sized,
+    {
+        let mut values = Vec::new();
+        let mut query_sql = SqlWriter::new();
+        self.prepare_query_statement(query, &mut query_sql, &mut |v| values.push(v));
+        write!(
+            sql,
+            "{}",
+            inject_parameters(&query_sql.result(), values, self)
+        )
+        .unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write the default of a column. Expressions other than literals and keywords are
+    /// parenthesized, as required by MySQL and SQLite.
+    fn prepare_column_default(&self, default: &SimpleExpr, sql: &mut SqlWriter)
+    where
+        Self: Sized,
+    {
+        match default {
+            SimpleExpr::Value(_) | SimpleExpr::Keyword(_) => {
+                self.prepare_simple_expr_inlined(default, sql)
+            }
+            _ => {
+                write!(sql, "(").unwrap();
+                self.prepare_simple_expr_inlined(default, sql);
+                write!(sql, ")").unwrap();
+            }
+        }
+    }
+
     /// Translate [`Value`] into SQL statement.
     fn prepare_value(&self, value: &Value, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
-        let (placeholder, numbered) = self.placeholder();
-        sql.push_param(placeholder, numbered);
-        collector(value.clone());
+        self.prepare_param(value, sql, collector);
     }
 
     /// Translate [`Keyword`] into SQL statement.
@@ -621,6 +1887,10 @@ pub trait QueryBuilder: QuotedBuilder {
                 "{}",
                 match keyword {
                     Keyword::Null => "NULL",
+                    Keyword::Default => "DEFAULT",
+                    Keyword::CurrentTimestamp => "CURRENT_TIMESTAMP",
+                    Keyword::CurrentDate => "CURRENT_DATE",
+                    Keyword::CurrentTime => "CURRENT_TIME",
                     Keyword::Custom(_) => "",
                 }
             )
@@ -644,7 +1914,8 @@ pub trait QueryBuilder: QuotedBuilder {
             | Value::Float(None)
             | Value::Double(None)
             | Value::String(None)
-            | Value::Bytes(None) => write!(s, "NULL").unwrap(),
+            | Value::Bytes(None)
+            | Value::Array(None) => write!(s, "NULL").unwrap(),
             #[cfg(feature = "with-json")]
             Value::Json(None) => write!(s, "NULL").unwrap(),
             #[cfg(feature = "with-chrono")]
@@ -654,14 +1925,30 @@ pub trait QueryBuilder: QuotedBuilder {
             #[cfg(feature = "with-chrono")]
             Value::DateTime(None) => write!(s, "NULL").unwrap(),
             #[cfg(feature = "with-chrono")]
+            Value::DateTimeUtc(None) => write!(s, "NULL").unwrap(),
+            #[cfg(feature = "with-chrono")]
+            Value::DateTimeLocal(None) => write!(s, "NULL").unwrap(),
+            #[cfg(feature = "with-chrono")]
             Value::DateTimeWithTimeZone(None) => write!(s, "NULL").unwrap(),
+            #[cfg(feature = "with-time")]
+            Value::TimeDate(None) => write!(s, "NULL").unwrap(),
+            #[cfg(feature = "with-time")]
+            Value::TimeTime(None) => write!(s, "NULL").unwrap(),
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTime(None) => write!(s, "NULL").unwrap(),
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTimeWithTimeZone(None) => write!(s, "NULL").unwrap(),
             #[cfg(feature = "with-rust_decimal")]
             Value::Decimal(None) => write!(s, "NULL").unwrap(),
             #[cfg(feature = "with-bigdecimal")]
             Value::BigDecimal(None) => write!(s, "NULL").unwrap(),
             #[cfg(feature = "with-uuid")]
             Value::Uuid(None) => write!(s, "NULL").unwrap(),
-            Value::Bool(Some(b)) => write!(s, "{}", if *b { "TRUE" } else { "FALSE" }).unwrap(),
+            #[cfg(feature = "with-ipnetwork")]
+            Value::IpNetwork(None) => write!(s, "NULL").unwrap(),
+            #[cfg(feature = "with-mac_address")]
+            Value::MacAddress(None) => write!(s, "NULL").unwrap(),
+            Value::Bool(Some(b)) => self.write_bool(*b, &mut s),
             Value::TinyInt(Some(v)) => write!(s, "{}", v).unwrap(),
             Value::SmallInt(Some(v)) => write!(s, "{}", v).unwrap(),
             Value::Int(Some(v)) => write!(s, "{}", v).unwrap(),
@@ -673,44 +1960,171 @@ pub trait QueryBuilder: QuotedBuilder {
             Value::Float(Some(v)) => write!(s, "{}", v).unwrap(),
             Value::Double(Some(v)) => write!(s, "{}", v).unwrap(),
             Value::String(Some(v)) => self.write_string_quoted(v, &mut s),
-            Value::Bytes(Some(v)) => write!(
-                s,
-                "x\'{}\'",
-                v.iter().map(|b| format!("{:02X}", b)).collect::<String>()
-            )
-            .unwrap(),
+            Value::Bytes(Some(v)) => self.write_bytes(v, &mut s),
+            Value::Array(Some(v)) => self.write_array(v, &mut s),
             #[cfg(feature = "with-json")]
             Value::Json(Some(v)) => self.write_string_quoted(&v.to_string(), &mut s),
             #[cfg(feature = "with-chrono")]
-            Value::Date(Some(v)) => write!(s, "\'{}\'", v.format("%Y-%m-%d").to_string()).unwrap(),
+            Value::Date(Some(v)) => {
+                self.write_date_time_keyword("DATE", &mut s);
+                write!(s, "\'{}\'", v.format("%Y-%m-%d")).unwrap()
+            }
+            #[cfg(feature = "with-chrono")]
+            Value::Time(Some(v)) => {
+                self.write_date_time_keyword("TIME", &mut s);
+                write!(s, "\'{}\'", v.format("%H:%M:%S")).unwrap()
+            }
+            #[cfg(feature = "with-chrono")]
+            Value::DateTime(Some(v)) => write!(s, "\'{}\'", v.format("%Y-%m-%d %H:%M:%S")).unwrap(),
             #[cfg(feature = "with-chrono")]
-            Value::Time(Some(v)) => write!(s, "\'{}\'", v.format("%H:%M:%S").to_string()).unwrap(),
+            Value::DateTimeUtc(Some(v)) => {
+                write!(s, "\'{}\'", v.format(self.date_time_tz_format())).unwrap()
+            }
             #[cfg(feature = "with-chrono")]
-            Value::DateTime(Some(v)) => {
-                write!(s, "\'{}\'", v.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
+            Value::DateTimeLocal(Some(v)) => {
+                write!(s, "\'{}\'", v.format(self.date_time_tz_format())).unwrap()
             }
             #[cfg(feature = "with-chrono")]
             Value::DateTimeWithTimeZone(Some(v)) => {
-                write!(s, "\'{}\'", v.format("%Y-%m-%d %H:%M:%S %:z").to_string()).unwrap()
+                write!(s, "\'{}\'", v.format(self.date_time_tz_format())).unwrap()
+            }
+            #[cfg(feature = "with-time")]
+            Value::TimeDate(Some(v)) => {
+                self.write_date_time_keyword("DATE", &mut s);
+                write!(s, "\'{}\'", v.format(time_format::FORMAT_DATE).unwrap()).unwrap()
+            }
+            #[cfg(feature = "with-time")]
+            Value::TimeTime(Some(v)) => {
+                self.write_date_time_keyword("TIME", &mut s);
+                write!(s, "\'{}\'", v.format(time_format::FORMAT_TIME).unwrap()).unwrap()
+            }
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTime(Some(v)) => {
+                write!(s, "\'{}\'", v.format(time_format::FORMAT_DATETIME).unwrap()).unwrap()
+            }
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTimeWithTimeZone(Some(v)) => {
+                let format = if self.iso_8601_offset() {
+                    time_format::FORMAT_DATETIME_TZ_ISO
+                } else {
+                    time_format::FORMAT_DATETIME_TZ
+                };
+                write!(s, "\'{}\'", v.format(format).unwrap()).unwrap()
             }
             #[cfg(feature = "with-rust_decimal")]
             Value::Decimal(Some(v)) => write!(s, "{}", v).unwrap(),
             #[cfg(feature = "with-bigdecimal")]
             Value::BigDecimal(Some(v)) => write!(s, "{}", v).unwrap(),
             #[cfg(feature = "with-uuid")]
-            Value::Uuid(Some(v)) => write!(s, "\'{}\'", v.to_string()).unwrap(),
+            Value::Uuid(Some(v)) => write!(s, "\'{}\'", v).unwrap(),
+            #[cfg(feature = "with-ipnetwork")]
+            Value::IpNetwork(Some(v)) => write!(s, "\'{}\'", v).unwrap(),
+            #[cfg(feature = "with-mac_address")]
+            Value::MacAddress(Some(v)) => write!(s, "\'{}\'", v).unwrap(),
         };
         s
     }
 
+    /// Translate [`OnConflict`] into SQL statement.
+    fn prepare_on_conflict(
+        &self,
+        on_conflict: &OnConflict,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if !self.on_conflict_supported() {
+            panic!("ON CONFLICT is not supported by this backend");
+        }
+        write!(sql, " ON CONFLICT").unwrap();
+        match &on_conflict.target {
+            Some(OnConflictTarget::Columns(columns)) => {
+                write!(sql, " (").unwrap();
+                columns.iter().fold(true, |first, col| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    col.prepare(sql, self.quote());
+                    false
+                });
+                write!(sql, ")").unwrap();
+            }
+            Some(OnConflictTarget::Constraint(name)) => {
+                write!(sql, " ON CONSTRAINT ").unwrap();
+                name.prepare(sql, self.quote());
+            }
+            None => {}
+        }
+        self.prepare_condition(&on_conflict.target_where, "WHERE", sql, collector);
+        match &on_conflict.action {
+            Some(OnConflictAction::Update(updates)) => {
+                write!(sql, " DO UPDATE SET ").unwrap();
+                updates.iter().fold(true, |first, update| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_on_conflict_update(update, sql, collector);
+                    false
+                });
+                self.prepare_condition(&on_conflict.action_where, "WHERE", sql, collector);
+            }
+            Some(OnConflictAction::DoNothing) | None => write!(sql, " DO NOTHING").unwrap(),
+        }
+    }
+
+    /// Translate [`OnConflictUpdate`] into SQL statement.
+    fn prepare_on_conflict_update(
+        &self,
+        update: &OnConflictUpdate,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        match update {
+            OnConflictUpdate::Column(col) => {
+                col.prepare(sql, self.quote());
+                write!(sql, " = ").unwrap();
+                self.prepare_on_conflict_excluded(col, sql);
+            }
+            OnConflictUpdate::Value(col, value) => {
+                col.prepare(sql, self.quote());
+                write!(sql, " = ").unwrap();
+                self.prepare_simple_expr(value, sql, collector);
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write the reference to the value proposed for insertion of a column, in an upsert.
+    fn prepare_on_conflict_excluded(&self, col: &DynIden, sql: &mut SqlWriter) {
+        Alias::new("excluded").prepare(sql, self.quote());
+        write!(sql, ".").unwrap();
+        col.prepare(sql, self.quote());
+    }
+
     #[doc(hidden)]
     /// Hook to insert "RETURNING" statements.
     fn prepare_returning(
         &self,
-        _returning: &Vec<SelectExpr>,
-        _sql: &mut SqlWriter,
-        _collector: &mut dyn FnMut(Value),
+        returning: &Option<ReturningClause>,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
     ) {
+        if returning.is_some() && !self.returning_supported() {
+            panic!("RETURNING is not supported by this backend");
+        }
+        match returning {
+            Some(ReturningClause::All) => write!(sql, " RETURNING *").unwrap(),
+            Some(ReturningClause::Exprs(exprs)) if !exprs.is_empty() => {
+                write!(sql, " RETURNING ").unwrap();
+                exprs.iter().fold(true, |first, expr| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_select_expr(expr, sql, collector);
+                    false
+                });
+            }
+            _ => {}
+        }
     }
 
     #[doc(hidden)]
@@ -795,7 +2209,7 @@ pub trait QueryBuilder: QuotedBuilder {
         sql: &mut SqlWriter,
         collector: &mut dyn FnMut(Value),
     ) {
-        let no_paren = matches!(op, BinOper::Equal | BinOper::NotEqual);
+        let no_paren = matches!(op, BinOper::Equal | BinOper::NotEqual | BinOper::Escape);
         let left_paren = left.need_parentheses()
             && left.is_binary()
             && *op != left.get_bin_oper().unwrap()
@@ -827,7 +2241,68 @@ pub trait QueryBuilder: QuotedBuilder {
     #[doc(hidden)]
     /// Write a string surrounded by escaped quotes.
     fn write_string_quoted(&self, string: &str, buffer: &mut String) {
-        write!(buffer, "\'{}\'", escape_string(string)).unwrap()
+        write!(buffer, "\'{}\'", self.escape_string(string)).unwrap()
+    }
+
+    #[doc(hidden)]
+    /// Write a binary literal, `x'...'` by default.
+    fn write_bytes(&self, bytes: &[u8], buffer: &mut String) {
+        write!(buffer, "x'").unwrap();
+        for b in bytes {
+            write!(buffer, "{:02X}", b).unwrap();
+        }
+        write!(buffer, "'").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write an array literal, `ARRAY[...]` by default.
+    fn write_array(&self, elements: &[Value], buffer: &mut String) {
+        write!(
+            buffer,
+            "ARRAY[{}]",
+            elements
+                .iter()
+                .map(|element| self.value_to_string(element))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+        .unwrap()
+    }
+
+    #[doc(hidden)]
+    /// Write a boolean literal.
+    fn write_bool(&self, value: bool, buffer: &mut String) {
+        write!(buffer, "{}", if value { "TRUE" } else { "FALSE" }).unwrap()
+    }
+
+    #[doc(hidden)]
+    /// Whether the offset of a date time literal follows the time without a space, as in ISO 8601.
+    fn iso_8601_offset(&self) -> bool {
+        false
+    }
+
+    #[doc(hidden)]
+    /// The `chrono` format of a date time literal with offset.
+    fn date_time_tz_format(&self) -> &str {
+        if self.iso_8601_offset() {
+            "%Y-%m-%d %H:%M:%S%:z"
+        } else {
+            "%Y-%m-%d %H:%M:%S %:z"
+        }
+    }
+
+    #[doc(hidden)]
+    /// Whether date and time literals are prefixed with their type, e.g. `DATE '2020-01-01'`.
+    fn typed_date_time_literal(&self) -> bool {
+        false
+    }
+
+    #[doc(hidden)]
+    /// Write the type keyword of a date or time literal, if the backend supports it.
+    fn write_date_time_keyword(&self, keyword: &str, buffer: &mut String) {
+        if self.typed_date_time_literal() {
+            write!(buffer, "{} ", keyword).unwrap()
+        }
     }
 
     #[doc(hidden)]
@@ -836,19 +2311,128 @@ pub trait QueryBuilder: QuotedBuilder {
         "IFNULL"
     }
 
+    #[doc(hidden)]
+    /// The name of the function that builds a JSON object from keys and values.
+    fn json_build_object_function(&self) -> &str {
+        "JSON_OBJECT"
+    }
+
+    #[doc(hidden)]
+    /// The name of the function that replaces a value inside a JSON document.
+    fn json_set_function(&self) -> &str {
+        "JSON_SET"
+    }
+
+    #[doc(hidden)]
+    /// The name of the function that returns the largest argument.
+    fn greatest_function(&self) -> &str {
+        "GREATEST"
+    }
+
+    #[doc(hidden)]
+    /// The name of the function that returns the smallest argument.
+    fn least_function(&self) -> &str {
+        "LEAST"
+    }
+
     #[doc(hidden)]
     /// The name of the function that returns the char length.
     fn char_length_function(&self) -> &str {
         "CHAR_LENGTH"
     }
+
+    #[doc(hidden)]
+    /// The name of a sequence function, i.e. `NEXTVAL`, `CURRVAL` or `SETVAL`.
+    fn sequence_function(&self, function: &Function) -> &str {
+        panic!("{:?} function is not supported by this backend", function)
+    }
 }
 
 pub(crate) struct CommonSqlQueryBuilder;
 
 impl QueryBuilder for CommonSqlQueryBuilder {}
 
+impl PlaceholderBuilder for CommonSqlQueryBuilder {}
+
+impl PaginationBuilder for CommonSqlQueryBuilder {}
+
+impl ColumnTypeBuilder for CommonSqlQueryBuilder {}
+
 impl QuotedBuilder for CommonSqlQueryBuilder {
     fn quote(&self) -> char {
         '"'
     }
 }
+
+/// Walks a statement and checks each of its nodes with the `check_*` methods of a
+/// [`QueryBuilder`], keeping the first error.
+pub(crate) struct SupportCheck<'a> {
+    query_builder: &'a dyn QueryBuilder,
+    result: error::Result<()>,
+}
+
+impl<'a> SupportCheck<'a> {
+    pub(crate) fn new(query_builder: &'a dyn QueryBuilder) -> Self {
+        Self {
+            query_builder,
+            result: Ok(()),
+        }
+    }
+
+    pub(crate) fn finish(self) -> error::Result<()> {
+        self.result
+    }
+
+    /// Run the check unless a previous one failed, and tell whether to keep walking.
+    fn check<F>(&mut self, check: F) -> bool
+    where
+        F: FnOnce(&dyn QueryBuilder) -> error::Result<()>,
+    {
+        if self.result.is_ok() {
+            self.result = check(self.query_builder);
+        }
+        self.result.is_ok()
+    }
+}
+
+impl Visitor for SupportCheck<'_> {
+    fn visit_select(&mut self, select: &SelectStatement) {
+        if self.check(|query_builder| query_builder.check_select_statement(select)) {
+            self.walk_select(select);
+        }
+    }
+
+    fn visit_insert(&mut self, insert: &InsertStatement) {
+        if self.check(|query_builder| query_builder.check_insert_statement(insert)) {
+            self.walk_insert(insert);
+        }
+    }
+
+    fn visit_update(&mut self, update: &UpdateStatement) {
+        if self.check(|query_builder| query_builder.check_update_statement(update)) {
+            self.walk_update(update);
+        }
+    }
+
+    fn visit_delete(&mut self, delete: &DeleteStatement) {
+        if self.check(|query_builder| query_builder.check_delete_statement(delete)) {
+            self.walk_delete(delete);
+        }
+    }
+
+    fn visit_with_query(&mut self, query: &WithQuery) {
+        if self.check(|query_builder| query_builder.check_with_query(query)) {
+            self.walk_with_query(query);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &SimpleExpr) {
+        if self.check(|query_builder| query_builder.check_expr(expr)) {
+            self.walk_expr(expr);
+        }
+    }
+
+    fn visit_value(&mut self, value: &Value) {
+        self.check(|query_builder| query_builder.check_value(value));
+    }
+}
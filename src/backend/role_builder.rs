@@ -0,0 +1,84 @@
+use crate::*;
+
+pub trait RoleBuilder: QuotedBuilder {
+    /// Translate [`RoleCreateStatement`] into SQL statement.
+    fn prepare_role_create_statement(&self, create: &RoleCreateStatement, sql: &mut SqlWriter) {
+        write!(sql, "CREATE {} ", if create.user { "USER" } else { "ROLE" }).unwrap();
+
+        if create.if_not_exists {
+            self.prepare_role_if_not_exists(sql);
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if let Some(host) = &create.host {
+            self.prepare_role_host(host, sql);
+        }
+
+        self.prepare_role_options(&create.options, sql);
+    }
+
+    /// Translate [`RoleDropStatement`] into SQL statement.
+    fn prepare_role_drop_statement(&self, drop: &RoleDropStatement, sql: &mut SqlWriter) {
+        write!(sql, "DROP {} ", if drop.user { "USER" } else { "ROLE" }).unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &drop.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if let Some(host) = &drop.host {
+            self.prepare_role_host(host, sql);
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write `IF NOT EXISTS` of role creation.
+    fn prepare_role_if_not_exists(&self, sql: &mut SqlWriter) {
+        write!(sql, "IF NOT EXISTS ").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write the host part of an account name.
+    fn prepare_role_host(&self, _host: &str, _sql: &mut SqlWriter) {
+        panic!("Role host is not supported by this backend");
+    }
+
+    #[doc(hidden)]
+    /// Write the options of role creation.
+    fn prepare_role_options(&self, options: &[RoleOption], sql: &mut SqlWriter) {
+        for option in options.iter() {
+            write!(sql, " ").unwrap();
+            self.prepare_role_option(option, sql);
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write a single role option.
+    fn prepare_role_option(&self, option: &RoleOption, sql: &mut SqlWriter) {
+        let no = |enabled: &bool| if *enabled { "" } else { "NO" };
+        match option {
+            RoleOption::Login(login) => write!(sql, "{}LOGIN", no(login)).unwrap(),
+            RoleOption::Superuser(superuser) => write!(sql, "{}SUPERUSER", no(superuser)).unwrap(),
+            RoleOption::CreateDb(create_db) => write!(sql, "{}CREATEDB", no(create_db)).unwrap(),
+            RoleOption::CreateRole(create_role) => {
+                write!(sql, "{}CREATEROLE", no(create_role)).unwrap()
+            }
+            RoleOption::Inherit(inherit) => write!(sql, "{}INHERIT", no(inherit)).unwrap(),
+            RoleOption::ConnectionLimit(limit) => {
+                write!(sql, "CONNECTION LIMIT {}", limit).unwrap()
+            }
+            RoleOption::Password(password) => {
+                write!(sql, "PASSWORD '{}'", self.escape_string(password)).unwrap()
+            }
+            RoleOption::ValidUntil(timestamp) => {
+                write!(sql, "VALID UNTIL '{}'", self.escape_string(timestamp)).unwrap()
+            }
+        }
+    }
+}
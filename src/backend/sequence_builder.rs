@@ -0,0 +1,82 @@
+use crate::*;
+
+pub trait SequenceBuilder: QuotedBuilder {
+    /// Translate [`SequenceCreateStatement`] into SQL statement.
+    fn prepare_sequence_create_statement(
+        &self,
+        create: &SequenceCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "CREATE SEQUENCE ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        for option in create.options.iter() {
+            write!(sql, " ").unwrap();
+            self.prepare_sequence_option(option, sql);
+        }
+    }
+
+    /// Translate [`SequenceAlterStatement`] into SQL statement.
+    fn prepare_sequence_alter_statement(
+        &self,
+        alter: &SequenceAlterStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if alter.options.is_empty() {
+            panic!("No alter option found")
+        }
+
+        write!(sql, "ALTER SEQUENCE ").unwrap();
+
+        if let Some(name) = &alter.name {
+            name.prepare(sql, self.quote());
+        }
+
+        for option in alter.options.iter() {
+            write!(sql, " ").unwrap();
+            self.prepare_sequence_option(option, sql);
+        }
+    }
+
+    /// Translate [`SequenceDropStatement`] into SQL statement.
+    fn prepare_sequence_drop_statement(&self, drop: &SequenceDropStatement, sql: &mut SqlWriter) {
+        write!(sql, "DROP SEQUENCE ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        drop.names.iter().fold(true, |first, name| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            name.prepare(sql, self.quote());
+            false
+        });
+    }
+
+    /// Translate [`SequenceOption`] into SQL statement.
+    fn prepare_sequence_option(&self, option: &SequenceOption, sql: &mut SqlWriter) {
+        match option {
+            SequenceOption::IncrementBy(value) => write!(sql, "INCREMENT BY {}", value),
+            SequenceOption::MinValue(Some(value)) => write!(sql, "MINVALUE {}", value),
+            SequenceOption::MinValue(None) => write!(sql, "NO MINVALUE"),
+            SequenceOption::MaxValue(Some(value)) => write!(sql, "MAXVALUE {}", value),
+            SequenceOption::MaxValue(None) => write!(sql, "NO MAXVALUE"),
+            SequenceOption::StartWith(value) => write!(sql, "START WITH {}", value),
+            SequenceOption::Restart(Some(value)) => write!(sql, "RESTART WITH {}", value),
+            SequenceOption::Restart(None) => write!(sql, "RESTART"),
+            SequenceOption::Cache(value) => write!(sql, "CACHE {}", value),
+            SequenceOption::Cycle(true) => write!(sql, "CYCLE"),
+            SequenceOption::Cycle(false) => write!(sql, "NO CYCLE"),
+        }
+        .unwrap()
+    }
+}
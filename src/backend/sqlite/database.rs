@@ -0,0 +1,21 @@
+use super::*;
+
+impl_versioned! {
+impl DatabaseBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
+    fn prepare_database_create_statement(&self, _: &DatabaseCreateStatement, _: &mut SqlWriter) {
+        panic!("Sqlite not support database");
+    }
+
+    fn prepare_database_drop_statement(&self, _: &DatabaseDropStatement, _: &mut SqlWriter) {
+        panic!("Sqlite not support database");
+    }
+
+    fn prepare_schema_create_statement(&self, _: &SchemaCreateStatement, _: &mut SqlWriter) {
+        panic!("Sqlite not support schema");
+    }
+
+    fn prepare_schema_drop_statement(&self, _: &SchemaDropStatement, _: &mut SqlWriter) {
+        panic!("Sqlite not support schema");
+    }
+}
+}
@@ -1,6 +1,7 @@
 use super::*;
 
-impl ForeignKeyBuilder for SqliteQueryBuilder {
+impl_versioned! {
+impl ForeignKeyBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
     fn prepare_foreign_key_drop_statement(
         &self,
         drop: &ForeignKeyDropStatement,
@@ -11,6 +12,9 @@ impl ForeignKeyBuilder for SqliteQueryBuilder {
             table.prepare(sql, '`');
         }
 
+        if drop.if_exists {
+            panic!("Sqlite not support IF EXISTS on foreign key");
+        }
         write!(sql, " DROP FOREIGN KEY ").unwrap();
         if let Some(name) = &drop.foreign_key.name {
             write!(sql, "`{}`", name).unwrap();
@@ -55,6 +59,11 @@ impl ForeignKeyBuilder for SqliteQueryBuilder {
             });
         write!(sql, ")").unwrap();
 
+        if let Some(foreign_key_match) = &create.foreign_key.match_type {
+            write!(sql, " MATCH ").unwrap();
+            self.prepare_foreign_key_match(foreign_key_match, sql);
+        }
+
         if let Some(foreign_key_action) = &create.foreign_key.on_delete {
             write!(sql, " ON DELETE ").unwrap();
             self.prepare_foreign_key_action(foreign_key_action, sql);
@@ -64,5 +73,11 @@ impl ForeignKeyBuilder for SqliteQueryBuilder {
             write!(sql, " ON UPDATE ").unwrap();
             self.prepare_foreign_key_action(foreign_key_action, sql);
         }
+
+        if let Some(foreign_key_deferrable) = &create.foreign_key.deferrable {
+            write!(sql, " ").unwrap();
+            self.prepare_foreign_key_deferrable(foreign_key_deferrable, sql);
+        }
     }
 }
+}
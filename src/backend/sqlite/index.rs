@@ -1,6 +1,7 @@
 use super::*;
 
-impl IndexBuilder for SqliteQueryBuilder {
+impl_versioned! {
+impl IndexBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
     fn prepare_table_index_expression(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
         self.prepare_index_prefix(create, sql);
         write!(sql, "KEY ").unwrap();
@@ -10,6 +11,8 @@ impl IndexBuilder for SqliteQueryBuilder {
         // self.prepare_index_type(&create.index_type, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include, sql);
     }
 
     fn prepare_index_create_statement(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
@@ -17,6 +20,14 @@ impl IndexBuilder for SqliteQueryBuilder {
         self.prepare_index_prefix(create, sql);
         write!(sql, "INDEX ").unwrap();
 
+        if create.concurrently {
+            panic!("Sqlite not support CONCURRENTLY on index");
+        }
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
         self.prepare_index_name(&create.index.name, sql);
 
         write!(sql, " ON ").unwrap();
@@ -27,10 +38,22 @@ impl IndexBuilder for SqliteQueryBuilder {
         // self.prepare_index_type(&create.index_type, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include, sql);
+
+        self.prepare_condition_inlined(&create.r#where, "WHERE", sql);
+
+        self.prepare_index_algorithm_lock(&create.algorithm, &create.lock, sql);
     }
 
     fn prepare_index_drop_statement(&self, drop: &IndexDropStatement, sql: &mut SqlWriter) {
         write!(sql, "DROP INDEX ").unwrap();
+        if drop.concurrently {
+            panic!("Sqlite not support CONCURRENTLY on index");
+        }
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
         if let Some(name) = &drop.index.name {
             write!(sql, "`{}`", name).unwrap();
         }
@@ -39,6 +62,24 @@ impl IndexBuilder for SqliteQueryBuilder {
         if let Some(table) = &drop.table {
             table.prepare(sql, '`');
         }
+
+        self.prepare_index_algorithm_lock(&drop.algorithm, &drop.lock, sql);
+    }
+
+    fn prepare_index_reindex_statement(
+        &self,
+        reindex: &IndexReindexStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "REINDEX ").unwrap();
+        if reindex.concurrently {
+            panic!("Sqlite not support CONCURRENTLY on index");
+        }
+        if let Some(name) = &reindex.index.name {
+            write!(sql, "`{}`", name).unwrap();
+        } else if let Some(table) = &reindex.table {
+            table.prepare(sql, '`');
+        }
     }
 
     fn write_column_index_prefix(&self, _col_prefix: &Option<u32>, _sql: &mut SqlWriter) {}
@@ -51,4 +92,9 @@ impl IndexBuilder for SqliteQueryBuilder {
             write!(sql, "UNIQUE ").unwrap();
         }
     }
+
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter) {
+        self.prepare_simple_expr_inlined(expr, sql);
+    }
+}
 }
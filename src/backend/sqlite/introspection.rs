@@ -0,0 +1,137 @@
+use super::*;
+use crate::backend::introspection_builder::{
+    column_text, parse_column_type, parse_default_expr, parse_flag, required_text,
+};
+use crate::error::{Error, Result};
+
+impl_versioned! {
+impl IntrospectionBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
+    fn tables_query(&self, schema: Option<&str>) -> SelectStatement {
+        let mut query = Query::select();
+        query.column(Alias::new("name"));
+        match schema {
+            Some(schema) => query.from((Alias::new(schema), Alias::new("sqlite_master"))),
+            None => query.from(Alias::new("sqlite_master")),
+        };
+        query
+            .and_where(Expr::col(Alias::new("type")).eq("table"))
+            .and_where(Expr::col(Alias::new("name")).not_like("sqlite_%"))
+            .order_by(Alias::new("name"), Order::Asc)
+            .take()
+    }
+
+    fn columns_query(&self, schema: Option<&str>, table: &str) -> SelectStatement {
+        let mut query = Query::select();
+        query
+            .column(Alias::new("name"))
+            .column(Alias::new("type"))
+            .expr(text(Expr::col(Alias::new("notnull"))))
+            .column(Alias::new("dflt_value"))
+            .expr(text(Expr::col(Alias::new("pk"))))
+            .from(Alias::new("pragma_table_info"))
+            .and_where(Expr::col(Alias::new("arg")).eq(table));
+        if let Some(schema) = schema {
+            query.and_where(Expr::col(Alias::new("schema")).eq(schema));
+        }
+        query.order_by(Alias::new("cid"), Order::Asc).take()
+    }
+
+    fn indexes_query(&self, schema: Option<&str>, table: &str) -> SelectStatement {
+        let (il, ii) = (Alias::new("il"), Alias::new("ii"));
+        let mut on =
+            Expr::tbl(ii.clone(), Alias::new("arg")).equals(il.clone(), Alias::new("name"));
+        if let Some(schema) = schema {
+            on = on.and(Expr::tbl(ii.clone(), Alias::new("schema")).eq(schema));
+        }
+        let mut query = Query::select();
+        query
+            .column((il.clone(), Alias::new("name")))
+            .expr(text(Expr::tbl(il.clone(), Alias::new("unique"))))
+            .expr(text(Expr::tbl(il.clone(), Alias::new("origin")).eq("pk")))
+            .column((ii.clone(), Alias::new("name")))
+            .from_as(Alias::new("pragma_index_list"), il.clone())
+            .join_as(
+                JoinType::InnerJoin,
+                Alias::new("pragma_index_info"),
+                ii.clone(),
+                on,
+            )
+            .and_where(Expr::tbl(il.clone(), Alias::new("arg")).eq(table));
+        if let Some(schema) = schema {
+            query.and_where(Expr::tbl(il.clone(), Alias::new("schema")).eq(schema));
+        }
+        query
+            .order_by((il, Alias::new("name")), Order::Asc)
+            .order_by((ii, Alias::new("seqno")), Order::Asc)
+            .take()
+    }
+
+    fn foreign_keys_query(&self, schema: Option<&str>, table: &str) -> SelectStatement {
+        let mut query = Query::select();
+        query
+            .expr(text(Expr::col(Alias::new("id"))))
+            .column(Alias::new("from"))
+            .column(Alias::new("table"))
+            .column(Alias::new("to"))
+            .column(Alias::new("on_update"))
+            .column(Alias::new("on_delete"))
+            .from(Alias::new("pragma_foreign_key_list"))
+            .and_where(Expr::col(Alias::new("arg")).eq(table));
+        if let Some(schema) = schema {
+            query.and_where(Expr::col(Alias::new("schema")).eq(schema));
+        }
+        query
+            .order_by(Alias::new("id"), Order::Asc)
+            .order_by(Alias::new("seq"), Order::Asc)
+            .take()
+    }
+
+    /// The row holds the name, the declared type, whether the column is `NOT NULL`, the
+    /// default as written in the table definition and the position in the primary key.
+    /// `AUTOINCREMENT` is not reported by SQLite.
+    fn parse_column(&self, row: &[Option<String>]) -> Result<ColumnDef> {
+        let mut column = ColumnDef::new(Alias::new(required_text(row, 0, "column name")?));
+        column.types = match required_text(row, 1, "column type")? {
+            "" => None,
+            column_type => Some(parse_column_type(column_type)),
+        };
+        if parse_flag(required_text(row, 2, "column nullability")?)? {
+            column.not_null();
+        }
+        if let Some(default) = column_text(row, 3)? {
+            column.default_expr(parse_default_expr(default));
+        }
+        Ok(column)
+    }
+
+    fn parse_column_primary_key(&self, row: &[Option<String>]) -> Result<Option<u32>> {
+        let position = required_text(row, 4, "column primary key")?;
+        match position.parse::<u32>() {
+            Ok(0) => Ok(None),
+            Ok(position) => Ok(Some(position)),
+            Err(_) => Err(Error::Introspection(format!(
+                "{} is not a primary key position",
+                position
+            ))),
+        }
+    }
+
+    /// Indexes of `UNIQUE` and `PRIMARY KEY` constraints are named `sqlite_autoindex_*`
+    fn index_name(&self, name: &str) -> Option<String> {
+        if name.starts_with("sqlite_autoindex_") {
+            None
+        } else {
+            Some(name.to_owned())
+        }
+    }
+
+    /// Foreign keys are not named in SQLite, they are told apart by a sequence number
+    fn foreign_key_name(&self, _name: &str) -> Option<String> {
+        None
+    }
+}
+}
+
+fn text(expr: impl Into<SimpleExpr>) -> SimpleExpr {
+    Expr::cast_as(expr.into(), ColumnType::Text).into()
+}
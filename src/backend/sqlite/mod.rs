@@ -1,11 +1,20 @@
+pub(crate) mod database;
 pub(crate) mod foreign_key;
 pub(crate) mod index;
+pub(crate) mod introspection;
 pub(crate) mod query;
+pub(crate) mod role;
+pub(crate) mod sequence;
 pub(crate) mod table;
+pub(crate) mod transaction;
+pub(crate) mod trigger;
+pub(crate) mod view;
 
 use super::*;
 
 /// Sqlite query builder.
+///
+/// Renders for the latest SQLite, use [`SqliteQueryBuilder::version`] to target an older version.
 #[derive(Debug)]
 pub struct SqliteQueryBuilder;
 
@@ -15,12 +24,61 @@ impl Default for SqliteQueryBuilder {
     }
 }
 
-impl GenericBuilder for SqliteQueryBuilder {}
+/// Sqlite query builder targeting a specific SQLite version.
+///
+/// Constructed with [`SqliteQueryBuilder::version`].
+#[derive(Debug, Clone, Copy)]
+pub struct VersionedSqliteQueryBuilder {
+    version: (u32, u32),
+}
+
+impl SqliteQueryBuilder {
+    /// Target a specific SQLite version, given as major and minor version.
+    /// Features introduced in later versions are rejected: `try_build` returns
+    /// [`Error::Unsupported`](crate::error::Error::Unsupported), while `build` panics.
+    pub fn version(self, major: u32, minor: u32) -> VersionedSqliteQueryBuilder {
+        VersionedSqliteQueryBuilder {
+            version: (major, minor),
+        }
+    }
+
+    pub(crate) fn version_at_least(&self, _major: u32, _minor: u32) -> bool {
+        true
+    }
+}
 
-impl SchemaBuilder for SqliteQueryBuilder {}
+impl VersionedSqliteQueryBuilder {
+    /// Whether the targeted version is at least the given one.
+    pub(crate) fn version_at_least(&self, major: u32, minor: u32) -> bool {
+        self.version >= (major, minor)
+    }
+}
+
+impl_versioned! {
+impl GenericBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {}
+}
 
-impl QuotedBuilder for SqliteQueryBuilder {
+impl_versioned! {
+impl SchemaBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {}
+}
+
+impl_versioned! {
+impl PlaceholderBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {}
+}
+
+impl_versioned! {
+impl PaginationBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {}
+}
+
+impl_versioned! {
+impl QuotedBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
     fn quote(&self) -> char {
         '`'
     }
+
+    fn escape_string(&self, string: &str) -> String {
+        // SQLite has no backslash escapes, a quote is escaped by doubling it
+        string.replace('\'', "''")
+    }
+}
 }
@@ -1,7 +1,225 @@
 use super::*;
 
-impl QueryBuilder for SqliteQueryBuilder {
+impl_versioned! {
+impl QueryBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
+    fn backend_name(&self) -> &'static str {
+        "SQLite"
+    }
+
     fn char_length_function(&self) -> &str {
         "LENGTH"
     }
+
+    fn greatest_function(&self) -> &str {
+        "MAX"
+    }
+
+    fn least_function(&self) -> &str {
+        "MIN"
+    }
+
+    fn write_bool(&self, value: bool, buffer: &mut String) {
+        write!(buffer, "{}", if value { 1 } else { 0 }).unwrap()
+    }
+
+    fn iso_8601_offset(&self) -> bool {
+        true
+    }
+
+    fn prepare_bin_oper(
+        &self,
+        bin_oper: &BinOper,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        match bin_oper {
+            BinOper::IsDistinctFrom => write!(sql, "IS NOT").unwrap(),
+            BinOper::IsNotDistinctFrom => write!(sql, "IS").unwrap(),
+            _ => self.prepare_bin_oper_common(bin_oper, sql, collector),
+        }
+    }
+
+    fn grouping_supported(&self, _grouping: &Grouping) -> bool {
+        false
+    }
+
+    fn prepare_group_by(
+        &self,
+        groups: &[SimpleExpr],
+        grouping: &Option<Grouping>,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if grouping.is_some() {
+            panic!("ROLLUP, CUBE and GROUPING SETS are not supported by this backend");
+        }
+        if groups.is_empty() {
+            return;
+        }
+        write!(sql, " GROUP BY ").unwrap();
+        groups.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_simple_expr(expr, sql, collector);
+            false
+        });
+    }
+
+    fn prepare_string_agg(
+        &self,
+        exprs: &[SimpleExpr],
+        separator: &str,
+        orders: &[OrderExpr],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "GROUP_CONCAT(").unwrap();
+        exprs.iter().for_each(|expr| {
+            self.prepare_simple_expr(expr, sql, collector);
+            write!(sql, ", ").unwrap();
+        });
+        self.prepare_string_agg_separator(separator, sql);
+        self.prepare_string_agg_order_by(orders, sql, collector);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_bit_xor(
+        &self,
+        left: &SimpleExpr,
+        right: &SimpleExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        // SQLite has no exclusive or operator
+        write!(sql, "(").unwrap();
+        self.binary_expr(left, &BinOper::BitOr, right, sql, collector);
+        write!(sql, ") - (").unwrap();
+        self.binary_expr(left, &BinOper::BitAnd, right, sql, collector);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_case_insensitive_regexp(
+        &self,
+        left: &SimpleExpr,
+        op: &BinOper,
+        right: &SimpleExpr,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        if left.is_binary() {
+            write!(sql, "(").unwrap();
+            self.prepare_simple_expr(left, sql, collector);
+            write!(sql, ")").unwrap();
+        } else {
+            self.prepare_simple_expr(left, sql, collector);
+        }
+        write!(sql, " ").unwrap();
+        self.prepare_bin_oper(op, sql, collector);
+        // The inline flag is understood by the `regexp` functions of common extensions
+        write!(sql, " '(?i)' || ").unwrap();
+        self.prepare_simple_expr(right, sql, collector);
+    }
+
+    fn multi_column_full_text_search_supported(&self) -> bool {
+        false
+    }
+
+    fn prepare_full_text_match(
+        &self,
+        columns: &[SimpleExpr],
+        query: &SimpleExpr,
+        _mode: &SearchMode,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        match columns {
+            [column] => self.prepare_simple_expr(column, sql, collector),
+            _ => panic!("Full text search matches a single column or FTS5 table in SQLite"),
+        }
+        write!(sql, " MATCH ").unwrap();
+        self.prepare_simple_expr(query, sql, collector);
+    }
+
+    fn distinct_on_supported(&self) -> bool {
+        false
+    }
+
+    fn full_outer_join_supported(&self) -> bool {
+        false
+    }
+
+    fn lateral_join_supported(&self) -> bool {
+        false
+    }
+
+    fn window_function_supported(&self) -> bool {
+        self.version_at_least(3, 25)
+    }
+
+    fn on_conflict_supported(&self) -> bool {
+        self.version_at_least(3, 24)
+    }
+
+    fn returning_supported(&self) -> bool {
+        self.version_at_least(3, 35)
+    }
+
+    fn array_value_supported(&self) -> bool {
+        false
+    }
+
+    fn quantified_comparison_supported(&self) -> bool {
+        false
+    }
+
+    fn insert_default_keyword_supported(&self) -> bool {
+        false
+    }
+
+    fn prepare_union_statement(
+        &self,
+        union_type: &UnionType,
+        select: &SelectStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, " ").unwrap();
+        self.prepare_union_type(union_type, sql, collector);
+        write!(sql, " ").unwrap();
+        self.prepare_select_statement(select, sql, collector);
+    }
+
+    fn prepare_values_list(
+        &self,
+        rows: &[Values],
+        alias: &DynIden,
+        cols: &[DynIden],
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        // SQLite cannot name the columns of a `VALUES` list, so select each row instead
+        write!(sql, "(").unwrap();
+        rows.iter().enumerate().for_each(|(i, row)| {
+            if i > 0 {
+                write!(sql, " UNION ALL ").unwrap();
+            }
+            write!(sql, "SELECT ").unwrap();
+            row.0.iter().enumerate().for_each(|(j, value)| {
+                if j > 0 {
+                    write!(sql, ", ").unwrap();
+                }
+                self.prepare_value(value, sql, collector);
+                if i == 0 {
+                    if let Some(col) = cols.get(j) {
+                        write!(sql, " AS ").unwrap();
+                        col.prepare(sql, self.quote());
+                    }
+                }
+            });
+        });
+        write!(sql, ") AS ").unwrap();
+        alias.prepare(sql, self.quote());
+    }
+}
 }
@@ -0,0 +1,13 @@
+use super::*;
+
+impl_versioned! {
+impl RoleBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
+    fn prepare_role_create_statement(&self, _: &RoleCreateStatement, _: &mut SqlWriter) {
+        panic!("Sqlite not support role");
+    }
+
+    fn prepare_role_drop_statement(&self, _: &RoleDropStatement, _: &mut SqlWriter) {
+        panic!("Sqlite not support role");
+    }
+}
+}
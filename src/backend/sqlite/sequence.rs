@@ -0,0 +1,17 @@
+use super::*;
+
+impl_versioned! {
+impl SequenceBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
+    fn prepare_sequence_create_statement(&self, _: &SequenceCreateStatement, _: &mut SqlWriter) {
+        panic!("Sqlite not support sequence");
+    }
+
+    fn prepare_sequence_alter_statement(&self, _: &SequenceAlterStatement, _: &mut SqlWriter) {
+        panic!("Sqlite not support sequence");
+    }
+
+    fn prepare_sequence_drop_statement(&self, _: &SequenceDropStatement, _: &mut SqlWriter) {
+        panic!("Sqlite not support sequence");
+    }
+}
+}
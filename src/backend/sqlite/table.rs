@@ -1,12 +1,17 @@
 use super::*;
 
-impl TableBuilder for SqliteQueryBuilder {
+impl_versioned! {
+impl TableBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
     fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut SqlWriter) {
         column_def.name.prepare(sql, '`');
 
         if let Some(column_type) = &column_def.types {
             write!(sql, " ").unwrap();
             self.prepare_column_type(column_type, sql);
+            if let ColumnType::Enum(_, variants) = column_type {
+                write!(sql, " ").unwrap();
+                self.prepare_enum_check(&column_def.name, variants, sql);
+            }
         }
 
         let mut is_primary_key = false;
@@ -21,6 +26,13 @@ impl TableBuilder for SqliteQueryBuilder {
                 is_auto_increment = true;
                 continue;
             }
+            if let ColumnSpec::Identity(identity) = column_spec {
+                if identity.start.is_some() || identity.increment.is_some() {
+                    panic!("Sqlite not support identity start or increment");
+                }
+                is_auto_increment = true;
+                continue;
+            }
             write!(sql, " ").unwrap();
             self.prepare_column_spec(column_spec, sql);
         }
@@ -35,6 +47,140 @@ impl TableBuilder for SqliteQueryBuilder {
         }
     }
 
+    fn prepare_column_spec(&self, column_spec: &ColumnSpec, sql: &mut SqlWriter) {
+        match column_spec {
+            ColumnSpec::Null => write!(sql, "NULL"),
+            ColumnSpec::NotNull => write!(sql, "NOT NULL"),
+            ColumnSpec::Default(value) => {
+                write!(sql, "DEFAULT ").unwrap();
+                self.prepare_column_default(value, sql);
+                Ok(())
+            }
+            ColumnSpec::AutoIncrement | ColumnSpec::Identity(_) => write!(sql, "AUTOINCREMENT"),
+            ColumnSpec::UniqueKey => write!(sql, "UNIQUE"),
+            ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY"),
+            ColumnSpec::Check(check) => {
+                self.prepare_check_constraint(check, sql);
+                Ok(())
+            }
+            ColumnSpec::Generated(expr, storage) => {
+                write!(sql, "GENERATED ALWAYS AS (").unwrap();
+                self.prepare_simple_expr_inlined(expr, sql);
+                write!(
+                    sql,
+                    ") {}",
+                    match storage {
+                        GeneratedStorage::Stored => "STORED",
+                        GeneratedStorage::Virtual => "VIRTUAL",
+                    }
+                )
+            }
+            ColumnSpec::Extra(string) => write!(sql, "{}", string),
+        }
+        .unwrap()
+    }
+
+    fn prepare_table_create_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(SqliteQueryBuilder)).unwrap();
+    }
+
+    fn prepare_check_constraint(&self, check: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, "CHECK (").unwrap();
+        self.prepare_simple_expr_inlined(check, sql);
+        write!(sql, ")").unwrap();
+    }
+
+    fn prepare_table_partition(&self, _table_partition: &TablePartition, _sql: &mut SqlWriter) {
+        panic!("Sqlite not support table partitioning");
+    }
+
+    fn prepare_partition_values(&self, _values: &PartitionValues, _sql: &mut SqlWriter) {
+        panic!("Sqlite not support table partitioning");
+    }
+
+    fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
+        if alter.options.is_empty() {
+            panic!("No alter option found")
+        }
+        // Sqlite allows one alteration per statement
+        alter.options.iter().fold(true, |first, option| {
+            if !first {
+                write!(sql, "; ").unwrap();
+            }
+            write!(sql, "ALTER TABLE ").unwrap();
+            if let Some(table) = &alter.table {
+                table.prepare(sql, '`');
+                write!(sql, " ").unwrap();
+            }
+            self.prepare_table_alter_option(option, sql);
+            false
+        });
+    }
+
+    fn prepare_table_alter_option(&self, alter_option: &TableAlterOption, sql: &mut SqlWriter) {
+        match alter_option {
+            TableAlterOption::AddColumn(column_def) => {
+                write!(sql, "ADD COLUMN ").unwrap();
+                self.prepare_column_def(column_def, sql);
+            }
+            TableAlterOption::ModifyColumn(_)
+            | TableAlterOption::ModifyColumnUsing(_, _)
+            | TableAlterOption::DropColumnDefault(_) => {
+                panic!("Sqlite not support modifying table column")
+            }
+            TableAlterOption::RenameColumn(from_name, to_name)
+            | TableAlterOption::ChangeColumn(from_name, ColumnDef { name: to_name, .. }) => {
+                write!(sql, "RENAME COLUMN ").unwrap();
+                from_name.prepare(sql, '`');
+                write!(sql, " TO ").unwrap();
+                to_name.prepare(sql, '`');
+            }
+            TableAlterOption::DropColumn(_) => {
+                panic!("Sqlite not support dropping table column")
+            }
+            TableAlterOption::AddCheckConstraint(_, _) => {
+                panic!("Sqlite not support adding table constraint")
+            }
+            TableAlterOption::DropConstraint(_) => {
+                panic!("Sqlite not support dropping table constraint")
+            }
+        }
+    }
+
+    fn prepare_table_truncate_statement(
+        &self,
+        truncate: &TableTruncateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if truncate.tables.len() > 1 {
+            panic!("Sqlite not support truncating multiple tables");
+        }
+        if truncate.identity.is_some() || !truncate.options.is_empty() {
+            panic!("Sqlite not support truncate options");
+        }
+
+        write!(sql, "DELETE FROM ").unwrap();
+
+        if let Some(table) = truncate.tables.first() {
+            table.prepare(sql, '`');
+        }
+    }
+
+    fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter) {
+        write!(sql, "ALTER TABLE ").unwrap();
+        if let Some(from_name) = &rename.from_name {
+            from_name.prepare(sql, '`');
+        }
+        write!(sql, " RENAME TO ").unwrap();
+        if let Some(to_name) = &rename.to_name {
+            to_name.prepare(sql, '`');
+        }
+    }
+}
+}
+
+impl_versioned! {
+impl ColumnTypeBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
     fn prepare_column_type(&self, column_type: &ColumnType, sql: &mut SqlWriter) {
         write!(
             sql,
@@ -106,65 +252,15 @@ impl TableBuilder for SqliteQueryBuilder {
                 ColumnType::Json => "text".into(),
                 ColumnType::JsonBinary => "text".into(),
                 ColumnType::Uuid => "text(36)".into(),
+                ColumnType::Inet => "text".into(),
+                ColumnType::Cidr => "text".into(),
+                ColumnType::MacAddr => "text".into(),
+                ColumnType::Enum(_, _) => "text".into(),
+                ColumnType::Array(_) => panic!("Sqlite not support array column type"),
                 ColumnType::Custom(iden) => iden.to_string(),
             }
         )
         .unwrap()
     }
-
-    fn prepare_column_spec(&self, column_spec: &ColumnSpec, sql: &mut SqlWriter) {
-        match column_spec {
-            ColumnSpec::Null => write!(sql, "NULL"),
-            ColumnSpec::NotNull => write!(sql, "NOT NULL"),
-            ColumnSpec::Default(value) => write!(sql, "DEFAULT {}", self.value_to_string(value)),
-            ColumnSpec::AutoIncrement => write!(sql, "AUTOINCREMENT"),
-            ColumnSpec::UniqueKey => write!(sql, "UNIQUE"),
-            ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY"),
-            ColumnSpec::Extra(string) => write!(sql, "{}", string),
-        }
-        .unwrap()
-    }
-
-    fn prepare_table_partition(&self, _table_partition: &TablePartition, _sql: &mut SqlWriter) {}
-
-    fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
-        let alter_option = match &alter.alter_option {
-            Some(alter_option) => alter_option,
-            None => panic!("No alter option found"),
-        };
-        write!(sql, "ALTER TABLE ").unwrap();
-        if let Some(table) = &alter.table {
-            table.prepare(sql, '`');
-            write!(sql, " ").unwrap();
-        }
-        match alter_option {
-            TableAlterOption::AddColumn(column_def) => {
-                write!(sql, "ADD COLUMN ").unwrap();
-                self.prepare_column_def(column_def, sql);
-            }
-            TableAlterOption::ModifyColumn(_) => {
-                panic!("Sqlite not support modifying table column")
-            }
-            TableAlterOption::RenameColumn(from_name, to_name) => {
-                write!(sql, "RENAME COLUMN ").unwrap();
-                from_name.prepare(sql, '`');
-                write!(sql, " TO ").unwrap();
-                to_name.prepare(sql, '`');
-            }
-            TableAlterOption::DropColumn(_) => {
-                panic!("Sqlite not support dropping table column")
-            }
-        }
-    }
-
-    fn prepare_table_rename_statement(&self, rename: &TableRenameStatement, sql: &mut SqlWriter) {
-        write!(sql, "ALTER TABLE ").unwrap();
-        if let Some(from_name) = &rename.from_name {
-            from_name.prepare(sql, '`');
-        }
-        write!(sql, " RENAME TO ").unwrap();
-        if let Some(to_name) = &rename.to_name {
-            to_name.prepare(sql, '`');
-        }
-    }
+}
 }
@@ -0,0 +1,20 @@
+use super::*;
+
+impl_versioned! {
+impl TransactionBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
+    fn prepare_transaction_begin_statement(
+        &self,
+        begin: &TransactionBeginStatement,
+        sql: &mut SqlWriter,
+    ) {
+        if begin.isolation_level.is_some() || begin.access_mode.is_some() {
+            panic!("Sqlite not support transaction modes");
+        }
+        write!(sql, "BEGIN").unwrap();
+    }
+
+    fn prepare_transaction_set_statement(&self, _: &TransactionSetStatement, _: &mut SqlWriter) {
+        panic!("Sqlite not support SET TRANSACTION");
+    }
+}
+}
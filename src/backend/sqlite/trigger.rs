@@ -0,0 +1,44 @@
+use super::*;
+
+impl_versioned! {
+impl TriggerBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
+    fn prepare_trigger_events(&self, events: &[TriggerEvent], sql: &mut SqlWriter) {
+        if events.len() > 1 {
+            panic!("Sqlite not support multiple trigger events");
+        }
+        match &events[0] {
+            TriggerEvent::Truncate => panic!("Sqlite not support TRUNCATE trigger"),
+            event => self.prepare_trigger_event(event, sql),
+        }
+    }
+
+    fn prepare_trigger_scope(&self, scope: &Option<TriggerScope>, sql: &mut SqlWriter) {
+        match scope {
+            Some(TriggerScope::Row) => write!(sql, " FOR EACH ROW").unwrap(),
+            Some(TriggerScope::Statement) => {
+                panic!("Sqlite not support FOR EACH STATEMENT trigger")
+            }
+            None => {}
+        }
+    }
+
+    fn prepare_trigger_when(&self, condition: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, " WHEN ").unwrap();
+        self.prepare_simple_expr_inlined(condition, sql);
+    }
+
+    fn prepare_trigger_action(&self, action: &TriggerAction, sql: &mut SqlWriter) {
+        match action {
+            TriggerAction::Function(_) => panic!("Sqlite not support trigger function"),
+            TriggerAction::Body(statements) => {
+                write!(sql, " BEGIN ").unwrap();
+                for statement in statements.iter() {
+                    self.prepare_query_statement_inlined(statement, sql);
+                    write!(sql, "; ").unwrap();
+                }
+                write!(sql, "END").unwrap();
+            }
+        }
+    }
+}
+}
@@ -0,0 +1,22 @@
+use super::*;
+
+impl_versioned! {
+impl ViewBuilder for SqliteQueryBuilder, VersionedSqliteQueryBuilder {
+    fn prepare_view_create_kind(&self, create: &ViewCreateStatement, _sql: &mut SqlWriter) {
+        if create.materialized {
+            panic!("Sqlite not support materialized view");
+        }
+        if create.or_replace {
+            panic!("Sqlite not support OR REPLACE on view");
+        }
+    }
+
+    fn prepare_view_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(SqliteQueryBuilder)).unwrap();
+    }
+
+    fn prepare_view_check_option(&self, _sql: &mut SqlWriter) {
+        panic!("Sqlite not support WITH CHECK OPTION");
+    }
+}
+}
@@ -1,9 +1,15 @@
 use crate::*;
 
-pub trait TableBuilder: IndexBuilder + ForeignKeyBuilder + QuotedBuilder {
+pub trait TableBuilder:
+    IndexBuilder + ForeignKeyBuilder + QuotedBuilder + ColumnTypeBuilder
+{
     /// Translate [`TableCreateStatement`] into SQL statement.
     fn prepare_table_create_statement(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
-        write!(sql, "CREATE TABLE ").unwrap();
+        write!(sql, "CREATE ").unwrap();
+
+        self.prepare_table_create_kind(create, sql);
+
+        write!(sql, "TABLE ").unwrap();
 
         if create.if_not_exists {
             write!(sql, "IF NOT EXISTS ").unwrap();
@@ -13,50 +19,132 @@ pub trait TableBuilder: IndexBuilder + ForeignKeyBuilder + QuotedBuilder {
             table.prepare(sql, self.quote());
         }
 
-        write!(sql, " ( ").unwrap();
-        let mut count = 0;
+        if let Some((parent, _)) = &create.partition_of {
+            self.prepare_table_partition_of(parent, sql);
+        }
 
-        for column_def in create.columns.iter() {
-            if count > 0 {
-                write!(sql, ", ").unwrap();
+        let has_definitions = !create.columns.is_empty()
+            || !create.indexes.is_empty()
+            || !create.foreign_keys.is_empty()
+            || !create.checks.is_empty();
+
+        if has_definitions || (create.query.is_none() && create.partition_of.is_none()) {
+            write!(sql, " ( ").unwrap();
+            let mut count = 0;
+
+            for column_def in create.columns.iter() {
+                if count > 0 {
+                    write!(sql, ", ").unwrap();
+                }
+                self.prepare_column_def(column_def, sql);
+                count += 1;
             }
-            self.prepare_column_def(column_def, sql);
-            count += 1;
-        }
 
-        for index in create.indexes.iter() {
-            if count > 0 {
-                write!(sql, ", ").unwrap();
+            for index in create.indexes.iter() {
+                if count > 0 {
+                    write!(sql, ", ").unwrap();
+                }
+                self.prepare_table_index_expression(index, sql);
+                count += 1;
             }
-            self.prepare_table_index_expression(index, sql);
-            count += 1;
-        }
 
-        for foreign_key in create.foreign_keys.iter() {
-            if count > 0 {
-                write!(sql, ", ").unwrap();
+            for foreign_key in create.foreign_keys.iter() {
+                if count > 0 {
+                    write!(sql, ", ").unwrap();
+                }
+                self.prepare_foreign_key_create_statement_internal(foreign_key, sql, true);
+                count += 1;
+            }
+
+            for check in create.checks.iter() {
+                if count > 0 {
+                    write!(sql, ", ").unwrap();
+                }
+                self.prepare_check_constraint(check, sql);
+                count += 1;
             }
-            self.prepare_foreign_key_create_statement_internal(foreign_key, sql, true);
-            count += 1;
+
+            write!(sql, " )").unwrap();
         }
 
-        write!(sql, " )").unwrap();
+        if let Some((_, values)) = &create.partition_of {
+            write!(sql, " ").unwrap();
+            self.prepare_partition_values(values, sql);
+        }
 
         for table_opt in create.options.iter() {
             write!(sql, " ").unwrap();
             self.prepare_table_opt(table_opt, sql);
         }
+
+        self.prepare_table_identity_opt(create, sql);
+
+        for partition in create.partitions.iter() {
+            if let TablePartition::By(..) = partition {
+                write!(sql, " ").unwrap();
+                self.prepare_table_partition(partition, sql);
+            }
+        }
+
+        let mut definitions = 0;
+        for partition in create.partitions.iter() {
+            if let TablePartition::Definition(..) = partition {
+                write!(sql, "{}", if definitions == 0 { " ( " } else { ", " }).unwrap();
+                self.prepare_table_partition(partition, sql);
+                definitions += 1;
+            }
+        }
+        if definitions > 0 {
+            write!(sql, " )").unwrap();
+        }
+
+        if let Some(query) = &create.query {
+            write!(sql, " AS ").unwrap();
+            self.prepare_table_create_query(query, sql);
+            if create.with_no_data {
+                self.prepare_table_create_no_data(sql);
+            }
+        }
+    }
+
+    /// Write `WITH NO DATA` after the query of a `CREATE TABLE ... AS SELECT`.
+    fn prepare_table_create_no_data(&self, _sql: &mut SqlWriter) {
+        panic!("WITH NO DATA is not supported by this backend");
+    }
+
+    /// Write the keywords between `CREATE` and `TABLE`, e.g. `TEMPORARY`.
+    fn prepare_table_create_kind(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        if create.temporary {
+            write!(sql, "TEMPORARY ").unwrap();
+        }
     }
 
+    /// Translate the query of a `CREATE TABLE ... AS SELECT` into SQL statement.
+    fn prepare_table_create_query(&self, query: &SelectStatement, sql: &mut SqlWriter);
+
     /// Translate [`ColumnDef`] into SQL statement.
     fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut SqlWriter);
 
-    /// Translate [`ColumnType`] into SQL statement.
-    fn prepare_column_type(&self, column_type: &ColumnType, sql: &mut SqlWriter);
+    #[doc(hidden)]
+    /// Check [`ColumnDef`] for features not supported by the backend.
+    fn check_column_def(&self, _column_def: &ColumnDef) -> error::Result<()> {
+        Ok(())
+    }
 
     /// Translate [`ColumnSpec`] into SQL statement.
     fn prepare_column_spec(&self, column_spec: &ColumnSpec, sql: &mut SqlWriter);
 
+    /// Translate a `CHECK` constraint into SQL statement.
+    fn prepare_check_constraint(&self, check: &SimpleExpr, sql: &mut SqlWriter);
+
+    /// Translate the variants of an enum column, stored as a string, into a `CHECK` constraint.
+    fn prepare_enum_check(&self, column: &DynIden, variants: &[DynIden], sql: &mut SqlWriter) {
+        self.prepare_check_constraint(
+            &Expr::col(column.clone()).is_in(variants.iter().map(|variant| variant.to_string())),
+            sql,
+        );
+    }
+
     /// Translate [`TableOpt`] into SQL statement.
     fn prepare_table_opt(&self, table_opt: &TableOpt, sql: &mut SqlWriter) {
         write!(
@@ -66,14 +154,58 @@ pub trait TableBuilder: IndexBuilder + ForeignKeyBuilder + QuotedBuilder {
                 TableOpt::Engine(s) => format!("ENGINE={}", s),
                 TableOpt::Collate(s) => format!("COLLATE={}", s),
                 TableOpt::CharacterSet(s) => format!("DEFAULT CHARSET={}", s),
+                TableOpt::OrderBy(_) => {
+                    panic!("ORDER BY table option is not supported by this backend")
+                }
             }
         )
         .unwrap()
     }
 
+    /// Hook to write the table options required by the identity columns of a table.
+    fn prepare_table_identity_opt(&self, _create: &TableCreateStatement, _sql: &mut SqlWriter) {}
+
     /// Translate [`TablePartition`] into SQL statement.
     fn prepare_table_partition(&self, table_partition: &TablePartition, sql: &mut SqlWriter);
 
+    /// Translate [`PartitionValues`] into SQL statement.
+    fn prepare_partition_values(&self, values: &PartitionValues, sql: &mut SqlWriter);
+
+    /// Write `PARTITION OF` the parent table.
+    fn prepare_table_partition_of(&self, _parent: &DynIden, _sql: &mut SqlWriter) {
+        panic!("PARTITION OF is not supported by this backend");
+    }
+
+    /// Write the partition type and columns of `PARTITION BY`.
+    fn prepare_partition_by(
+        &self,
+        r#type: &PartitionType,
+        columns: &[DynIden],
+        sql: &mut SqlWriter,
+    ) {
+        write!(
+            sql,
+            "PARTITION BY {} (",
+            match r#type {
+                PartitionType::Range => "RANGE",
+                PartitionType::List => "LIST",
+                PartitionType::Hash => "HASH",
+            }
+        )
+        .unwrap();
+        columns.iter().fold(true, |first, column| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            column.prepare(sql, self.quote());
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    /// Translate [`TableAlterOption`] into SQL statement.
+    fn prepare_table_alter_option(&self, alter_option: &TableAlterOption, sql: &mut SqlWriter);
+
     /// Translate [`TableDropStatement`] into SQL statement.
     fn prepare_table_drop_statement(&self, drop: &TableDropStatement, sql: &mut SqlWriter) {
         write!(sql, "DROP TABLE ").unwrap();
@@ -117,8 +249,29 @@ pub trait TableBuilder: IndexBuilder + ForeignKeyBuilder + QuotedBuilder {
     ) {
         write!(sql, "TRUNCATE TABLE ").unwrap();
 
-        if let Some(table) = &truncate.table {
+        truncate.tables.iter().fold(true, |first, table| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
             table.prepare(sql, self.quote());
+            false
+        });
+
+        if let Some(identity) = &truncate.identity {
+            write!(
+                sql,
+                " {}",
+                match identity {
+                    TableTruncateIdentity::Restart => "RESTART IDENTITY",
+                    TableTruncateIdentity::Continue => "CONTINUE IDENTITY",
+                }
+            )
+            .unwrap();
+        }
+
+        for truncate_opt in truncate.options.iter() {
+            write!(sql, " ").unwrap();
+            self.prepare_table_drop_opt(truncate_opt, sql);
         }
     }
 
@@ -0,0 +1,86 @@
+use crate::*;
+
+pub trait TransactionBuilder: QuotedBuilder {
+    /// Translate [`TransactionStatement`] into SQL statement.
+    fn prepare_transaction_statement(&self, statement: &TransactionStatement, sql: &mut SqlWriter) {
+        match statement {
+            TransactionStatement::Begin(begin) => {
+                self.prepare_transaction_begin_statement(begin, sql)
+            }
+            TransactionStatement::Set(set) => self.prepare_transaction_set_statement(set, sql),
+            TransactionStatement::Commit => write!(sql, "COMMIT").unwrap(),
+            TransactionStatement::Rollback(savepoint) => {
+                write!(sql, "ROLLBACK").unwrap();
+                if let Some(savepoint) = savepoint {
+                    write!(sql, " TO SAVEPOINT ").unwrap();
+                    savepoint.prepare(sql, self.quote());
+                }
+            }
+            TransactionStatement::Savepoint(savepoint) => {
+                write!(sql, "SAVEPOINT ").unwrap();
+                savepoint.prepare(sql, self.quote());
+            }
+            TransactionStatement::ReleaseSavepoint(savepoint) => {
+                write!(sql, "RELEASE SAVEPOINT ").unwrap();
+                savepoint.prepare(sql, self.quote());
+            }
+        }
+    }
+
+    /// Translate [`TransactionBeginStatement`] into SQL statement.
+    fn prepare_transaction_begin_statement(
+        &self,
+        begin: &TransactionBeginStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "START TRANSACTION").unwrap();
+        self.prepare_transaction_modes(&begin.isolation_level, &begin.access_mode, sql);
+    }
+
+    /// Translate [`TransactionSetStatement`] into SQL statement.
+    fn prepare_transaction_set_statement(
+        &self,
+        set: &TransactionSetStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "SET TRANSACTION").unwrap();
+        self.prepare_transaction_modes(&set.isolation_level, &set.access_mode, sql);
+    }
+
+    #[doc(hidden)]
+    /// Write isolation level and access mode, separated by comma.
+    fn prepare_transaction_modes(
+        &self,
+        isolation_level: &Option<TransactionIsolationLevel>,
+        access_mode: &Option<TransactionAccessMode>,
+        sql: &mut SqlWriter,
+    ) {
+        if let Some(level) = isolation_level {
+            write!(
+                sql,
+                " ISOLATION LEVEL {}",
+                match level {
+                    TransactionIsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+                    TransactionIsolationLevel::ReadCommitted => "READ COMMITTED",
+                    TransactionIsolationLevel::RepeatableRead => "REPEATABLE READ",
+                    TransactionIsolationLevel::Serializable => "SERIALIZABLE",
+                }
+            )
+            .unwrap();
+        }
+        if let Some(mode) = access_mode {
+            if isolation_level.is_some() {
+                write!(sql, ",").unwrap();
+            }
+            write!(
+                sql,
+                " {}",
+                match mode {
+                    TransactionAccessMode::ReadOnly => "READ ONLY",
+                    TransactionAccessMode::ReadWrite => "READ WRITE",
+                }
+            )
+            .unwrap();
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::*;
+
+pub trait TriggerBuilder: QuotedBuilder {
+    /// Translate [`TriggerCreateStatement`] into SQL statement.
+    fn prepare_trigger_create_statement(
+        &self,
+        create: &TriggerCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "CREATE TRIGGER ").unwrap();
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if let Some(timing) = &create.timing {
+            write!(sql, " ").unwrap();
+            self.prepare_trigger_timing(timing, sql);
+        }
+
+        if create.events.is_empty() {
+            panic!("No trigger event found");
+        }
+        write!(sql, " ").unwrap();
+        self.prepare_trigger_events(&create.events, sql);
+
+        write!(sql, " ON ").unwrap();
+        if let Some(table) = &create.table {
+            table.prepare(sql, self.quote());
+        }
+
+        self.prepare_trigger_scope(&create.scope, sql);
+
+        if let Some(when) = &create.when {
+            self.prepare_trigger_when(when, sql);
+        }
+
+        match &create.action {
+            Some(action) => self.prepare_trigger_action(action, sql),
+            None => panic!("No trigger action found"),
+        }
+    }
+
+    /// Translate [`TriggerDropStatement`] into SQL statement.
+    fn prepare_trigger_drop_statement(&self, drop: &TriggerDropStatement, sql: &mut SqlWriter) {
+        write!(sql, "DROP TRIGGER ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &drop.name {
+            name.prepare(sql, self.quote());
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write the timing of a trigger.
+    fn prepare_trigger_timing(&self, timing: &TriggerTiming, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            "{}",
+            match timing {
+                TriggerTiming::Before => "BEFORE",
+                TriggerTiming::After => "AFTER",
+                TriggerTiming::InsteadOf => "INSTEAD OF",
+            }
+        )
+        .unwrap()
+    }
+
+    #[doc(hidden)]
+    /// Write the events firing a trigger.
+    fn prepare_trigger_events(&self, events: &[TriggerEvent], sql: &mut SqlWriter) {
+        events.iter().fold(true, |first, event| {
+            if !first {
+                write!(sql, " OR ").unwrap();
+            }
+            self.prepare_trigger_event(event, sql);
+            false
+        });
+    }
+
+    #[doc(hidden)]
+    /// Write an event firing a trigger.
+    fn prepare_trigger_event(&self, event: &TriggerEvent, sql: &mut SqlWriter) {
+        match event {
+            TriggerEvent::Insert => write!(sql, "INSERT").unwrap(),
+            TriggerEvent::Update(columns) => {
+                write!(sql, "UPDATE").unwrap();
+                if !columns.is_empty() {
+                    write!(sql, " OF ").unwrap();
+                    columns.iter().fold(true, |first, column| {
+                        if !first {
+                            write!(sql, ", ").unwrap();
+                        }
+                        column.prepare(sql, self.quote());
+                        false
+                    });
+                }
+            }
+            TriggerEvent::Delete => write!(sql, "DELETE").unwrap(),
+            TriggerEvent::Truncate => write!(sql, "TRUNCATE").unwrap(),
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write whether a trigger fires for each row or statement.
+    fn prepare_trigger_scope(&self, scope: &Option<TriggerScope>, sql: &mut SqlWriter) {
+        if let Some(scope) = scope {
+            write!(
+                sql,
+                " FOR EACH {}",
+                match scope {
+                    TriggerScope::Row => "ROW",
+                    TriggerScope::Statement => "STATEMENT",
+                }
+            )
+            .unwrap();
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write the condition of a trigger.
+    fn prepare_trigger_when(&self, condition: &SimpleExpr, sql: &mut SqlWriter);
+
+    #[doc(hidden)]
+    /// Write the action of a trigger.
+    fn prepare_trigger_action(&self, action: &TriggerAction, sql: &mut SqlWriter);
+}
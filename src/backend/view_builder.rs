@@ -0,0 +1,67 @@
+use crate::*;
+
+pub trait ViewBuilder: QuotedBuilder {
+    /// Translate [`ViewCreateStatement`] into SQL statement.
+    fn prepare_view_create_statement(&self, create: &ViewCreateStatement, sql: &mut SqlWriter) {
+        write!(sql, "CREATE ").unwrap();
+
+        self.prepare_view_create_kind(create, sql);
+
+        write!(sql, "VIEW ").unwrap();
+
+        if let Some(view) = &create.view {
+            view.prepare(sql, self.quote());
+        }
+
+        if !create.columns.is_empty() {
+            write!(sql, " (").unwrap();
+            create.columns.iter().fold(true, |first, col| {
+                if !first {
+                    write!(sql, ", ").unwrap();
+                }
+                col.prepare(sql, self.quote());
+                false
+            });
+            write!(sql, ")").unwrap();
+        }
+
+        write!(sql, " AS ").unwrap();
+        if let Some(query) = &create.query {
+            self.prepare_view_query(query, sql);
+        }
+
+        if create.check_option {
+            self.prepare_view_check_option(sql);
+        }
+    }
+
+    /// Translate [`ViewRefreshStatement`] into SQL statement.
+    fn prepare_view_refresh_statement(
+        &self,
+        _refresh: &ViewRefreshStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        panic!("REFRESH MATERIALIZED VIEW is not supported by this backend");
+    }
+
+    #[doc(hidden)]
+    /// Write the kind of view, i.e. `OR REPLACE` and `MATERIALIZED`.
+    fn prepare_view_create_kind(&self, create: &ViewCreateStatement, sql: &mut SqlWriter) {
+        if create.materialized {
+            panic!("Materialized view is not supported by this backend");
+        }
+        if create.or_replace {
+            write!(sql, "OR REPLACE ").unwrap();
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write the query defining the view.
+    fn prepare_view_query(&self, query: &SelectStatement, sql: &mut SqlWriter);
+
+    #[doc(hidden)]
+    /// Write `WITH CHECK OPTION`.
+    fn prepare_view_check_option(&self, sql: &mut SqlWriter) {
+        write!(sql, " WITH CHECK OPTION").unwrap();
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Create a database. Not supported by Sqlite.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let database = Database::create()
+///     .name(Alias::new("tenant_1"))
+///     .charset("utf8mb4")
+///     .collate("utf8mb4_unicode_ci")
+///     .to_owned();
+///
+/// assert_eq!(
+///     database.to_string(MysqlQueryBuilder),
+///     r#"CREATE DATABASE `tenant_1` CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci"#
+/// );
+///
+/// let database = Database::create()
+///     .name(Alias::new("tenant_1"))
+///     .owner(Alias::new("tenant_1_owner"))
+///     .charset("UTF8")
+///     .collate("en_US.utf8")
+///     .to_owned();
+///
+/// assert_eq!(
+///     database.to_string(PostgresQueryBuilder),
+///     r#"CREATE DATABASE "tenant_1" OWNER "tenant_1_owner" ENCODING 'UTF8' LC_COLLATE 'en_US.utf8'"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct DatabaseCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) owner: Option<DynIden>,
+    pub(crate) charset: Option<String>,
+    pub(crate) collate: Option<String>,
+}
+
+impl Default for DatabaseCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatabaseCreateStatement {
+    /// Construct a new [`DatabaseCreateStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            if_not_exists: false,
+            owner: None,
+            charset: None,
+            collate: None,
+        }
+    }
+
+    /// Set database name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Create database if it does not exist. Not supported by Postgres.
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set the role owning the database. Postgres only.
+    pub fn owner<T>(&mut self, owner: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.owner = Some(owner.into_iden());
+        self
+    }
+
+    /// Set the character set, i.e. `ENCODING` in Postgres
+    pub fn charset(&mut self, charset: &str) -> &mut Self {
+        self.charset = Some(charset.to_owned());
+        self
+    }
+
+    /// Set the collation, i.e. `LC_COLLATE` in Postgres
+    pub fn collate(&mut self, collate: &str) -> &mut Self {
+        self.collate = Some(collate.to_owned());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            if_not_exists: self.if_not_exists,
+            owner: self.owner.take(),
+            charset: self.charset.take(),
+            collate: self.collate.take(),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for DatabaseCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_create_statement(self, &mut sql);
+        sql.result()
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Drop a database. Not supported by Sqlite.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let database = Database::drop()
+///     .if_exists()
+///     .name(Alias::new("tenant_1"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     database.to_string(MysqlQueryBuilder),
+///     r#"DROP DATABASE IF EXISTS `tenant_1`"#
+/// );
+/// assert_eq!(
+///     database.to_string(PostgresQueryBuilder),
+///     r#"DROP DATABASE IF EXISTS "tenant_1""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct DatabaseDropStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_exists: bool,
+}
+
+impl Default for DatabaseDropStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatabaseDropStatement {
+    /// Construct a new [`DatabaseDropStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            if_exists: false,
+        }
+    }
+
+    /// Set database name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Drop database only if it exists
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+impl SchemaStatementBuilder for DatabaseDropStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_drop_statement(self, &mut sql);
+        sql.result()
+    }
+}
@@ -0,0 +1,58 @@
+//! Database and schema definition statements.
+//!
+//! # Usage
+//!
+//! - Database Create, see [`DatabaseCreateStatement`]
+//! - Database Drop, see [`DatabaseDropStatement`]
+//! - Schema Create, see [`SchemaCreateStatement`]
+//! - Schema Drop, see [`SchemaDropStatement`]
+
+mod create;
+mod drop;
+mod schema;
+mod shim;
+
+pub use create::*;
+pub use drop::*;
+pub use schema::*;
+
+/// Shorthand for constructing any database statement
+#[derive(Debug, Clone)]
+pub struct Database;
+
+/// Shorthand for constructing any schema statement
+#[derive(Debug, Clone)]
+pub struct Schema;
+
+/// All available types of database and schema statement
+#[derive(Debug, Clone)]
+pub enum DatabaseStatement {
+    Create(DatabaseCreateStatement),
+    Drop(DatabaseDropStatement),
+    SchemaCreate(SchemaCreateStatement),
+    SchemaDrop(SchemaDropStatement),
+}
+
+impl Database {
+    /// Construct database [`DatabaseCreateStatement`]
+    pub fn create() -> DatabaseCreateStatement {
+        DatabaseCreateStatement::new()
+    }
+
+    /// Construct database [`DatabaseDropStatement`]
+    pub fn drop() -> DatabaseDropStatement {
+        DatabaseDropStatement::new()
+    }
+}
+
+impl Schema {
+    /// Construct schema [`SchemaCreateStatement`]
+    pub fn create() -> SchemaCreateStatement {
+        SchemaCreateStatement::new()
+    }
+
+    /// Construct schema [`SchemaDropStatement`]
+    pub fn drop() -> SchemaDropStatement {
+        SchemaDropStatement::new()
+    }
+}
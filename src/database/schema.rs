@@ -0,0 +1,186 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Create a schema, a namespace of tables inside a database. Not supported by Sqlite.
+/// In MySQL, a schema is a database.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let schema = Schema::create()
+///     .if_not_exists()
+///     .name(Alias::new("tenant_1"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     schema.to_string(MysqlQueryBuilder),
+///     r#"CREATE SCHEMA IF NOT EXISTS `tenant_1`"#
+/// );
+/// assert_eq!(
+///     schema.to_string(PostgresQueryBuilder),
+///     r#"CREATE SCHEMA IF NOT EXISTS "tenant_1""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SchemaCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) authorization: Option<DynIden>,
+}
+
+/// Drop a schema. Not supported by Sqlite.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let schema = Schema::drop()
+///     .if_exists()
+///     .name(Alias::new("tenant_1"))
+///     .cascade()
+///     .to_owned();
+///
+/// assert_eq!(
+///     schema.to_string(PostgresQueryBuilder),
+///     r#"DROP SCHEMA IF EXISTS "tenant_1" CASCADE"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SchemaDropStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_exists: bool,
+    pub(crate) cascade: bool,
+}
+
+impl Default for SchemaCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SchemaCreateStatement {
+    /// Construct a new [`SchemaCreateStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            if_not_exists: false,
+            authorization: None,
+        }
+    }
+
+    /// Set schema name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Create schema if it does not exist
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set the role owning the schema. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let schema = Schema::create()
+    ///     .name(Alias::new("tenant_1"))
+    ///     .authorization(Alias::new("tenant_1_owner"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     schema.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE SCHEMA "tenant_1" AUTHORIZATION "tenant_1_owner""#
+    /// );
+    /// ```
+    pub fn authorization<T>(&mut self, role: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.authorization = Some(role.into_iden());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            if_not_exists: self.if_not_exists,
+            authorization: self.authorization.take(),
+        }
+    }
+}
+
+impl Default for SchemaDropStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SchemaDropStatement {
+    /// Construct a new [`SchemaDropStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            if_exists: false,
+            cascade: false,
+        }
+    }
+
+    /// Set schema name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Drop schema only if it exists
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Drop the objects contained in the schema too. Postgres only.
+    pub fn cascade(&mut self) -> &mut Self {
+        self.cascade = true;
+        self
+    }
+}
+
+impl SchemaStatementBuilder for SchemaCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_schema_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_schema_create_statement(self, &mut sql);
+        sql.result()
+    }
+}
+
+impl SchemaStatementBuilder for SchemaDropStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_schema_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_schema_drop_statement(self, &mut sql);
+        sql.result()
+    }
+}
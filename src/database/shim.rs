@@ -0,0 +1,6 @@
+use crate::impl_schema_statement_builder;
+
+impl_schema_statement_builder!(database_create_statement_builder, DatabaseCreateStatement);
+impl_schema_statement_builder!(database_drop_statement_builder, DatabaseDropStatement);
+impl_schema_statement_builder!(schema_create_statement_builder, SchemaCreateStatement);
+impl_schema_statement_builder!(schema_drop_statement_builder, SchemaDropStatement);
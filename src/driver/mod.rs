@@ -1,5 +1,8 @@
 //! Integration with different database drivers.
 
+#[cfg(feature = "mysql")]
+mod mysql;
+
 #[cfg(feature = "postgres")]
 mod postgres;
 #[cfg(feature = "postgres")]
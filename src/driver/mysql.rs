@@ -0,0 +1,228 @@
+use crate::{sea_value_to_json_value, Value, Values};
+use mysql_common::{params::Params, Value as MysqlValue};
+
+/// Convert into the value of the `mysql` and `mysql_async` crates, which share `mysql_common`.
+///
+/// Unsigned integers are kept unsigned and date times with an offset are converted into UTC,
+/// as MySQL date times carry no offset. Arrays are serialized as JSON, as MySQL has no array type.
+impl From<Value> for MysqlValue {
+    fn from(value: Value) -> MysqlValue {
+        macro_rules! convert {
+            ( $v: expr, $conv: expr ) => {
+                match $v {
+                    Some(v) => $conv(v),
+                    None => MysqlValue::NULL,
+                }
+            };
+        }
+        match value {
+            Value::Bool(v) => convert!(v, |v| MysqlValue::Int(v as i64)),
+            Value::TinyInt(v) => convert!(v, |v| MysqlValue::Int(v as i64)),
+            Value::SmallInt(v) => convert!(v, |v| MysqlValue::Int(v as i64)),
+            Value::Int(v) => convert!(v, |v| MysqlValue::Int(v as i64)),
+            Value::BigInt(v) => convert!(v, MysqlValue::Int),
+            Value::TinyUnsigned(v) => convert!(v, |v| MysqlValue::UInt(v as u64)),
+            Value::SmallUnsigned(v) => convert!(v, |v| MysqlValue::UInt(v as u64)),
+            Value::Unsigned(v) => convert!(v, |v| MysqlValue::UInt(v as u64)),
+            Value::BigUnsigned(v) => convert!(v, MysqlValue::UInt),
+            Value::Float(v) => convert!(v, MysqlValue::Float),
+            Value::Double(v) => convert!(v, MysqlValue::Double),
+            Value::String(v) => convert!(v, |v: Box<String>| MysqlValue::Bytes(v.into_bytes())),
+            Value::Bytes(v) => convert!(v, |v: Box<Vec<u8>>| MysqlValue::Bytes(*v)),
+            Value::Array(v) => convert!(v, |v: Box<Vec<Value>>| MysqlValue::Bytes(
+                sea_value_to_json_value(&Value::Array(Some(v)))
+                    .to_string()
+                    .into_bytes()
+            )),
+            Value::Json(v) => convert!(v, |v: Box<serde_json::Value>| MysqlValue::Bytes(
+                v.to_string().into_bytes()
+            )),
+            #[cfg(feature = "with-chrono")]
+            Value::Date(v) => convert!(v, |v: Box<chrono::NaiveDate>| chrono_date(*v)),
+            #[cfg(feature = "with-chrono")]
+            Value::Time(v) => convert!(v, |v: Box<chrono::NaiveTime>| chrono_time(*v)),
+            #[cfg(feature = "with-chrono")]
+            Value::DateTime(v) => convert!(v, |v: Box<chrono::NaiveDateTime>| chrono_date_time(*v)),
+            #[cfg(feature = "with-chrono")]
+            Value::DateTimeUtc(v) => convert!(v, |v: Box<chrono::DateTime<chrono::Utc>>| {
+                chrono_date_time(v.naive_utc())
+            }),
+            #[cfg(feature = "with-chrono")]
+            Value::DateTimeLocal(v) => convert!(v, |v: Box<chrono::DateTime<chrono::Local>>| {
+                chrono_date_time(v.naive_utc())
+            }),
+            #[cfg(feature = "with-chrono")]
+            Value::DateTimeWithTimeZone(v) => {
+                convert!(v, |v: Box<chrono::DateTime<chrono::FixedOffset>>| {
+                    chrono_date_time(v.naive_utc())
+                })
+            }
+            #[cfg(feature = "with-time")]
+            Value::TimeDate(v) => convert!(v, |v: Box<time::Date>| time_date_time(
+                time::PrimitiveDateTime::new(*v, time::Time::MIDNIGHT)
+            )),
+            #[cfg(feature = "with-time")]
+            Value::TimeTime(v) => convert!(v, |v: Box<time::Time>| MysqlValue::Time(
+                false,
+                0,
+                v.hour(),
+                v.minute(),
+                v.second(),
+                v.microsecond()
+            )),
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTime(v) => {
+                convert!(v, |v: Box<time::PrimitiveDateTime>| { time_date_time(*v) })
+            }
+            #[cfg(feature = "with-time")]
+            Value::TimeDateTimeWithTimeZone(v) => {
+                convert!(v, |v: Box<time::OffsetDateTime>| {
+                    let v = v.to_offset(time::UtcOffset::UTC);
+                    time_date_time(time::PrimitiveDateTime::new(v.date(), v.time()))
+                })
+            }
+            #[cfg(feature = "with-uuid")]
+            Value::Uuid(v) => convert!(v, |v: Box<uuid::Uuid>| MysqlValue::Bytes(
+                v.as_bytes().to_vec()
+            )),
+            #[cfg(feature = "with-rust_decimal")]
+            Value::Decimal(v) => convert!(v, |v: Box<rust_decimal::Decimal>| MysqlValue::Bytes(
+                v.to_string().into_bytes()
+            )),
+            #[cfg(feature = "with-bigdecimal")]
+            Value::BigDecimal(v) => {
+                convert!(v, |v: Box<bigdecimal::BigDecimal>| MysqlValue::Bytes(
+                    v.to_string().into_bytes()
+                ))
+            }
+            #[cfg(feature = "with-ipnetwork")]
+            Value::IpNetwork(v) => convert!(v, |v: Box<ipnetwork::IpNetwork>| MysqlValue::Bytes(
+                v.to_string().into_bytes()
+            )),
+            #[cfg(feature = "with-mac_address")]
+            Value::MacAddress(v) => {
+                convert!(v, |v: Box<mac_address::MacAddress>| MysqlValue::Bytes(
+                    v.to_string().into_bytes()
+                ))
+            }
+        }
+    }
+}
+
+/// Convert into positional parameters of the `mysql` and `mysql_async` crates.
+impl From<Values> for Params {
+    fn from(values: Values) -> Params {
+        if values.0.is_empty() {
+            Params::Empty
+        } else {
+            Params::Positional(values.0.into_iter().map(MysqlValue::from).collect())
+        }
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+fn chrono_date_time(v: chrono::NaiveDateTime) -> MysqlValue {
+    use chrono::{Datelike, Timelike};
+
+    MysqlValue::Date(
+        v.year() as u16,
+        v.month() as u8,
+        v.day() as u8,
+        v.hour() as u8,
+        v.minute() as u8,
+        v.second() as u8,
+        v.nanosecond() / 1000,
+    )
+}
+
+#[cfg(feature = "with-chrono")]
+fn chrono_date(v: chrono::NaiveDate) -> MysqlValue {
+    use chrono::Datelike;
+
+    MysqlValue::Date(v.year() as u16, v.month() as u8, v.day() as u8, 0, 0, 0, 0)
+}
+
+#[cfg(feature = "with-chrono")]
+fn chrono_time(v: chrono::NaiveTime) -> MysqlValue {
+    use chrono::Timelike;
+
+    MysqlValue::Time(
+        false,
+        0,
+        v.hour() as u8,
+        v.minute() as u8,
+        v.second() as u8,
+        v.nanosecond() / 1000,
+    )
+}
+
+#[cfg(feature = "with-time")]
+fn time_date_time(v: time::PrimitiveDateTime) -> MysqlValue {
+    MysqlValue::Date(
+        v.year() as u16,
+        v.month() as u8,
+        v.day(),
+        v.hour(),
+        v.minute(),
+        v.second(),
+        v.microsecond(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values_to_params() {
+        assert_eq!(
+            Params::from(Values(vec![
+                Value::BigUnsigned(Some(u64::MAX)),
+                Value::TinyInt(Some(-1)),
+                Value::Bool(Some(true)),
+                Value::String(None),
+                Value::Bytes(Some(Box::new(vec![0xAB, 0xCD]))),
+            ])),
+            Params::Positional(vec![
+                MysqlValue::UInt(u64::MAX),
+                MysqlValue::Int(-1),
+                MysqlValue::Int(1),
+                MysqlValue::NULL,
+                MysqlValue::Bytes(vec![0xAB, 0xCD]),
+            ])
+        );
+        assert_eq!(Params::from(Values(Vec::new())), Params::Empty);
+    }
+
+    #[test]
+    fn test_array_to_value() {
+        assert_eq!(
+            MysqlValue::from(Value::from(vec![1, 2, 3])),
+            MysqlValue::Bytes(b"[1,2,3]".to_vec())
+        );
+        assert_eq!(
+            MysqlValue::from(Value::from(vec!["a".to_owned(), "b".to_owned()])),
+            MysqlValue::Bytes(br#"["a","b"]"#.to_vec())
+        );
+        assert_eq!(MysqlValue::from(Value::Array(None)), MysqlValue::NULL);
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn test_chrono_to_value() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone};
+
+        assert_eq!(
+            MysqlValue::from(Value::from(
+                NaiveDate::from_ymd(2020, 8, 20).and_hms_micro(10, 20, 30, 400)
+            )),
+            MysqlValue::Date(2020, 8, 20, 10, 20, 30, 400)
+        );
+        assert_eq!(
+            MysqlValue::from(Value::from(
+                FixedOffset::east(3600).ymd(2020, 8, 20).and_hms(0, 30, 0)
+            )),
+            MysqlValue::Date(2020, 8, 19, 23, 30, 0, 0)
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{Value, Values};
 use bytes::BytesMut;
 use postgres_types::{to_sql_checked, IsNull, ToSql, Type};
-use std::error::Error;
+use std::{convert::TryFrom, error::Error};
 
+/// Parameters for the `postgres` and `tokio-postgres` crates, which share [`ToSql`].
 pub trait PostgresDriver<'a> {
     fn as_params(&'a self) -> Vec<&'a (dyn ToSql + Sync)>;
 }
@@ -21,6 +22,14 @@ impl ToSql for Value {
                 }
             };
         }
+        macro_rules! int_to_sql {
+            ( $v: expr ) => {
+                match $v {
+                    Some(v) => int_to_sql(i128::from(*v), ty, out),
+                    None => None::<i64>.to_sql(ty, out),
+                }
+            };
+        }
         macro_rules! box_to_sql {
             ( $v: expr, $ty: ty ) => {
                 match $v {
@@ -31,18 +40,19 @@ impl ToSql for Value {
         }
         match self {
             Value::Bool(v) => to_sql!(v, bool),
-            Value::TinyInt(v) => to_sql!(v, i8),
-            Value::SmallInt(v) => to_sql!(v, i16),
-            Value::Int(v) => to_sql!(v, i32),
-            Value::BigInt(v) => to_sql!(v, i64),
-            Value::TinyUnsigned(v) => to_sql!(v, u32),
-            Value::SmallUnsigned(v) => to_sql!(v, u32),
-            Value::Unsigned(v) => to_sql!(v, u32),
-            Value::BigUnsigned(v) => to_sql!(v, i64),
+            Value::TinyInt(v) => int_to_sql!(v),
+            Value::SmallInt(v) => int_to_sql!(v),
+            Value::Int(v) => int_to_sql!(v),
+            Value::BigInt(v) => int_to_sql!(v),
+            Value::TinyUnsigned(v) => int_to_sql!(v),
+            Value::SmallUnsigned(v) => int_to_sql!(v),
+            Value::Unsigned(v) => int_to_sql!(v),
+            Value::BigUnsigned(v) => int_to_sql!(v),
             Value::Float(v) => to_sql!(v, f32),
             Value::Double(v) => to_sql!(v, f64),
             Value::String(v) => box_to_sql!(v, String),
             Value::Bytes(v) => box_to_sql!(v, Vec<u8>),
+            Value::Array(v) => box_to_sql!(v, Vec<Value>),
             #[cfg(feature = "postgres-json")]
             Value::Json(v) => box_to_sql!(v, serde_json::Value),
             #[cfg(feature = "postgres-chrono")]
@@ -52,13 +62,47 @@ impl ToSql for Value {
             #[cfg(feature = "postgres-chrono")]
             Value::DateTime(v) => box_to_sql!(v, chrono::NaiveDateTime),
             #[cfg(feature = "postgres-chrono")]
+            Value::DateTimeUtc(v) => box_to_sql!(v, chrono::DateTime<chrono::Utc>),
+            #[cfg(feature = "postgres-chrono")]
+            Value::DateTimeLocal(v) => box_to_sql!(v, chrono::DateTime<chrono::Local>),
+            #[cfg(feature = "postgres-chrono")]
             Value::DateTimeWithTimeZone(v) => box_to_sql!(v, chrono::DateTime<chrono::FixedOffset>),
+            #[cfg(feature = "postgres-time")]
+            Value::TimeDate(v) => box_to_sql!(v, time::Date),
+            #[cfg(feature = "postgres-time")]
+            Value::TimeTime(v) => box_to_sql!(v, time::Time),
+            #[cfg(feature = "postgres-time")]
+            Value::TimeDateTime(v) => box_to_sql!(v, time::PrimitiveDateTime),
+            #[cfg(feature = "postgres-time")]
+            Value::TimeDateTimeWithTimeZone(v) => box_to_sql!(v, time::OffsetDateTime),
             #[cfg(feature = "postgres-rust_decimal")]
             Value::Decimal(v) => box_to_sql!(v, rust_decimal::Decimal),
             #[cfg(feature = "postgres-bigdecimal")]
-            Value::BigDecimal(_) => unimplemented!("Not supported"),
+            Value::BigDecimal(v) => match v {
+                Some(v) => {
+                    big_decimal_to_sql(v, out);
+                    Ok(IsNull::No)
+                }
+                None => Ok(IsNull::Yes),
+            },
             #[cfg(feature = "postgres-uuid")]
             Value::Uuid(v) => box_to_sql!(v, uuid::Uuid),
+            #[cfg(feature = "postgres-ipnetwork")]
+            Value::IpNetwork(v) => match v {
+                Some(v) => {
+                    ip_network_to_sql(v, out);
+                    Ok(IsNull::No)
+                }
+                None => Ok(IsNull::Yes),
+            },
+            #[cfg(feature = "postgres-mac_address")]
+            Value::MacAddress(v) => match v {
+                Some(v) => {
+                    out.extend_from_slice(&v.bytes());
+                    Ok(IsNull::No)
+                }
+                None => Ok(IsNull::Yes),
+            },
         }
     }
 
@@ -69,6 +113,111 @@ impl ToSql for Value {
     to_sql_checked!();
 }
 
+/// Encode an integer as the type Postgres expects for the parameter, as Postgres has no
+/// unsigned integers and the binary format of each integer type has a fixed width.
+/// Fails instead of wrapping around if the integer does not fit.
+fn int_to_sql(
+    v: i128,
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+    if *ty == Type::INT2 {
+        i16::try_from(v)?.to_sql(ty, out)
+    } else if *ty == Type::INT4 {
+        i32::try_from(v)?.to_sql(ty, out)
+    } else if *ty == Type::OID {
+        u32::try_from(v)?.to_sql(ty, out)
+    } else if *ty == Type::CHAR {
+        i8::try_from(v)?.to_sql(ty, out)
+    } else if *ty == Type::FLOAT4 {
+        (v as f32).to_sql(ty, out)
+    } else if *ty == Type::FLOAT8 {
+        (v as f64).to_sql(ty, out)
+    } else {
+        i64::try_from(v)?.to_sql(ty, out)
+    }
+}
+
+/// Encode a [`bigdecimal::BigDecimal`] in the binary format of Postgres `NUMERIC`,
+/// which stores base-10000 digits aligned at the decimal point.
+#[cfg(feature = "postgres-bigdecimal")]
+fn big_decimal_to_sql(v: &bigdecimal::BigDecimal, out: &mut BytesMut) {
+    use bytes::BufMut;
+
+    let (int, exp) = v.as_bigint_and_exponent();
+    let mut digits = int.to_string();
+    let negative = digits.starts_with('-');
+    if negative {
+        digits.remove(0);
+    }
+    let scale = if exp < 0 {
+        digits.push_str(&"0".repeat(-exp as usize));
+        0
+    } else {
+        exp as usize
+    };
+    if digits.len() <= scale {
+        digits = format!("{}{}", "0".repeat(scale - digits.len() + 1), digits);
+    }
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    let int_part = format!("{}{}", "0".repeat((4 - int_part.len() % 4) % 4), int_part);
+    let frac_part = format!("{}{}", frac_part, "0".repeat((4 - frac_part.len() % 4) % 4));
+
+    let mut groups: Vec<i16> = int_part
+        .as_bytes()
+        .chunks(4)
+        .chain(frac_part.as_bytes().chunks(4))
+        .map(|chunk| std::str::from_utf8(chunk).unwrap().parse().unwrap())
+        .collect();
+    let mut weight = (int_part.len() / 4) as i16 - 1;
+    while groups.first() == Some(&0) {
+        groups.remove(0);
+        weight -= 1;
+    }
+    while groups.last() == Some(&0) {
+        groups.pop();
+    }
+    if groups.is_empty() {
+        weight = 0;
+    }
+
+    out.put_i16(groups.len() as i16);
+    out.put_i16(weight);
+    out.put_u16(if negative && !groups.is_empty() {
+        0x4000
+    } else {
+        0x0000
+    });
+    out.put_u16(scale as u16);
+    for group in groups {
+        out.put_i16(group);
+    }
+}
+
+/// Encode an [`ipnetwork::IpNetwork`] in the binary format of Postgres `inet` and `cidr`.
+#[cfg(feature = "postgres-ipnetwork")]
+fn ip_network_to_sql(v: &ipnetwork::IpNetwork, out: &mut BytesMut) {
+    use bytes::BufMut;
+    use ipnetwork::IpNetwork;
+
+    match v {
+        IpNetwork::V4(net) => {
+            out.put_u8(2);
+            out.put_u8(net.prefix());
+            out.put_u8(0);
+            out.put_u8(4);
+            out.put_slice(&net.ip().octets());
+        }
+        IpNetwork::V6(net) => {
+            out.put_u8(3);
+            out.put_u8(net.prefix());
+            out.put_u8(0);
+            out.put_u8(16);
+            out.put_slice(&net.ip().octets());
+        }
+    }
+}
+
 impl From<Vec<Value>> for Values {
     fn from(v: Vec<Value>) -> Values {
         Values(v)
@@ -86,3 +235,66 @@ impl<'a> PostgresDriver<'a> for Values {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(feature = "postgres-bigdecimal")]
+    fn test_big_decimal_to_sql() {
+        use super::*;
+        use std::str::FromStr;
+
+        macro_rules! test_numeric {
+            ( $val: literal, $bytes: expr ) => {
+                let mut out = BytesMut::new();
+                big_decimal_to_sql(&bigdecimal::BigDecimal::from_str($val).unwrap(), &mut out);
+                assert_eq!(out.as_ref(), $bytes);
+            };
+        }
+
+        test_numeric!("0", &[0, 0, 0, 0, 0, 0, 0, 0]);
+        test_numeric!("1", &[0, 1, 0, 0, 0, 0, 0, 0, 0, 1]);
+        test_numeric!(
+            "-12345.678",
+            &[0, 3, 0, 1, 0x40, 0, 0, 3, 0, 1, 0x09, 0x29, 0x1A, 0x7C]
+        );
+        test_numeric!("0.0001", &[0, 1, 0xFF, 0xFF, 0, 0, 0, 4, 0, 1]);
+        test_numeric!("1E+8", &[0, 1, 0, 2, 0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_int_to_sql() {
+        use super::*;
+
+        let mut out = BytesMut::new();
+        Value::TinyUnsigned(Some(200))
+            .to_sql(&Type::INT2, &mut out)
+            .unwrap();
+        assert_eq!(out.as_ref(), &[0, 200]);
+
+        let mut out = BytesMut::new();
+        Value::Int(Some(-2)).to_sql(&Type::INT8, &mut out).unwrap();
+        assert_eq!(
+            out.as_ref(),
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]
+        );
+
+        let mut out = BytesMut::new();
+        assert!(Value::BigUnsigned(Some(u64::MAX))
+            .to_sql(&Type::INT8, &mut out)
+            .is_err());
+        assert!(Value::Int(Some(40000))
+            .to_sql(&Type::INT2, &mut out)
+            .is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "postgres-ipnetwork")]
+    fn test_ip_network_to_sql() {
+        use super::*;
+
+        let mut out = BytesMut::new();
+        ip_network_to_sql(&"192.168.0.0/24".parse().unwrap(), &mut out);
+        assert_eq!(out.as_ref(), &[2, 24, 0, 4, 192, 168, 0, 0]);
+    }
+}
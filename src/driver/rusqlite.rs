@@ -2,8 +2,11 @@
 macro_rules! sea_query_driver_rusqlite {
     () => {
         mod sea_query_driver_rusqlite {
-            use rusqlite::{types::ToSqlOutput, Result, ToSql};
-            use sea_query::{Value, Values};
+            use rusqlite::{
+                types::{Null, ToSqlOutput},
+                Result, ToSql,
+            };
+            use $crate::{Value, Values};
 
             pub struct RusqliteValue(pub Value);
 
@@ -27,6 +30,34 @@ macro_rules! sea_query_driver_rusqlite {
                 }
             }
 
+            /// Iterate over the values, e.g. to bind them with [`rusqlite::params_from_iter`].
+            impl<'a> IntoIterator for &'a RusqliteValues {
+                type Item = &'a RusqliteValue;
+                type IntoIter = std::slice::Iter<'a, RusqliteValue>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    self.0.iter()
+                }
+            }
+
+            pub trait RusqliteBinder {
+                /// Build the statement and its values, ready to be bound as rusqlite parameters.
+                fn build_rusqlite<T: $crate::QueryBuilder>(
+                    &self,
+                    query_builder: T,
+                ) -> (String, RusqliteValues);
+            }
+
+            impl<S: $crate::QueryStatementBuilder> RusqliteBinder for S {
+                fn build_rusqlite<T: $crate::QueryBuilder>(
+                    &self,
+                    query_builder: T,
+                ) -> (String, RusqliteValues) {
+                    let (sql, values) = self.build(query_builder);
+                    (sql, values.into())
+                }
+            }
+
             impl ToSql for RusqliteValue {
                 fn to_sql(&self) -> Result<ToSqlOutput<'_>> {
                     macro_rules! to_sql {
@@ -59,6 +90,7 @@ macro_rules! sea_query_driver_rusqlite {
                         Value::Double(v) => to_sql!(v, f64),
                         Value::String(v) => box_to_sql!(v, String),
                         Value::Bytes(v) => box_to_sql!(v, Vec<u8>),
+                        _ if self.0.is_null() => Ok(ToSqlOutput::from(Null)),
                         _ => {
                             if self.0.is_json() {
                                 (*self.0.as_ref_json()).to_sql()
@@ -68,12 +100,34 @@ macro_rules! sea_query_driver_rusqlite {
                                 (*self.0.as_ref_time()).to_sql()
                             } else if self.0.is_date_time() {
                                 (*self.0.as_ref_date_time()).to_sql()
+                            } else if self.0.is_date_time_utc() {
+                                (*self.0.as_ref_date_time_utc()).to_sql()
+                            } else if self.0.is_date_time_local() {
+                                (*self.0.as_ref_date_time_local()).to_sql()
                             } else if self.0.is_date_time_with_time_zone() {
                                 (*self.0.as_ref_date_time_with_time_zone()).to_sql()
+                            } else if self.0.is_time_date() {
+                                (*self.0.as_ref_time_date()).to_sql()
+                            } else if self.0.is_time_time() {
+                                (*self.0.as_ref_time_time()).to_sql()
+                            } else if self.0.is_time_date_time() {
+                                (*self.0.as_ref_time_date_time()).to_sql()
+                            } else if self.0.is_time_date_time_with_time_zone() {
+                                (*self.0.as_ref_time_date_time_with_time_zone()).to_sql()
+                            } else if self.0.is_decimal() {
+                                Ok(self.0.as_ref_decimal().to_string().into())
+                            } else if self.0.is_big_decimal() {
+                                Ok(self.0.as_ref_big_decimal().to_string().into())
                             } else if self.0.is_uuid() {
                                 (*self.0.as_ref_uuid()).to_sql()
+                            } else if self.0.is_ip_network() {
+                                Ok(self.0.as_ref_ip_network().to_string().into())
+                            } else if self.0.is_mac_address() {
+                                Ok(self.0.as_ref_mac_address().to_string().into())
                             } else {
-                                unimplemented!();
+                                Err(rusqlite::Error::ToSqlConversionFailure(
+                                    format!("{:?} is not supported by SQLite", self.0).into(),
+                                ))
                             }
                         }
                     }
@@ -34,22 +34,40 @@ macro_rules! bind_params_sqlx_mysql {
                 Value::String(v) => bind_box!(v, String),
                 Value::Bytes(v) => bind_box!(v, Vec<u8>),
                 _ => {
-                    if value.is_json() {
-                        query.bind(value.as_ref_json())
-                    } else if value.is_date() {
-                        query.bind(value.as_ref_date())
-                    } else if value.is_time() {
-                        query.bind(value.as_ref_time())
-                    } else if value.is_date_time() {
-                        query.bind(value.as_ref_date_time())
-                    } else if value.is_decimal() {
-                        query.bind(value.as_ref_decimal())
-                    } else if value.is_big_decimal() {
-                        query.bind(value.as_ref_big_decimal())
-                    } else if value.is_uuid() {
-                        query.bind(value.as_ref_uuid())
+                    if let Ok(v) = value.try_as_ref_json() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_date() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_time() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_date_time() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_date_time_utc() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_date_time_local() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_time_date() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_time_time() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_time_date_time() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_time_date_time_with_time_zone() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_decimal() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_big_decimal() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_uuid() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_ip_network() {
+                        query.bind(v.map(|v| v.to_string()))
+                    } else if let Ok(v) = value.try_as_ref_mac_address() {
+                        query.bind(v.map(|v| v.to_string()))
+                    } else if value.is_array() {
+                        panic!("Array values are not supported by MySQL");
                     } else {
-                        unimplemented!();
+                        panic!("{:?} is not supported by the MySQL driver", value);
                     }
                 }
             };
@@ -78,6 +96,46 @@ macro_rules! sea_query_driver_mysql {
             ) -> SqlxQueryAs<'a, T> {
                 $crate::bind_params_sqlx_mysql!(query, params.0)
             }
+
+            /// Values of a statement, to be bound with [`sqlx::query_with`] or [`sqlx::query_as_with`].
+            pub struct SqlxValues(pub Values);
+
+            pub trait SqlxBinder {
+                /// Build the statement and its values, ready to be bound as sqlx arguments.
+                fn build_sqlx<T: $crate::QueryBuilder>(
+                    &self,
+                    query_builder: T,
+                ) -> (String, SqlxValues);
+            }
+
+            impl<S: $crate::QueryStatementBuilder> SqlxBinder for S {
+                fn build_sqlx<T: $crate::QueryBuilder>(
+                    &self,
+                    query_builder: T,
+                ) -> (String, SqlxValues) {
+                    let (sql, values) = self.build(query_builder);
+                    (sql, SqlxValues(values))
+                }
+            }
+
+            impl<'q> sqlx::IntoArguments<'q, MySql> for &'q SqlxValues {
+                fn into_arguments(self) -> MySqlArguments {
+                    let binder = ArgumentsBinder(Default::default());
+                    $crate::bind_params_sqlx_mysql!(binder, self.0 .0).0
+                }
+            }
+
+            struct ArgumentsBinder(MySqlArguments);
+
+            impl ArgumentsBinder {
+                fn bind<'q, T>(mut self, value: T) -> Self
+                where
+                    T: 'q + Send + sqlx::encode::Encode<'q, MySql> + sqlx::Type<MySql>,
+                {
+                    sqlx::Arguments::add(&mut self.0, value);
+                    self
+                }
+            }
         }
     };
 }
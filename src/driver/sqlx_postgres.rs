@@ -19,6 +19,45 @@ macro_rules! bind_params_sqlx_postgres {
                     }
                 };
             }
+            macro_rules! bind_array {
+                ( $values: expr, $variant: ident, $ty: ty ) => {
+                    query.bind(
+                        $values
+                            .iter()
+                            .map(|e| match e {
+                                Value::$variant(e) => e.map(|e| e as $ty),
+                                _ => panic!("Array elements must all be of the same type"),
+                            })
+                            .collect::<Vec<Option<$ty>>>(),
+                    )
+                };
+            }
+            macro_rules! bind_box_array {
+                ( $values: expr, $variant: ident, $ty: ty ) => {
+                    query.bind(
+                        $values
+                            .iter()
+                            .map(|e| match e {
+                                Value::$variant(e) => e.as_ref().map(|e| e.as_ref().clone()),
+                                _ => panic!("Array elements must all be of the same type"),
+                            })
+                            .collect::<Vec<Option<$ty>>>(),
+                    )
+                };
+            }
+            macro_rules! bind_array_of {
+                ( $values: expr, $try_as_ref: ident ) => {
+                    query.bind(
+                        $values
+                            .iter()
+                            .map(|e| match e.$try_as_ref() {
+                                Ok(e) => e.cloned(),
+                                Err(_) => panic!("Array elements must all be of the same type"),
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                };
+            }
             query = match value {
                 Value::Bool(v) => bind!(v, bool),
                 Value::TinyInt(v) => bind!(v, i8),
@@ -33,23 +72,99 @@ macro_rules! bind_params_sqlx_postgres {
                 Value::Double(v) => bind!(v, f64),
                 Value::String(v) => bind_box!(v, String),
                 Value::Bytes(v) => bind_box!(v, Vec<u8>),
+                Value::Array(v) => match v.as_deref() {
+                    // The element type of a NULL or empty array is unknown, so it is bound as text[]
+                    None => query.bind(None::<Vec<Option<String>>>),
+                    Some(v) if v.is_empty() => query.bind(Vec::<Option<String>>::new()),
+                    Some(v) => match &v[0] {
+                        Value::Bool(_) => bind_array!(v, Bool, bool),
+                        Value::TinyInt(_) => bind_array!(v, TinyInt, i8),
+                        Value::SmallInt(_) => bind_array!(v, SmallInt, i16),
+                        Value::Int(_) => bind_array!(v, Int, i32),
+                        Value::BigInt(_) => bind_array!(v, BigInt, i64),
+                        Value::TinyUnsigned(_) => bind_array!(v, TinyUnsigned, u32),
+                        Value::SmallUnsigned(_) => bind_array!(v, SmallUnsigned, u32),
+                        Value::Unsigned(_) => bind_array!(v, Unsigned, u32),
+                        Value::BigUnsigned(_) => bind_array!(v, BigUnsigned, i64),
+                        Value::Float(_) => bind_array!(v, Float, f32),
+                        Value::Double(_) => bind_array!(v, Double, f64),
+                        Value::String(_) => bind_box_array!(v, String, String),
+                        Value::Bytes(_) => bind_box_array!(v, Bytes, Vec<u8>),
+                        Value::Array(_) => panic!("Multidimensional arrays are not supported"),
+                        first => {
+                            if first.is_json() {
+                                panic!("Arrays of JSON are not supported")
+                            } else if first.is_date() {
+                                bind_array_of!(v, try_as_ref_date)
+                            } else if first.is_time() {
+                                bind_array_of!(v, try_as_ref_time)
+                            } else if first.is_date_time() {
+                                bind_array_of!(v, try_as_ref_date_time)
+                            } else if first.is_date_time_utc() {
+                                bind_array_of!(v, try_as_ref_date_time_utc)
+                            } else if first.is_date_time_local() {
+                                bind_array_of!(v, try_as_ref_date_time_local)
+                            } else if first.is_date_time_with_time_zone() {
+                                bind_array_of!(v, try_as_ref_date_time_with_time_zone)
+                            } else if first.is_time_date() {
+                                bind_array_of!(v, try_as_ref_time_date)
+                            } else if first.is_time_time() {
+                                bind_array_of!(v, try_as_ref_time_time)
+                            } else if first.is_time_date_time() {
+                                bind_array_of!(v, try_as_ref_time_date_time)
+                            } else if first.is_time_date_time_with_time_zone() {
+                                bind_array_of!(v, try_as_ref_time_date_time_with_time_zone)
+                            } else if first.is_decimal() {
+                                bind_array_of!(v, try_as_ref_decimal)
+                            } else if first.is_big_decimal() {
+                                bind_array_of!(v, try_as_ref_big_decimal)
+                            } else if first.is_uuid() {
+                                bind_array_of!(v, try_as_ref_uuid)
+                            } else if first.is_ip_network() {
+                                bind_array_of!(v, try_as_ref_ip_network)
+                            } else if first.is_mac_address() {
+                                bind_array_of!(v, try_as_ref_mac_address)
+                            } else {
+                                panic!("Arrays of {:?} are not supported by the Postgres driver", first);
+                            }
+                        }
+                    },
+                },
                 _ => {
-                    if value.is_json() {
-                        query.bind(value.as_ref_json())
-                    } else if value.is_date() {
-                        query.bind(value.as_ref_date())
-                    } else if value.is_time() {
-                        query.bind(value.as_ref_time())
-                    } else if value.is_date_time() {
-                        query.bind(value.as_ref_date_time())
-                    } else if value.is_decimal() {
-                        query.bind(value.as_ref_decimal())
-                    } else if value.is_big_decimal() {
-                        query.bind(value.as_ref_big_decimal())
-                    } else if value.is_uuid() {
-                        query.bind(value.as_ref_uuid())
+                    if let Ok(v) = value.try_as_ref_json() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_date() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_time() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_date_time() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_date_time_utc() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_date_time_local() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_date_time_with_time_zone() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_time_date() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_time_time() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_time_date_time() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_time_date_time_with_time_zone() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_decimal() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_big_decimal() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_uuid() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_ip_network() {
+                        query.bind(v)
+                    } else if let Ok(v) = value.try_as_ref_mac_address() {
+                        query.bind(v)
                     } else {
-                        unimplemented!();
+                        panic!("{:?} is not supported by the Postgres driver", value);
                     }
                 }
             };
@@ -78,6 +193,46 @@ macro_rules! sea_query_driver_postgres {
             ) -> SqlxQueryAs<'a, T> {
                 $crate::bind_params_sqlx_postgres!(query, params.0)
             }
+
+            /// Values of a statement, to be bound with [`sqlx::query_with`] or [`sqlx::query_as_with`].
+            pub struct SqlxValues(pub Values);
+
+            pub trait SqlxBinder {
+                /// Build the statement and its values, ready to be bound as sqlx arguments.
+                fn build_sqlx<T: $crate::QueryBuilder>(
+                    &self,
+                    query_builder: T,
+                ) -> (String, SqlxValues);
+            }
+
+            impl<S: $crate::QueryStatementBuilder> SqlxBinder for S {
+                fn build_sqlx<T: $crate::QueryBuilder>(
+                    &self,
+                    query_builder: T,
+                ) -> (String, SqlxValues) {
+                    let (sql, values) = self.build(query_builder);
+                    (sql, SqlxValues(values))
+                }
+            }
+
+            impl<'q> sqlx::IntoArguments<'q, Postgres> for &'q SqlxValues {
+                fn into_arguments(self) -> PgArguments {
+                    let binder = ArgumentsBinder(Default::default());
+                    $crate::bind_params_sqlx_postgres!(binder, self.0 .0).0
+                }
+            }
+
+            struct ArgumentsBinder(PgArguments);
+
+            impl ArgumentsBinder {
+                fn bind<'q, T>(mut self, value: T) -> Self
+                where
+                    T: 'q + Send + sqlx::encode::Encode<'q, Postgres> + sqlx::Type<Postgres>,
+                {
+                    sqlx::Arguments::add(&mut self.0, value);
+                    self
+                }
+            }
         }
     };
 }
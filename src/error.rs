@@ -8,4 +8,27 @@ pub enum Error {
     /// Column and value vector having different length
     #[error("Columns and values length mismatch: {col_len} != {val_len}")]
     ColValNumMismatch { col_len: usize, val_len: usize },
+
+    /// Value variant not matching the requested Rust type
+    #[error("Type mismatch: expected {expected}, got {got}")]
+    TypeMismatch { expected: &'static str, got: String },
+
+    /// Feature not supported by the backend the statement is built for
+    #[error("{feature} is not supported by {backend}")]
+    Unsupported {
+        backend: &'static str,
+        feature: &'static str,
+    },
+
+    /// SQL that failed to parse, or that has no counterpart in sea-query
+    #[error("Cannot parse SQL: {0}")]
+    Parse(String),
+
+    /// Statement without the name of the table it applies to
+    #[error("No table name found")]
+    NoTableName,
+
+    /// Row of an introspection query that cannot be read back into a table definition
+    #[error("Cannot read schema: {0}")]
+    Introspection(String),
 }
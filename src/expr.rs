@@ -21,7 +21,8 @@ pub struct Expr {
 ///
 /// [`SimpleExpr`] is a node in the expression tree and can represent identifiers, function calls,
 /// various operators and sub-queries.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SimpleExpr {
     Column(ColumnRef),
     Unary(UnOper, Box<SimpleExpr>),
@@ -30,6 +31,12 @@ pub enum SimpleExpr {
     SubQuery(Box<SelectStatement>),
     Value(Value),
     Values(Vec<Value>),
+    Tuple(Vec<SimpleExpr>),
+    Cast(Box<SimpleExpr>, CastType),
+    Excluded(#[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden),
+    #[cfg(feature = "backend-postgres")]
+    Subscript(Box<SimpleExpr>, Box<SimpleExpr>),
+    FullTextMatch(Vec<SimpleExpr>, Box<SimpleExpr>, SearchMode),
     Custom(String),
     CustomWithValues(String, Vec<Value>),
     Keyword(Keyword),
@@ -141,6 +148,50 @@ impl Expr {
         Self::col((t.into_iden(), c.into_iden()))
     }
 
+    /// Express the value proposed for insertion of a column, in the `DO UPDATE` assignments of an
+    /// upsert. Translated to `excluded.col` in Postgres and SQLite, and to `VALUES(col)` in MySQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Id, Glyph::Aspect])
+    ///     .values_panic(vec![1.into(), 2.into()])
+    ///     .on_conflict(
+    ///         OnConflict::column(Glyph::Id)
+    ///             .update_value(
+    ///                 Glyph::Aspect,
+    ///                 Expr::col(Glyph::Aspect)
+    ///                     .into_simple_expr()
+    ///                     .add(Expr::excluded(Glyph::Aspect)),
+    ///             )
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`id`, `aspect`) VALUES (1, 2) ON DUPLICATE KEY UPDATE `aspect` = `aspect` + VALUES(`aspect`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("id", "aspect") VALUES (1, 2) ON CONFLICT ("id") DO UPDATE SET "aspect" = "aspect" + "excluded"."aspect""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`id`, `aspect`) VALUES (1, 2) ON CONFLICT (`id`) DO UPDATE SET `aspect` = `aspect` + `excluded`.`aspect`"#
+    /// );
+    /// ```
+    pub fn excluded<T>(col: T) -> Self
+    where
+        T: IntoIden,
+    {
+        Self::new_with_left(SimpleExpr::Excluded(col.into_iden()))
+    }
+
     /// Express a [`Value`], returning a [`Expr`].
     ///
     /// # Examples
@@ -176,6 +227,46 @@ impl Expr {
         Self::new_with_left(SimpleExpr::Value(v.into()))
     }
 
+    /// Express the `CURRENT_TIMESTAMP` keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::current_timestamp())
+    ///     .expr(Expr::current_date())
+    ///     .expr(Expr::current_time())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CURRENT_TIMESTAMP, CURRENT_DATE, CURRENT_TIME"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CURRENT_TIMESTAMP, CURRENT_DATE, CURRENT_TIME"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CURRENT_TIMESTAMP, CURRENT_DATE, CURRENT_TIME"#
+    /// );
+    /// ```
+    pub fn current_timestamp() -> Self {
+        Self::new_with_left(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+    }
+
+    /// Express the `CURRENT_DATE` keyword. See [`Expr::current_timestamp`].
+    pub fn current_date() -> Self {
+        Self::new_with_left(SimpleExpr::Keyword(Keyword::CurrentDate))
+    }
+
+    /// Express the `CURRENT_TIME` keyword. See [`Expr::current_timestamp`].
+    pub fn current_time() -> Self {
+        Self::new_with_left(SimpleExpr::Keyword(Keyword::CurrentTime))
+    }
+
     /// Wrap a [`SimpleExpr`] and perform some operation on it.
     ///
     /// # Examples
@@ -206,6 +297,197 @@ impl Expr {
         Self::new_with_left(expr)
     }
 
+    /// Wrap a [`SelectStatement`] as a scalar subquery.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .expr_as(
+    ///         Expr::subquery(
+    ///             Query::select()
+    ///                 .column(Font::Name)
+    ///                 .from(Font::Table)
+    ///                 .and_where(Expr::tbl(Font::Table, Font::Id).equals(Char::Table, Char::FontId))
+    ///                 .to_owned(),
+    ///         ),
+    ///         Alias::new("font_name"),
+    ///     )
+    ///     .from(Char::Table)
+    ///     .and_where(
+    ///         Expr::subquery(
+    ///             Query::select()
+    ///                 .expr(Expr::col(Font::Id).max())
+    ///                 .from(Font::Table)
+    ///                 .to_owned(),
+    ///         )
+    ///         .gt(10),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, (SELECT `name` FROM `font` WHERE `font`.`id` = `character`.`font_id`) AS `font_name` FROM `character` WHERE (SELECT MAX(`id`) FROM `font`) > 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", (SELECT "name" FROM "font" WHERE "font"."id" = "character"."font_id") AS "font_name" FROM "character" WHERE (SELECT MAX("id") FROM "font") > 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, (SELECT `name` FROM `font` WHERE `font`.`id` = `character`.`font_id`) AS `font_name` FROM `character` WHERE (SELECT MAX(`id`) FROM `font`) > 10"#
+    /// );
+    /// ```
+    pub fn subquery(sel: SelectStatement) -> Self {
+        Self::new_with_left(SimpleExpr::SubQuery(Box::new(sel)))
+    }
+
+    /// Express a `CAST` expression. A [`ColumnType`](crate::ColumnType) is translated to a type
+    /// name the backend accepts as cast target, e.g. integers are cast to `signed` in MySQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::cast_as(Expr::col(Char::Character).into(), ColumnType::BigInteger(None)))
+    ///     .expr(Expr::cast_as(Expr::val("2").into(), Alias::new("money")))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CAST(`character` AS signed), CAST('2' AS money) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CAST("character" AS bigint), CAST('2' AS money) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CAST(`character` AS integer), CAST('2' AS money) FROM `character`"#
+    /// );
+    /// ```
+    pub fn cast_as<T>(expr: SimpleExpr, r#type: T) -> Self
+    where
+        T: Into<CastType>,
+    {
+        Self::new_with_left(SimpleExpr::Cast(Box::new(expr), r#type.into()))
+    }
+
+    /// Express a row value, i.e. a tuple of expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(
+    ///         Expr::tuple(vec![Expr::col(Char::SizeW).into(), Expr::col(Char::SizeH).into()])
+    ///             .into_simple_expr()
+    ///             .greater_or_equal(Expr::tuple(vec![Expr::val(1).into(), Expr::val(2).into()])),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE (`size_w`, `size_h`) >= (1, 2)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE ("size_w", "size_h") >= (1, 2)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE (`size_w`, `size_h`) >= (1, 2)"#
+    /// );
+    /// ```
+    pub fn tuple<I>(n: I) -> Self
+    where
+        I: IntoIterator<Item = SimpleExpr>,
+    {
+        Self::new_with_left(SimpleExpr::Tuple(n.into_iter().collect()))
+    }
+
+    /// Express an `EXISTS` sub-query expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Name)
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::exists(
+    ///         Query::select()
+    ///             .column(Char::Id)
+    ///             .from(Char::Table)
+    ///             .and_where(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///             .to_owned(),
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `name` FROM `font` WHERE EXISTS (SELECT `id` FROM `character` WHERE `character`.`font_id` = `font`.`id`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "name" FROM "font" WHERE EXISTS (SELECT "id" FROM "character" WHERE "character"."font_id" = "font"."id")"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `name` FROM `font` WHERE EXISTS (SELECT `id` FROM `character` WHERE `character`.`font_id` = `font`.`id`)"#
+    /// );
+    /// ```
+    pub fn exists(sel: SelectStatement) -> SimpleExpr {
+        Self::subquery(sel).un_oper(UnOper::Exists)
+    }
+
+    /// Express a `NOT EXISTS` sub-query expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Name)
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::not_exists(
+    ///         Query::select()
+    ///             .column(Char::Id)
+    ///             .from(Char::Table)
+    ///             .and_where(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///             .to_owned(),
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `name` FROM `font` WHERE NOT EXISTS (SELECT `id` FROM `character` WHERE `character`.`font_id` = `font`.`id`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "name" FROM "font" WHERE NOT EXISTS (SELECT "id" FROM "character" WHERE "character"."font_id" = "font"."id")"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `name` FROM `font` WHERE NOT EXISTS (SELECT `id` FROM `character` WHERE `character`.`font_id` = `font`.`id`)"#
+    /// );
+    /// ```
+    pub fn not_exists(sel: SelectStatement) -> SimpleExpr {
+        Self::expr(Self::exists(sel)).un_oper(UnOper::Not)
+    }
+
     /// Express a [`Value`], returning a [`SimpleExpr`].
     ///
     /// # Examples
@@ -318,6 +600,33 @@ impl Expr {
     ///     r#"SELECT 6 = 2 * 3"#
     /// );
     /// ```
+    /// Values are collected as parameters in order, together with the other values of the statement
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::Id).gt(1))
+    ///     .and_where(Expr::cust_with_values("size_w BETWEEN ? AND ?", vec![2, 3]).into())
+    ///     .and_where(Expr::col(Char::Character).eq("A"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(MysqlQueryBuilder),
+    ///     (
+    ///         r#"SELECT `id` FROM `character` WHERE `id` > ? AND size_w BETWEEN ? AND ? AND `character` = ?"#.to_owned(),
+    ///         Values(vec![1.into(), 2.into(), 3.into(), "A".into()])
+    ///     )
+    /// );
+    /// assert_eq!(
+    ///     query.build(PostgresQueryBuilder),
+    ///     (
+    ///         r#"SELECT "id" FROM "character" WHERE "id" > $1 AND size_w BETWEEN $2 AND $3 AND "character" = $4"#.to_owned(),
+    ///         Values(vec![1.into(), 2.into(), 3.into(), "A".into()])
+    ///     )
+    /// );
+    /// ```
     /// Postgres only: use `??` to escape `?`
     /// ```
     /// use sea_query::{*, tests_cfg::*};
@@ -419,6 +728,76 @@ impl Expr {
         self.bin_oper(BinOper::NotEqual, SimpleExpr::Value(v.into()))
     }
 
+    /// Express a null-safe inequality, treating `NULL` as a comparable value.
+    ///
+    /// Translated to `NOT (... <=> ...)` in MySQL and `IS NOT` in SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).is_distinct_from(Expr::col(Char::SizeH)))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE NOT (`size_w` <=> `size_h`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "size_w" IS DISTINCT FROM "size_h""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `size_w` IS NOT `size_h`"#
+    /// );
+    /// ```
+    pub fn is_distinct_from<T>(self, expr: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.bin_oper(BinOper::IsDistinctFrom, expr.into())
+    }
+
+    /// Express a null-safe equality, treating `NULL` as a comparable value.
+    ///
+    /// Translated to `<=>` in MySQL and `IS` in SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).is_not_distinct_from(Expr::val(Option::<i32>::None)))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` <=> NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "font_id" IS NOT DISTINCT FROM NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` IS NULL"#
+    /// );
+    /// ```
+    pub fn is_not_distinct_from<T>(self, expr: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.bin_oper(BinOper::IsNotDistinctFrom, expr.into())
+    }
+
     /// Express a equal expression between two table columns,
     /// you will mainly use this to relate identical value between two table columns.
     ///
@@ -725,7 +1104,9 @@ impl Expr {
         self.bin_oper(BinOper::Div, SimpleExpr::Value(v.into()))
     }
 
-    /// Express a `BETWEEN` expression.
+    /// Express a string concatenation.
+    ///
+    /// Translated to `CONCAT` in MySQL, where `||` is logical `OR`.
     ///
     /// # Examples
     ///
@@ -733,32 +1114,35 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).between(1, 10))
+    ///     .expr(
+    ///         Expr::col(Font::Name)
+    ///             .concat(Expr::val(" "))
+    ///             .concat(Expr::col(Font::Variant)),
+    ///     )
+    ///     .from(Font::Table)
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` BETWEEN 1 AND 10"#
+    ///     r#"SELECT CONCAT(`name`, ' ', `variant`) FROM `font`"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" BETWEEN 1 AND 10"#
+    ///     r#"SELECT "name" || ' ' || "variant" FROM "font""#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` BETWEEN 1 AND 10"#
+    ///     r#"SELECT `name` || ' ' || `variant` FROM `font`"#
     /// );
     /// ```
-    pub fn between<V>(self, a: V, b: V) -> SimpleExpr
+    pub fn concat<T>(self, right: T) -> SimpleExpr
     where
-        V: Into<Value>,
+        T: Into<SimpleExpr>,
     {
-        self.between_or_not_between(BinOper::Between, a, b)
+        self.bin_oper(BinOper::Concat, right.into())
     }
 
-    /// Express a `NOT BETWEEN` expression.
+    /// Express a bitwise `AND` operation.
     ///
     /// # Examples
     ///
@@ -766,25 +1150,184 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).not_between(1, 10))
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::expr(Expr::col(Glyph::Aspect).bit_and(4)).eq(4))
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` NOT BETWEEN 1 AND 10"#
+    ///     r#"SELECT `id` FROM `glyph` WHERE `aspect` & 4 = 4"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" NOT BETWEEN 1 AND 10"#
+    ///     r#"SELECT "id" FROM "glyph" WHERE "aspect" & 4 = 4"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` NOT BETWEEN 1 AND 10"#
+    ///     r#"SELECT `id` FROM `glyph` WHERE `aspect` & 4 = 4"#
     /// );
     /// ```
-    pub fn not_between<V>(self, a: V, b: V) -> SimpleExpr
+    pub fn bit_and<V>(self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        self.bin_oper(BinOper::BitAnd, SimpleExpr::Value(v.into()))
+    }
+
+    /// Express a bitwise `OR` operation.
+    pub fn bit_or<V>(self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        self.bin_oper(BinOper::BitOr, SimpleExpr::Value(v.into()))
+    }
+
+    /// Express a bitwise exclusive `OR` operation.
+    ///
+    /// Translated to `#` in Postgres. SQLite has no such operator, so it is expanded to
+    /// `(a | b) - (a & b)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::col(Glyph::Aspect).bit_xor(1))
+    ///     .from(Glyph::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `aspect` ^ 1 FROM `glyph`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "aspect" # 1 FROM "glyph""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT (`aspect` | 1) - (`aspect` & 1) FROM `glyph`"#
+    /// );
+    /// ```
+    pub fn bit_xor<V>(self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        self.bin_oper(BinOper::BitXor, SimpleExpr::Value(v.into()))
+    }
+
+    /// Express a bitwise left shift operation.
+    pub fn left_shift<V>(self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        self.bin_oper(BinOper::LShift, SimpleExpr::Value(v.into()))
+    }
+
+    /// Express a bitwise right shift operation.
+    pub fn right_shift<V>(self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        self.bin_oper(BinOper::RShift, SimpleExpr::Value(v.into()))
+    }
+
+    /// Express a bitwise `NOT` operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value_expr(
+    ///         Glyph::Aspect,
+    ///         Expr::col(Glyph::Aspect)
+    ///             .into_simple_expr()
+    ///             .bit_and(Expr::expr(Expr::val(4).left_shift(1)).bit_not()),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `aspect` = `aspect` & ~(4 << 1)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "aspect" = "aspect" & ~(4 << 1)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `aspect` = `aspect` & ~(4 << 1)"#
+    /// );
+    /// ```
+    pub fn bit_not(self) -> SimpleExpr {
+        self.un_oper(UnOper::BitNot)
+    }
+
+    /// Express a `BETWEEN` expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).between(1, 10))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` BETWEEN 1 AND 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" BETWEEN 1 AND 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` BETWEEN 1 AND 10"#
+    /// );
+    /// ```
+    pub fn between<V>(self, a: V, b: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        self.between_or_not_between(BinOper::Between, a, b)
+    }
+
+    /// Express a `NOT BETWEEN` expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).not_between(1, 10))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` NOT BETWEEN 1 AND 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" NOT BETWEEN 1 AND 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` NOT BETWEEN 1 AND 10"#
+    /// );
+    /// ```
+    pub fn not_between<V>(self, a: V, b: V) -> SimpleExpr
     where
         V: Into<Value>,
     {
@@ -824,11 +1367,11 @@ impl Expr {
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."character" LIKE E'Ours\'%'"#
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."character" LIKE 'Ours''%'"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`character` LIKE 'Ours\'%'"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`character` LIKE 'Ours''%'"#
     /// );
     /// ```
     pub fn like(self, v: &str) -> SimpleExpr {
@@ -845,7 +1388,9 @@ impl Expr {
         )
     }
 
-    /// Express a `IS NULL` expression.
+    /// Express a regular expression match.
+    ///
+    /// Translated to `~` in Postgres. SQLite requires a `regexp` function to be registered.
     ///
     /// # Examples
     ///
@@ -853,30 +1398,44 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_null())
+    ///     .column(Font::Name)
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::col(Font::Name).regexp("^[A-Z]"))
+    ///     .and_where(Expr::col(Font::Variant).not_regexp("Bold$"))
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NULL"#
+    ///     r#"SELECT `name` FROM `font` WHERE `name` REGEXP '^[A-Z]' AND `variant` NOT REGEXP 'Bold$'"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" IS NULL"#
+    ///     r#"SELECT "name" FROM "font" WHERE "name" ~ '^[A-Z]' AND "variant" !~ 'Bold$'"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NULL"#
+    ///     r#"SELECT `name` FROM `font` WHERE `name` REGEXP '^[A-Z]' AND `variant` NOT REGEXP 'Bold$'"#
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn is_null(self) -> SimpleExpr {
-        self.bin_oper(BinOper::Is, SimpleExpr::Keyword(Keyword::Null))
+    pub fn regexp(self, pattern: &str) -> SimpleExpr {
+        self.bin_oper(
+            BinOper::Regexp,
+            SimpleExpr::Value(Value::String(Some(Box::new(pattern.to_owned())))),
+        )
     }
 
-    /// Express a `IS NOT NULL` expression.
+    /// Express a negated regular expression match, see [`Expr::regexp`].
+    pub fn not_regexp(self, pattern: &str) -> SimpleExpr {
+        self.bin_oper(
+            BinOper::NotRegexp,
+            SimpleExpr::Value(Value::String(Some(Box::new(pattern.to_owned())))),
+        )
+    }
+
+    /// Express a case insensitive regular expression match.
+    ///
+    /// Translated to `~*` in Postgres and `REGEXP_LIKE` in MySQL 8.0+. SQLite prefixes the
+    /// pattern with the `(?i)` flag.
     ///
     /// # Examples
     ///
@@ -884,30 +1443,41 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_null())
+    ///     .column(Font::Name)
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::col(Font::Name).iregexp("^mono"))
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NOT NULL"#
+    ///     r#"SELECT `name` FROM `font` WHERE REGEXP_LIKE(`name`, '^mono', 'i')"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" IS NOT NULL"#
+    ///     r#"SELECT "name" FROM "font" WHERE "name" ~* '^mono'"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NOT NULL"#
+    ///     r#"SELECT `name` FROM `font` WHERE `name` REGEXP '(?i)' || '^mono'"#
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn is_not_null(self) -> SimpleExpr {
-        self.bin_oper(BinOper::IsNot, SimpleExpr::Keyword(Keyword::Null))
+    pub fn iregexp(self, pattern: &str) -> SimpleExpr {
+        self.bin_oper(
+            BinOper::IRegexp,
+            SimpleExpr::Value(Value::String(Some(Box::new(pattern.to_owned())))),
+        )
     }
 
-    /// Negates an expression with `NOT`.
+    /// Express a negated case insensitive regular expression match, see [`Expr::iregexp`].
+    pub fn not_iregexp(self, pattern: &str) -> SimpleExpr {
+        self.bin_oper(
+            BinOper::NotIRegexp,
+            SimpleExpr::Value(Value::String(Some(Box::new(pattern.to_owned())))),
+        )
+    }
+
+    /// Express a `LIKE` expression with an escape character, which makes the following `%`, `_`
+    /// or escape character in the pattern match literally.
     ///
     /// # Examples
     ///
@@ -915,30 +1485,33 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .column(Char::Character)
     ///     .from(Char::Table)
-    ///     .and_where(Expr::expr(Expr::tbl(Char::Table, Char::SizeW).is_null()).not())
+    ///     .and_where(Expr::col(Char::Character).like_with_escape("100!%%", '!'))
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE NOT `character`.`size_w` IS NULL"#
+    ///     r#"SELECT `character` FROM `character` WHERE `character` LIKE '100!%%' ESCAPE '!'"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE NOT "character"."size_w" IS NULL"#
+    ///     r#"SELECT "character" FROM "character" WHERE "character" LIKE '100!%%' ESCAPE '!'"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE NOT `character`.`size_w` IS NULL"#
+    ///     r#"SELECT `character` FROM `character` WHERE `character` LIKE '100!%%' ESCAPE '!'"#
     /// );
     /// ```
-    #[allow(clippy::should_implement_trait)]
-    pub fn not(self) -> SimpleExpr {
-        self.un_oper(UnOper::Not)
+    pub fn like_with_escape(self, v: &str, escape: char) -> SimpleExpr {
+        self.like(v).binary(
+            BinOper::Escape,
+            SimpleExpr::Value(Value::String(Some(Box::new(escape.to_string())))),
+        )
     }
 
-    /// Express a `MAX` function.
+    /// Express a `LIKE` expression matching values starting with the given text.
+    /// `%` and `_` in the text are escaped.
     ///
     /// # Examples
     ///
@@ -946,29 +1519,38 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .expr(Expr::tbl(Char::Table, Char::SizeW).max())
+    ///     .column(Char::Character)
     ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::Character).starts_with("50%_"))
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT MAX(`character`.`size_w`) FROM `character`"#
+    ///     r#"SELECT `character` FROM `character` WHERE `character` LIKE '50!%!_%' ESCAPE '!'"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT MAX("character"."size_w") FROM "character""#
+    ///     r#"SELECT "character" FROM "character" WHERE "character" LIKE '50!%!_%' ESCAPE '!'"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT MAX(`character`.`size_w`) FROM `character`"#
+    ///     r#"SELECT `character` FROM `character` WHERE `character` LIKE '50!%!_%' ESCAPE '!'"#
     /// );
     /// ```
-    pub fn max(mut self) -> SimpleExpr {
-        let left = self.left.take();
-        Self::func_with_args(Function::Max, vec![left.unwrap()])
+    pub fn starts_with(self, v: &str) -> SimpleExpr {
+        let pattern = format!("{}%", escape_like_pattern(v));
+        self.like_with_escape(&pattern, LIKE_ESCAPE)
     }
 
-    /// Express a `MIN` function.
+    /// Express a `LIKE` expression matching values ending with the given text.
+    /// `%` and `_` in the text are escaped.
+    pub fn ends_with(self, v: &str) -> SimpleExpr {
+        let pattern = format!("%{}", escape_like_pattern(v));
+        self.like_with_escape(&pattern, LIKE_ESCAPE)
+    }
+
+    /// Express a `LIKE` expression matching values containing the given text.
+    /// `%` and `_` in the text are escaped.
     ///
     /// # Examples
     ///
@@ -976,29 +1558,89 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .expr(Expr::tbl(Char::Table, Char::SizeW).min())
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::Character).contains_text("a_b"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "character" LIKE '%a!_b%' ESCAPE '!'"#
+    /// );
+    /// ```
+    pub fn contains_text(self, v: &str) -> SimpleExpr {
+        let pattern = format!("%{}%", escape_like_pattern(v));
+        self.like_with_escape(&pattern, LIKE_ESCAPE)
+    }
+
+    /// Express a postgres case insensitive `ILIKE` expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Name)
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::col(Font::Name).ilike("%serif"))
+    ///     .and_where(Expr::col(Font::Name).not_ilike("sans%"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "name" FROM "font" WHERE "name" ILIKE '%serif' AND "name" NOT ILIKE 'sans%'"#
+    /// );
+    /// ```
+    #[cfg(feature = "backend-postgres")]
+    pub fn ilike(self, v: &str) -> SimpleExpr {
+        self.bin_oper(
+            BinOper::ILike,
+            SimpleExpr::Value(Value::String(Some(Box::new(v.to_owned())))),
+        )
+    }
+
+    /// Express a postgres case insensitive `NOT ILIKE` expression.
+    #[cfg(feature = "backend-postgres")]
+    pub fn not_ilike(self, v: &str) -> SimpleExpr {
+        self.bin_oper(
+            BinOper::NotILike,
+            SimpleExpr::Value(Value::String(Some(Box::new(v.to_owned())))),
+        )
+    }
+
+    /// Express a `IS NULL` expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
     ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_null())
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT MIN(`character`.`size_w`) FROM `character`"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NULL"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT MIN("character"."size_w") FROM "character""#
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" IS NULL"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT MIN(`character`.`size_w`) FROM `character`"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NULL"#
     /// );
     /// ```
-    pub fn min(mut self) -> SimpleExpr {
-        let left = self.left.take();
-        Self::func_with_args(Function::Min, vec![left.unwrap()])
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_null(self) -> SimpleExpr {
+        self.bin_oper(BinOper::Is, SimpleExpr::Keyword(Keyword::Null))
     }
 
-    /// Express a `SUM` function.
+    /// Express a `IS NOT NULL` expression.
     ///
     /// # Examples
     ///
@@ -1006,24 +1648,146 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .expr(Expr::tbl(Char::Table, Char::SizeW).sum())
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
     ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_null())
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT SUM(`character`.`size_w`) FROM `character`"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NOT NULL"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT SUM("character"."size_w") FROM "character""#
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" IS NOT NULL"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT SUM(`character`.`size_w`) FROM `character`"#
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `character`.`size_w` IS NOT NULL"#
     /// );
     /// ```
-    pub fn sum(mut self) -> SimpleExpr {
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_not_null(self) -> SimpleExpr {
+        self.bin_oper(BinOper::IsNot, SimpleExpr::Keyword(Keyword::Null))
+    }
+
+    /// Negates an expression with `NOT`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::expr(Expr::tbl(Char::Table, Char::SizeW).is_null()).not())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE NOT `character`.`size_w` IS NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE NOT "character"."size_w" IS NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE NOT `character`.`size_w` IS NULL"#
+    /// );
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> SimpleExpr {
+        self.un_oper(UnOper::Not)
+    }
+
+    /// Express a `MAX` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::tbl(Char::Table, Char::SizeW).max())
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT MAX(`character`.`size_w`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT MAX("character"."size_w") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT MAX(`character`.`size_w`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn max(mut self) -> SimpleExpr {
+        let left = self.left.take();
+        Self::func_with_args(Function::Max, vec![left.unwrap()])
+    }
+
+    /// Express a `MIN` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::tbl(Char::Table, Char::SizeW).min())
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT MIN(`character`.`size_w`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT MIN("character"."size_w") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT MIN(`character`.`size_w`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn min(mut self) -> SimpleExpr {
+        let left = self.left.take();
+        Self::func_with_args(Function::Min, vec![left.unwrap()])
+    }
+
+    /// Express a `SUM` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::tbl(Char::Table, Char::SizeW).sum())
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT SUM(`character`.`size_w`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT SUM("character"."size_w") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT SUM(`character`.`size_w`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn sum(mut self) -> SimpleExpr {
         let left = self.left.take();
         Self::func_with_args(Function::Sum, vec![left.unwrap()])
     }
@@ -1036,29 +1800,636 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .expr(Expr::tbl(Char::Table, Char::SizeW).count())
-    ///     .from(Char::Table)
+    ///     .expr(Expr::tbl(Char::Table, Char::SizeW).count())
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT COUNT(`character`.`size_w`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT COUNT("character"."size_w") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT COUNT(`character`.`size_w`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn count(mut self) -> SimpleExpr {
+        let left = self.left.take();
+        Self::func_with_args(Function::Count, vec![left.unwrap()])
+    }
+
+    /// Express a `IF NULL` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::tbl(Char::Table, Char::SizeW).if_null(0))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT IFNULL(`character`.`size_w`, 0) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT COALESCE("character"."size_w", 0) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT IFNULL(`character`.`size_w`, 0) FROM `character`"#
+    /// );
+    /// ```
+    pub fn if_null<V>(mut self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        let left = self.left.take();
+        Self::func_with_args(
+            Function::IfNull,
+            vec![left.unwrap(), SimpleExpr::Value(v.into())],
+        )
+    }
+
+    /// Express a `IN` expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Id])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_in(vec![1, 2, 3]))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` IN (1, 2, 3)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE "character"."size_w" IN (1, 2, 3)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` IN (1, 2, 3)"#
+    /// );
+    /// ```
+    /// Empty value list
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Id])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_in(Vec::<u8>::new()))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE 1 = 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE 1 = 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE 1 = 2"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_in<V, I>(mut self, v: I) -> SimpleExpr
+    where
+        V: Into<Value>,
+        I: IntoIterator<Item = V>,
+    {
+        self.bopr = Some(BinOper::In);
+        self.right = Some(SimpleExpr::Values(
+            v.into_iter().map(|v| v.into()).collect(),
+        ));
+        self.into()
+    }
+
+    /// Express a `NOT IN` expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Id])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_in(vec![1, 2, 3]))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` NOT IN (1, 2, 3)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE "character"."size_w" NOT IN (1, 2, 3)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` NOT IN (1, 2, 3)"#
+    /// );
+    /// ```
+    /// Empty value list
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Id])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_in(Vec::<u8>::new()))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE 1 = 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE 1 = 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE 1 = 1"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_not_in<V, I>(mut self, v: I) -> SimpleExpr
+    where
+        V: Into<Value>,
+        I: IntoIterator<Item = V>,
+    {
+        self.bopr = Some(BinOper::NotIn);
+        self.right = Some(SimpleExpr::Values(
+            v.into_iter().map(|v| v.into()).collect(),
+        ));
+        self.into()
+    }
+
+    /// Express a `IN` sub-query expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).in_subquery(
+    ///         Query::select()
+    ///             .expr(Expr::cust("3 + 2 * 2"))
+    ///             .take()
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` IN (SELECT 3 + 2 * 2)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "size_w" IN (SELECT 3 + 2 * 2)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` IN (SELECT 3 + 2 * 2)"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn in_subquery(mut self, sel: SelectStatement) -> SimpleExpr {
+        self.bopr = Some(BinOper::In);
+        self.right = Some(SimpleExpr::SubQuery(Box::new(sel)));
+        self.into()
+    }
+
+    /// Express a `NOT IN` sub-query expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).not_in_subquery(
+    ///         Query::select()
+    ///             .expr(Expr::cust("3 + 2 * 2"))
+    ///             .take()
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` NOT IN (SELECT 3 + 2 * 2)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "size_w" NOT IN (SELECT 3 + 2 * 2)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` NOT IN (SELECT 3 + 2 * 2)"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn not_in_subquery(mut self, sel: SelectStatement) -> SimpleExpr {
+        self.bopr = Some(BinOper::NotIn);
+        self.right = Some(SimpleExpr::SubQuery(Box::new(sel)));
+        self.into()
+    }
+
+    /// Express an `IN` expression on a row value, see [`Expr::tuple`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(
+    ///         Expr::tuple(vec![Expr::col(Char::SizeW).into(), Expr::col(Char::SizeH).into()])
+    ///             .in_tuples(vec![(1, 2), (3, 4)]),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE (`size_w`, `size_h`) IN ((1, 2), (3, 4))"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE ("size_w", "size_h") IN ((1, 2), (3, 4))"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE (`size_w`, `size_h`) IN ((1, 2), (3, 4))"#
+    /// );
+    /// ```
+    pub fn in_tuples<V, I>(mut self, v: I) -> SimpleExpr
+    where
+        V: IntoValueTuple,
+        I: IntoIterator<Item = V>,
+    {
+        self.bopr = Some(BinOper::In);
+        self.right = Some(Self::value_tuples(v));
+        self.into()
+    }
+
+    /// Express a `NOT IN` expression on a row value, see [`Expr::in_tuples`].
+    pub fn not_in_tuples<V, I>(mut self, v: I) -> SimpleExpr
+    where
+        V: IntoValueTuple,
+        I: IntoIterator<Item = V>,
+    {
+        self.bopr = Some(BinOper::NotIn);
+        self.right = Some(Self::value_tuples(v));
+        self.into()
+    }
+
+    fn value_tuples<V, I>(v: I) -> SimpleExpr
+    where
+        V: IntoValueTuple,
+        I: IntoIterator<Item = V>,
+    {
+        let tuples: Vec<SimpleExpr> = v
+            .into_iter()
+            .map(|v| SimpleExpr::Values(v.into_value_tuple().into_iter().collect()))
+            .collect();
+        if tuples.is_empty() {
+            // An empty list is translated to a constant condition, as in `is_in`
+            SimpleExpr::Values(Vec::new())
+        } else {
+            SimpleExpr::Tuple(tuples)
+        }
+    }
+
+    /// Express a `= ANY` quantified comparison against a sub-query, or a Postgres array.
+    /// `ANY` is equivalent to `SOME`. Not supported by SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).eq_any(
+    ///         Query::select()
+    ///             .column(Font::Id)
+    ///             .from(Font::Table)
+    ///             .to_owned(),
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = ANY (SELECT `id` FROM `font`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = ANY (SELECT "id" FROM "font")"#
+    /// );
+    /// assert_eq!(
+    ///     query.try_build(SqliteQueryBuilder),
+    ///     Err(error::Error::Unsupported {
+    ///         backend: "SQLite",
+    ///         feature: "ANY and ALL comparisons"
+    ///     })
+    /// );
+    /// ```
+    ///
+    /// Postgres array:
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).eq_any(Expr::val(Value::Array(Some(Box::new(vec![
+    ///         1.into(),
+    ///         2.into(),
+    ///     ]))))))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = ANY(ARRAY[1, 2])"#
+    /// );
+    /// ```
+    pub fn eq_any<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::Equal, UnOper::Any, right)
+    }
+
+    /// Express a `<> any` quantified comparison. See [`Expr::eq_any`].
+    pub fn ne_any<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::NotEqual, UnOper::Any, right)
+    }
+
+    /// Express a `> any` quantified comparison. See [`Expr::eq_any`].
+    pub fn gt_any<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::GreaterThan, UnOper::Any, right)
+    }
+
+    /// Express a `>= any` quantified comparison. See [`Expr::eq_any`].
+    pub fn gte_any<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::GreaterThanOrEqual, UnOper::Any, right)
+    }
+
+    /// Express a `< any` quantified comparison. See [`Expr::eq_any`].
+    pub fn lt_any<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::SmallerThan, UnOper::Any, right)
+    }
+
+    /// Express a `<= any` quantified comparison. See [`Expr::eq_any`].
+    pub fn lte_any<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::SmallerThanOrEqual, UnOper::Any, right)
+    }
+
+    /// Express a `> ALL` quantified comparison against a sub-query, or a Postgres array.
+    /// Not supported by SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).gt_all(
+    ///         Query::select()
+    ///             .column(Char::SizeH)
+    ///             .from(Char::Table)
+    ///             .to_owned(),
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `size_w` > ALL (SELECT `size_h` FROM `character`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "size_w" > ALL (SELECT "size_h" FROM "character")"#
+    /// );
+    /// assert_eq!(
+    ///     query.try_build(SqliteQueryBuilder),
+    ///     Err(error::Error::Unsupported {
+    ///         backend: "SQLite",
+    ///         feature: "ANY and ALL comparisons"
+    ///     })
+    /// );
+    /// ```
+    pub fn gt_all<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::GreaterThan, UnOper::All, right)
+    }
+
+    /// Express a `= all` quantified comparison. See [`Expr::gt_all`].
+    pub fn eq_all<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::Equal, UnOper::All, right)
+    }
+
+    /// Express a `<> all` quantified comparison. See [`Expr::gt_all`].
+    pub fn ne_all<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::NotEqual, UnOper::All, right)
+    }
+
+    /// Express a `>= all` quantified comparison. See [`Expr::gt_all`].
+    pub fn gte_all<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::GreaterThanOrEqual, UnOper::All, right)
+    }
+
+    /// Express a `< all` quantified comparison. See [`Expr::gt_all`].
+    pub fn lt_all<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::SmallerThan, UnOper::All, right)
+    }
+
+    /// Express a `<= all` quantified comparison. See [`Expr::gt_all`].
+    pub fn lte_all<T>(self, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.quantified(BinOper::SmallerThanOrEqual, UnOper::All, right)
+    }
+
+    /// Express a full text search over columns.
+    ///
+    /// Translated to `MATCH ... AGAINST` in MySQL, which requires a `FULLTEXT` index over the columns,
+    /// and to `TO_TSVECTOR(...) @@ TO_TSQUERY(...)` in Postgres. SQLite matches a single column or
+    /// the FTS5 table itself with `MATCH`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Id)
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::full_text_match(vec![Font::Name], "serif", SearchMode::NaturalLanguage))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `font` WHERE MATCH (`name`) AGAINST ('serif' IN NATURAL LANGUAGE MODE)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "font" WHERE TO_TSVECTOR("name") @@ PLAINTO_TSQUERY('serif')"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `font` WHERE `name` MATCH 'serif'"#
+    /// );
+    /// ```
+    ///
+    /// Boolean mode over several columns:
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Id)
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::full_text_match(
+    ///         vec![Font::Name, Font::Variant],
+    ///         "serif & !bold",
+    ///         SearchMode::Boolean,
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "font" WHERE TO_TSVECTOR(CONCAT_WS(' ', "name", "variant")) @@ TO_TSQUERY('serif & !bold')"#
+    /// );
+    /// ```
+    pub fn full_text_match<T, I, V>(columns: I, query: V, mode: SearchMode) -> SimpleExpr
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+        V: Into<Value>,
+    {
+        SimpleExpr::FullTextMatch(
+            columns
+                .into_iter()
+                .map(|col| SimpleExpr::Column(col.into_column_ref()))
+                .collect(),
+            Box::new(SimpleExpr::Value(query.into())),
+            mode,
+        )
+    }
+
+    /// Express an postgres fulltext search matches (`@@`) expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Font::Name, Font::Variant, Font::Language])
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::val("a & b").matches(Expr::val("a b")))
+    ///     .and_where(Expr::col(Font::Name).matches(Expr::val("a b")))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "name", "variant", "language" FROM "font" WHERE 'a & b' @@ 'a b' AND "name" @@ 'a b'"#
+    /// );
+    /// ```
+    #[cfg(feature = "backend-postgres")]
+    pub fn matches<T>(self, expr: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.bin_oper(BinOper::Matches, expr.into())
+    }
+
+    /// Express an postgres fulltext search contains (`@>`) expression.
+    /// Also tests containment of JSONB documents and arrays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Font::Name, Font::Variant, Font::Language])
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::val("a & b").contains(Expr::val("a b")))
+    ///     .and_where(Expr::col(Font::Name).contains(Expr::val("a b")))
     ///     .to_owned();
     ///
     /// assert_eq!(
-    ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT COUNT(`character`.`size_w`) FROM `character`"#
-    /// );
-    /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT COUNT("character"."size_w") FROM "character""#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT COUNT(`character`.`size_w`) FROM `character`"#
+    ///     r#"SELECT "name", "variant", "language" FROM "font" WHERE 'a & b' @> 'a b' AND "name" @> 'a b'"#
     /// );
     /// ```
-    pub fn count(mut self) -> SimpleExpr {
-        let left = self.left.take();
-        Self::func_with_args(Function::Count, vec![left.unwrap()])
+    #[cfg(feature = "backend-postgres")]
+    pub fn contains<T>(self, expr: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.bin_oper(BinOper::Contains, expr.into())
     }
 
-    /// Express a `IF NULL` function.
+    /// Express an postgres fulltext search contained (`<@`) expression.
     ///
     /// # Examples
     ///
@@ -1066,35 +2437,28 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .expr(Expr::tbl(Char::Table, Char::SizeW).if_null(0))
-    ///     .from(Char::Table)
+    ///     .columns(vec![Font::Name, Font::Variant, Font::Language])
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::val("a & b").contained(Expr::val("a b")))
+    ///     .and_where(Expr::col(Font::Name).contained(Expr::val("a b")))
     ///     .to_owned();
     ///
     /// assert_eq!(
-    ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT IFNULL(`character`.`size_w`, 0) FROM `character`"#
-    /// );
-    /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT COALESCE("character"."size_w", 0) FROM "character""#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT IFNULL(`character`.`size_w`, 0) FROM `character`"#
+    ///     r#"SELECT "name", "variant", "language" FROM "font" WHERE 'a & b' <@ 'a b' AND "name" <@ 'a b'"#
     /// );
     /// ```
-    pub fn if_null<V>(mut self, v: V) -> SimpleExpr
+    #[cfg(feature = "backend-postgres")]
+    pub fn contained<T>(self, expr: T) -> SimpleExpr
     where
-        V: Into<Value>,
+        T: Into<SimpleExpr>,
     {
-        let left = self.left.take();
-        Self::func_with_args(
-            Function::IfNull,
-            vec![left.unwrap(), SimpleExpr::Value(v.into())],
-        )
+        self.bin_oper(BinOper::Contained, expr.into())
     }
 
-    /// Express a `IN` expression.
+    /// Express a JSON field access (`->`), returning JSON.
+    ///
+    /// The right operand is an object key in Postgres, and a JSON path in MySQL and SQLite.
     ///
     /// # Examples
     ///
@@ -1102,123 +2466,71 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Char::Id])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_in(vec![1, 2, 3]))
+    ///     .expr(Expr::col(Alias::new("metadata")).get_json_field("$.size"))
+    ///     .from(Glyph::Table)
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` IN (1, 2, 3)"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "id" FROM "character" WHERE "character"."size_w" IN (1, 2, 3)"#
+    ///     r#"SELECT `metadata` -> '$.size' FROM `glyph`"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` IN (1, 2, 3)"#
+    ///     r#"SELECT `metadata` -> '$.size' FROM `glyph`"#
     /// );
-    /// ```
-    /// Empty value list
-    /// ```
-    /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Char::Id])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_in(Vec::<u8>::new()))
+    ///     .expr(Expr::col(Alias::new("metadata")).get_json_field("size"))
+    ///     .from(Glyph::Table)
     ///     .to_owned();
     ///
     /// assert_eq!(
-    ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE 1 = 2"#
-    /// );
-    /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "id" FROM "character" WHERE 1 = 2"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE 1 = 2"#
+    ///     r#"SELECT "metadata" -> 'size' FROM "glyph""#
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn is_in<V, I>(mut self, v: I) -> SimpleExpr
+    pub fn get_json_field<V>(self, right: V) -> SimpleExpr
     where
         V: Into<Value>,
-        I: IntoIterator<Item = V>,
     {
-        self.bopr = Some(BinOper::In);
-        self.right = Some(SimpleExpr::Values(
-            v.into_iter().map(|v| v.into()).collect(),
-        ));
-        self.into()
+        self.bin_oper(BinOper::GetJsonField, SimpleExpr::Value(right.into()))
     }
 
-    /// Express a `NOT IN` expression.
-    ///
-    /// # Examples
+    /// Express a JSON field access as text (`->>`).
     ///
-    /// ```
-    /// use sea_query::{*, tests_cfg::*};
+    /// The right operand is an object key in Postgres, and a JSON path in MySQL and SQLite.
     ///
-    /// let query = Query::select()
-    ///     .columns(vec![Char::Id])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_in(vec![1, 2, 3]))
-    ///     .to_owned();
+    /// # Examples
     ///
-    /// assert_eq!(
-    ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` NOT IN (1, 2, 3)"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "id" FROM "character" WHERE "character"."size_w" NOT IN (1, 2, 3)"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE `character`.`size_w` NOT IN (1, 2, 3)"#
-    /// );
-    /// ```
-    /// Empty value list
     /// ```
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Char::Id])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::tbl(Char::Table, Char::SizeW).is_not_in(Vec::<u8>::new()))
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(
+    ///         Expr::expr(Expr::col(Alias::new("metadata")).cast_json_field("$.name"))
+    ///             .eq("A"),
+    ///     )
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE 1 = 1"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "id" FROM "character" WHERE 1 = 1"#
+    ///     r#"SELECT `id` FROM `glyph` WHERE `metadata` ->> '$.name' = 'A'"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `id` FROM `character` WHERE 1 = 1"#
+    ///     r#"SELECT `id` FROM `glyph` WHERE `metadata` ->> '$.name' = 'A'"#
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn is_not_in<V, I>(mut self, v: I) -> SimpleExpr
+    pub fn cast_json_field<V>(self, right: V) -> SimpleExpr
     where
         V: Into<Value>,
-        I: IntoIterator<Item = V>,
     {
-        self.bopr = Some(BinOper::NotIn);
-        self.right = Some(SimpleExpr::Values(
-            v.into_iter().map(|v| v.into()).collect(),
-        ));
-        self.into()
+        self.bin_oper(BinOper::CastJsonField, SimpleExpr::Value(right.into()))
     }
 
-    /// Express a `IN` sub-query expression.
+    /// Express a postgres JSON path access (`#>`), returning JSON.
     ///
     /// # Examples
     ///
@@ -1226,36 +2538,24 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::col(Char::SizeW).in_subquery(
-    ///         Query::select()
-    ///             .expr(Expr::cust("3 + 2 * 2"))
-    ///             .take()
-    ///     ))
+    ///     .expr(Expr::col(Alias::new("metadata")).get_json_path("{size,width}"))
+    ///     .from(Glyph::Table)
     ///     .to_owned();
     ///
     /// assert_eq!(
-    ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` IN (SELECT 3 + 2 * 2)"#
-    /// );
-    /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "size_w" IN (SELECT 3 + 2 * 2)"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` IN (SELECT 3 + 2 * 2)"#
+    ///     r#"SELECT "metadata" #> '{size,width}' FROM "glyph""#
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn in_subquery(mut self, sel: SelectStatement) -> SimpleExpr {
-        self.bopr = Some(BinOper::In);
-        self.right = Some(SimpleExpr::SubQuery(Box::new(sel)));
-        self.into()
+    #[cfg(feature = "backend-postgres")]
+    pub fn get_json_path<V>(self, right: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        self.bin_oper(BinOper::GetJsonPath, SimpleExpr::Value(right.into()))
     }
 
-    /// Express a `NOT IN` sub-query expression.
+    /// Express a postgres JSON path access as text (`#>>`).
     ///
     /// # Examples
     ///
@@ -1263,36 +2563,24 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
-    ///     .from(Char::Table)
-    ///     .and_where(Expr::col(Char::SizeW).not_in_subquery(
-    ///         Query::select()
-    ///             .expr(Expr::cust("3 + 2 * 2"))
-    ///             .take()
-    ///     ))
+    ///     .expr(Expr::col(Alias::new("metadata")).cast_json_path("{size,width}"))
+    ///     .from(Glyph::Table)
     ///     .to_owned();
     ///
     /// assert_eq!(
-    ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` NOT IN (SELECT 3 + 2 * 2)"#
-    /// );
-    /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "size_w" NOT IN (SELECT 3 + 2 * 2)"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `size_w`, `size_h` FROM `character` WHERE `size_w` NOT IN (SELECT 3 + 2 * 2)"#
+    ///     r#"SELECT "metadata" #>> '{size,width}' FROM "glyph""#
     /// );
     /// ```
-    #[allow(clippy::wrong_self_convention)]
-    pub fn not_in_subquery(mut self, sel: SelectStatement) -> SimpleExpr {
-        self.bopr = Some(BinOper::NotIn);
-        self.right = Some(SimpleExpr::SubQuery(Box::new(sel)));
-        self.into()
+    #[cfg(feature = "backend-postgres")]
+    pub fn cast_json_path<V>(self, right: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        self.bin_oper(BinOper::CastJsonPath, SimpleExpr::Value(right.into()))
     }
 
-    /// Express an postgres fulltext search matches (`@@`) expression.
+    /// Express a postgres JSONB key existence (`?`) expression.
     ///
     /// # Examples
     ///
@@ -1300,26 +2588,25 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Font::Name, Font::Variant, Font::Language])
-    ///     .from(Font::Table)
-    ///     .and_where(Expr::val("a & b").matches(Expr::val("a b")))
-    ///     .and_where(Expr::col(Font::Name).matches(Expr::val("a b")))
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Alias::new("metadata")).has_key("size"))
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "name", "variant", "language" FROM "font" WHERE 'a & b' @@ 'a b' AND "name" @@ 'a b'"#
+    ///     r#"SELECT "id" FROM "glyph" WHERE "metadata" ? 'size'"#
     /// );
     /// ```
     #[cfg(feature = "backend-postgres")]
-    pub fn matches<T>(self, expr: T) -> SimpleExpr
+    pub fn has_key<V>(self, key: V) -> SimpleExpr
     where
-        T: Into<SimpleExpr>,
+        V: Into<Value>,
     {
-        self.bin_oper(BinOper::Matches, expr.into())
+        self.bin_oper(BinOper::HasKey, SimpleExpr::Value(key.into()))
     }
 
-    /// Express an postgres fulltext search contains (`@>`) expression.
+    /// Express a postgres array overlap (`&&`) expression.
     ///
     /// # Examples
     ///
@@ -1327,26 +2614,27 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Font::Name, Font::Variant, Font::Language])
+    ///     .column(Font::Name)
     ///     .from(Font::Table)
-    ///     .and_where(Expr::val("a & b").contains(Expr::val("a b")))
-    ///     .and_where(Expr::col(Font::Name).contains(Expr::val("a b")))
+    ///     .and_where(Expr::col(Alias::new("tags")).overlap(Expr::val(Value::Array(Some(Box::new(
+    ///         vec!["serif".into(), "mono".into()],
+    ///     ))))))
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "name", "variant", "language" FROM "font" WHERE 'a & b' @> 'a b' AND "name" @> 'a b'"#
+    ///     r#"SELECT "name" FROM "font" WHERE "tags" && ARRAY['serif', 'mono']"#
     /// );
     /// ```
     #[cfg(feature = "backend-postgres")]
-    pub fn contains<T>(self, expr: T) -> SimpleExpr
+    pub fn overlap<T>(self, expr: T) -> SimpleExpr
     where
         T: Into<SimpleExpr>,
     {
-        self.bin_oper(BinOper::Contains, expr.into())
+        self.bin_oper(BinOper::Overlap, expr.into())
     }
 
-    /// Express an postgres fulltext search contained (`<@`) expression.
+    /// Express a postgres array subscript, i.e. the element at a 1-based index.
     ///
     /// # Examples
     ///
@@ -1354,23 +2642,25 @@ impl Expr {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![Font::Name, Font::Variant, Font::Language])
+    ///     .expr(Expr::col(Alias::new("tags")).subscript(1))
     ///     .from(Font::Table)
-    ///     .and_where(Expr::val("a & b").contained(Expr::val("a b")))
-    ///     .and_where(Expr::col(Font::Name).contained(Expr::val("a b")))
+    ///     .and_where(Expr::col(Alias::new("tags")).subscript(2).eq("mono"))
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "name", "variant", "language" FROM "font" WHERE 'a & b' <@ 'a b' AND "name" <@ 'a b'"#
+    ///     r#"SELECT "tags"[1] FROM "font" WHERE "tags"[2] = 'mono'"#
     /// );
     /// ```
     #[cfg(feature = "backend-postgres")]
-    pub fn contained<T>(self, expr: T) -> SimpleExpr
+    pub fn subscript<V>(self, index: V) -> Self
     where
-        T: Into<SimpleExpr>,
+        V: Into<Value>,
     {
-        self.bin_oper(BinOper::Contained, expr.into())
+        Self::new_with_left(SimpleExpr::Subscript(
+            Box::new(self.into()),
+            Box::new(SimpleExpr::Value(index.into())),
+        ))
     }
 
     pub(crate) fn func(func: Function) -> Self {
@@ -1408,6 +2698,13 @@ impl Expr {
         self.into()
     }
 
+    fn quantified<T>(self, o: BinOper, q: UnOper, right: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.bin_oper(o, SimpleExpr::Unary(q, Box::new(right.into())))
+    }
+
     fn bin_oper(mut self, o: BinOper, e: SimpleExpr) -> SimpleExpr {
         self.bopr = Some(o);
         self.right = Some(e);
@@ -1420,6 +2717,21 @@ impl Expr {
     }
 }
 
+/// Escape character of the patterns built by [`Expr::starts_with`] and friends.
+/// SQLite has no default escape character, so it is always given explicitly.
+const LIKE_ESCAPE: char = '!';
+
+fn escape_like_pattern(v: &str) -> String {
+    v.chars()
+        .fold(String::with_capacity(v.len()), |mut pattern, c| {
+            if matches!(c, '%' | '_') || c == LIKE_ESCAPE {
+                pattern.push(LIKE_ESCAPE);
+            }
+            pattern.push(c);
+            pattern
+        })
+}
+
 impl Into<SimpleExpr> for Expr {
     /// Convert into SimpleExpr. Will panic if this Expr is missing an operand
     fn into(self) -> SimpleExpr {
@@ -1576,6 +2888,38 @@ impl SimpleExpr {
         self.binary(BinOper::NotEqual, right.into())
     }
 
+    /// Express a greater than (`>`) comparison with another [`SimpleExpr`].
+    pub fn greater_than<T>(self, right: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.binary(BinOper::GreaterThan, right.into())
+    }
+
+    /// Express a greater than or equal (`>=`) comparison with another [`SimpleExpr`].
+    pub fn greater_or_equal<T>(self, right: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.binary(BinOper::GreaterThanOrEqual, right.into())
+    }
+
+    /// Express a less than (`<`) comparison with another [`SimpleExpr`].
+    pub fn less_than<T>(self, right: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.binary(BinOper::SmallerThan, right.into())
+    }
+
+    /// Express a less than or equal (`<=`) comparison with another [`SimpleExpr`].
+    pub fn less_or_equal<T>(self, right: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.binary(BinOper::SmallerThanOrEqual, right.into())
+    }
+
     /// Perform addition with another [`SimpleExpr`].
     ///
     /// # Examples
@@ -1642,6 +2986,30 @@ impl SimpleExpr {
         self.binary(BinOper::Sub, right.into())
     }
 
+    /// Concatenate with another [`SimpleExpr`], see [`Expr::concat`].
+    pub fn concat<T>(self, right: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.binary(BinOper::Concat, right.into())
+    }
+
+    /// Perform bitwise `AND` with another [`SimpleExpr`], see [`Expr::bit_and`].
+    pub fn bit_and<T>(self, right: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.binary(BinOper::BitAnd, right.into())
+    }
+
+    /// Perform bitwise `OR` with another [`SimpleExpr`], see [`Expr::bit_or`].
+    pub fn bit_or<T>(self, right: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.binary(BinOper::BitOr, right.into())
+    }
+
     pub(crate) fn binary(self, op: BinOper, right: SimpleExpr) -> Self {
         SimpleExpr::Binary(Box::new(self), op, Box::new(right))
     }
@@ -1670,6 +3038,10 @@ impl SimpleExpr {
         }
     }
 
+    pub(crate) fn is_sub_query(&self) -> bool {
+        matches!(self, Self::SubQuery(_))
+    }
+
     pub(crate) fn is_binary(&self) -> bool {
         matches!(self, Self::Binary(_, _, _))
     }
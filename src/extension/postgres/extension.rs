@@ -0,0 +1,169 @@
+use crate::{prepare::*, types::*};
+
+/// Helper for constructing any extension statement
+#[derive(Debug)]
+pub struct Extension;
+
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) schema: Option<DynIden>,
+    pub(crate) version: Option<String>,
+    pub(crate) cascade: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionDropStatement {
+    pub(crate) names: Vec<DynIden>,
+    pub(crate) if_exists: bool,
+    pub(crate) cascade: bool,
+}
+
+pub trait ExtensionBuilder {
+    /// Translate [`ExtensionCreateStatement`] into database specific SQL statement.
+    fn prepare_extension_create_statement(
+        &self,
+        create: &ExtensionCreateStatement,
+        sql: &mut SqlWriter,
+    );
+
+    /// Translate [`ExtensionDropStatement`] into database specific SQL statement.
+    fn prepare_extension_drop_statement(&self, drop: &ExtensionDropStatement, sql: &mut SqlWriter);
+}
+
+impl Extension {
+    /// Construct extension [`ExtensionCreateStatement`]
+    pub fn create() -> ExtensionCreateStatement {
+        ExtensionCreateStatement::new()
+    }
+
+    /// Construct extension [`ExtensionDropStatement`]
+    pub fn drop() -> ExtensionDropStatement {
+        ExtensionDropStatement::new()
+    }
+}
+
+impl ExtensionCreateStatement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Install an extension
+    ///
+    /// ```
+    /// use sea_query::{*, extension::postgres::Extension};
+    ///
+    /// assert_eq!(
+    ///     Extension::create()
+    ///         .if_not_exists()
+    ///         .name(Alias::new("pg_trgm"))
+    ///         .schema(Alias::new("extensions"))
+    ///         .version("1.6")
+    ///         .cascade()
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"CREATE EXTENSION IF NOT EXISTS "pg_trgm" WITH SCHEMA "extensions" VERSION '1.6' CASCADE"#
+    /// );
+    /// ```
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Install the objects of the extension into a schema
+    pub fn schema<T>(&mut self, schema: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.schema = Some(schema.into_iden());
+        self
+    }
+
+    pub fn version(&mut self, version: &str) -> &mut Self {
+        self.version = Some(version.to_owned());
+        self
+    }
+
+    /// Install the extensions this extension depends on too
+    pub fn cascade(&mut self) -> &mut Self {
+        self.cascade = true;
+        self
+    }
+
+    pub fn build<T: ExtensionBuilder>(&self, extension_builder: T) -> String {
+        self.build_ref(&extension_builder)
+    }
+
+    pub fn build_ref<T: ExtensionBuilder>(&self, extension_builder: &T) -> String {
+        let mut sql = SqlWriter::new();
+        extension_builder.prepare_extension_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T: ExtensionBuilder>(&self, extension_builder: T) -> String {
+        self.build(extension_builder)
+    }
+}
+
+impl ExtensionDropStatement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove an extension
+    ///
+    /// ```
+    /// use sea_query::{*, extension::postgres::Extension};
+    ///
+    /// assert_eq!(
+    ///     Extension::drop()
+    ///         .if_exists()
+    ///         .name(Alias::new("pg_trgm"))
+    ///         .name(Alias::new("hstore"))
+    ///         .cascade()
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"DROP EXTENSION IF EXISTS "pg_trgm", "hstore" CASCADE"#
+    /// );
+    /// ```
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.names.push(name.into_iden());
+        self
+    }
+
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    pub fn cascade(&mut self) -> &mut Self {
+        self.cascade = true;
+        self
+    }
+
+    pub fn build<T: ExtensionBuilder>(&self, extension_builder: T) -> String {
+        self.build_ref(&extension_builder)
+    }
+
+    pub fn build_ref<T: ExtensionBuilder>(&self, extension_builder: &T) -> String {
+        let mut sql = SqlWriter::new();
+        extension_builder.prepare_extension_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T: ExtensionBuilder>(&self, extension_builder: T) -> String {
+        self.build(extension_builder)
+    }
+}
@@ -3,7 +3,8 @@
 use crate::{expr::*, func::Function};
 
 /// Functions
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PgFunction {
     ToTsquery,
     ToTsvector,
@@ -12,6 +13,8 @@ pub enum PgFunction {
     WebsearchToTsquery,
     TsRank,
     TsRankCd,
+    ArrayAgg,
+    Unnest,
 }
 
 /// Function call helper.
@@ -229,4 +232,55 @@ impl PgFunc {
     {
         Expr::func(Function::PgFunction(PgFunction::TsRankCd)).args(vec![vector, query])
     }
+
+    /// Call `ARRAY_AGG` function, collecting the values of a group into an array. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::FontId)
+    ///     .expr(PgFunc::array_agg(Expr::col(Char::Id)))
+    ///     .from(Char::Table)
+    ///     .group_by_col(Char::FontId)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", ARRAY_AGG("id") FROM "character" GROUP BY "font_id""#
+    /// );
+    /// ```
+    pub fn array_agg<T>(expr: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        Expr::func(Function::PgFunction(PgFunction::ArrayAgg)).arg(expr)
+    }
+
+    /// Call `UNNEST` function, expanding an array into a set of rows. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Id)
+    ///     .expr(PgFunc::unnest(Expr::col(Alias::new("tags"))))
+    ///     .from(Font::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id", UNNEST("tags") FROM "font""#
+    /// );
+    /// ```
+    pub fn unnest<T>(expr: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        Expr::func(Function::PgFunction(PgFunction::Unnest)).arg(expr)
+    }
 }
@@ -1,5 +1,7 @@
+pub(crate) mod extension;
 pub(crate) mod func;
 pub(crate) mod types;
 
+pub use extension::*;
 pub use func::*;
 pub use types::*;
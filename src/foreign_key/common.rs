@@ -1,7 +1,7 @@
 use crate::types::*;
 
 /// Specification of a foreign key
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TableForeignKey {
     pub(crate) name: Option<String>,
     pub(crate) table: Option<DynIden>,
@@ -10,10 +10,12 @@ pub struct TableForeignKey {
     pub(crate) ref_columns: Vec<DynIden>,
     pub(crate) on_delete: Option<ForeignKeyAction>,
     pub(crate) on_update: Option<ForeignKeyAction>,
+    pub(crate) match_type: Option<ForeignKeyMatch>,
+    pub(crate) deferrable: Option<ForeignKeyDeferrable>,
 }
 
 /// Foreign key on update & on delete actions
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ForeignKeyAction {
     Restrict,
     Cascade,
@@ -22,6 +24,26 @@ pub enum ForeignKeyAction {
     SetDefault,
 }
 
+/// Foreign key matching of composite keys with null columns
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForeignKeyMatch {
+    /// Null columns are allowed only if all of them are null
+    Full,
+    Partial,
+    /// Any null column exempts the row from the check
+    Simple,
+}
+
+/// Foreign key check timing within a transaction
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForeignKeyDeferrable {
+    NotDeferrable,
+    /// Checked after each statement, unless deferred with `SET CONSTRAINTS`
+    InitiallyImmediate,
+    /// Checked at commit, allowing circular references to be inserted in any order
+    InitiallyDeferred,
+}
+
 impl Default for TableForeignKey {
     fn default() -> Self {
         Self::new()
@@ -39,6 +61,8 @@ impl TableForeignKey {
             ref_columns: Vec::new(),
             on_delete: None,
             on_update: None,
+            match_type: None,
+            deferrable: None,
         }
     }
 
@@ -96,6 +120,18 @@ impl TableForeignKey {
         self
     }
 
+    /// Set match type
+    pub fn match_type(&mut self, match_type: ForeignKeyMatch) -> &mut Self {
+        self.match_type = Some(match_type);
+        self
+    }
+
+    /// Set check timing
+    pub fn deferrable(&mut self, deferrable: ForeignKeyDeferrable) -> &mut Self {
+        self.deferrable = Some(deferrable);
+        self
+    }
+
     pub fn get_ref_table(&self) -> Option<String> {
         self.ref_table.as_ref().map(|ref_tbl| ref_tbl.to_string())
     }
@@ -120,6 +156,8 @@ impl TableForeignKey {
             ref_columns: std::mem::take(&mut self.ref_columns),
             on_delete: self.on_delete.take(),
             on_update: self.on_update.take(),
+            match_type: self.match_type.take(),
+            deferrable: self.deferrable.take(),
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
-    backend::SchemaBuilder, prepare::*, types::*, ForeignKeyAction, SchemaStatementBuilder,
-    TableForeignKey,
+    backend::SchemaBuilder, prepare::*, types::*, ForeignKeyAction, ForeignKeyDeferrable,
+    ForeignKeyMatch, SchemaStatementBuilder, TableForeignKey,
 };
 
 /// Create a foreign key constraint for an existing table. Unsupported by Sqlite
@@ -67,7 +67,7 @@ use crate::{
 ///     ].join(" ")
 /// );
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ForeignKeyCreateStatement {
     pub(crate) foreign_key: TableForeignKey,
 }
@@ -196,6 +196,43 @@ impl ForeignKeyCreateStatement {
         self
     }
 
+    /// Set match type, deciding how composite keys with null columns are checked
+    pub fn match_type(&mut self, match_type: ForeignKeyMatch) -> &mut Self {
+        self.foreign_key.match_type(match_type);
+        self
+    }
+
+    /// Set check timing. Not supported by MySQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let foreign_key = ForeignKey::create()
+    ///     .name("FK_character_font")
+    ///     .from(Char::Table, Char::FontId)
+    ///     .to(Font::Table, Font::Id)
+    ///     .match_type(ForeignKeyMatch::Full)
+    ///     .on_delete(ForeignKeyAction::SetDefault)
+    ///     .deferrable(ForeignKeyDeferrable::InitiallyDeferred)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     foreign_key.to_string(PostgresQueryBuilder),
+    ///     vec![
+    ///         r#"ALTER TABLE "character" ADD CONSTRAINT "FK_character_font""#,
+    ///         r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id") MATCH FULL"#,
+    ///         r#"ON DELETE SET DEFAULT DEFERRABLE INITIALLY DEFERRED"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn deferrable(&mut self, deferrable: ForeignKeyDeferrable) -> &mut Self {
+        self.foreign_key.deferrable(deferrable);
+        self
+    }
+
     pub fn get_foreign_key(&self) -> &TableForeignKey {
         &self.foreign_key
     }
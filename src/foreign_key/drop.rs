@@ -28,6 +28,7 @@ use crate::{
 pub struct ForeignKeyDropStatement {
     pub(crate) foreign_key: TableForeignKey,
     pub(crate) table: Option<DynIden>,
+    pub(crate) if_exists: bool,
 }
 
 impl Default for ForeignKeyDropStatement {
@@ -42,10 +43,34 @@ impl ForeignKeyDropStatement {
         Self {
             foreign_key: Default::default(),
             table: None,
+            if_exists: false,
         }
     }
 
     /// Set foreign key name
+    /// Drop foreign key only if it exists. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let foreign_key = ForeignKey::drop()
+    ///     .if_exists()
+    ///     .name("FK_character_font")
+    ///     .table(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     foreign_key.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "character" DROP CONSTRAINT IF EXISTS "FK_character_font""#
+    /// );
+    /// ```
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
     pub fn name(&mut self, name: &str) -> &mut Self {
         self.foreign_key.name(name);
         self
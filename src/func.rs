@@ -1,12 +1,13 @@
 //! For calling built-in SQL functions.
 
-use crate::{expr::*, types::*};
+use crate::{expr::*, types::*, value::*};
 
 #[cfg(feature = "backend-postgres")]
 pub use crate::extension::postgres::{PgFunc, PgFunction};
 
 /// Functions
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Function {
     Max,
     Min,
@@ -14,10 +15,27 @@ pub enum Function {
     Avg,
     Count,
     IfNull,
+    Coalesce,
+    NullIf,
+    Greatest,
+    Least,
+    /// String aggregation with separator and ordering
+    StringAgg(String, Vec<OrderExpr>),
+    /// JSON extraction along a path of object keys
+    JsonExtract(Vec<String>),
+    JsonBuildObject,
+    /// JSON modification along a path of object keys
+    JsonSet(Vec<String>),
     CharLength,
+    /// Advance a sequence and return its new value
+    NextVal,
+    /// Current value of a sequence in this session
+    CurrVal,
+    /// Set the current value of a sequence
+    SetVal,
     #[cfg(feature = "backend-postgres")]
     PgFunction(PgFunction),
-    Custom(DynIden),
+    Custom(#[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden),
 }
 
 /// Function call helper.
@@ -160,6 +178,38 @@ impl Func {
         Expr::func(Function::Sum).arg(expr)
     }
 
+    /// Call `SUM` function over distinct values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::sum_distinct(Expr::tbl(Char::Table, Char::FontId)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT SUM(DISTINCT `character`.`font_id`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT SUM(DISTINCT "character"."font_id") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT SUM(DISTINCT `character`.`font_id`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn sum_distinct<T>(expr: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        Expr::func(Function::Sum).arg(SimpleExpr::Unary(UnOper::Distinct, Box::new(expr.into())))
+    }
+
     /// Call `AVG` function.
     ///
     /// # Examples
@@ -192,6 +242,38 @@ impl Func {
         Expr::func(Function::Avg).arg(expr)
     }
 
+    /// Call `AVG` function over distinct values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::avg_distinct(Expr::tbl(Char::Table, Char::FontId)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT AVG(DISTINCT `character`.`font_id`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT AVG(DISTINCT "character"."font_id") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT AVG(DISTINCT `character`.`font_id`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn avg_distinct<T>(expr: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        Expr::func(Function::Avg).arg(SimpleExpr::Unary(UnOper::Distinct, Box::new(expr.into())))
+    }
+
     /// Call `COUNT` function.
     ///
     /// # Examples
@@ -224,6 +306,38 @@ impl Func {
         Expr::func(Function::Count).arg(expr)
     }
 
+    /// Call `COUNT` function over distinct values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::count_distinct(Expr::tbl(Char::Table, Char::FontId)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT COUNT(DISTINCT `character`.`font_id`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT COUNT(DISTINCT "character"."font_id") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT COUNT(DISTINCT `character`.`font_id`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn count_distinct<T>(expr: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        Expr::func(Function::Count).arg(SimpleExpr::Unary(UnOper::Distinct, Box::new(expr.into())))
+    }
+
     /// Call `CHAR_LENGTH` function.
     ///
     /// # Examples
@@ -288,4 +402,453 @@ impl Func {
     {
         Expr::func(Function::IfNull).args(vec![a.into(), b.into()])
     }
+
+    /// Call `COALESCE` function, returning the first argument that is not null.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::coalesce(vec![
+    ///         Expr::col(Char::SizeW).into_simple_expr(),
+    ///         Expr::col(Char::SizeH).into_simple_expr(),
+    ///         Expr::val(12).into_simple_expr(),
+    ///     ]))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT COALESCE(`size_w`, `size_h`, 12) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT COALESCE("size_w", "size_h", 12) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT COALESCE(`size_w`, `size_h`, 12) FROM `character`"#
+    /// );
+    /// ```
+    pub fn coalesce<T, I>(args: I) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        Expr::func(Function::Coalesce).args(args)
+    }
+
+    /// Call `NULLIF` function, returning null if both arguments are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::null_if(Expr::col(Char::Character), Expr::val("")))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT NULLIF(`character`, '') FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT NULLIF("character", '') FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT NULLIF(`character`, '') FROM `character`"#
+    /// );
+    /// ```
+    pub fn null_if<A, B>(a: A, b: B) -> SimpleExpr
+    where
+        A: Into<SimpleExpr>,
+        B: Into<SimpleExpr>,
+    {
+        Expr::func(Function::NullIf).args(vec![a.into(), b.into()])
+    }
+
+    /// Call `GREATEST` function, returning the largest argument. SQLite has no `GREATEST`;
+    /// the multi-argument `MAX` is used instead, which would be the aggregate `MAX`
+    /// with a single argument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than two arguments are given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::greatest(vec![Expr::col(Char::SizeW), Expr::col(Char::SizeH)]))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT GREATEST(`size_w`, `size_h`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT GREATEST("size_w", "size_h") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT MAX(`size_w`, `size_h`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn greatest<T, I>(args: I) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        let args: Vec<SimpleExpr> = args.into_iter().map(|arg| arg.into()).collect();
+        if args.len() < 2 {
+            panic!("GREATEST requires at least two arguments");
+        }
+        Expr::func(Function::Greatest).args(args)
+    }
+
+    /// Call `LEAST` function, returning the smallest argument. SQLite has no `LEAST`;
+    /// the multi-argument `MIN` is used instead, which would be the aggregate `MIN`
+    /// with a single argument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than two arguments are given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::least(vec![Expr::col(Char::SizeW), Expr::col(Char::SizeH)]))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT LEAST(`size_w`, `size_h`) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT LEAST("size_w", "size_h") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT MIN(`size_w`, `size_h`) FROM `character`"#
+    /// );
+    /// ```
+    pub fn least<T, I>(args: I) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        let args: Vec<SimpleExpr> = args.into_iter().map(|arg| arg.into()).collect();
+        if args.len() < 2 {
+            panic!("LEAST requires at least two arguments");
+        }
+        Expr::func(Function::Least).args(args)
+    }
+
+    /// Call `STRING_AGG` function, concatenating the values of a group with a separator.
+    /// Translated to `GROUP_CONCAT` in MySQL and SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::FontId)
+    ///     .expr(Func::string_agg(Expr::col(Char::Character), ", "))
+    ///     .from(Char::Table)
+    ///     .group_by_col(Char::FontId)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `font_id`, GROUP_CONCAT(`character` SEPARATOR ', ') FROM `character` GROUP BY `font_id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", STRING_AGG("character", ', ') FROM "character" GROUP BY "font_id""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `font_id`, GROUP_CONCAT(`character`, ', ') FROM `character` GROUP BY `font_id`"#
+    /// );
+    /// ```
+    pub fn string_agg<T>(expr: T, separator: &str) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        Self::string_agg_order_by(expr, separator, Vec::<(SimpleExpr, Order)>::new())
+    }
+
+    /// Call `STRING_AGG` function, concatenating the values of a group in the given order.
+    /// Ordering inside `GROUP_CONCAT` requires SQLite 3.44 or later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::FontId)
+    ///     .expr(Func::string_agg_order_by(
+    ///         Expr::col(Char::Character),
+    ///         ",",
+    ///         vec![(Expr::col(Char::SizeW), Order::Desc)],
+    ///     ))
+    ///     .from(Char::Table)
+    ///     .group_by_col(Char::FontId)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `font_id`, GROUP_CONCAT(`character` ORDER BY `size_w` DESC SEPARATOR ',') FROM `character` GROUP BY `font_id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", STRING_AGG("character", ',' ORDER BY "size_w" DESC) FROM "character" GROUP BY "font_id""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `font_id`, GROUP_CONCAT(`character`, ',' ORDER BY `size_w` DESC) FROM `character` GROUP BY `font_id`"#
+    /// );
+    /// ```
+    pub fn string_agg_order_by<T, O, I>(expr: T, separator: &str, orders: I) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+        O: Into<SimpleExpr>,
+        I: IntoIterator<Item = (O, Order)>,
+    {
+        let orders = orders
+            .into_iter()
+            .map(|(expr, order)| OrderExpr {
+                expr: expr.into(),
+                order,
+                nulls: None,
+                field: None,
+            })
+            .collect();
+        Expr::func(Function::StringAgg(separator.to_owned(), orders)).arg(expr)
+    }
+
+    /// Call `JSON_EXTRACT` function, getting the value at a path of object keys from a JSON document.
+    /// Translated to `JSONB_EXTRACT_PATH` in Postgres.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::json_extract(
+    ///         Expr::col(Alias::new("metadata")),
+    ///         vec!["size", "width"],
+    ///     ))
+    ///     .from(Glyph::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT JSON_EXTRACT(`metadata`, '$.size.width') FROM `glyph`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT JSONB_EXTRACT_PATH("metadata", 'size', 'width') FROM "glyph""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT JSON_EXTRACT(`metadata`, '$.size.width') FROM `glyph`"#
+    /// );
+    /// ```
+    pub fn json_extract<T, K, I>(expr: T, keys: I) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+        K: Into<String>,
+        I: IntoIterator<Item = K>,
+    {
+        let keys = keys.into_iter().map(Into::into).collect();
+        Expr::func(Function::JsonExtract(keys)).arg(expr)
+    }
+
+    /// Call `JSON_BUILD_OBJECT` function, building a JSON object from pairs of keys and values.
+    /// Translated to `JSON_OBJECT` in MySQL and SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::json_build_object(vec![
+    ///         ("id", Expr::col(Glyph::Id).into_simple_expr()),
+    ///         ("aspect", Expr::col(Glyph::Aspect).into_simple_expr()),
+    ///     ]))
+    ///     .from(Glyph::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT JSON_OBJECT('id', `id`, 'aspect', `aspect`) FROM `glyph`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT JSON_BUILD_OBJECT('id', "id", 'aspect', "aspect") FROM "glyph""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT JSON_OBJECT('id', `id`, 'aspect', `aspect`) FROM `glyph`"#
+    /// );
+    /// ```
+    pub fn json_build_object<K, V, I>(pairs: I) -> SimpleExpr
+    where
+        K: Into<String>,
+        V: Into<SimpleExpr>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let args = pairs
+            .into_iter()
+            .fold(Vec::new(), |mut args, (key, value)| {
+                args.push(SimpleExpr::Value(Value::String(Some(Box::new(key.into())))));
+                args.push(value.into());
+                args
+            });
+        Expr::func(Function::JsonBuildObject).args(args)
+    }
+
+    /// Call `JSON_SET` function, replacing the value at a path of object keys in a JSON document.
+    /// Translated to `JSONB_SET` in Postgres, where the value has to be `jsonb`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value_expr(
+    ///         Alias::new("metadata"),
+    ///         Func::json_set(Expr::col(Alias::new("metadata")), vec!["size", "width"], Expr::val(12)),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `metadata` = JSON_SET(`metadata`, '$.size.width', 12)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "glyph" SET "metadata" = JSONB_SET("metadata", ARRAY['size', 'width'], 12)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `metadata` = JSON_SET(`metadata`, '$.size.width', 12)"#
+    /// );
+    /// ```
+    pub fn json_set<T, K, I, V>(expr: T, keys: I, value: V) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+        K: Into<String>,
+        I: IntoIterator<Item = K>,
+        V: Into<SimpleExpr>,
+    {
+        let keys = keys.into_iter().map(Into::into).collect();
+        Expr::func(Function::JsonSet(keys)).args(vec![expr.into(), value.into()])
+    }
+
+    /// Call `NEXTVAL` function, advancing a sequence. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Id, Glyph::Aspect])
+    ///     .exprs_panic(vec![Func::nextval(Alias::new("glyph_id_seq")), Expr::val(2).into()])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("id", "aspect") VALUES (NEXTVAL('glyph_id_seq'), 2)"#
+    /// );
+    /// ```
+    pub fn nextval<T>(sequence: T) -> SimpleExpr
+    where
+        T: IntoIden,
+    {
+        Expr::func(Function::NextVal).arg(Self::sequence_name(sequence))
+    }
+
+    /// Call `CURRVAL` function, returning the value last obtained from a sequence in this
+    /// session. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::currval(Alias::new("glyph_id_seq")))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CURRVAL('glyph_id_seq')"#
+    /// );
+    /// ```
+    pub fn currval<T>(sequence: T) -> SimpleExpr
+    where
+        T: IntoIden,
+    {
+        Expr::func(Function::CurrVal).arg(Self::sequence_name(sequence))
+    }
+
+    /// Call `SETVAL` function, setting the current value of a sequence. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::setval(
+    ///         Alias::new("glyph_id_seq"),
+    ///         Func::max(Expr::col(Glyph::Id)),
+    ///     ))
+    ///     .from(Glyph::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT SETVAL('glyph_id_seq', MAX("id")) FROM "glyph""#
+    /// );
+    /// ```
+    pub fn setval<T, V>(sequence: T, value: V) -> SimpleExpr
+    where
+        T: IntoIden,
+        V: Into<SimpleExpr>,
+    {
+        Expr::func(Function::SetVal).args(vec![Self::sequence_name(sequence), value.into()])
+    }
+
+    fn sequence_name<T>(sequence: T) -> SimpleExpr
+    where
+        T: IntoIden,
+    {
+        Expr::val(sequence.into_iden().to_string()).into()
+    }
 }
@@ -1,25 +1,79 @@
-use crate::types::*;
+use crate::{expr::SimpleExpr, types::*};
 
 /// Specification of a table index
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TableIndex {
     pub(crate) name: Option<String>,
     pub(crate) columns: Vec<IndexColumn>,
 }
 
-#[derive(Debug, Clone)]
+/// Indexed column or expression, with its options
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let index = Index::create()
+///     .name("idx-glyph-image")
+///     .table(Glyph::Table)
+///     .col(
+///         IndexColumn::new(Glyph::Image)
+///             .opclass(Alias::new("text_pattern_ops"))
+///             .order(IndexOrder::Desc)
+///             .nulls(NullOrdering::Last)
+///             .to_owned(),
+///     )
+///     .to_owned();
+///
+/// assert_eq!(
+///     index.to_string(PostgresQueryBuilder),
+///     r#"CREATE INDEX "idx-glyph-image" ON "glyph" ("image" text_pattern_ops DESC NULLS LAST)"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
 pub struct IndexColumn {
-    pub(crate) name: DynIden,
+    pub(crate) expr: IndexExpr,
     pub(crate) prefix: Option<u32>,
     pub(crate) order: Option<IndexOrder>,
+    pub(crate) nulls: Option<NullOrdering>,
+    pub(crate) opclass: Option<DynIden>,
 }
 
-#[derive(Debug, Clone)]
+/// Indexed column or expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexExpr {
+    Column(DynIden),
+    /// Expression computed from the columns of a row, e.g. `lower(email)`
+    Expr(SimpleExpr),
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum IndexOrder {
     Asc,
     Desc,
 }
 
+/// Algorithm used by MySQL to build or drop an index, i.e. `ALGORITHM=...`
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexAlgorithm {
+    Default,
+    /// Build the index in place, without copying the table
+    Inplace,
+    Copy,
+}
+
+/// Level of concurrent access allowed by MySQL while building or dropping an index,
+/// i.e. `LOCK=...`
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexLock {
+    Default,
+    /// Allow concurrent reads and writes
+    None,
+    Shared,
+    Exclusive,
+}
+
 pub trait IntoIndexColumn {
     fn into_index_column(self) -> IndexColumn;
 }
@@ -42,9 +96,11 @@ where
 {
     fn into_index_column(self) -> IndexColumn {
         IndexColumn {
-            name: self.into_iden(),
+            expr: IndexExpr::Column(self.into_iden()),
             prefix: None,
             order: None,
+            nulls: None,
+            opclass: None,
         }
     }
 }
@@ -55,9 +111,11 @@ where
 {
     fn into_index_column(self) -> IndexColumn {
         IndexColumn {
-            name: self.0.into_iden(),
+            expr: IndexExpr::Column(self.0.into_iden()),
             prefix: Some(self.1),
             order: None,
+            nulls: None,
+            opclass: None,
         }
     }
 }
@@ -68,9 +126,11 @@ where
 {
     fn into_index_column(self) -> IndexColumn {
         IndexColumn {
-            name: self.0.into_iden(),
+            expr: IndexExpr::Column(self.0.into_iden()),
             prefix: None,
             order: Some(self.1),
+            nulls: None,
+            opclass: None,
         }
     }
 }
@@ -81,13 +141,77 @@ where
 {
     fn into_index_column(self) -> IndexColumn {
         IndexColumn {
-            name: self.0.into_iden(),
+            expr: IndexExpr::Column(self.0.into_iden()),
             prefix: Some(self.1),
             order: Some(self.2),
+            nulls: None,
+            opclass: None,
         }
     }
 }
 
+impl IntoIndexColumn for SimpleExpr {
+    fn into_index_column(self) -> IndexColumn {
+        IndexColumn {
+            expr: IndexExpr::Expr(self),
+            prefix: None,
+            order: None,
+            nulls: None,
+            opclass: None,
+        }
+    }
+}
+
+impl IntoIndexColumn for (SimpleExpr, IndexOrder) {
+    fn into_index_column(self) -> IndexColumn {
+        IndexColumn {
+            expr: IndexExpr::Expr(self.0),
+            prefix: None,
+            order: Some(self.1),
+            nulls: None,
+            opclass: None,
+        }
+    }
+}
+
+impl IndexColumn {
+    /// Construct a new index column from a column, an expression or a tuple of them with
+    /// options, see [`IntoIndexColumn`]
+    pub fn new<C>(col: C) -> Self
+    where
+        C: IntoIndexColumn,
+    {
+        col.into_index_column()
+    }
+
+    /// Index only the first characters of a string column. MySQL only.
+    pub fn prefix(&mut self, prefix: u32) -> &mut Self {
+        self.prefix = Some(prefix);
+        self
+    }
+
+    /// Set sort order
+    pub fn order(&mut self, order: IndexOrder) -> &mut Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Set ordering of nulls. Postgres only.
+    pub fn nulls(&mut self, nulls: NullOrdering) -> &mut Self {
+        self.nulls = Some(nulls);
+        self
+    }
+
+    /// Set operator class, e.g. `text_pattern_ops`. Postgres only.
+    pub fn opclass<T>(&mut self, opclass: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.opclass = Some(opclass.into_iden());
+        self
+    }
+}
+
 impl TableIndex {
     /// Construct a new table index
     pub fn new() -> Self {
@@ -109,10 +233,14 @@ impl TableIndex {
         self
     }
 
+    /// Names of the indexed columns, skipping expressions
     pub fn get_column_names(&self) -> Vec<String> {
         self.columns
             .iter()
-            .map(|col| col.name.to_string())
+            .filter_map(|col| match &col.expr {
+                IndexExpr::Column(name) => Some(name.to_string()),
+                IndexExpr::Expr(_) => None,
+            })
             .collect()
     }
 
@@ -1,5 +1,8 @@
 use super::common::*;
-use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder};
+use crate::{
+    backend::SchemaBuilder, prepare::*, types::*, ConditionHolder, ConditionalStatement,
+    IntoCondition, LogicalChainOper, SchemaStatementBuilder,
+};
 
 /// Create an index for an existing table
 ///
@@ -96,21 +99,80 @@ use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder
 ///     r#"CREATE INDEX `idx-glyph-aspect` ON `glyph` (`aspect` ASC)"#
 /// );
 /// ```
-#[derive(Debug, Clone)]
+/// Index on expressions, each wrapped in parentheses
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let index = Index::create()
+///     .name("idx-font-name")
+///     .table(Font::Table)
+///     .col(Func::cust(Alias::new("lower")).args(vec![Expr::col(Font::Name)]))
+///     .col((Expr::col(Font::Id).add(1), IndexOrder::Desc))
+///     .to_owned();
+///
+/// assert_eq!(
+///     index.to_string(MysqlQueryBuilder),
+///     r#"CREATE INDEX `idx-font-name` ON `font` ((lower(`name`)), (`id` + 1) DESC)"#
+/// );
+/// assert_eq!(
+///     index.to_string(PostgresQueryBuilder),
+///     r#"CREATE INDEX "idx-font-name" ON "font" ((lower("name")), ("id" + 1) DESC)"#
+/// );
+/// assert_eq!(
+///     index.to_string(SqliteQueryBuilder),
+///     r#"CREATE INDEX `idx-font-name` ON `font` ((lower(`name`)), (`id` + 1) DESC)"#
+/// );
+/// ```
+/// Partial index, covering only the rows matching the condition. Not supported by MySQL.
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let index = Index::create()
+///     .unique()
+///     .name("idx-font-name")
+///     .table(Font::Table)
+///     .col(Font::Name)
+///     .and_where(Expr::col(Font::Variant).ne("Deleted"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     index.to_string(PostgresQueryBuilder),
+///     r#"CREATE UNIQUE INDEX "idx-font-name" ON "font" ("name") WHERE "variant" <> 'Deleted'"#
+/// );
+/// assert_eq!(
+///     index.to_string(SqliteQueryBuilder),
+///     r#"CREATE UNIQUE INDEX `idx-font-name` ON `font` (`name`) WHERE `variant` <> 'Deleted'"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
 pub struct IndexCreateStatement {
     pub(crate) table: Option<DynIden>,
     pub(crate) index: TableIndex,
     pub(crate) primary: bool,
     pub(crate) unique: bool,
     pub(crate) index_type: Option<IndexType>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) r#where: ConditionHolder,
+    pub(crate) include: Vec<DynIden>,
+    pub(crate) concurrently: bool,
+    pub(crate) algorithm: Option<IndexAlgorithm>,
+    pub(crate) lock: Option<IndexLock>,
 }
 
 /// Specification of a table index
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum IndexType {
     BTree,
     FullText,
     Hash,
+    /// Generalized inverted index, e.g. for `jsonb` and arrays. Postgres only.
+    Gin,
+    /// Generalized search tree, e.g. for geometric types and ranges. Postgres only.
+    Gist,
+    /// Block range index, for large naturally ordered tables. Postgres only.
+    Brin,
+    /// Spatial index. On MySQL, this is `SPATIAL`. On PgSQL, this is `GIST`.
+    Spatial,
     Custom(DynIden),
 }
 
@@ -129,9 +191,98 @@ impl IndexCreateStatement {
             primary: false,
             unique: false,
             index_type: None,
+            if_not_exists: false,
+            r#where: ConditionHolder::new(),
+            include: Vec::new(),
+            concurrently: false,
+            algorithm: None,
+            lock: None,
         }
     }
 
+    /// Create index if it does not exist. Not supported by MySQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let index = Index::create()
+    ///     .if_not_exists()
+    ///     .name("idx-glyph-aspect")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Aspect)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE INDEX IF NOT EXISTS "idx-glyph-aspect" ON "glyph" ("aspect")"#
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE INDEX IF NOT EXISTS `idx-glyph-aspect` ON `glyph` (`aspect`)"#
+    /// );
+    /// ```
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Build the index without locking out writes to the table. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let index = Index::create()
+    ///     .concurrently()
+    ///     .name("idx-glyph-aspect")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Aspect)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE INDEX CONCURRENTLY "idx-glyph-aspect" ON "glyph" ("aspect")"#
+    /// );
+    /// ```
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// Set the algorithm building the index. MySQL only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let index = Index::create()
+    ///     .name("idx-glyph-aspect")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Aspect)
+    ///     .algorithm(IndexAlgorithm::Inplace)
+    ///     .lock(IndexLock::None)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE INDEX `idx-glyph-aspect` ON `glyph` (`aspect`) ALGORITHM=INPLACE LOCK=NONE"#
+    /// );
+    /// ```
+    pub fn algorithm(&mut self, algorithm: IndexAlgorithm) -> &mut Self {
+        self.algorithm = Some(algorithm);
+        self
+    }
+
+    /// Set the concurrent access allowed while building the index. MySQL only.
+    pub fn lock(&mut self, lock: IndexLock) -> &mut Self {
+        self.lock = Some(lock);
+        self
+    }
+
     /// Set index name
     pub fn name(&mut self, name: &str) -> &mut Self {
         self.index.name(name);
@@ -156,6 +307,36 @@ impl IndexCreateStatement {
         self
     }
 
+    /// Add a non-key column stored in the index, allowing index-only scans to cover it.
+    /// Postgres 11+ only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let index = Index::create()
+    ///     .unique()
+    ///     .name("idx-glyph-id")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Id)
+    ///     .include(Glyph::Image)
+    ///     .include(Glyph::Aspect)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE UNIQUE INDEX "idx-glyph-id" ON "glyph" ("id") INCLUDE ("image", "aspect")"#
+    /// );
+    /// ```
+    pub fn include<C>(&mut self, col: C) -> &mut Self
+    where
+        C: IntoIden,
+    {
+        self.include.push(col.into_iden());
+        self
+    }
+
     /// Set index as primary
     pub fn primary(&mut self) -> &mut Self {
         self.primary = true;
@@ -175,7 +356,32 @@ impl IndexCreateStatement {
         self.index_type(IndexType::FullText)
     }
 
+    /// Set index as spatial.
+    /// On MySQL, this is `SPATIAL`.
+    /// On PgSQL, this is `GIST`.
+    pub fn spatial(&mut self) -> &mut Self {
+        self.index_type(IndexType::Spatial)
+    }
+
     /// Set index type. Not available on Sqlite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let index = Index::create()
+    ///     .name("idx-glyph-image")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Image)
+    ///     .index_type(IndexType::Gin)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE INDEX "idx-glyph-image" ON "glyph" USING GIN ("image")"#
+    /// );
+    /// ```
     pub fn index_type(&mut self, index_type: IndexType) -> &mut Self {
         self.index_type = Some(index_type);
         self
@@ -196,10 +402,31 @@ impl IndexCreateStatement {
             primary: self.primary,
             unique: self.unique,
             index_type: self.index_type.take(),
+            if_not_exists: self.if_not_exists,
+            r#where: std::mem::take(&mut self.r#where),
+            include: std::mem::take(&mut self.include),
+            concurrently: self.concurrently,
+            algorithm: self.algorithm.take(),
+            lock: self.lock.take(),
         }
     }
 }
 
+impl ConditionalStatement for IndexCreateStatement {
+    fn and_or_where(&mut self, condition: LogicalChainOper) -> &mut Self {
+        self.r#where.add_and_or(condition);
+        self
+    }
+
+    fn cond_where<C>(&mut self, condition: C) -> &mut Self
+    where
+        C: IntoCondition,
+    {
+        self.r#where.add_condition(condition.into_condition());
+        self
+    }
+}
+
 impl SchemaStatementBuilder for IndexCreateStatement {
     fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
         let mut sql = SqlWriter::new();
@@ -1,4 +1,7 @@
-use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder, TableIndex};
+use crate::{
+    backend::SchemaBuilder, prepare::*, types::*, IndexAlgorithm, IndexLock,
+    SchemaStatementBuilder, TableIndex,
+};
 
 /// Drop an index for an existing table
 ///
@@ -29,6 +32,10 @@ use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder
 pub struct IndexDropStatement {
     pub(crate) table: Option<DynIden>,
     pub(crate) index: TableIndex,
+    pub(crate) if_exists: bool,
+    pub(crate) concurrently: bool,
+    pub(crate) algorithm: Option<IndexAlgorithm>,
+    pub(crate) lock: Option<IndexLock>,
 }
 
 impl Default for IndexDropStatement {
@@ -43,9 +50,75 @@ impl IndexDropStatement {
         Self {
             table: None,
             index: Default::default(),
+            if_exists: false,
+            concurrently: false,
+            algorithm: None,
+            lock: None,
         }
     }
 
+    /// Drop index only if it exists. Not supported by MySQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let index = Index::drop()
+    ///     .if_exists()
+    ///     .name("idx-glyph-aspect")
+    ///     .table(Glyph::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"DROP INDEX IF EXISTS "idx-glyph-aspect""#
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(SqliteQueryBuilder),
+    ///     r#"DROP INDEX IF EXISTS `idx-glyph-aspect` ON `glyph`"#
+    /// );
+    /// ```
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Drop the index without locking out access to the table. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let index = Index::drop()
+    ///     .concurrently()
+    ///     .name("idx-glyph-aspect")
+    ///     .table(Glyph::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"DROP INDEX CONCURRENTLY "idx-glyph-aspect""#
+    /// );
+    /// ```
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// Set the algorithm dropping the index. MySQL only.
+    pub fn algorithm(&mut self, algorithm: IndexAlgorithm) -> &mut Self {
+        self.algorithm = Some(algorithm);
+        self
+    }
+
+    /// Set the concurrent access allowed while dropping the index. MySQL only.
+    pub fn lock(&mut self, lock: IndexLock) -> &mut Self {
+        self.lock = Some(lock);
+        self
+    }
+
     /// Set index name
     pub fn name(&mut self, name: &str) -> &mut Self {
         self.index.name(name);
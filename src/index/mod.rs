@@ -4,15 +4,18 @@
 //!
 //! - Table Index Create, see [`IndexCreateStatement`]
 //! - Table Index Drop, see [`IndexDropStatement`]
+//! - Table Index Rebuild, see [`IndexReindexStatement`]
 
 mod common;
 mod create;
 mod drop;
+mod reindex;
 mod shim;
 
 pub use common::*;
 pub use create::*;
 pub use drop::*;
+pub use reindex::*;
 
 /// Shorthand for constructing any index statement
 #[derive(Debug, Clone)]
@@ -23,6 +26,7 @@ pub struct Index;
 pub enum IndexStatement {
     Create(IndexCreateStatement),
     Drop(IndexDropStatement),
+    Reindex(IndexReindexStatement),
 }
 
 impl Index {
@@ -35,4 +39,9 @@ impl Index {
     pub fn drop() -> IndexDropStatement {
         IndexDropStatement::new()
     }
+
+    /// Construct index [`IndexReindexStatement`]
+    pub fn reindex() -> IndexReindexStatement {
+        IndexReindexStatement::new()
+    }
 }
@@ -0,0 +1,110 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder, TableIndex};
+
+/// Rebuild an index, or all indexes of a table. Not supported by MySQL.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let reindex = Index::reindex().name("idx-glyph-aspect").to_owned();
+///
+/// assert_eq!(
+///     reindex.to_string(PostgresQueryBuilder),
+///     r#"REINDEX INDEX "idx-glyph-aspect""#
+/// );
+/// assert_eq!(
+///     reindex.to_string(SqliteQueryBuilder),
+///     r#"REINDEX `idx-glyph-aspect`"#
+/// );
+/// ```
+///
+/// Rebuild all indexes of a table
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let reindex = Index::reindex().table(Glyph::Table).to_owned();
+///
+/// assert_eq!(
+///     reindex.to_string(PostgresQueryBuilder),
+///     r#"REINDEX TABLE "glyph""#
+/// );
+/// assert_eq!(
+///     reindex.to_string(SqliteQueryBuilder),
+///     r#"REINDEX `glyph`"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexReindexStatement {
+    pub(crate) table: Option<DynIden>,
+    pub(crate) index: TableIndex,
+    pub(crate) concurrently: bool,
+}
+
+impl Default for IndexReindexStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IndexReindexStatement {
+    /// Construct a new [`IndexReindexStatement`]
+    pub fn new() -> Self {
+        Self {
+            table: None,
+            index: Default::default(),
+            concurrently: false,
+        }
+    }
+
+    /// Rebuild without locking out writes to the table. Postgres 12+ only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let reindex = Index::reindex()
+    ///     .concurrently()
+    ///     .name("idx-glyph-aspect")
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     reindex.to_string(PostgresQueryBuilder),
+    ///     r#"REINDEX INDEX CONCURRENTLY "idx-glyph-aspect""#
+    /// );
+    /// ```
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// Set name of the index to rebuild
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.index.name(name);
+        self
+    }
+
+    /// Set table to rebuild all indexes of, unless an index name is set
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: Iden + 'static,
+    {
+        self.table = Some(SeaRc::new(table));
+        self
+    }
+}
+
+impl SchemaStatementBuilder for IndexReindexStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_index_reindex_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_index_reindex_statement(self, &mut sql);
+        sql.result()
+    }
+}
@@ -24,6 +24,7 @@
 //! SeaQuery is a query builder to help you construct dynamic SQL queries in Rust.
 //! You can construct expressions, queries and schema as abstract syntax trees using an ergonomic API.
 //! We support MySQL, Postgres and SQLite behind a common interface that aligns their behaviour where appropriate.
+//! ClickHouse and BigQuery are supported as well, mapping their own syntax onto the same statements.
 //!
 //! This library is the foundation of [SeaORM](https://github.com/SeaQL/sea-orm), an async & dynamic ORM for Rust.
 //!
@@ -109,9 +110,19 @@
 //! ### Integration
 //!
 //! We provide integration for [SQLx](https://crates.io/crates/sqlx),
-//! [postgres](https://crates.io/crates/postgres) and [rusqlite](https://crates.io/crates/rusqlite).
+//! [postgres](https://crates.io/crates/postgres) (the same `ToSql` works with
+//! [tokio-postgres](https://crates.io/crates/tokio-postgres)) and [rusqlite](https://crates.io/crates/rusqlite).
 //! See [examples](https://github.com/SeaQL/sea-query/blob/master/examples) for usage.
 //!
+//! With SQLx, the module generated by `sea_query_driver_postgres!()` (or its MySQL and SQLite
+//! counterparts) provides `SqlxBinder`, whose `build_sqlx` returns values that can be passed to
+//! `sqlx::query_with` as arguments directly. Likewise, `sea_query_driver_rusqlite!()` provides
+//! `RusqliteBinder`, whose values can be passed to `rusqlite::params_from_iter`.
+//!
+//! With the `mysql` feature, `Values` converts into the `Params` of
+//! [mysql](https://crates.io/crates/mysql) and [mysql_async](https://crates.io/crates/mysql_async),
+//! keeping unsigned integers unsigned, sending date times in the binary protocol and arrays as JSON.
+//!
 //! ### Iden
 //!
 //! `Iden` is a trait for identifiers used in any query statement.
@@ -166,6 +177,22 @@
 //! assert_eq!(Glyph.to_string(), "glyph");
 //! ```
 //!
+//! Identifiers only known at runtime can be given as `&str` or `String` directly, and are quoted
+//! like any other identifier. Names that must be written as is, e.g. `CURRENT_TIMESTAMP`, can be
+//! wrapped in [`UnquotedAlias`].
+//!
+//! ```rust
+//! # use sea_query::*;
+//! assert_eq!(
+//!     Query::select()
+//!         .column("character")
+//!         .column(UnquotedAlias::new("CURRENT_TIMESTAMP"))
+//!         .from(String::from("character"))
+//!         .to_string(PostgresQueryBuilder),
+//!     r#"SELECT "character", CURRENT_TIMESTAMP FROM "character""#
+//! );
+//! ```
+//!
 //! ### Expression
 //!
 //! Use [`Expr`] to construct select, join, where and having expression in query.
@@ -258,6 +285,8 @@
 //! # use sea_query::{*};
 //! # trait ExampleSchemaBuilder {
 //! fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String;
+//!
+//! fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String;
 //! # }
 //! ```
 //!
@@ -269,6 +298,10 @@
 //! fn build<T: QueryBuilder>(&self, query_builder: T) -> (String, Values);
 //!
 //! fn to_string<T: QueryBuilder>(&self, query_builder: T) -> String;
+//!
+//! fn build_any(&self, query_builder: &dyn QueryBuilder) -> (String, Values);
+//!
+//! fn to_string_any(&self, query_builder: &dyn QueryBuilder) -> String;
 //! # }
 //! ```
 //!
@@ -278,6 +311,23 @@
 //! `to_string` builds a SQL statement as string with parameters injected. This is good for testing
 //! and debugging.
 //!
+//! `build_any` and `to_string_any` take the backend as a trait object, so applications supporting
+//! multiple databases can select it at runtime, e.g. from a `Box<dyn QueryBuilder>`.
+//!
+//! With the `with-serde` feature, query statements and expressions implement `Serialize` and
+//! `Deserialize`, so they can be stored or sent to another service and built there. Identifiers
+//! are kept by name and come back as `Alias`.
+//!
+//! With the `sqlparser` feature, `QueryStatement::parse` converts SQL text parsed by
+//! [`sqlparser`](https://crates.io/crates/sqlparser) into query statements, so that SQL which
+//! originates as text can be rewritten with the builder methods, e.g. to add filters or change
+//! the limit, and built again.
+//!
+//! `IntrospectionBuilder`, implemented by the MySQL, Postgres and SQLite query builders, builds
+//! the catalog queries discovering the tables, columns, indexes and foreign keys of a database, and
+//! parses their result rows back into `TableCreateStatement`s, e.g. to be compared with the desired
+//! definitions by `TableDiff`.
+//!
 //! ### Query Select
 //!
 //! ```rust
@@ -550,7 +600,7 @@
 //! );
 //! assert_eq!(
 //!     table.to_string(SqliteQueryBuilder),
-//!     r#"TRUNCATE TABLE `font`"#
+//!     r#"DELETE FROM `font`"#
 //! );
 //! ```
 //!
@@ -673,6 +723,7 @@
     html_logo_url = "https://raw.githubusercontent.com/SeaQL/sea-query/master/docs/SeaQL icon dark.png"
 )]
 pub mod backend;
+pub mod database;
 pub mod driver;
 pub mod error;
 pub mod expr;
@@ -682,15 +733,21 @@ pub mod func;
 pub mod index;
 pub mod prepare;
 pub mod query;
+pub mod role;
 pub mod schema;
+pub mod sequence;
 mod shim;
 pub mod table;
 pub mod tests_cfg;
 pub mod token;
+pub mod transaction;
+pub mod trigger;
 pub mod types;
 pub mod value;
+pub mod view;
 
 pub use backend::*;
+pub use database::*;
 pub use driver::*;
 //pub use extension::*;
 pub use foreign_key::*;
@@ -701,12 +758,17 @@ pub use table::*;
 pub use expr::*;
 pub use func::*;
 pub use prepare::*;
+pub use role::*;
 pub use schema::*;
+pub use sequence::*;
 //pub use shim::*;
 //pub use tests_cfg::*;
 pub use token::*;
+pub use transaction::*;
+pub use trigger::*;
 pub use types::*;
 pub use value::*;
+pub use view::*;
 
 #[cfg(feature = "derive")]
 pub use sea_query_derive::Iden;
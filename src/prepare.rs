@@ -9,11 +9,57 @@ pub struct SqlWriter {
     pub(crate) string: String,
 }
 
+/// Style of the placeholders written by [`rewrite_parameters`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceholderStyle {
+    /// `?` for every value, as in MySQL and SQLite
+    QuestionMark,
+    /// The sign followed by the position of the value, e.g. `$1` as in Postgres
+    Numbered(&'static str),
+    /// The sign followed by the name of the value, e.g. `:p1` or `@p1`;
+    /// values are named `p1`, `p2` and so on, in the order they are collected
+    Named(&'static str),
+}
+
+impl PlaceholderStyle {
+    /// Name of the value at the given position, counting from zero.
+    pub fn param_name(index: usize) -> String {
+        format!("p{}", index + 1)
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        match self {
+            Self::QuestionMark => "?".to_owned(),
+            Self::Numbered(sign) => format!("{}{}", sign, index + 1),
+            Self::Named(sign) => format!("{}{}", sign, Self::param_name(index)),
+        }
+    }
+}
+
 pub fn inject_parameters<I>(sql: &str, params: I, query_builder: &dyn QueryBuilder) -> String
 where
     I: IntoIterator<Item = Value>,
 {
     let params: Vec<Value> = params.into_iter().collect();
+    replace_parameters(sql, query_builder, |index| {
+        query_builder.value_to_string(&params[index])
+    })
+}
+
+/// Rewrite the placeholders written by `query_builder` in another [`PlaceholderStyle`].
+pub fn rewrite_parameters(
+    sql: &str,
+    query_builder: &dyn QueryBuilder,
+    style: PlaceholderStyle,
+) -> String {
+    replace_parameters(sql, query_builder, |index| style.placeholder(index))
+}
+
+/// Replace every placeholder with the string given for the position of its value.
+fn replace_parameters<F>(sql: &str, query_builder: &dyn QueryBuilder, mut replace: F) -> String
+where
+    F: FnMut(usize) -> String,
+{
     let tokenizer = Tokenizer::new(sql);
     let tokens: Vec<Token> = tokenizer.iter().collect();
     let mut counter = 0;
@@ -24,16 +70,19 @@ where
         match token {
             Token::Punctuation(mark) => {
                 if (mark.as_ref(), false) == query_builder.placeholder() {
-                    output.push(query_builder.value_to_string(&params[counter]));
+                    output.push(replace(counter));
                     counter += 1;
                     i += 1;
                     continue;
-                } else if (mark.as_ref(), true) == query_builder.placeholder()
-                    && i + 1 < tokens.len()
-                {
-                    if let Token::Unquoted(next) = &tokens[i + 1] {
-                        if let Ok(num) = next.parse::<usize>() {
-                            output.push(query_builder.value_to_string(&params[num - 1]));
+                } else if let (sign, true) = query_builder.placeholder() {
+                    // The sign may continue after the punctuation, e.g. `@p` of `@p1`
+                    let prefix = sign.strip_prefix(mark.as_str());
+                    if let (Some(prefix), Some(Token::Unquoted(next))) = (prefix, tokens.get(i + 1))
+                    {
+                        if let Some(Ok(num)) =
+                            next.strip_prefix(prefix).map(|num| num.parse::<usize>())
+                        {
+                            output.push(replace(num - 1));
                             i += 2;
                             continue;
                         }
@@ -155,7 +204,7 @@ mod tests {
     fn inject_parameters_6() {
         assert_eq!(
             inject_parameters("WHERE A = $1", vec!["B'C".into()], &PostgresQueryBuilder),
-            "WHERE A = E'B\\'C'"
+            "WHERE A = 'B''C'"
         );
     }
 
@@ -170,4 +219,40 @@ mod tests {
             "x'ABCDEF'"
         );
     }
+
+    #[test]
+    fn rewrite_parameters_1() {
+        assert_eq!(
+            rewrite_parameters(
+                "WHERE A = ? AND B = '?' AND C = ?",
+                &MysqlQueryBuilder,
+                PlaceholderStyle::Named(":")
+            ),
+            "WHERE A = :p1 AND B = '?' AND C = :p2"
+        );
+    }
+
+    #[test]
+    fn rewrite_parameters_2() {
+        assert_eq!(
+            rewrite_parameters(
+                "WHERE A = $1 AND C = $2",
+                &PostgresQueryBuilder,
+                PlaceholderStyle::QuestionMark
+            ),
+            "WHERE A = ? AND C = ?"
+        );
+    }
+
+    #[test]
+    fn rewrite_parameters_3() {
+        assert_eq!(
+            rewrite_parameters(
+                "WHERE A = ? AND C = ?",
+                &MysqlQueryBuilder,
+                PlaceholderStyle::Numbered("@")
+            ),
+            "WHERE A = @1 AND C = @2"
+        );
+    }
 }
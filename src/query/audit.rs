@@ -0,0 +1,584 @@
+use crate::{expr::*, query::*, types::*};
+use std::collections::BTreeSet;
+
+/// Tables and columns referenced by a statement, see [`SelectStatement::audit`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryAudit {
+    pub tables: BTreeSet<TableAccess>,
+    pub columns: BTreeSet<ColumnAccess>,
+    /// Whether the statement contains custom SQL, whose tables and columns are not reported
+    pub has_custom_sql: bool,
+}
+
+/// Whether a table or column is read or written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccessType {
+    Read,
+    Write,
+}
+
+/// Name of a table, qualified as written in the statement
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TableName {
+    pub database: Option<String>,
+    pub schema: Option<String>,
+    pub table: String,
+}
+
+/// A table accessed by a statement
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TableAccess {
+    pub table: TableName,
+    pub access: AccessType,
+}
+
+/// A column accessed by a statement
+///
+/// The table is `None` if the column is not qualified and the statement selects from more than one
+/// table, i.e. it can only be resolved with the database schema.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ColumnAccess {
+    pub table: Option<TableName>,
+    pub column: String,
+    pub access: AccessType,
+}
+
+impl QueryAudit {
+    /// Tables read by the statement
+    pub fn read_tables(&self) -> Vec<&TableName> {
+        self.tables_by(AccessType::Read)
+    }
+
+    /// Tables written by the statement
+    pub fn write_tables(&self) -> Vec<&TableName> {
+        self.tables_by(AccessType::Write)
+    }
+
+    fn tables_by(&self, access: AccessType) -> Vec<&TableName> {
+        self.tables
+            .iter()
+            .filter(|table| table.access == access)
+            .map(|table| &table.table)
+            .collect()
+    }
+}
+
+impl TableName {
+    fn new(database: Option<&DynIden>, schema: Option<&DynIden>, table: &DynIden) -> Self {
+        Self {
+            database: database.map(|database| database.to_string()),
+            schema: schema.map(|schema| schema.to_string()),
+            table: table.to_string(),
+        }
+    }
+}
+
+impl SelectStatement {
+    /// Tables and columns read by this statement, including sub-queries.
+    ///
+    /// Tables of sub-queries in `FROM` and of common table expressions are not reported, as
+    /// their columns are read from the tables they select from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let audit = Query::select()
+    ///     .column((Alias::new("c"), Char::Character))
+    ///     .from_as(Char::Table, Alias::new("c"))
+    ///     .and_where(Expr::col(Char::FontId).in_subquery(
+    ///         Query::select()
+    ///             .column(Font::Id)
+    ///             .from((Alias::new("fonts"), Font::Table))
+    ///             .take(),
+    ///     ))
+    ///     .audit();
+    ///
+    /// let character = TableName {
+    ///     database: None,
+    ///     schema: None,
+    ///     table: "character".to_owned(),
+    /// };
+    /// let font = TableName {
+    ///     database: None,
+    ///     schema: Some("fonts".to_owned()),
+    ///     table: "font".to_owned(),
+    /// };
+    /// assert_eq!(audit.read_tables(), vec![&character, &font]);
+    /// assert!(audit.write_tables().is_empty());
+    /// assert!(!audit.has_custom_sql);
+    /// assert_eq!(
+    ///     audit
+    ///         .columns
+    ///         .into_iter()
+    ///         .map(|column| (column.table.unwrap().table, column.column))
+    ///         .collect::<Vec<_>>(),
+    ///     vec![
+    ///         ("character".to_owned(), "character".to_owned()),
+    ///         ("character".to_owned(), "font_id".to_owned()),
+    ///         ("font".to_owned(), "id".to_owned()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn audit(&self) -> QueryAudit {
+        let mut auditor = Auditor::default();
+        auditor.visit_select(self);
+        auditor.audit
+    }
+}
+
+impl InsertStatement {
+    /// Tables and columns read and written by this statement, see [`SelectStatement::audit`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let audit = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Image])
+    ///     .select_from(Query::select().column(Font::Name).from(Font::Table).take())
+    ///     .unwrap()
+    ///     .to_owned()
+    ///     .audit();
+    ///
+    /// assert_eq!(audit.write_tables()[0].table, "glyph");
+    /// assert_eq!(audit.read_tables()[0].table, "font");
+    /// ```
+    pub fn audit(&self) -> QueryAudit {
+        let mut auditor = Auditor::default();
+        auditor.visit_insert(self);
+        auditor.audit
+    }
+}
+
+impl UpdateStatement {
+    /// Tables and columns read and written by this statement, see [`SelectStatement::audit`]
+    pub fn audit(&self) -> QueryAudit {
+        let mut auditor = Auditor::default();
+        auditor.visit_update(self);
+        auditor.audit
+    }
+}
+
+impl DeleteStatement {
+    /// Tables and columns read and written by this statement, see [`SelectStatement::audit`]
+    pub fn audit(&self) -> QueryAudit {
+        let mut auditor = Auditor::default();
+        auditor.visit_delete(self);
+        auditor.audit
+    }
+}
+
+impl WithQuery {
+    /// Tables and columns read and written by this statement, see [`SelectStatement::audit`]
+    pub fn audit(&self) -> QueryAudit {
+        let mut auditor = Auditor::default();
+        auditor.visit_with_query(self);
+        auditor.audit
+    }
+}
+
+impl QueryStatement {
+    /// Tables and columns read and written by this statement, see [`SelectStatement::audit`]
+    pub fn audit(&self) -> QueryAudit {
+        let mut auditor = Auditor::default();
+        auditor.visit_query(self);
+        auditor.audit
+    }
+}
+
+/// What a name in scope refers to
+enum Source {
+    Table(TableName),
+    /// Sub-query, `VALUES` list or common table expression
+    Derived,
+}
+
+#[derive(Default)]
+struct Auditor {
+    audit: QueryAudit,
+    /// Tables of the enclosing statements, innermost last, by the name they are referenced with
+    scopes: Vec<Vec<(String, Source)>>,
+    ctes: Vec<String>,
+}
+
+impl Auditor {
+    fn source(&self, table: &TableRef) -> (String, Source) {
+        let named = |alias: &DynIden, table: TableName| {
+            let source = if table.database.is_none()
+                && table.schema.is_none()
+                && self.ctes.contains(&table.table)
+            {
+                Source::Derived
+            } else {
+                Source::Table(table)
+            };
+            (alias.to_string(), source)
+        };
+        match table {
+            TableRef::Table(tbl) => named(tbl, TableName::new(None, None, tbl)),
+            TableRef::SchemaTable(schema, tbl) => {
+                named(tbl, TableName::new(None, Some(schema), tbl))
+            }
+            TableRef::TableAlias(tbl, alias) => named(alias, TableName::new(None, None, tbl)),
+            TableRef::SchemaTableAlias(schema, tbl, alias) => {
+                named(alias, TableName::new(None, Some(schema), tbl))
+            }
+            TableRef::DatabaseSchemaTable(database, schema, tbl) => {
+                named(tbl, TableName::new(Some(database), Some(schema), tbl))
+            }
+            TableRef::DatabaseSchemaTableAlias(database, schema, tbl, alias) => {
+                named(alias, TableName::new(Some(database), Some(schema), tbl))
+            }
+            TableRef::SubQuery(_, alias) | TableRef::ValuesList(_, alias, _) => {
+                (alias.to_string(), Source::Derived)
+            }
+        }
+    }
+
+    fn push_scope<'a, I>(&mut self, tables: I)
+    where
+        I: IntoIterator<Item = &'a TableRef>,
+    {
+        let scope = tables.into_iter().map(|table| self.source(table)).collect();
+        self.scopes.push(scope);
+    }
+
+    fn add_table(&mut self, table: &TableRef, access: AccessType) {
+        if let (_, Source::Table(table)) = self.source(table) {
+            self.audit.tables.insert(TableAccess { table, access });
+        }
+    }
+
+    fn add_column(&mut self, column: &ColumnRef, access: AccessType) {
+        let (table, column) = match column {
+            ColumnRef::Column(column) => {
+                let table = match self.scopes.last().map(|scope| scope.as_slice()) {
+                    Some([(_, Source::Table(table))]) => Some(table.clone()),
+                    Some([(_, Source::Derived)]) => return,
+                    _ => None,
+                };
+                (table, column)
+            }
+            ColumnRef::TableColumn(name, column) => {
+                let name = name.to_string();
+                let source = self
+                    .scopes
+                    .iter()
+                    .rev()
+                    .flat_map(|scope| scope.iter())
+                    .find(|(alias, _)| alias == &name);
+                let table = match source {
+                    Some((_, Source::Table(table))) => table.clone(),
+                    Some((_, Source::Derived)) => return,
+                    None => TableName {
+                        database: None,
+                        schema: None,
+                        table: name,
+                    },
+                };
+                (Some(table), column)
+            }
+        };
+        self.audit.columns.insert(ColumnAccess {
+            table,
+            column: column.to_string(),
+            access,
+        });
+    }
+
+    fn add_write_column(&mut self, target: &Option<Box<TableRef>>, column: String) {
+        let table = match target.as_deref().map(|table| self.source(table)) {
+            Some((_, Source::Table(table))) => Some(table),
+            Some((_, Source::Derived)) => return,
+            None => None,
+        };
+        self.audit.columns.insert(ColumnAccess {
+            table,
+            column,
+            access: AccessType::Write,
+        });
+    }
+}
+
+impl Visitor for Auditor {
+    fn visit_select(&mut self, select: &SelectStatement) {
+        self.push_scope(
+            select
+                .from
+                .iter()
+                .map(|table| table.as_ref())
+                .chain(select.join.iter().map(|join| join.table.as_ref())),
+        );
+        self.walk_select(select);
+        self.scopes.pop();
+    }
+
+    fn visit_insert(&mut self, insert: &InsertStatement) {
+        self.push_scope(insert.table.iter().map(|table| table.as_ref()));
+        if let Some(table) = &insert.table {
+            self.add_table(table, AccessType::Write);
+        }
+        for column in insert.columns.iter() {
+            self.add_write_column(&insert.table, column.to_string());
+        }
+        match &insert.source {
+            Some(InsertValueSource::Values(rows)) => {
+                for row in rows.iter() {
+                    self.walk_exprs(row);
+                }
+            }
+            Some(InsertValueSource::Select(select)) => self.visit_select(select),
+            None => {}
+        }
+        if let Some(on_conflict) = &insert.on_conflict {
+            self.walk_condition_holder(&on_conflict.target_where);
+            if let Some(OnConflictAction::Update(updates)) = &on_conflict.action {
+                for update in updates.iter() {
+                    match update {
+                        OnConflictUpdate::Column(column) => {
+                            self.add_write_column(&insert.table, column.to_string())
+                        }
+                        OnConflictUpdate::Value(column, expr) => {
+                            self.add_write_column(&insert.table, column.to_string());
+                            self.visit_expr(expr);
+                        }
+                    }
+                }
+            }
+            self.walk_condition_holder(&on_conflict.action_where);
+        }
+        self.walk_returning(&insert.returning);
+        self.scopes.pop();
+    }
+
+    fn visit_update(&mut self, update: &UpdateStatement) {
+        self.push_scope(
+            update
+                .table
+                .iter()
+                .map(|table| table.as_ref())
+                .chain(update.from.iter()),
+        );
+        if let Some(table) = &update.table {
+            self.add_table(table, AccessType::Write);
+        }
+        for (column, expr) in update.values.iter() {
+            self.add_write_column(&update.table, column.clone());
+            self.visit_expr(expr);
+        }
+        for table in update.from.iter() {
+            self.visit_table_ref(table);
+        }
+        self.walk_condition_holder(&update.wherei);
+        self.walk_order_exprs(&update.orders);
+        self.walk_returning(&update.returning);
+        self.scopes.pop();
+    }
+
+    fn visit_delete(&mut self, delete: &DeleteStatement) {
+        self.push_scope(
+            delete
+                .table
+                .iter()
+                .map(|table| table.as_ref())
+                .chain(delete.using.iter()),
+        );
+        if let Some(table) = &delete.table {
+            self.add_table(table, AccessType::Write);
+        }
+        for table in delete.using.iter() {
+            self.visit_table_ref(table);
+        }
+        self.walk_condition_holder(&delete.wherei);
+        self.walk_order_exprs(&delete.orders);
+        self.walk_returning(&delete.returning);
+        self.scopes.pop();
+    }
+
+    fn visit_with_query(&mut self, query: &WithQuery) {
+        for cte in query.with_clause.cte_expressions.iter() {
+            if let Some(name) = &cte.table_name {
+                self.ctes.push(name.to_string());
+            }
+        }
+        self.walk_with_query(query);
+    }
+
+    fn visit_table_ref(&mut self, table: &TableRef) {
+        self.add_table(table, AccessType::Read);
+        self.walk_table_ref(table);
+    }
+
+    fn visit_column_ref(&mut self, column: &ColumnRef) {
+        self.add_column(column, AccessType::Read);
+    }
+
+    fn visit_expr(&mut self, expr: &SimpleExpr) {
+        if let SimpleExpr::Custom(_) | SimpleExpr::CustomWithValues(_, _) = expr {
+            self.audit.has_custom_sql = true;
+        }
+        self.walk_expr(expr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests_cfg::*, *};
+
+    fn accesses(audit: QueryAudit) -> Vec<String> {
+        let access = |access: AccessType| match access {
+            AccessType::Read => "read",
+            AccessType::Write => "write",
+        };
+        let tables = audit
+            .tables
+            .into_iter()
+            .map(|table| format!("{} {}", access(table.access), table.table.table));
+        let columns = audit.columns.into_iter().map(|column| {
+            format!(
+                "{} {}.{}",
+                access(column.access),
+                column.table.map(|table| table.table).unwrap_or_default(),
+                column.column
+            )
+        });
+        tables.chain(columns).collect()
+    }
+
+    #[test]
+    fn test_audit_select_join() {
+        let query = Query::select()
+            .column((Char::Table, Char::Character))
+            .column(Font::Name)
+            .from(Char::Table)
+            .inner_join(
+                Font::Table,
+                Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id),
+            )
+            .join_subquery(
+                JoinType::LeftJoin,
+                Query::select().column(Glyph::Id).from(Glyph::Table).take(),
+                Alias::new("sub"),
+                Expr::tbl(Alias::new("sub"), Glyph::Id).equals(Char::Table, Char::Id),
+            )
+            .to_owned();
+
+        assert_eq!(
+            accesses(query.audit()),
+            vec![
+                "read character",
+                "read font",
+                "read glyph",
+                "read .name",
+                "read character.character",
+                "read character.font_id",
+                "read character.id",
+                "read font.id",
+                "read glyph.id",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audit_update() {
+        let query = Query::update()
+            .table(Glyph::Table)
+            .value_expr(Glyph::Aspect, Expr::tbl(Font::Table, Font::Id).into())
+            .from(Font::Table)
+            .and_where(Expr::col(Glyph::Image).eq("A"))
+            .to_owned();
+
+        assert_eq!(
+            accesses(query.audit()),
+            vec![
+                "read font",
+                "write glyph",
+                "read .image",
+                "read font.id",
+                "write glyph.aspect",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audit_insert_on_conflict() {
+        let query = Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Id, Glyph::Aspect])
+            .values_panic(vec![1.into(), 2.into()])
+            .on_conflict(
+                OnConflict::column(Glyph::Id)
+                    .update_column(Glyph::Image)
+                    .to_owned(),
+            )
+            .returning_col(Glyph::Id)
+            .to_owned();
+
+        assert_eq!(
+            accesses(query.audit()),
+            vec![
+                "write glyph",
+                "write glyph.aspect",
+                "read glyph.id",
+                "write glyph.id",
+                "write glyph.image",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audit_delete_with_cte() {
+        let cte = CommonTableExpression::new()
+            .query(
+                Query::select()
+                    .column(Font::Id)
+                    .from(Font::Table)
+                    .and_where(Expr::col(Font::Name).eq("A"))
+                    .to_owned(),
+            )
+            .table_name(Alias::new("cte"))
+            .to_owned();
+        let query = Query::delete()
+            .from_table(Char::Table)
+            .and_where(
+                Expr::col(Char::FontId).in_subquery(
+                    Query::select()
+                        .column(Font::Id)
+                        .from(Alias::new("cte"))
+                        .take(),
+                ),
+            )
+            .to_owned()
+            .with(WithClause::new().cte(cte).to_owned());
+
+        assert_eq!(
+            accesses(query.audit()),
+            vec![
+                "write character",
+                "read font",
+                "read character.font_id",
+                "read font.id",
+                "read font.name",
+            ]
+        );
+    }
+    #[test]
+    fn test_audit_custom_sql() {
+        let query = Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .and_where(Expr::cust_with_values("font_id = ?", vec![1]))
+            .to_owned();
+        let audit = query.audit();
+
+        assert!(audit.has_custom_sql);
+        assert_eq!(
+            accesses(audit),
+            vec!["read character", "read character.character"]
+        );
+    }
+}
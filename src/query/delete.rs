@@ -1,10 +1,11 @@
 use crate::{
-    backend::QueryBuilder,
+    backend::{QueryBuilder, SupportCheck},
+    error::*,
     prepare::*,
-    query::{condition::*, OrderedStatement},
+    query::{condition::*, OrderedStatement, Visitor},
     types::*,
     value::*,
-    QueryStatementBuilder,
+    Query, QueryStatementBuilder, ReturningClause, WithClause, WithQuery,
 };
 
 /// Delete existing rows from the table
@@ -34,11 +35,14 @@ use crate::{
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeleteStatement {
     pub(crate) table: Option<Box<TableRef>>,
+    pub(crate) using: Vec<TableRef>,
     pub(crate) wherei: ConditionHolder,
     pub(crate) orders: Vec<OrderExpr>,
     pub(crate) limit: Option<Value>,
+    pub(crate) returning: Option<ReturningClause>,
 }
 
 impl Default for DeleteStatement {
@@ -52,9 +56,11 @@ impl DeleteStatement {
     pub fn new() -> Self {
         Self {
             table: None,
+            using: Vec::new(),
             wherei: ConditionHolder::new(),
             orders: Vec::new(),
             limit: None,
+            returning: None,
         }
     }
 
@@ -92,11 +98,152 @@ impl DeleteStatement {
         self
     }
 
-    /// Limit number of updated rows.
+    /// Delete rows depending on rows of other tables, which can be referenced in the conditions.
+    /// Translated to `USING` in Postgres, and to a multi-table delete with `JOIN` in MySQL.
+    /// SQLite does not support deleting with other tables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Char::Table)
+    ///     .using(Font::Table)
+    ///     .and_where(Expr::tbl(Font::Table, Font::Id).equals(Char::Table, Char::FontId))
+    ///     .and_where(Expr::tbl(Font::Table, Font::Name).eq("Retired"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"DELETE `character` FROM `character` JOIN `font` WHERE `font`.`id` = `character`.`font_id` AND `font`.`name` = 'Retired'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "character" USING "font" WHERE "font"."id" = "character"."font_id" AND "font"."name" = 'Retired'"#
+    /// );
+    /// ```
+    pub fn using<T>(&mut self, tbl_ref: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.using.push(tbl_ref.into_table_ref());
+        self
+    }
+
+    /// Limit number of deleted rows. Together with `ORDER BY`, supported by MySQL and by
+    /// SQLite built with `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`. For Postgres, `build` panics
+    /// and `try_build` returns [`Error::Unsupported`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::Error, tests_cfg::*, *};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Image).like("A%"))
+    ///     .order_by(Glyph::Id, Order::Desc)
+    ///     .limit(10)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"DELETE FROM `glyph` WHERE `image` LIKE 'A%' ORDER BY `id` DESC LIMIT 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"DELETE FROM `glyph` WHERE `image` LIKE 'A%' ORDER BY `id` DESC LIMIT 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.try_build(PostgresQueryBuilder),
+    ///     Err(Error::Unsupported {
+    ///         backend: "Postgres",
+    ///         feature: "ORDER BY and LIMIT in DELETE"
+    ///     })
+    /// );
+    /// ```
     pub fn limit(&mut self, limit: u64) -> &mut Self {
         self.limit = Some(Value::BigUnsigned(Some(limit)));
         self
     }
+
+    /// RETURNING expressions. Postgres and SQLite only.
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Id).eq(1))
+    ///     .returning(Query::returning().exprs(vec![
+    ///         Expr::col(Glyph::Id).into_simple_expr(),
+    ///         Expr::col(Glyph::Aspect).mul(2),
+    ///     ]))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"DELETE FROM `glyph` WHERE `id` = 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "glyph" WHERE "id" = 1 RETURNING "id", "aspect" * 2"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"DELETE FROM `glyph` WHERE `id` = 1 RETURNING `id`, `aspect` * 2"#
+    /// );
+    /// ```
+    pub fn returning<R>(&mut self, returning: R) -> &mut Self
+    where
+        R: Into<ReturningClause>,
+    {
+        self.returning = Some(returning.into());
+        self
+    }
+
+    /// RETURNING a column after deletion. Postgres and SQLite only.
+    /// Wrapper over [`DeleteStatement::returning()`].
+    pub fn returning_col<C>(&mut self, col: C) -> &mut Self
+    where
+        C: IntoIden,
+    {
+        self.returning(Query::returning().column(col.into_iden()))
+    }
+
+    /// RETURNING all columns, i.e. `RETURNING *`. Postgres and SQLite only.
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::delete()
+    ///     .from_table(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Id).eq(1))
+    ///     .returning_all()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"DELETE FROM `glyph` WHERE `id` = 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"DELETE FROM "glyph" WHERE "id" = 1 RETURNING *"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"DELETE FROM `glyph` WHERE `id` = 1 RETURNING *"#
+    /// );
+    /// ```
+    pub fn returning_all(&mut self) -> &mut Self {
+        self.returning(Query::returning().all())
+    }
+
+    /// Prefix this statement with a `WITH` clause, see [`CommonTableExpression`](crate::CommonTableExpression).
+    pub fn with(self, clause: WithClause) -> WithQuery {
+        clause.query(self)
+    }
 }
 
 impl QueryStatementBuilder for DeleteStatement {
@@ -150,6 +297,12 @@ impl QueryStatementBuilder for DeleteStatement {
         query_builder.prepare_delete_statement(self, &mut sql, collector);
         sql.result()
     }
+
+    fn check(&self, query_builder: &dyn QueryBuilder) -> Result<()> {
+        let mut check = SupportCheck::new(query_builder);
+        check.visit_delete(self);
+        check.finish()
+    }
 }
 
 impl OrderedStatement for DeleteStatement {
@@ -1,6 +1,12 @@
 use crate::{
-    backend::QueryBuilder, error::*, prepare::*, types::*, value::*, Query, QueryStatementBuilder,
-    SelectExpr, SelectStatement,
+    backend::{QueryBuilder, SupportCheck},
+    error::*,
+    expr::*,
+    prepare::*,
+    types::*,
+    value::*,
+    OnConflict, Query, QueryStatementBuilder, ReturningClause, SelectStatement, Visitor,
+    WithClause, WithQuery,
 };
 
 /// Insert any new rows into an existing table
@@ -40,11 +46,23 @@ use crate::{
 /// );
 /// ```
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsertStatement {
     pub(crate) table: Option<Box<TableRef>>,
+    #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde::vec"))]
     pub(crate) columns: Vec<DynIden>,
-    pub(crate) values: Vec<Vec<Value>>,
-    pub(crate) returning: Vec<SelectExpr>,
+    pub(crate) source: Option<InsertValueSource>,
+    pub(crate) default_values: bool,
+    pub(crate) on_conflict: Option<OnConflict>,
+    pub(crate) returning: Option<ReturningClause>,
+}
+
+/// Source of the rows to be inserted
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum InsertValueSource {
+    Values(Vec<Vec<SimpleExpr>>),
+    Select(Box<SelectStatement>),
 }
 
 impl InsertStatement {
@@ -121,6 +139,57 @@ impl InsertStatement {
     pub fn values<I>(&mut self, values: I) -> Result<&mut Self>
     where
         I: IntoIterator<Item = Value>,
+    {
+        self.exprs(values.into_iter().map(SimpleExpr::Value))
+    }
+
+    /// Specify a row of values to be inserted, variation of [`InsertStatement::values`].
+    pub fn values_panic<I>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        self.values(values).unwrap()
+    }
+
+    /// Specify a row of expressions to be inserted, e.g. `DEFAULT`, a function call or a
+    /// scalar subquery. SQLite does not accept `DEFAULT` in a row, it can only insert a row of
+    /// default values, see [`InsertStatement::or_default_values`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::Error, tests_cfg::*, *};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Id, Glyph::Aspect, Glyph::Image])
+    ///     .exprs(vec![
+    ///         SimpleExpr::Keyword(Keyword::Default),
+    ///         Expr::val(2).add(1),
+    ///         Expr::cust("LOWER('24B')"),
+    ///     ])
+    ///     .unwrap()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`id`, `aspect`, `image`) VALUES (DEFAULT, 2 + 1, LOWER('24B'))"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("id", "aspect", "image") VALUES (DEFAULT, 2 + 1, LOWER('24B'))"#
+    /// );
+    /// assert_eq!(
+    ///     query.try_build(SqliteQueryBuilder),
+    ///     Err(Error::Unsupported {
+    ///         backend: "SQLite",
+    ///         feature: "DEFAULT in VALUES"
+    ///     })
+    /// );
+    /// ```
+    pub fn exprs<I>(&mut self, values: I) -> Result<&mut Self>
+    where
+        I: IntoIterator<Item = SimpleExpr>,
     {
         let values = values.into_iter().collect::<Vec<_>>();
         if self.columns.len() != values.len() {
@@ -129,19 +198,104 @@ impl InsertStatement {
                 val_len: values.len(),
             });
         }
-        self.values.push(values);
+        match &mut self.source {
+            Some(InsertValueSource::Values(rows)) => rows.push(values),
+            _ => self.source = Some(InsertValueSource::Values(vec![values])),
+        }
         Ok(self)
     }
 
-    /// Specify a row of values to be inserted, variation of [`InsertStatement::values`].
-    pub fn values_panic<I>(&mut self, values: I) -> &mut Self
+    /// Specify a row of expressions to be inserted, variation of [`InsertStatement::exprs`].
+    pub fn exprs_panic<I>(&mut self, values: I) -> &mut Self
     where
-        I: IntoIterator<Item = Value>,
+        I: IntoIterator<Item = SimpleExpr>,
     {
-        self.values(values).unwrap()
+        self.exprs(values).unwrap()
     }
 
-    /// RETURNING expressions. Postgres only.
+    /// Specify a select statement producing the rows to be inserted. It replaces any values
+    /// previously specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Aspect, Glyph::Image])
+    ///     .select_from(
+    ///         Query::select()
+    ///             .column(Glyph::Aspect)
+    ///             .column(Glyph::Image)
+    ///             .from(Glyph::Table)
+    ///             .and_where(Expr::col(Glyph::Image).like("0%"))
+    ///             .to_owned(),
+    ///     )
+    ///     .unwrap()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`aspect`, `image`) SELECT `aspect`, `image` FROM `glyph` WHERE `image` LIKE '0%'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("aspect", "image") SELECT "aspect", "image" FROM "glyph" WHERE "image" LIKE '0%'"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`aspect`, `image`) SELECT `aspect`, `image` FROM `glyph` WHERE `image` LIKE '0%'"#
+    /// );
+    /// ```
+    pub fn select_from(&mut self, select: SelectStatement) -> Result<&mut Self> {
+        if !self.columns.is_empty() && self.columns.len() != select.selects.len() {
+            return Err(Error::ColValNumMismatch {
+                col_len: self.columns.len(),
+                val_len: select.selects.len(),
+            });
+        }
+        self.source = Some(InsertValueSource::Select(Box::new(select)));
+        Ok(self)
+    }
+
+    /// Insert a row of default values if no values nor select statement is specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .or_default_values()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` () VALUES ()"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" DEFAULT VALUES"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"INSERT INTO `glyph` DEFAULT VALUES"#
+    /// );
+    /// ```
+    pub fn or_default_values(&mut self) -> &mut Self {
+        self.default_values = true;
+        self
+    }
+
+    /// Handle conflicts with existing rows, i.e. upsert. See [`OnConflict`].
+    pub fn on_conflict(&mut self, on_conflict: OnConflict) -> &mut Self {
+        self.on_conflict = Some(on_conflict);
+        self
+    }
+
+    /// RETURNING expressions. Postgres and SQLite only.
     ///
     /// ```
     /// use sea_query::{*, tests_cfg::*};
@@ -167,15 +321,18 @@ impl InsertStatement {
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     "INSERT INTO `glyph` (`image`) VALUES ('12A')"
+    ///     r#"INSERT INTO `glyph` (`image`) VALUES ('12A') RETURNING `id`"#
     /// );
     /// ```
-    pub fn returning(&mut self, select: SelectStatement) -> &mut Self {
-        self.returning = select.selects;
+    pub fn returning<R>(&mut self, returning: R) -> &mut Self
+    where
+        R: Into<ReturningClause>,
+    {
+        self.returning = Some(returning.into());
         self
     }
 
-    /// RETURNING a column after insertion. Postgres only. This is equivalent to MySQL's LAST_INSERT_ID.
+    /// RETURNING a column after insertion. Postgres and SQLite only. This is equivalent to MySQL's LAST_INSERT_ID.
     /// Wrapper over [`InsertStatement::returning()`].
     ///
     /// ```
@@ -202,14 +359,78 @@ impl InsertStatement {
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     "INSERT INTO `glyph` (`image`) VALUES ('12A')"
+    ///     r#"INSERT INTO `glyph` (`image`) VALUES ('12A') RETURNING `id`"#
     /// );
     /// ```
     pub fn returning_col<C>(&mut self, col: C) -> &mut Self
     where
         C: IntoIden,
     {
-        self.returning(Query::select().column(col.into_iden()).take())
+        self.returning(Query::returning().column(col.into_iden()))
+    }
+
+    /// RETURNING all columns, i.e. `RETURNING *`. Postgres and SQLite only.
+    ///
+    /// # Examples
+    ///
+    /// See [`InsertStatement::returning()`].
+    pub fn returning_all(&mut self) -> &mut Self {
+        self.returning(Query::returning().all())
+    }
+
+    /// Prefix this statement with a `WITH` clause, see [`CommonTableExpression`](crate::CommonTableExpression).
+    ///
+    /// MySQL places the clause right before the select the rows are inserted from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Aspect, Glyph::Image])
+    ///     .select_from(
+    ///         Query::select()
+    ///             .column(Glyph::Aspect)
+    ///             .column(Glyph::Image)
+    ///             .from(Alias::new("cte"))
+    ///             .to_owned(),
+    ///     )
+    ///     .unwrap()
+    ///     .to_owned()
+    ///     .with(
+    ///         Query::with()
+    ///             .cte(
+    ///                 CommonTableExpression::new()
+    ///                     .query(
+    ///                         Query::select()
+    ///                             .column(Glyph::Aspect)
+    ///                             .column(Glyph::Image)
+    ///                             .from(Glyph::Table)
+    ///                             .to_owned(),
+    ///                     )
+    ///                     .table_name(Alias::new("cte"))
+    ///                     .to_owned(),
+    ///             )
+    ///             .to_owned(),
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`aspect`, `image`) WITH `cte` AS (SELECT `aspect`, `image` FROM `glyph`) SELECT `aspect`, `image` FROM `cte`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"WITH "cte" AS (SELECT "aspect", "image" FROM "glyph") INSERT INTO "glyph" ("aspect", "image") SELECT "aspect", "image" FROM "cte""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"WITH `cte` AS (SELECT `aspect`, `image` FROM `glyph`) INSERT INTO `glyph` (`aspect`, `image`) SELECT `aspect`, `image` FROM `cte`"#
+    /// );
+    /// ```
+    pub fn with(self, clause: WithClause) -> WithQuery {
+        clause.query(self)
     }
 }
 
@@ -272,4 +493,10 @@ impl QueryStatementBuilder for InsertStatement {
         query_builder.prepare_insert_statement(self, &mut sql, collector);
         sql.result()
     }
+
+    fn check(&self, query_builder: &dyn QueryBuilder) -> Result<()> {
+        let mut check = SupportCheck::new(query_builder);
+        check.visit_insert(self);
+        check.finish()
+    }
 }
@@ -6,30 +6,53 @@
 //! - Query Insert, see [`InsertStatement`]
 //! - Query Update, see [`UpdateStatement`]
 //! - Query Delete, see [`DeleteStatement`]
+//! - Common table expressions, see [`WithClause`]
+//! - Keyset pagination, see [`Keyset`]
+//! - Walking statements, see [`Visitor`] and [`VisitorMut`]
+//! - Tables and columns accessed by a statement, see [`QueryAudit`]
+//! - Converting SQL parsed by `sqlparser`, see [`QueryStatement::parse`] (feature `sqlparser`)
 
+mod audit;
 mod condition;
 mod delete;
 mod insert;
+mod on_conflict;
 mod ordered;
+mod pagination;
+#[cfg(feature = "sqlparser")]
+mod parse;
+mod returning;
 mod select;
 mod shim;
 mod traits;
 mod update;
+mod visitor;
+mod window;
+mod with;
 
+pub use audit::*;
 pub use condition::*;
 pub use delete::*;
 pub use insert::*;
+pub use on_conflict::*;
 pub use ordered::*;
+pub use pagination::*;
+pub use returning::*;
 pub use select::*;
 pub use traits::*;
 pub use update::*;
+pub use visitor::*;
+pub use window::*;
+pub use with::*;
 
 /// Shorthand for constructing any table query
 #[derive(Debug, Clone)]
 pub struct Query;
 
 /// All available types of table query
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum QueryStatement {
     Select(SelectStatement),
     Insert(InsertStatement),
@@ -38,6 +61,11 @@ pub enum QueryStatement {
 }
 
 impl Query {
+    /// Construct a [`WithClause`]
+    pub fn with() -> WithClause {
+        WithClause::new()
+    }
+
     /// Construct table [`SelectStatement`]
     pub fn select() -> SelectStatement {
         SelectStatement::new()
@@ -57,4 +85,9 @@ impl Query {
     pub fn delete() -> DeleteStatement {
         DeleteStatement::new()
     }
+
+    /// Construct [`Returning`]
+    pub fn returning() -> Returning {
+        Returning::new()
+    }
 }
@@ -0,0 +1,328 @@
+use crate::{expr::*, query::condition::*, types::*};
+
+/// Conflict handling of an [`InsertStatement`](crate::InsertStatement), i.e. upsert.
+///
+/// Translated to `ON CONFLICT` in Postgres and SQLite, and to `ON DUPLICATE KEY UPDATE` in MySQL.
+/// MySQL ignores the conflict target, since the conflict is detected on any unique key, and
+/// rejects `WHERE` clauses, see [`OnConflict::action_and_where`].
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let query = Query::insert()
+///     .into_table(Glyph::Table)
+///     .columns(vec![Glyph::Id, Glyph::Aspect, Glyph::Image])
+///     .values_panic(vec![1.into(), 2.into(), "24B".into()])
+///     .on_conflict(
+///         OnConflict::column(Glyph::Id)
+///             .update_columns(vec![Glyph::Aspect, Glyph::Image])
+///             .to_owned(),
+///     )
+///     .to_owned();
+///
+/// assert_eq!(
+///     query.to_string(MysqlQueryBuilder),
+///     r#"INSERT INTO `glyph` (`id`, `aspect`, `image`) VALUES (1, 2, '24B') ON DUPLICATE KEY UPDATE `aspect` = VALUES(`aspect`), `image` = VALUES(`image`)"#
+/// );
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"INSERT INTO "glyph" ("id", "aspect", "image") VALUES (1, 2, '24B') ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect", "image" = "excluded"."image""#
+/// );
+/// assert_eq!(
+///     query.to_string(SqliteQueryBuilder),
+///     r#"INSERT INTO `glyph` (`id`, `aspect`, `image`) VALUES (1, 2, '24B') ON CONFLICT (`id`) DO UPDATE SET `aspect` = `excluded`.`aspect`, `image` = `excluded`.`image`"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnConflict {
+    pub(crate) target: Option<OnConflictTarget>,
+    pub(crate) target_where: ConditionHolder,
+    pub(crate) action: Option<OnConflictAction>,
+    pub(crate) action_where: ConditionHolder,
+}
+
+/// Conflict target
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OnConflictTarget {
+    /// Columns of a unique index
+    Columns(
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde::vec"))]
+        Vec<DynIden>,
+    ),
+    /// Name of a unique constraint. Postgres only.
+    Constraint(
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+    ),
+}
+
+/// Conflict action
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OnConflictAction {
+    DoNothing,
+    Update(Vec<OnConflictUpdate>),
+}
+
+/// Assignment of a `DO UPDATE` conflict action
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OnConflictUpdate {
+    /// Set the column to the value proposed for insertion, i.e. `excluded.col`
+    Column(#[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden),
+    /// Set the column to an expression
+    Value(
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        SimpleExpr,
+    ),
+}
+
+impl OnConflict {
+    /// Construct a new [`OnConflict`] without conflict target
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the conflict target to a column
+    pub fn column<C>(column: C) -> Self
+    where
+        C: IntoIden,
+    {
+        Self::columns(vec![column])
+    }
+
+    /// Set the conflict target to columns
+    pub fn columns<C, I>(columns: I) -> Self
+    where
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+    {
+        Self {
+            target: Some(OnConflictTarget::Columns(
+                columns.into_iter().map(IntoIden::into_iden).collect(),
+            )),
+            ..Default::default()
+        }
+    }
+
+    /// Set the conflict target to a unique constraint. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Id, Glyph::Image])
+    ///     .values_panic(vec![1.into(), "24B".into()])
+    ///     .on_conflict(
+    ///         OnConflict::constraint(Alias::new("glyph_pkey"))
+    ///             .do_nothing()
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("id", "image") VALUES (1, '24B') ON CONFLICT ON CONSTRAINT "glyph_pkey" DO NOTHING"#
+    /// );
+    /// ```
+    pub fn constraint<T>(name: T) -> Self
+    where
+        T: IntoIden,
+    {
+        Self {
+            target: Some(OnConflictTarget::Constraint(name.into_iden())),
+            ..Default::default()
+        }
+    }
+
+    /// Ignore the conflicting row. MySQL has no direct equivalent; it is translated to
+    /// assigning the first target column to itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Id, Glyph::Image])
+    ///     .values_panic(vec![1.into(), "24B".into()])
+    ///     .on_conflict(OnConflict::column(Glyph::Id).do_nothing().to_owned())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`id`, `image`) VALUES (1, '24B') ON DUPLICATE KEY UPDATE `id` = `id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("id", "image") VALUES (1, '24B') ON CONFLICT ("id") DO NOTHING"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`id`, `image`) VALUES (1, '24B') ON CONFLICT (`id`) DO NOTHING"#
+    /// );
+    /// ```
+    pub fn do_nothing(&mut self) -> &mut Self {
+        self.action = Some(OnConflictAction::DoNothing);
+        self
+    }
+
+    /// Update a column to the value proposed for insertion
+    pub fn update_column<C>(&mut self, column: C) -> &mut Self
+    where
+        C: IntoIden,
+    {
+        self.update_columns(vec![column])
+    }
+
+    /// Update columns to the values proposed for insertion
+    pub fn update_columns<C, I>(&mut self, columns: I) -> &mut Self
+    where
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+    {
+        self.push_updates(
+            columns
+                .into_iter()
+                .map(|c| OnConflictUpdate::Column(c.into_iden())),
+        )
+    }
+
+    /// Update a column to an expression
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Id, Glyph::Aspect])
+    ///     .values_panic(vec![1.into(), 2.into()])
+    ///     .on_conflict(
+    ///         OnConflict::column(Glyph::Id)
+    ///             .update_value(Glyph::Aspect, Expr::tbl(Glyph::Table, Glyph::Aspect).add(1))
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`id`, `aspect`) VALUES (1, 2) ON DUPLICATE KEY UPDATE `aspect` = `glyph`.`aspect` + 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("id", "aspect") VALUES (1, 2) ON CONFLICT ("id") DO UPDATE SET "aspect" = "glyph"."aspect" + 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`id`, `aspect`) VALUES (1, 2) ON CONFLICT (`id`) DO UPDATE SET `aspect` = `glyph`.`aspect` + 1"#
+    /// );
+    /// ```
+    ///
+    /// The existing row is referred to by the table name, an unqualified column is ambiguous
+    /// in Postgres. The row proposed for insertion is `excluded`, see [`Expr::excluded`].
+    pub fn update_value<C>(&mut self, column: C, value: SimpleExpr) -> &mut Self
+    where
+        C: IntoIden,
+    {
+        self.update_values(vec![(column, value)])
+    }
+
+    /// Update columns to expressions
+    pub fn update_values<C, I>(&mut self, values: I) -> &mut Self
+    where
+        C: IntoIden,
+        I: IntoIterator<Item = (C, SimpleExpr)>,
+    {
+        self.push_updates(
+            values
+                .into_iter()
+                .map(|(c, v)| OnConflictUpdate::Value(c.into_iden(), v)),
+        )
+    }
+
+    /// `WHERE` condition on the conflict target, to match a partial unique index.
+    /// Not supported by MySQL.
+    pub fn target_and_where(&mut self, other: SimpleExpr) -> &mut Self {
+        self.target_where.add_and_or(LogicalChainOper::And(other));
+        self
+    }
+
+    /// `WHERE` condition on the conflict target, see [`OnConflict::target_and_where`].
+    pub fn target_cond_where<C>(&mut self, condition: C) -> &mut Self
+    where
+        C: IntoCondition,
+    {
+        self.target_where.add_condition(condition.into_condition());
+        self
+    }
+
+    /// `WHERE` condition on the `DO UPDATE` action; rows not matching are left unchanged.
+    /// Not supported by MySQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::Error, tests_cfg::*, *};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Id, Glyph::Aspect])
+    ///     .values_panic(vec![1.into(), 2.into()])
+    ///     .on_conflict(
+    ///         OnConflict::column(Glyph::Id)
+    ///             .update_column(Glyph::Aspect)
+    ///             .action_and_where(Expr::tbl(Glyph::Table, Glyph::Aspect).lt(10))
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.try_build(MysqlQueryBuilder),
+    ///     Err(Error::Unsupported {
+    ///         backend: "MySQL",
+    ///         feature: "WHERE in ON CONFLICT"
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("id", "aspect") VALUES (1, 2) ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect" WHERE "glyph"."aspect" < 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`id`, `aspect`) VALUES (1, 2) ON CONFLICT (`id`) DO UPDATE SET `aspect` = `excluded`.`aspect` WHERE `glyph`.`aspect` < 10"#
+    /// );
+    /// ```
+    pub fn action_and_where(&mut self, other: SimpleExpr) -> &mut Self {
+        self.action_where.add_and_or(LogicalChainOper::And(other));
+        self
+    }
+
+    /// `WHERE` condition on the `DO UPDATE` action, see [`OnConflict::action_and_where`].
+    pub fn action_cond_where<C>(&mut self, condition: C) -> &mut Self
+    where
+        C: IntoCondition,
+    {
+        self.action_where.add_condition(condition.into_condition());
+        self
+    }
+
+    fn push_updates<I>(&mut self, updates: I) -> &mut Self
+    where
+        I: IntoIterator<Item = OnConflictUpdate>,
+    {
+        match &mut self.action {
+            Some(OnConflictAction::Update(existing)) => existing.extend(updates),
+            _ => self.action = Some(OnConflictAction::Update(updates.into_iter().collect())),
+        }
+        self
+    }
+}
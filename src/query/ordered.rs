@@ -1,4 +1,4 @@
-use crate::{expr::*, types::*};
+use crate::{expr::*, types::*, value::*};
 
 pub trait OrderedStatement {
     #[doc(hidden)]
@@ -32,6 +32,8 @@ pub trait OrderedStatement {
         self.add_order_by(OrderExpr {
             expr: SimpleExpr::Column(col.into_column_ref()),
             order,
+            nulls: None,
+            field: None,
         })
     }
 
@@ -48,8 +50,84 @@ pub trait OrderedStatement {
     }
 
     /// Order by [`SimpleExpr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .from(Glyph::Table)
+    ///     .order_by_expr(Expr::col(Glyph::Aspect).mul(2), Order::Desc)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `aspect` FROM `glyph` ORDER BY `aspect` * 2 DESC"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "aspect" FROM "glyph" ORDER BY "aspect" * 2 DESC"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `aspect` FROM `glyph` ORDER BY `aspect` * 2 DESC"#
+    /// );
+    /// ```
     fn order_by_expr(&mut self, expr: SimpleExpr, order: Order) -> &mut Self {
-        self.add_order_by(OrderExpr { expr, order })
+        self.add_order_by(OrderExpr {
+            expr,
+            order,
+            nulls: None,
+            field: None,
+        })
+    }
+
+    /// Order by the position of a column's value in a list, translated to `FIELD` in MySQL
+    /// and a `CASE` expression elsewhere. Rows with unlisted values come last.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Id)
+    ///     .from(Font::Table)
+    ///     .order_by_field(Font::Variant, vec!["Bold".into(), "Regular".into()])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `font` ORDER BY FIELD(`variant`, 'Regular', 'Bold') DESC"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "font" ORDER BY CASE WHEN "variant" = 'Bold' THEN 0 WHEN "variant" = 'Regular' THEN 1 ELSE 2 END ASC"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `font` ORDER BY CASE WHEN `variant` = 'Bold' THEN 0 WHEN `variant` = 'Regular' THEN 1 ELSE 2 END ASC"#
+    /// );
+    /// ```
+    fn order_by_field<T>(&mut self, col: T, values: Vec<Value>) -> &mut Self
+    where
+        T: IntoColumnRef,
+    {
+        if values.is_empty() {
+            panic!("Cannot order by the position in an empty list of values");
+        }
+        self.add_order_by(OrderExpr {
+            expr: SimpleExpr::Column(col.into_column_ref()),
+            order: Order::Asc,
+            nulls: None,
+            field: Some(Values(values)),
+        })
     }
 
     /// Order by custom string.
@@ -61,6 +139,8 @@ pub trait OrderedStatement {
             self.add_order_by(OrderExpr {
                 expr: SimpleExpr::Custom(c.to_string()),
                 order,
+                nulls: None,
+                field: None,
             });
         });
         self
@@ -75,11 +155,67 @@ pub trait OrderedStatement {
             self.add_order_by(OrderExpr {
                 expr: SimpleExpr::Column(c.into_column_ref()),
                 order,
+                nulls: None,
+                field: None,
             });
         });
         self
     }
 
+    /// Order by column with nulls ordering. MySQL has no `NULLS FIRST` or `NULLS LAST`;
+    /// it is emulated by sorting on `IS NULL` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .from(Glyph::Table)
+    ///     .order_by_with_nulls(Glyph::Image, Order::Desc, NullOrdering::Last)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `aspect` FROM `glyph` ORDER BY `image` IS NULL ASC, `image` DESC"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "aspect" FROM "glyph" ORDER BY "image" DESC NULLS LAST"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `aspect` FROM `glyph` ORDER BY `image` DESC NULLS LAST"#
+    /// );
+    /// ```
+    fn order_by_with_nulls<T>(&mut self, col: T, order: Order, nulls: NullOrdering) -> &mut Self
+    where
+        T: IntoColumnRef,
+    {
+        self.add_order_by(OrderExpr {
+            expr: SimpleExpr::Column(col.into_column_ref()),
+            order,
+            nulls: Some(nulls),
+            field: None,
+        })
+    }
+
+    /// Order by [`SimpleExpr`] with nulls ordering.
+    fn order_by_expr_with_nulls(
+        &mut self,
+        expr: SimpleExpr,
+        order: Order,
+        nulls: NullOrdering,
+    ) -> &mut Self {
+        self.add_order_by(OrderExpr {
+            expr,
+            order,
+            nulls: Some(nulls),
+            field: None,
+        })
+    }
+
     #[deprecated(
         since = "0.9.0",
         note = "Please use the [`OrderedStatement::order_by_columns`] with a tuple as [`ColumnRef`]"
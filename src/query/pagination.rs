@@ -0,0 +1,174 @@
+use crate::{error::*, expr::*, query::*, types::*, value::*};
+
+/// Keyset (cursor) pagination over ordered columns.
+///
+/// Instead of skipping rows with `OFFSET`, a page starts right after (or before) the last-seen
+/// values of the ordering columns, compared as a row value. The columns should form a unique key,
+/// e.g. end with the primary key, and all share the same direction.
+///
+/// Paging backward reverses the `ORDER BY`, so rows of the page are returned in reverse order.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let query = Query::select()
+///     .columns(vec![Char::Id, Char::Character])
+///     .from(Char::Table)
+///     .paginate(
+///         Keyset::asc(vec![Char::FontSize, Char::Id])
+///             .after(vec![12.into(), 100.into()])
+///             .to_owned(),
+///     )
+///     .unwrap()
+///     .limit(20)
+///     .to_owned();
+///
+/// assert_eq!(
+///     query.to_string(MysqlQueryBuilder),
+///     r#"SELECT `id`, `character` FROM `character` WHERE (`font_size`, `id`) > (12, 100) ORDER BY `font_size` ASC, `id` ASC LIMIT 20"#
+/// );
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"SELECT "id", "character" FROM "character" WHERE ("font_size", "id") > (12, 100) ORDER BY "font_size" ASC, "id" ASC LIMIT 20"#
+/// );
+/// assert_eq!(
+///     query.to_string(SqliteQueryBuilder),
+///     r#"SELECT `id`, `character` FROM `character` WHERE (`font_size`, `id`) > (12, 100) ORDER BY `font_size` ASC, `id` ASC LIMIT 20"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keyset {
+    pub(crate) columns: Vec<ColumnRef>,
+    pub(crate) desc: bool,
+    pub(crate) cursor: Option<(PageDirection, Vec<Value>)>,
+}
+
+/// Direction of a page relative to the cursor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PageDirection {
+    Forward,
+    Backward,
+}
+
+impl Keyset {
+    /// Paginate over columns in ascending order
+    pub fn asc<T, I>(columns: I) -> Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        Self::new(columns, false)
+    }
+
+    /// Paginate over columns in descending order
+    pub fn desc<T, I>(columns: I) -> Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        Self::new(columns, true)
+    }
+
+    fn new<T, I>(columns: I, desc: bool) -> Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        Self {
+            columns: columns.into_iter().map(|c| c.into_column_ref()).collect(),
+            desc,
+            cursor: None,
+        }
+    }
+
+    /// Fetch the page following the row with these values
+    pub fn after<I>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        self.cursor = Some((PageDirection::Forward, values.into_iter().collect()));
+        self
+    }
+
+    /// Fetch the page preceding the row with these values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .paginate(Keyset::desc(vec![Char::Id]).before(vec![100.into()]).to_owned())
+    ///     .unwrap()
+    ///     .limit(20)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `id` > 100 ORDER BY `id` ASC LIMIT 20"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE "id" > 100 ORDER BY "id" ASC LIMIT 20"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id` FROM `character` WHERE `id` > 100 ORDER BY `id` ASC LIMIT 20"#
+    /// );
+    /// ```
+    pub fn before<I>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        self.cursor = Some((PageDirection::Backward, values.into_iter().collect()));
+        self
+    }
+
+    /// Append the `WHERE` and `ORDER BY` of this page to a [`SelectStatement`]
+    pub(crate) fn apply(self, select: &mut SelectStatement) -> Result<()> {
+        let backward = matches!(self.cursor, Some((PageDirection::Backward, _)));
+        // Paging backward reads the rows in the opposite order
+        let desc = self.desc != backward;
+        if let Some((_, values)) = self.cursor {
+            if self.columns.len() != values.len() {
+                return Err(Error::ColValNumMismatch {
+                    col_len: self.columns.len(),
+                    val_len: values.len(),
+                });
+            }
+            let (left, right) = if self.columns.len() == 1 {
+                (
+                    SimpleExpr::Column(self.columns[0].clone()),
+                    SimpleExpr::Value(values.into_iter().next().unwrap()),
+                )
+            } else {
+                (
+                    SimpleExpr::Tuple(
+                        self.columns
+                            .iter()
+                            .cloned()
+                            .map(SimpleExpr::Column)
+                            .collect(),
+                    ),
+                    SimpleExpr::Values(values),
+                )
+            };
+            select.and_where(if desc {
+                left.less_than(right)
+            } else {
+                left.greater_than(right)
+            });
+        }
+        let order = if desc { Order::Desc } else { Order::Asc };
+        for column in self.columns {
+            select.order_by(column, order);
+        }
+        Ok(())
+    }
+}
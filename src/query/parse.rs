@@ -0,0 +1,1038 @@
+use std::convert::{TryFrom, TryInto};
+
+use sqlparser::{
+    ast::{
+        self, BinaryOperator, Distinct, FunctionArg, FunctionArgExpr, GroupByExpr, Ident,
+        JoinConstraint, JoinOperator, ObjectName, OrderByExpr, SelectItem, SetExpr, SetOperator,
+        SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    },
+    dialect::Dialect,
+    parser::Parser,
+};
+
+use crate::{
+    error::{Error, Result},
+    *,
+};
+
+impl QueryStatement {
+    /// Parse SQL text with [`sqlparser`] and convert every statement into a query statement,
+    /// so that it can be rewritten with the builder methods before being built again.
+    ///
+    /// Identifiers keep their quoting: quoted names are quoted for the target backend and
+    /// unquoted names are written as is. Expressions that have no counterpart in sea-query, e.g.
+    /// `CASE` or window functions, and clauses that cannot be represented, e.g. `WITH`, `FETCH`
+    /// or locking, are rejected with [`Error::Parse`] rather than kept as custom SQL, which
+    /// would hide the tables and columns they reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    /// use sqlparser::dialect::GenericDialect;
+    ///
+    /// let mut statements = QueryStatement::parse(
+    ///     &GenericDialect {},
+    ///     r#"SELECT "id", "image" FROM "glyph" WHERE "aspect" > 2 LIMIT 100"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let query = match statements.remove(0) {
+    ///     QueryStatement::Select(mut select) => select
+    ///         .and_where(Expr::col(Glyph::Image).like("A%"))
+    ///         .limit(10)
+    ///         .to_owned(),
+    ///     _ => unreachable!(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `id`, `image` FROM `glyph` WHERE `aspect` > 2 AND `image` LIKE 'A%' LIMIT 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "id", "image" FROM "glyph" WHERE "aspect" > 2 AND "image" LIKE 'A%' LIMIT 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `id`, `image` FROM `glyph` WHERE `aspect` > 2 AND `image` LIKE 'A%' LIMIT 10"#
+    /// );
+    /// ```
+    pub fn parse(dialect: &dyn Dialect, sql: &str) -> Result<Vec<QueryStatement>> {
+        Parser::parse_sql(dialect, sql)
+            .map_err(|e| Error::Parse(e.to_string()))?
+            .into_iter()
+            .map(QueryStatement::try_from)
+            .collect()
+    }
+}
+
+/// Convert a parsed `SELECT`, `INSERT`, `UPDATE` or `DELETE` statement.
+impl TryFrom<Statement> for QueryStatement {
+    type Error = Error;
+
+    fn try_from(statement: Statement) -> Result<Self> {
+        Ok(match statement {
+            Statement::Query(_) => QueryStatement::Select(statement.try_into()?),
+            Statement::Insert { .. } => QueryStatement::Insert(statement.try_into()?),
+            Statement::Update { .. } => QueryStatement::Update(statement.try_into()?),
+            Statement::Delete { .. } => QueryStatement::Delete(statement.try_into()?),
+            _ => {
+                return Err(unsupported(
+                    "statement other than SELECT, INSERT, UPDATE or DELETE",
+                ))
+            }
+        })
+    }
+}
+
+/// Convert a parsed `SELECT` statement.
+impl TryFrom<Statement> for SelectStatement {
+    type Error = Error;
+
+    fn try_from(statement: Statement) -> Result<Self> {
+        match statement {
+            Statement::Query(query) => SelectStatement::try_from(*query),
+            _ => Err(unsupported("statement other than SELECT")),
+        }
+    }
+}
+
+/// Convert a parsed query, including its set operations, `ORDER BY`, `LIMIT` and `OFFSET`.
+impl TryFrom<ast::Query> for SelectStatement {
+    type Error = Error;
+
+    fn try_from(query: ast::Query) -> Result<Self> {
+        if query.with.is_some() {
+            return Err(unsupported("WITH"));
+        }
+        if !query.limit_by.is_empty() {
+            return Err(unsupported("LIMIT BY"));
+        }
+        if query.fetch.is_some() {
+            return Err(unsupported("FETCH"));
+        }
+        if !query.locks.is_empty() {
+            return Err(unsupported("locking clause"));
+        }
+        if query.for_clause.is_some() {
+            return Err(unsupported("FOR clause"));
+        }
+        let mut select = set_expr(*query.body)?;
+        if !query.order_by.is_empty() && !select.orders.is_empty()
+            || query.limit.is_some() && select.limit.is_some()
+            || query.offset.is_some() && select.offset.is_some()
+        {
+            return Err(unsupported("nested ORDER BY, LIMIT or OFFSET"));
+        }
+        for order in query.order_by {
+            select.add_order_by(order_expr(order)?);
+        }
+        if let Some(limit) = query.limit {
+            select.limit(count(limit)?);
+        }
+        if let Some(offset) = query.offset {
+            select.offset(count(offset.value)?);
+        }
+        Ok(select)
+    }
+}
+
+/// Convert a parsed `INSERT` statement.
+impl TryFrom<Statement> for InsertStatement {
+    type Error = Error;
+
+    fn try_from(statement: Statement) -> Result<Self> {
+        let (table_name, columns, source, returning) = match statement {
+            Statement::Insert {
+                or: None,
+                ignore: false,
+                table_name,
+                columns,
+                overwrite: false,
+                source,
+                partitioned: None,
+                after_columns,
+                table: false,
+                on: None,
+                returning,
+                ..
+            } if after_columns.is_empty() => (table_name, columns, source, returning),
+            Statement::Insert { .. } => return Err(unsupported("INSERT modifier")),
+            _ => return Err(unsupported("statement other than INSERT")),
+        };
+        let mut insert = Query::insert();
+        insert
+            .into_table(table_ref(table_name, None)?)
+            .columns(columns.into_iter().map(iden));
+        match source {
+            Some(query) => match *query {
+                ast::Query {
+                    with: None,
+                    body,
+                    order_by,
+                    limit: None,
+                    offset: None,
+                    fetch: None,
+                    ..
+                } if order_by.is_empty() && matches!(*body, SetExpr::Values(_)) => {
+                    if let SetExpr::Values(values) = *body {
+                        for row in values.rows {
+                            insert.exprs(exprs(row)?)?;
+                        }
+                    }
+                }
+                query => {
+                    insert.select_from(query.try_into()?)?;
+                }
+            },
+            None => {
+                insert.or_default_values();
+            }
+        }
+        if let Some(returning) = returning {
+            insert.returning(returning_clause(returning)?);
+        }
+        Ok(insert)
+    }
+}
+
+/// Convert a parsed `UPDATE` statement.
+impl TryFrom<Statement> for UpdateStatement {
+    type Error = Error;
+
+    fn try_from(statement: Statement) -> Result<Self> {
+        let (table, assignments, from, selection, returning) = match statement {
+            Statement::Update {
+                table,
+                assignments,
+                from,
+                selection,
+                returning,
+            } => (table, assignments, from, selection, returning),
+            _ => return Err(unsupported("statement other than UPDATE")),
+        };
+        let mut update = Query::update();
+        update.table(single_table(table)?);
+        for assignment in assignments {
+            let col = assignment
+                .id
+                .into_iter()
+                .last()
+                .ok_or_else(|| unsupported("assignment without column"))?;
+            update.value_expr(iden(col), expr(assignment.value)?);
+        }
+        if let Some(from) = from {
+            update.from(single_table(from)?);
+        }
+        if let Some(selection) = selection {
+            for condition in conditions(selection)? {
+                update.and_where(condition);
+            }
+        }
+        if let Some(returning) = returning {
+            update.returning(returning_clause(returning)?);
+        }
+        Ok(update)
+    }
+}
+
+/// Convert a parsed `DELETE` statement.
+impl TryFrom<Statement> for DeleteStatement {
+    type Error = Error;
+
+    fn try_from(statement: Statement) -> Result<Self> {
+        let (tables, from, using, selection, returning, order_by, limit) = match statement {
+            Statement::Delete {
+                tables,
+                from,
+                using,
+                selection,
+                returning,
+                order_by,
+                limit,
+            } => (tables, from, using, selection, returning, order_by, limit),
+            _ => return Err(unsupported("statement other than DELETE")),
+        };
+        if !tables.is_empty() {
+            return Err(unsupported("multiple table DELETE"));
+        }
+        let mut from = from.into_iter();
+        let mut delete = Query::delete();
+        match (from.next(), from.next()) {
+            (Some(table), None) => delete.from_table(single_table(table)?),
+            _ => return Err(unsupported("multiple table DELETE")),
+        };
+        for table in using.into_iter().flatten() {
+            delete.using(single_table(table)?);
+        }
+        if let Some(selection) = selection {
+            for condition in conditions(selection)? {
+                delete.and_where(condition);
+            }
+        }
+        for order in order_by {
+            delete.add_order_by(order_expr(order)?);
+        }
+        if let Some(limit) = limit {
+            delete.limit(count(limit)?);
+        }
+        if let Some(returning) = returning {
+            delete.returning(returning_clause(returning)?);
+        }
+        Ok(delete)
+    }
+}
+
+fn unsupported(construct: &str) -> Error {
+    Error::Parse(format!("{} is not supported", construct))
+}
+
+/// Quoted identifiers are quoted for the target backend, unquoted ones are written as is.
+fn iden(ident: Ident) -> DynIden {
+    match ident.quote_style {
+        Some(_) => SeaRc::new(Alias::new(&ident.value)),
+        None => SeaRc::new(UnquotedAlias::new(&ident.value)),
+    }
+}
+
+fn set_expr(body: SetExpr) -> Result<SelectStatement> {
+    match body {
+        SetExpr::Select(select) => select_body(*select),
+        SetExpr::Query(query) => SelectStatement::try_from(*query),
+        SetExpr::SetOperation {
+            op,
+            set_quantifier,
+            left,
+            right,
+        } => {
+            let union_type = match (op, set_quantifier) {
+                (SetOperator::Union, SetQuantifier::All) => UnionType::All,
+                (SetOperator::Union, SetQuantifier::None | SetQuantifier::Distinct) => {
+                    UnionType::Distinct
+                }
+                (SetOperator::Intersect, SetQuantifier::None) => UnionType::Intersect,
+                (SetOperator::Except, SetQuantifier::None) => UnionType::Except,
+                (op, set_quantifier) => {
+                    return Err(Error::Parse(format!(
+                        "{} {} is not supported",
+                        op, set_quantifier
+                    )))
+                }
+            };
+            let mut select = set_expr(*left)?;
+            if !select.orders.is_empty() || select.limit.is_some() || select.offset.is_some() {
+                return Err(unsupported(
+                    "ORDER BY, LIMIT or OFFSET before a set operation",
+                ));
+            }
+            select.union(union_type, set_expr(*right)?);
+            Ok(select)
+        }
+        _ => Err(unsupported("query body other than SELECT")),
+    }
+}
+
+fn select_body(body: ast::Select) -> Result<SelectStatement> {
+    if body.top.is_some() {
+        return Err(unsupported("TOP"));
+    }
+    if body.into.is_some() {
+        return Err(unsupported("SELECT INTO"));
+    }
+    if !body.lateral_views.is_empty() {
+        return Err(unsupported("LATERAL VIEW"));
+    }
+    if !body.cluster_by.is_empty() || !body.distribute_by.is_empty() || !body.sort_by.is_empty() {
+        return Err(unsupported("CLUSTER BY, DISTRIBUTE BY or SORT BY"));
+    }
+    if !body.named_window.is_empty() {
+        return Err(unsupported("WINDOW"));
+    }
+    if body.qualify.is_some() {
+        return Err(unsupported("QUALIFY"));
+    }
+    let mut select = Query::select();
+    match body.distinct {
+        Some(Distinct::Distinct) => select.distinct = Some(SelectDistinct::Distinct),
+        Some(Distinct::On(cols)) => select.distinct_on = exprs(cols)?,
+        None => (),
+    }
+    for item in body.projection {
+        select.selects.push(select_expr(item)?);
+    }
+    for (i, table) in body.from.into_iter().enumerate() {
+        let relation = table_factor(table.relation)?;
+        if i == 0 {
+            if relation.lateral {
+                return Err(unsupported("LATERAL in FROM"));
+            }
+            select.from(relation.table);
+        } else {
+            select.join.push(JoinExpr {
+                join: JoinType::CrossJoin,
+                table: Box::new(relation.table),
+                on: None,
+                lateral: relation.lateral,
+            });
+        }
+        for join in table.joins {
+            select.join.push(join_expr(join)?);
+        }
+    }
+    if let Some(selection) = body.selection {
+        for condition in conditions(selection)? {
+            select.and_where(condition);
+        }
+    }
+    match body.group_by {
+        GroupByExpr::Expressions(groups) => {
+            for group in groups {
+                select.add_group_by([expr(group)?]);
+            }
+        }
+        GroupByExpr::All => return Err(unsupported("GROUP BY ALL")),
+    }
+    if let Some(having) = body.having {
+        for condition in conditions(having)? {
+            select.and_having(condition);
+        }
+    }
+    Ok(select)
+}
+
+fn select_expr(item: SelectItem) -> Result<SelectExpr> {
+    Ok(match item {
+        SelectItem::UnnamedExpr(e) => expr(e)?.into(),
+        SelectItem::ExprWithAlias { expr: e, alias } => SelectExpr {
+            expr: expr(e)?,
+            alias: Some(iden(alias)),
+            window: None,
+        },
+        SelectItem::Wildcard(options) => {
+            if options != Default::default() {
+                return Err(unsupported("wildcard option"));
+            }
+            SimpleExpr::Column(ColumnRef::Column(SeaRc::new(UnquotedAlias::new("*")))).into()
+        }
+        SelectItem::QualifiedWildcard(name, options) => {
+            if options != Default::default() {
+                return Err(unsupported("wildcard option"));
+            }
+            let table = name
+                .0
+                .into_iter()
+                .last()
+                .ok_or_else(|| unsupported("wildcard without table"))?;
+            SimpleExpr::Column(ColumnRef::TableColumn(
+                iden(table),
+                SeaRc::new(UnquotedAlias::new("*")),
+            ))
+            .into()
+        }
+    })
+}
+
+struct Relation {
+    table: TableRef,
+    lateral: bool,
+}
+
+fn table_factor(factor: TableFactor) -> Result<Relation> {
+    match factor {
+        TableFactor::Table {
+            name,
+            alias,
+            args: None,
+            with_hints,
+            version: None,
+            partitions,
+        } if with_hints.is_empty() && partitions.is_empty() => Ok(Relation {
+            table: table_ref(name, alias)?,
+            lateral: false,
+        }),
+        TableFactor::Derived {
+            lateral,
+            subquery,
+            alias: Some(alias),
+        } => Ok(Relation {
+            table: TableRef::SubQuery(SelectStatement::try_from(*subquery)?, table_alias(alias)?),
+            lateral,
+        }),
+        TableFactor::Derived { alias: None, .. } => Err(unsupported("subquery without alias")),
+        _ => Err(unsupported("table factor other than table or subquery")),
+    }
+}
+
+fn table_ref(name: ObjectName, alias: Option<TableAlias>) -> Result<TableRef> {
+    let alias = alias.map(table_alias).transpose()?;
+    let mut parts = name.0.into_iter().map(iden);
+    Ok(
+        match (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            alias,
+        ) {
+            (Some(table), None, None, None, None) => TableRef::Table(table),
+            (Some(table), None, None, None, Some(alias)) => TableRef::TableAlias(table, alias),
+            (Some(schema), Some(table), None, None, None) => TableRef::SchemaTable(schema, table),
+            (Some(schema), Some(table), None, None, Some(alias)) => {
+                TableRef::SchemaTableAlias(schema, table, alias)
+            }
+            (Some(database), Some(schema), Some(table), None, None) => {
+                TableRef::DatabaseSchemaTable(database, schema, table)
+            }
+            (Some(database), Some(schema), Some(table), None, Some(alias)) => {
+                TableRef::DatabaseSchemaTableAlias(database, schema, table, alias)
+            }
+            _ => return Err(unsupported("table name with more than three parts")),
+        },
+    )
+}
+
+fn table_alias(alias: TableAlias) -> Result<DynIden> {
+    if !alias.columns.is_empty() {
+        return Err(unsupported("table alias with column names"));
+    }
+    Ok(iden(alias.name))
+}
+
+/// A table of `UPDATE` or `DELETE`, which cannot be joined.
+fn single_table(table: TableWithJoins) -> Result<TableRef> {
+    if !table.joins.is_empty() {
+        return Err(unsupported("JOIN in UPDATE or DELETE"));
+    }
+    match table_factor(table.relation)? {
+        Relation {
+            table,
+            lateral: false,
+        } => Ok(table),
+        _ => Err(unsupported("LATERAL in UPDATE or DELETE")),
+    }
+}
+
+fn join_expr(join: ast::Join) -> Result<JoinExpr> {
+    let (join_type, constraint) = match join.join_operator {
+        JoinOperator::Inner(constraint) => (JoinType::InnerJoin, constraint),
+        JoinOperator::LeftOuter(constraint) => (JoinType::LeftJoin, constraint),
+        JoinOperator::RightOuter(constraint) => (JoinType::RightJoin, constraint),
+        JoinOperator::FullOuter(constraint) => (JoinType::FullOuterJoin, constraint),
+        JoinOperator::CrossJoin => (JoinType::CrossJoin, JoinConstraint::None),
+        _ => {
+            return Err(unsupported(
+                "join other than INNER, LEFT, RIGHT, FULL or CROSS",
+            ))
+        }
+    };
+    let on = match constraint {
+        JoinConstraint::On(on) => Some(JoinOn::Condition(Box::new(
+            conditions(on)?
+                .into_iter()
+                .fold(Condition::all(), Condition::add),
+        ))),
+        JoinConstraint::Using(cols) => Some(JoinOn::Columns(
+            cols.into_iter()
+                .map(|col| SimpleExpr::Column(ColumnRef::Column(iden(col))))
+                .collect(),
+        )),
+        JoinConstraint::None => None,
+        JoinConstraint::Natural => return Err(unsupported("NATURAL JOIN")),
+    };
+    let relation = table_factor(join.relation)?;
+    Ok(JoinExpr {
+        join: join_type,
+        table: Box::new(relation.table),
+        on,
+        lateral: relation.lateral,
+    })
+}
+
+fn order_expr(order: OrderByExpr) -> Result<OrderExpr> {
+    Ok(OrderExpr {
+        expr: expr(order.expr)?,
+        order: match order.asc {
+            Some(false) => Order::Desc,
+            _ => Order::Asc,
+        },
+        nulls: order.nulls_first.map(|first| match first {
+            true => NullOrdering::First,
+            false => NullOrdering::Last,
+        }),
+        field: None,
+    })
+}
+
+fn returning_clause(items: Vec<SelectItem>) -> Result<ReturningClause> {
+    if let [SelectItem::Wildcard(options)] = items.as_slice() {
+        if *options == Default::default() {
+            return Ok(ReturningClause::All);
+        }
+    }
+    Ok(ReturningClause::Exprs(
+        items.into_iter().map(select_expr).collect::<Result<_>>()?,
+    ))
+}
+
+/// A `LIMIT` or `OFFSET`, which sea-query only accepts as a number.
+fn count(e: ast::Expr) -> Result<u64> {
+    match e {
+        ast::Expr::Value(ast::Value::Number(n, _)) => n
+            .parse()
+            .map_err(|_| Error::Parse(format!("{} is not a valid row count", n))),
+        e => Err(Error::Parse(format!("{} is not a valid row count", e))),
+    }
+}
+
+/// Split a condition on its top level `AND`, so that more conditions can be chained with
+/// `and_where`. An `OR` whose right operand is not binary would lose its parentheses in a chain.
+fn conditions(e: ast::Expr) -> Result<Vec<SimpleExpr>> {
+    let mut operands = Vec::new();
+    flatten(e, BinOper::And, &mut operands);
+    operands
+        .into_iter()
+        .map(|e| {
+            Ok(match expr(e)? {
+                SimpleExpr::Binary(left, BinOper::Or, right) if !right.is_binary() => {
+                    SimpleExpr::Tuple(vec![SimpleExpr::Binary(left, BinOper::Or, right)])
+                }
+                e => e,
+            })
+        })
+        .collect()
+}
+
+fn exprs(list: Vec<ast::Expr>) -> Result<Vec<SimpleExpr>> {
+    list.into_iter().map(expr).collect()
+}
+
+fn boxed(e: ast::Expr) -> Result<Box<SimpleExpr>> {
+    expr(e).map(Box::new)
+}
+
+fn binary(left: ast::Expr, op: BinOper, right: SimpleExpr) -> Result<SimpleExpr> {
+    Ok(SimpleExpr::Binary(boxed(left)?, op, Box::new(right)))
+}
+
+fn expr(e: ast::Expr) -> Result<SimpleExpr> {
+    use ast::Expr;
+
+    Ok(match e {
+        Expr::Identifier(col) => SimpleExpr::Column(ColumnRef::Column(iden(col))),
+        Expr::CompoundIdentifier(idents) if idents.len() == 2 => {
+            let mut idents = idents.into_iter().map(iden);
+            SimpleExpr::Column(ColumnRef::TableColumn(
+                idents.next().unwrap(),
+                idents.next().unwrap(),
+            ))
+        }
+        Expr::IsNull(e) => binary(*e, BinOper::Is, SimpleExpr::Keyword(Keyword::Null))?,
+        Expr::IsNotNull(e) => binary(*e, BinOper::IsNot, SimpleExpr::Keyword(Keyword::Null))?,
+        Expr::IsDistinctFrom(left, right) => binary(*left, BinOper::IsDistinctFrom, expr(*right)?)?,
+        Expr::IsNotDistinctFrom(left, right) => {
+            binary(*left, BinOper::IsNotDistinctFrom, expr(*right)?)?
+        }
+        Expr::InList {
+            expr: e,
+            list,
+            negated,
+        } => binary(
+            *e,
+            if negated { BinOper::NotIn } else { BinOper::In },
+            SimpleExpr::Tuple(exprs(list)?),
+        )?,
+        Expr::InSubquery {
+            expr: e,
+            subquery,
+            negated,
+        } => binary(
+            *e,
+            if negated { BinOper::NotIn } else { BinOper::In },
+            SimpleExpr::SubQuery(Box::new((*subquery).try_into()?)),
+        )?,
+        Expr::Between {
+            expr: e,
+            negated,
+            low,
+            high,
+        } => binary(
+            *e,
+            if negated {
+                BinOper::NotBetween
+            } else {
+                BinOper::Between
+            },
+            SimpleExpr::Binary(boxed(*low)?, BinOper::And, boxed(*high)?),
+        )?,
+        Expr::BinaryOp { left, op, right } => match bin_oper(&op) {
+            // Chains of `AND` and `OR` are kept left associative, as they are built
+            Some(op @ (BinOper::And | BinOper::Or)) => {
+                let mut operands = Vec::new();
+                flatten(*left, op, &mut operands);
+                flatten(*right, op, &mut operands);
+                let mut operands = operands.into_iter().map(expr);
+                let first = operands.next().unwrap()?;
+                operands.try_fold(first, |left, right| {
+                    Ok(SimpleExpr::Binary(Box::new(left), op, Box::new(right?)))
+                })?
+            }
+            Some(op) => binary(*left, op, expr(*right)?)?,
+            None => return Err(unsupported(&format!("operator {}", op))),
+        },
+        Expr::Like {
+            negated,
+            expr: e,
+            pattern,
+            escape_char,
+        } => {
+            let like = binary(
+                *e,
+                if negated {
+                    BinOper::NotLike
+                } else {
+                    BinOper::Like
+                },
+                expr(*pattern)?,
+            )?;
+            match escape_char {
+                Some(c) => like.binary(BinOper::Escape, SimpleExpr::Value(c.to_string().into())),
+                None => like,
+            }
+        }
+        Expr::RLike {
+            negated,
+            expr: e,
+            pattern,
+            ..
+        } => binary(
+            *e,
+            if negated {
+                BinOper::NotRegexp
+            } else {
+                BinOper::Regexp
+            },
+            expr(*pattern)?,
+        )?,
+        Expr::AnyOp {
+            left,
+            compare_op,
+            right,
+        } if bin_oper(&compare_op).is_some() => binary(
+            *left,
+            bin_oper(&compare_op).unwrap(),
+            SimpleExpr::Unary(UnOper::Any, boxed(*right)?),
+        )?,
+        Expr::AllOp {
+            left,
+            compare_op,
+            right,
+        } if bin_oper(&compare_op).is_some() => binary(
+            *left,
+            bin_oper(&compare_op).unwrap(),
+            SimpleExpr::Unary(UnOper::All, boxed(*right)?),
+        )?,
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr: e,
+        } => match expr(*e)? {
+            // `NOT` binds tighter than `AND` and `OR`, so the operand keeps its parentheses
+            e if e.is_logical() => {
+                SimpleExpr::Unary(UnOper::Not, Box::new(SimpleExpr::Tuple(vec![e])))
+            }
+            e => SimpleExpr::Unary(UnOper::Not, Box::new(e)),
+        },
+        Expr::UnaryOp {
+            op: UnaryOperator::PGBitwiseNot,
+            expr: e,
+        } => SimpleExpr::Unary(UnOper::BitNot, boxed(*e)?),
+        Expr::UnaryOp {
+            op: UnaryOperator::Plus,
+            expr: e,
+        } => expr(*e)?,
+        Expr::UnaryOp {
+            op: UnaryOperator::Minus,
+            expr: e,
+        } => match *e {
+            Expr::Value(ast::Value::Number(n, long)) => {
+                value(ast::Value::Number(format!("-{}", n), long))?
+            }
+            _ => return Err(unsupported("unary minus of an expression")),
+        },
+        Expr::Nested(e) => expr(*e)?,
+        Expr::Value(v) => value(v)?,
+        Expr::Function(func) => function(func)?,
+        Expr::Exists { subquery, negated } => {
+            let exists = SimpleExpr::Unary(
+                UnOper::Exists,
+                Box::new(SimpleExpr::SubQuery(Box::new((*subquery).try_into()?))),
+            );
+            match negated {
+                true => SimpleExpr::Unary(UnOper::Not, Box::new(exists)),
+                false => exists,
+            }
+        }
+        Expr::Subquery(query) => SimpleExpr::SubQuery(Box::new((*query).try_into()?)),
+        Expr::Tuple(list) => SimpleExpr::Tuple(exprs(list)?),
+        Expr::Cast {
+            expr: e,
+            data_type,
+            format: None,
+        } => SimpleExpr::Cast(
+            boxed(*e)?,
+            CastType::Custom(SeaRc::new(UnquotedAlias::new(&data_type.to_string()))),
+        ),
+        e => return Err(unsupported(&format!("expression {}", e))),
+    })
+}
+
+/// Collect the operands of a chain of `AND` or `OR`, ignoring redundant parentheses.
+fn flatten(e: ast::Expr, op: BinOper, operands: &mut Vec<ast::Expr>) {
+    match e {
+        ast::Expr::BinaryOp {
+            left,
+            op: inner,
+            right,
+        } if bin_oper(&inner) == Some(op) => {
+            flatten(*left, op, operands);
+            flatten(*right, op, operands);
+        }
+        ast::Expr::Nested(e) if matches!(&*e, ast::Expr::BinaryOp { op: inner, .. } if bin_oper(inner) == Some(op)) => {
+            flatten(*e, op, operands)
+        }
+        e => operands.push(e),
+    }
+}
+
+fn bin_oper(op: &BinaryOperator) -> Option<BinOper> {
+    Some(match op {
+        BinaryOperator::Plus => BinOper::Add,
+        BinaryOperator::Minus => BinOper::Sub,
+        BinaryOperator::Multiply => BinOper::Mul,
+        BinaryOperator::Divide => BinOper::Div,
+        BinaryOperator::StringConcat => BinOper::Concat,
+        BinaryOperator::Gt => BinOper::GreaterThan,
+        BinaryOperator::Lt => BinOper::SmallerThan,
+        BinaryOperator::GtEq => BinOper::GreaterThanOrEqual,
+        BinaryOperator::LtEq => BinOper::SmallerThanOrEqual,
+        BinaryOperator::Eq => BinOper::Equal,
+        BinaryOperator::NotEq => BinOper::NotEqual,
+        BinaryOperator::And => BinOper::And,
+        BinaryOperator::Or => BinOper::Or,
+        BinaryOperator::BitwiseOr => BinOper::BitOr,
+        BinaryOperator::BitwiseAnd => BinOper::BitAnd,
+        BinaryOperator::BitwiseXor => BinOper::BitXor,
+        BinaryOperator::PGBitwiseShiftLeft => BinOper::LShift,
+        BinaryOperator::PGBitwiseShiftRight => BinOper::RShift,
+        _ => return None,
+    })
+}
+
+fn value(v: ast::Value) -> Result<SimpleExpr> {
+    use ast::Value as V;
+
+    Ok(match v {
+        V::Number(n, false) => match (n.parse::<i64>(), n.parse::<f64>()) {
+            (Ok(i), _) => SimpleExpr::Value(i.into()),
+            (_, Ok(f)) if f.is_finite() => SimpleExpr::Value(f.into()),
+            _ => return Err(unsupported(&format!("number {}", n))),
+        },
+        V::SingleQuotedString(s) | V::DoubleQuotedString(s) => SimpleExpr::Value(s.into()),
+        V::Boolean(b) => SimpleExpr::Value(b.into()),
+        V::Null => SimpleExpr::Keyword(Keyword::Null),
+        // Would clash with the placeholders of the values collected when building
+        V::Placeholder(_) => return Err(unsupported("placeholder")),
+        v => return Err(unsupported(&format!("value {}", v))),
+    })
+}
+
+fn function(func: ast::Function) -> Result<SimpleExpr> {
+    let plain_args = func.args.iter().all(|arg| {
+        matches!(
+            arg,
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(_) | FunctionArgExpr::Wildcard)
+        )
+    });
+    if !plain_args
+        || func.distinct && func.args.len() != 1
+        || func.filter.is_some()
+        || func.null_treatment.is_some()
+        || func.over.is_some()
+        || func.special
+        || !func.order_by.is_empty()
+    {
+        return Err(unsupported(&format!(
+            "function call {}",
+            ast::Expr::Function(func)
+        )));
+    }
+    let function = match func.name.to_string().to_uppercase().as_str() {
+        "MAX" => Function::Max,
+        "MIN" => Function::Min,
+        "SUM" => Function::Sum,
+        "AVG" => Function::Avg,
+        "COUNT" => Function::Count,
+        "IFNULL" => Function::IfNull,
+        "COALESCE" => Function::Coalesce,
+        "NULLIF" => Function::NullIf,
+        _ => Function::Custom(SeaRc::new(UnquotedAlias::new(&func.name.to_string()))),
+    };
+    let mut args = Vec::with_capacity(func.args.len());
+    for arg in func.args {
+        let arg = match arg {
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(e)) => expr(e)?,
+            _ => SimpleExpr::Column(ColumnRef::Column(SeaRc::new(UnquotedAlias::new("*")))),
+        };
+        args.push(match func.distinct {
+            true => SimpleExpr::Unary(UnOper::Distinct, Box::new(arg)),
+            false => arg,
+        });
+    }
+    Ok(SimpleExpr::FunctionCall(function, args))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlparser::dialect::GenericDialect;
+
+    fn parse(sql: &str) -> QueryStatement {
+        QueryStatement::parse(&GenericDialect {}, sql)
+            .unwrap()
+            .remove(0)
+    }
+
+    fn to_string<T: QueryBuilder>(statement: &QueryStatement, query_builder: T) -> String {
+        match statement {
+            QueryStatement::Select(select) => select.to_string(query_builder),
+            QueryStatement::Insert(insert) => insert.to_string(query_builder),
+            QueryStatement::Update(update) => update.to_string(query_builder),
+            QueryStatement::Delete(delete) => delete.to_string(query_builder),
+        }
+    }
+
+    #[test]
+    fn test_parse_select() {
+        let statement = parse(
+            r#"SELECT DISTINCT g.id, COUNT(*) AS "count", g.* FROM glyph AS g
+            LEFT JOIN "character" c ON c.glyph_id = g.id AND (c.size > 1 OR c.size IS NULL)
+            WHERE NOT (g.aspect = 1 OR g.aspect = 2) AND g.image IN ('A', 'B')
+            GROUP BY g.id HAVING COUNT(*) BETWEEN -1 AND 2.5
+            ORDER BY g.id DESC NULLS LAST LIMIT 10 OFFSET 5"#,
+        );
+
+        assert_eq!(
+            to_string(&statement, PostgresQueryBuilder),
+            [
+                r#"SELECT DISTINCT g.id, COUNT(*) AS "count", g.* FROM glyph AS g"#,
+                r#"LEFT JOIN "character" AS c ON c.glyph_id = g.id AND ((c.size > 1) OR (c.size IS NULL))"#,
+                r#"WHERE NOT ((g.aspect = 1) OR (g.aspect = 2)) AND g.image IN ('A', 'B')"#,
+                r#"GROUP BY g.id HAVING COUNT(*) BETWEEN -1 AND 2.5"#,
+                r#"ORDER BY g.id DESC NULLS LAST LIMIT 10 OFFSET 5"#,
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn test_parse_union() {
+        let statement = parse("SELECT a FROM t UNION ALL SELECT a FROM s.u ORDER BY a LIMIT 1");
+
+        assert_eq!(
+            to_string(&statement, SqliteQueryBuilder),
+            "SELECT a FROM t UNION ALL SELECT a FROM s.u ORDER BY a ASC LIMIT 1"
+        );
+    }
+
+    #[test]
+    fn test_parse_rewrite() {
+        let mut select = match parse("SELECT id FROM glyph WHERE aspect > 1 OR image IS NULL") {
+            QueryStatement::Select(select) => select,
+            _ => unreachable!(),
+        };
+        select.and_where(Expr::col(Alias::new("tenant_id")).eq(7));
+
+        assert_eq!(
+            select.to_string(MysqlQueryBuilder),
+            "SELECT id FROM glyph WHERE ((aspect > 1) OR (image IS NULL)) AND `tenant_id` = 7"
+        );
+        assert_eq!(
+            select.build(PostgresQueryBuilder),
+            (
+                r#"SELECT id FROM glyph WHERE ((aspect > $1) OR (image IS NULL)) AND "tenant_id" = $2"#
+                    .to_owned(),
+                Values(vec![Value::BigInt(Some(1)), Value::Int(Some(7))])
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_insert_update_delete() {
+        let insert =
+            parse("INSERT INTO glyph (aspect, image) VALUES (1.5, 'a'), (2, NULL) RETURNING id");
+        let update = parse("UPDATE glyph SET aspect = aspect + 1 WHERE id = 1 RETURNING *");
+        let delete = parse("DELETE FROM glyph WHERE id IN (SELECT id FROM old)");
+
+        assert_eq!(
+            to_string(&insert, PostgresQueryBuilder),
+            "INSERT INTO glyph (aspect, image) VALUES (1.5, 'a'), (2, NULL) RETURNING id"
+        );
+        assert_eq!(
+            to_string(&update, PostgresQueryBuilder),
+            r#"UPDATE glyph SET "aspect" = aspect + 1 WHERE id = 1 RETURNING *"#
+        );
+        assert_eq!(
+            to_string(&delete, PostgresQueryBuilder),
+            "DELETE FROM glyph WHERE id IN (SELECT id FROM old)"
+        );
+    }
+
+    #[test]
+    fn test_parse_unsupported() {
+        let dialect = GenericDialect {};
+
+        assert_eq!(
+            QueryStatement::parse(&dialect, "WITH c AS (SELECT 1) SELECT * FROM c").unwrap_err(),
+            Error::Parse("WITH is not supported".to_owned())
+        );
+        assert_eq!(
+            QueryStatement::parse(&dialect, "SELECT * FROM glyph WHERE id = $1").unwrap_err(),
+            Error::Parse("placeholder is not supported".to_owned())
+        );
+        assert_eq!(
+            QueryStatement::parse(
+                &dialect,
+                "SELECT CASE WHEN id IN (SELECT id FROM old) THEN 1 END FROM glyph"
+            )
+            .unwrap_err(),
+            Error::Parse(
+                "expression CASE WHEN id IN (SELECT id FROM old) THEN 1 END is not supported"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            QueryStatement::parse(&dialect, "SELECT COUNT(*) OVER () FROM glyph").unwrap_err(),
+            Error::Parse("function call COUNT(*) OVER () is not supported".to_owned())
+        );
+        assert_eq!(
+            QueryStatement::parse(&dialect, "SELECT -(SELECT 1) FROM glyph").unwrap_err(),
+            Error::Parse("unary minus of an expression is not supported".to_owned())
+        );
+        assert_eq!(
+            QueryStatement::parse(&dialect, "SELECT X'AB' FROM glyph").unwrap_err(),
+            Error::Parse("value X'AB' is not supported".to_owned())
+        );
+        assert_eq!(
+            QueryStatement::parse(&dialect, "SELECT 1e999999 FROM glyph").unwrap_err(),
+            Error::Parse("number 1e999999 is not supported".to_owned())
+        );
+        assert!(matches!(
+            QueryStatement::parse(&dialect, "DROP TABLE glyph"),
+            Err(Error::Parse(_))
+        ));
+        assert!(matches!(
+            QueryStatement::parse(&dialect, "SELEC 1"),
+            Err(Error::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{expr::*, types::*, SelectExpr, SelectStatement};
+
+/// RETURNING clause.
+///
+/// Supported by Postgres and SQLite 3.35+; ignored by MySQL.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReturningClause {
+    All,
+    Exprs(Vec<SelectExpr>),
+}
+
+/// Shorthand for constructing [`ReturningClause`]
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let query = Query::delete()
+///     .from_table(Glyph::Table)
+///     .and_where(Expr::col(Glyph::Id).eq(1))
+///     .returning(Query::returning().columns(vec![Glyph::Id, Glyph::Image]))
+///     .to_owned();
+///
+/// assert_eq!(
+///     query.to_string(MysqlQueryBuilder),
+///     r#"DELETE FROM `glyph` WHERE `id` = 1"#
+/// );
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"DELETE FROM "glyph" WHERE "id" = 1 RETURNING "id", "image""#
+/// );
+/// assert_eq!(
+///     query.to_string(SqliteQueryBuilder),
+///     r#"DELETE FROM `glyph` WHERE `id` = 1 RETURNING `id`, `image`"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Returning;
+
+impl Returning {
+    /// Construct a new [`Returning`]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// `RETURNING *`
+    pub fn all(&self) -> ReturningClause {
+        ReturningClause::All
+    }
+
+    /// Return a column
+    pub fn column<C>(&self, col: C) -> ReturningClause
+    where
+        C: IntoColumnRef,
+    {
+        self.columns(vec![col])
+    }
+
+    /// Return columns
+    pub fn columns<T, I>(&self, cols: I) -> ReturningClause
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.exprs(
+            cols.into_iter()
+                .map(|col| SimpleExpr::Column(col.into_column_ref())),
+        )
+    }
+
+    /// Return an expression
+    pub fn expr<T>(&self, expr: T) -> ReturningClause
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.exprs(vec![expr])
+    }
+
+    /// Return expressions
+    pub fn exprs<T, I>(&self, exprs: I) -> ReturningClause
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        ReturningClause::Exprs(
+            exprs
+                .into_iter()
+                .map(|expr| {
+                    let expr: SimpleExpr = expr.into();
+                    expr.into()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl From<SelectStatement> for ReturningClause {
+    fn from(select: SelectStatement) -> Self {
+        ReturningClause::Exprs(select.selects)
+    }
+}
@@ -1,11 +1,12 @@
 use crate::{
-    backend::QueryBuilder,
+    backend::{QueryBuilder, SupportCheck},
+    error::*,
     expr::*,
     prepare::*,
-    query::{condition::*, OrderedStatement},
+    query::{condition::*, Keyset, OrderedStatement, Visitor, WindowSelectType, WindowStatement},
     types::*,
     value::*,
-    QueryStatementBuilder,
+    QueryStatementBuilder, WithClause, WithQuery,
 };
 use std::iter::FromIterator;
 
@@ -38,41 +39,84 @@ use std::iter::FromIterator;
 ///     r#"SELECT `character`, `font`.`name` FROM `character` LEFT JOIN `font` ON `character`.`font_id` = `font`.`id` WHERE `size_w` IN (3, 4) AND `character` LIKE 'A%'"#
 /// );
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectStatement {
     pub(crate) distinct: Option<SelectDistinct>,
+    pub(crate) distinct_on: Vec<SimpleExpr>,
     pub(crate) selects: Vec<SelectExpr>,
     pub(crate) from: Option<Box<TableRef>>,
+    pub(crate) r#final: bool,
+    pub(crate) sample: Option<Value>,
     pub(crate) join: Vec<JoinExpr>,
     pub(crate) wherei: ConditionHolder,
     pub(crate) groups: Vec<SimpleExpr>,
+    pub(crate) grouping: Option<Grouping>,
     pub(crate) having: ConditionHolder,
     pub(crate) orders: Vec<OrderExpr>,
     pub(crate) limit: Option<Value>,
     pub(crate) offset: Option<Value>,
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "crate::types::iden_serde::vec_named")
+    )]
+    pub(crate) windows: Vec<(DynIden, WindowStatement)>,
+    pub(crate) unions: Vec<(UnionType, SelectStatement)>,
 }
 
-/// List of distinct keywords that can be used in select statement
+/// List of distinct keywords that can be used in select statement,
+/// see [`SelectStatement::distinct_on`] for `DISTINCT ON`
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelectDistinct {
     All,
     Distinct,
     DistinctRow,
 }
 
+/// Grouping sets modifier of `GROUP BY`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Grouping {
+    /// `ROLLUP` over the group by expressions
+    Rollup,
+    /// `CUBE` over the group by expressions
+    Cube,
+    /// `GROUPING SETS`, after the group by expressions
+    Sets(Vec<Vec<SimpleExpr>>),
+}
+
+/// List of set operators that combine the results of two select statements
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnionType {
+    Distinct,
+    All,
+    Intersect,
+    Except,
+}
+
 /// Select expression used in select statement
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectExpr {
     pub expr: SimpleExpr,
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "crate::types::iden_serde::option")
+    )]
     pub alias: Option<DynIden>,
+    pub window: Option<WindowSelectType>,
 }
 
 /// Join expression used in select statement
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JoinExpr {
     pub join: JoinType,
     pub table: Box<TableRef>,
     pub on: Option<JoinOn>,
+    pub lateral: bool,
 }
 
 impl Into<SelectExpr> for SimpleExpr {
@@ -80,10 +124,17 @@ impl Into<SelectExpr> for SimpleExpr {
         SelectExpr {
             expr: self,
             alias: None,
+            window: None,
         }
     }
 }
 
+impl From<SelectStatement> for SimpleExpr {
+    fn from(sel: SelectStatement) -> Self {
+        SimpleExpr::SubQuery(Box::new(sel))
+    }
+}
+
 impl Default for SelectStatement {
     fn default() -> Self {
         Self::new()
@@ -95,15 +146,21 @@ impl SelectStatement {
     pub fn new() -> Self {
         Self {
             distinct: None,
+            distinct_on: Vec::new(),
             selects: Vec::new(),
             from: None,
+            r#final: false,
+            sample: None,
             join: Vec::new(),
             wherei: ConditionHolder::new(),
             groups: Vec::new(),
+            grouping: None,
             having: ConditionHolder::new(),
             orders: Vec::new(),
             limit: None,
             offset: None,
+            windows: Vec::new(),
+            unions: Vec::new(),
         }
     }
 
@@ -111,61 +168,24 @@ impl SelectStatement {
     pub fn take(&mut self) -> Self {
         Self {
             distinct: self.distinct.take(),
+            distinct_on: std::mem::take(&mut self.distinct_on),
             selects: std::mem::take(&mut self.selects),
             from: self.from.take(),
+            r#final: std::mem::take(&mut self.r#final),
+            sample: self.sample.take(),
             join: std::mem::take(&mut self.join),
             wherei: std::mem::replace(&mut self.wherei, ConditionHolder::new()),
             groups: std::mem::take(&mut self.groups),
+            grouping: self.grouping.take(),
             having: std::mem::replace(&mut self.having, ConditionHolder::new()),
             orders: std::mem::take(&mut self.orders),
             limit: self.limit.take(),
             offset: self.offset.take(),
+            windows: std::mem::take(&mut self.windows),
+            unions: std::mem::take(&mut self.unions),
         }
     }
 
-    /// A shorthand to express if ... else ... when constructing the select statement.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use sea_query::{*, tests_cfg::*};
-    ///
-    /// let query = Query::select()
-    ///     .column(Char::Character)
-    ///     .from(Char::Table)
-    ///     .conditions(
-    ///         true,
-    ///         |x| { x.and_where(Expr::col(Char::FontId).eq(5)); },
-    ///         |x| { x.and_where(Expr::col(Char::FontId).eq(10)); }
-    ///     )
-    ///     .to_owned();
-    ///
-    /// assert_eq!(
-    ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = 5"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5"#
-    /// );
-    /// assert_eq!(
-    ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = 5"#
-    /// );
-    /// ```
-    pub fn conditions<T, F>(&mut self, b: bool, if_true: T, if_false: F) -> &mut Self
-    where
-        T: FnOnce(&mut Self),
-        F: FnOnce(&mut Self),
-    {
-        if b {
-            if_true(self)
-        } else {
-            if_false(self)
-        }
-        self
-    }
-
     /// Clear the select list
     pub fn clear_selects(&mut self) -> &mut Self {
         self.selects = Vec::new();
@@ -257,8 +277,75 @@ impl SelectStatement {
     }
 
     /// Select distinct
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .distinct()
+    ///     .columns(vec![Char::FontId, Char::FontSize])
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT DISTINCT `font_id`, `font_size` FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT DISTINCT "font_id", "font_size" FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT DISTINCT `font_id`, `font_size` FROM `character`"#
+    /// );
+    /// ```
     pub fn distinct(&mut self) -> &mut Self {
         self.distinct = Some(SelectDistinct::Distinct);
+        self.distinct_on.clear();
+        self
+    }
+
+    /// Select distinct on columns, keeping the first row of each group.
+    /// Not supported by MySQL and SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .distinct_on(vec![Char::FontId])
+    ///     .columns(vec![Char::FontId, Char::Character])
+    ///     .from(Char::Table)
+    ///     .order_by(Char::FontId, Order::Asc)
+    ///     .order_by(Char::SizeW, Order::Desc)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT DISTINCT ON ("font_id") "font_id", "character" FROM "character" ORDER BY "font_id" ASC, "size_w" DESC"#
+    /// );
+    /// assert_eq!(
+    ///     query.try_build(MysqlQueryBuilder),
+    ///     Err(error::Error::Unsupported {
+    ///         backend: "MySQL",
+    ///         feature: "DISTINCT ON"
+    ///     })
+    /// );
+    /// ```
+    pub fn distinct_on<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.distinct = None;
+        self.distinct_on = cols
+            .into_iter()
+            .map(|col| SimpleExpr::Column(col.into_column_ref()))
+            .collect();
         self
     }
 
@@ -446,6 +533,7 @@ impl SelectStatement {
         self.expr(SelectExpr {
             expr: expr.into(),
             alias: Some(alias.into_iden()),
+            window: None,
         });
         self
     }
@@ -462,6 +550,227 @@ impl SelectStatement {
         self.expr_as(expr, alias)
     }
 
+    /// Select column with window function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .expr_window(
+    ///         Expr::cust("ROW_NUMBER()"),
+    ///         WindowStatement::partition_by(Char::FontSize)
+    ///             .order_by(Char::Character, Order::Asc)
+    ///             .to_owned(),
+    ///     )
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, ROW_NUMBER() OVER (PARTITION BY `font_size` ORDER BY `character` ASC) FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", ROW_NUMBER() OVER (PARTITION BY "font_size" ORDER BY "character" ASC) FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, ROW_NUMBER() OVER (PARTITION BY `font_size` ORDER BY `character` ASC) FROM `character`"#
+    /// );
+    /// ```
+    pub fn expr_window<T>(&mut self, expr: T, window: WindowStatement) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.expr(SelectExpr {
+            expr: expr.into(),
+            alias: None,
+            window: Some(WindowSelectType::Query(window)),
+        });
+        self
+    }
+
+    /// Select column with window function and label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .expr_window_as(
+    ///         Expr::col(Char::SizeW).sum(),
+    ///         WindowStatement::partition_by(Char::FontSize)
+    ///             .order_by(Char::Character, Order::Asc)
+    ///             .frame_between(FrameType::Rows, Frame::Preceding(1), Frame::CurrentRow)
+    ///             .to_owned(),
+    ///         Alias::new("C"),
+    ///     )
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, SUM(`size_w`) OVER (PARTITION BY `font_size` ORDER BY `character` ASC ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS `C` FROM `character`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", SUM("size_w") OVER (PARTITION BY "font_size" ORDER BY "character" ASC ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS "C" FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, SUM(`size_w`) OVER (PARTITION BY `font_size` ORDER BY `character` ASC ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS `C` FROM `character`"#
+    /// );
+    /// ```
+    pub fn expr_window_as<T, A>(&mut self, expr: T, window: WindowStatement, alias: A) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+        A: IntoIden,
+    {
+        self.expr(SelectExpr {
+            expr: expr.into(),
+            alias: Some(alias.into_iden()),
+            window: Some(WindowSelectType::Query(window)),
+        });
+        self
+    }
+
+    /// Select column with window function referencing a named window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .expr_window_name(Expr::cust("ROW_NUMBER()"), Alias::new("w"))
+    ///     .from(Char::Table)
+    ///     .window(
+    ///         Alias::new("w"),
+    ///         WindowStatement::partition_by(Char::FontSize),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, ROW_NUMBER() OVER `w` FROM `character` WINDOW `w` AS (PARTITION BY `font_size`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", ROW_NUMBER() OVER "w" FROM "character" WINDOW "w" AS (PARTITION BY "font_size")"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, ROW_NUMBER() OVER `w` FROM `character` WINDOW `w` AS (PARTITION BY `font_size`)"#
+    /// );
+    /// ```
+    pub fn expr_window_name<T, W>(&mut self, expr: T, window: W) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+        W: IntoIden,
+    {
+        self.expr(SelectExpr {
+            expr: expr.into(),
+            alias: None,
+            window: Some(WindowSelectType::Name(window.into_iden())),
+        });
+        self
+    }
+
+    /// Select column with window function referencing a named window, and label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .expr_window_name_as(Expr::cust("RANK()"), Alias::new("w"), Alias::new("C"))
+    ///     .from(Char::Table)
+    ///     .window(
+    ///         Alias::new("w"),
+    ///         WindowStatement::new()
+    ///             .order_by(Char::SizeW, Order::Desc)
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, RANK() OVER `w` AS `C` FROM `character` WINDOW `w` AS (ORDER BY `size_w` DESC)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", RANK() OVER "w" AS "C" FROM "character" WINDOW "w" AS (ORDER BY "size_w" DESC)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, RANK() OVER `w` AS `C` FROM `character` WINDOW `w` AS (ORDER BY `size_w` DESC)"#
+    /// );
+    /// ```
+    pub fn expr_window_name_as<T, W, A>(&mut self, expr: T, window: W, alias: A) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+        W: IntoIden,
+        A: IntoIden,
+    {
+        self.expr(SelectExpr {
+            expr: expr.into(),
+            alias: Some(alias.into_iden()),
+            window: Some(WindowSelectType::Name(window.into_iden())),
+        });
+        self
+    }
+
+    /// Define a named window in the `WINDOW` clause. See [`SelectStatement::expr_window_name`].
+    ///
+    /// Calling it again adds another window to the clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .expr_window_name(Expr::cust("ROW_NUMBER()"), Alias::new("w1"))
+    ///     .expr_window_name(Expr::cust("RANK()"), Alias::new("w2"))
+    ///     .from(Char::Table)
+    ///     .window(Alias::new("w1"), WindowStatement::partition_by(Char::FontSize))
+    ///     .window(
+    ///         Alias::new("w2"),
+    ///         WindowStatement::new()
+    ///             .order_by(Char::SizeW, Order::Desc)
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT ROW_NUMBER() OVER `w1`, RANK() OVER `w2` FROM `character` WINDOW `w1` AS (PARTITION BY `font_size`), `w2` AS (ORDER BY `size_w` DESC)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT ROW_NUMBER() OVER "w1", RANK() OVER "w2" FROM "character" WINDOW "w1" AS (PARTITION BY "font_size"), "w2" AS (ORDER BY "size_w" DESC)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT ROW_NUMBER() OVER `w1`, RANK() OVER `w2` FROM `character` WINDOW `w1` AS (PARTITION BY `font_size`), `w2` AS (ORDER BY `size_w` DESC)"#
+    /// );
+    /// ```
+    pub fn window<A>(&mut self, name: A, window: WindowStatement) -> &mut Self
+    where
+        A: IntoIden,
+    {
+        self.windows.push((name.into_iden(), window));
+        self
+    }
+
     /// From table.
     ///
     /// # Examples
@@ -619,30 +928,239 @@ impl SelectStatement {
     pub fn from_alias<R, A>(&mut self, tbl_ref: R, alias: A) -> &mut Self
     where
         R: IntoTableRef,
-        A: IntoIden,
-    {
-        self.from_as(tbl_ref, alias)
-    }
-
-    #[deprecated(
-        since = "0.9.0",
-        note = "Please use the [`SelectStatement::from_as`] with a tuple as [`TableRef`]"
-    )]
-    pub fn from_schema_as<S: 'static, T: 'static, A>(
-        &mut self,
-        schema: S,
-        table: T,
-        alias: A,
-    ) -> &mut Self
-    where
-        S: IntoIden,
-        T: IntoIden,
-        A: IntoIden,
+        A: IntoIden,
+    {
+        self.from_as(tbl_ref, alias)
+    }
+
+    #[deprecated(
+        since = "0.9.0",
+        note = "Please use the [`SelectStatement::from_as`] with a tuple as [`TableRef`]"
+    )]
+    pub fn from_schema_as<S: 'static, T: 'static, A>(
+        &mut self,
+        schema: S,
+        table: T,
+        alias: A,
+    ) -> &mut Self
+    where
+        S: IntoIden,
+        T: IntoIden,
+        A: IntoIden,
+    {
+        self.from_as((schema, table), alias)
+    }
+
+    /// From sub-query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![
+    ///         Glyph::Image
+    ///     ])
+    ///     .from_subquery(
+    ///         Query::select()
+    ///             .columns(vec![
+    ///                 Glyph::Image, Glyph::Aspect
+    ///             ])
+    ///             .from(Glyph::Table)
+    ///             .take(),
+    ///         Alias::new("subglyph")
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `image` FROM (SELECT `image`, `aspect` FROM `glyph`) AS `subglyph`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "image" FROM (SELECT "image", "aspect" FROM "glyph") AS "subglyph""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `image` FROM (SELECT `image`, `aspect` FROM `glyph`) AS `subglyph`"#
+    /// );
+    /// ```
+    pub fn from_subquery<T>(&mut self, query: SelectStatement, alias: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.from_from(TableRef::SubQuery(query, alias.into_iden()))
+    }
+
+    /// From a `VALUES` list, see [`TableRef::values_list`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column((Alias::new("v"), Alias::new("id")))
+    ///     .from_values(
+    ///         vec![Values(vec![1.into()]), Values(vec![2.into()])],
+    ///         Alias::new("v"),
+    ///         vec![Alias::new("id")],
+    ///     )
+    ///     .left_join(
+    ///         Char::Table,
+    ///         Expr::tbl(Char::Table, Char::Id).equals(Alias::new("v"), Alias::new("id")),
+    ///     )
+    ///     .and_where(Expr::tbl(Char::Table, Char::Id).is_null())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `v`.`id` FROM (VALUES ROW(1), ROW(2)) AS `v` (`id`) LEFT JOIN `character` ON `character`.`id` = `v`.`id` WHERE `character`.`id` IS NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "v"."id" FROM (VALUES (1), (2)) AS "v" ("id") LEFT JOIN "character" ON "character"."id" = "v"."id" WHERE "character"."id" IS NULL"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `v`.`id` FROM (SELECT 1 AS `id` UNION ALL SELECT 2) AS `v` LEFT JOIN `character` ON `character`.`id` = `v`.`id` WHERE `character`.`id` IS NULL"#
+    /// );
+    /// ```
+    pub fn from_values<A, C, I>(&mut self, rows: Vec<Values>, alias: A, cols: I) -> &mut Self
+    where
+        A: IntoIden,
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+    {
+        self.from_from(TableRef::values_list(rows, alias, cols))
+    }
+
+    fn from_from(&mut self, select: TableRef) -> &mut Self {
+        self.from = Some(Box::new(select));
+        self
+    }
+
+    /// Read the fully merged rows of the table with the `FINAL` modifier. ClickHouse only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .final_()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(ClickHouseQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` FINAL"#
+    /// );
+    /// ```
+    pub fn final_(&mut self) -> &mut Self {
+        self.r#final = true;
+        self
+    }
+
+    /// Read a sample of the table, either a ratio or an approximate number of rows. ClickHouse only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .sample(0.1)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(ClickHouseQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` SAMPLE 0.1"#
+    /// );
+    /// ```
+    pub fn sample<V>(&mut self, sample: V) -> &mut Self
+    where
+        V: Into<Value>,
+    {
+        self.sample = Some(sample.into());
+        self
+    }
+
+    /// Left join.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .table_column(Font::Table, Font::Name)
+    ///     .from(Char::Table)
+    ///     .left_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` LEFT JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "font"."name" FROM "character" LEFT JOIN "font" ON "character"."font_id" = "font"."id""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` LEFT JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    /// );
+    /// ```
+    pub fn left_join<R, C>(&mut self, tbl_ref: R, condition: C) -> &mut Self
+    where
+        R: IntoTableRef,
+        C: IntoCondition,
+    {
+        self.join(JoinType::LeftJoin, tbl_ref, condition)
+    }
+
+    /// Inner join.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .table_column(Font::Table, Font::Name)
+    ///     .from(Char::Table)
+    ///     .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` INNER JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "font"."name" FROM "character" INNER JOIN "font" ON "character"."font_id" = "font"."id""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` INNER JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    /// );
+    /// ```
+    pub fn inner_join<R, C>(&mut self, tbl_ref: R, condition: C) -> &mut Self
+    where
+        R: IntoTableRef,
+        C: IntoCondition,
     {
-        self.from_as((schema, table), alias)
+        self.join(JoinType::InnerJoin, tbl_ref, condition)
     }
 
-    /// From sub-query.
+    /// Cross join, i.e. the cartesian product with another table.
     ///
     /// # Examples
     ///
@@ -650,46 +1168,66 @@ impl SelectStatement {
     /// use sea_query::{*, tests_cfg::*};
     ///
     /// let query = Query::select()
-    ///     .columns(vec![
-    ///         Glyph::Image
-    ///     ])
-    ///     .from_subquery(
-    ///         Query::select()
-    ///             .columns(vec![
-    ///                 Glyph::Image, Glyph::Aspect
-    ///             ])
-    ///             .from(Glyph::Table)
-    ///             .take(),
-    ///         Alias::new("subglyph")
-    ///     )
+    ///     .column(Char::Character)
+    ///     .table_column(Font::Table, Font::Name)
+    ///     .from(Char::Table)
+    ///     .cross_join(Font::Table)
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `image` FROM (SELECT `image`, `aspect` FROM `glyph`) AS `subglyph`"#
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` CROSS JOIN `font`"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "image" FROM (SELECT "image", "aspect" FROM "glyph") AS "subglyph""#
+    ///     r#"SELECT "character", "font"."name" FROM "character" CROSS JOIN "font""#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `image` FROM (SELECT `image`, `aspect` FROM `glyph`) AS `subglyph`"#
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` CROSS JOIN `font`"#
     /// );
     /// ```
-    pub fn from_subquery<T>(&mut self, query: SelectStatement, alias: T) -> &mut Self
+    pub fn cross_join<R>(&mut self, tbl_ref: R) -> &mut Self
     where
-        T: IntoIden,
+        R: IntoTableRef,
     {
-        self.from_from(TableRef::SubQuery(query, alias.into_iden()))
+        self.join.push(JoinExpr {
+            join: JoinType::CrossJoin,
+            table: Box::new(tbl_ref.into_table_ref()),
+            on: None,
+            lateral: false,
+        });
+        self
     }
 
-    fn from_from(&mut self, select: TableRef) -> &mut Self {
-        self.from = Some(Box::new(select));
-        self
+    /// Full outer join. Not supported by MySQL and SQLite; building the statement panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .table_column(Font::Table, Font::Name)
+    ///     .from(Char::Table)
+    ///     .full_outer_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character", "font"."name" FROM "character" FULL OUTER JOIN "font" ON "character"."font_id" = "font"."id""#
+    /// );
+    /// ```
+    pub fn full_outer_join<R, C>(&mut self, tbl_ref: R, condition: C) -> &mut Self
+    where
+        R: IntoTableRef,
+        C: IntoCondition,
+    {
+        self.join(JoinType::FullOuterJoin, tbl_ref, condition)
     }
 
-    /// Left join.
+    /// Join with other table by [`JoinType`].
     ///
     /// # Examples
     ///
@@ -700,32 +1238,24 @@ impl SelectStatement {
     ///     .column(Char::Character)
     ///     .table_column(Font::Table, Font::Name)
     ///     .from(Char::Table)
-    ///     .left_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .join(JoinType::RightJoin, Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `font`.`name` FROM `character` LEFT JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` RIGHT JOIN `font` ON `character`.`font_id` = `font`.`id`"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "font"."name" FROM "character" LEFT JOIN "font" ON "character"."font_id" = "font"."id""#
+    ///     r#"SELECT "character", "font"."name" FROM "character" RIGHT JOIN "font" ON "character"."font_id" = "font"."id""#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `font`.`name` FROM `character` LEFT JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` RIGHT JOIN `font` ON `character`.`font_id` = `font`.`id`"#
     /// );
     /// ```
-    pub fn left_join<R>(&mut self, tbl_ref: R, condition: SimpleExpr) -> &mut Self
-    where
-        R: IntoTableRef,
-    {
-        self.join(JoinType::LeftJoin, tbl_ref, condition)
-    }
-
-    /// Inner join.
     ///
-    /// # Examples
+    /// Join on a [`Condition`]:
     ///
     /// ```
     /// use sea_query::{*, tests_cfg::*};
@@ -734,30 +1264,45 @@ impl SelectStatement {
     ///     .column(Char::Character)
     ///     .table_column(Font::Table, Font::Name)
     ///     .from(Char::Table)
-    ///     .inner_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .join(
+    ///         JoinType::InnerJoin,
+    ///         Font::Table,
+    ///         Cond::all()
+    ///             .add(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///             .add(
+    ///                 Cond::any()
+    ///                     .add(Expr::tbl(Font::Table, Font::Variant).eq("Bold"))
+    ///                     .add(Expr::tbl(Font::Table, Font::Language).eq("en")),
+    ///             ),
+    ///     )
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `font`.`name` FROM `character` INNER JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` INNER JOIN `font` ON `character`.`font_id` = `font`.`id` AND (`font`.`variant` = 'Bold' OR `font`.`language` = 'en')"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "font"."name" FROM "character" INNER JOIN "font" ON "character"."font_id" = "font"."id""#
+    ///     r#"SELECT "character", "font"."name" FROM "character" INNER JOIN "font" ON "character"."font_id" = "font"."id" AND ("font"."variant" = 'Bold' OR "font"."language" = 'en')"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `font`.`name` FROM `character` INNER JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    ///     r#"SELECT `character`, `font`.`name` FROM `character` INNER JOIN `font` ON `character`.`font_id` = `font`.`id` AND (`font`.`variant` = 'Bold' OR `font`.`language` = 'en')"#
     /// );
     /// ```
-    pub fn inner_join<R>(&mut self, tbl_ref: R, condition: SimpleExpr) -> &mut Self
+    pub fn join<R, C>(&mut self, join: JoinType, tbl_ref: R, condition: C) -> &mut Self
     where
         R: IntoTableRef,
+        C: IntoCondition,
     {
-        self.join(JoinType::InnerJoin, tbl_ref, condition)
+        self.join_join(
+            join,
+            tbl_ref.into_table_ref(),
+            JoinOn::Condition(Box::new(condition.into_condition())),
+        )
     }
 
-    /// Join with other table by [`JoinType`].
+    /// Join with other table by [`JoinType`] on columns of the same name in both tables, i.e. `USING`.
     ///
     /// # Examples
     ///
@@ -766,32 +1311,38 @@ impl SelectStatement {
     ///
     /// let query = Query::select()
     ///     .column(Char::Character)
-    ///     .table_column(Font::Table, Font::Name)
+    ///     .column(Font::Name)
     ///     .from(Char::Table)
-    ///     .join(JoinType::RightJoin, Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .join_using(JoinType::InnerJoin, Font::Table, vec![Font::Id])
     ///     .to_owned();
     ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
-    ///     r#"SELECT `character`, `font`.`name` FROM `character` RIGHT JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    ///     r#"SELECT `character`, `name` FROM `character` INNER JOIN `font` USING (`id`)"#
     /// );
     /// assert_eq!(
     ///     query.to_string(PostgresQueryBuilder),
-    ///     r#"SELECT "character", "font"."name" FROM "character" RIGHT JOIN "font" ON "character"."font_id" = "font"."id""#
+    ///     r#"SELECT "character", "name" FROM "character" INNER JOIN "font" USING ("id")"#
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"SELECT `character`, `font`.`name` FROM `character` RIGHT JOIN `font` ON `character`.`font_id` = `font`.`id`"#
+    ///     r#"SELECT `character`, `name` FROM `character` INNER JOIN `font` USING (`id`)"#
     /// );
     /// ```
-    pub fn join<R>(&mut self, join: JoinType, tbl_ref: R, condition: SimpleExpr) -> &mut Self
+    pub fn join_using<R, C, I>(&mut self, join: JoinType, tbl_ref: R, cols: I) -> &mut Self
     where
         R: IntoTableRef,
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
     {
         self.join_join(
             join,
             tbl_ref.into_table_ref(),
-            JoinOn::Condition(Box::new(condition)),
+            JoinOn::Columns(
+                cols.into_iter()
+                    .map(|col| SimpleExpr::Column(ColumnRef::Column(col.into_iden())))
+                    .collect(),
+            ),
         )
     }
 
@@ -827,21 +1378,22 @@ impl SelectStatement {
     ///     r#"SELECT `character`, `font`.`name` FROM `character` RIGHT JOIN `font` AS `f` ON `character`.`font_id` = `font`.`id`"#
     /// );
     /// ```
-    pub fn join_as<R, A>(
+    pub fn join_as<R, A, C>(
         &mut self,
         join: JoinType,
         tbl_ref: R,
         alias: A,
-        condition: SimpleExpr,
+        condition: C,
     ) -> &mut Self
     where
         R: IntoTableRef,
         A: IntoIden,
+        C: IntoCondition,
     {
         self.join_join(
             join,
             tbl_ref.into_table_ref().alias(alias.into_iden()),
-            JoinOn::Condition(Box::new(condition)),
+            JoinOn::Condition(Box::new(condition.into_condition())),
         )
     }
 
@@ -849,16 +1401,17 @@ impl SelectStatement {
         since = "0.6.1",
         note = "Please use the [`SelectStatement::join_as`] instead"
     )]
-    pub fn join_alias<R, A>(
+    pub fn join_alias<R, A, C>(
         &mut self,
         join: JoinType,
         tbl_ref: R,
         alias: A,
-        condition: SimpleExpr,
+        condition: C,
     ) -> &mut Self
     where
         R: IntoTableRef,
         A: IntoIden,
+        C: IntoCondition,
     {
         self.join_as(join, tbl_ref, alias, condition)
     }
@@ -899,28 +1452,83 @@ impl SelectStatement {
     /// );
     /// ```
     ///
-    pub fn join_subquery<T>(
+    pub fn join_subquery<T, C>(
         &mut self,
         join: JoinType,
         query: SelectStatement,
         alias: T,
-        condition: SimpleExpr,
+        condition: C,
     ) -> &mut Self
     where
         T: IntoIden,
+        C: IntoCondition,
     {
         self.join_join(
             join,
             TableRef::SubQuery(query, alias.into_iden()),
-            JoinOn::Condition(Box::new(condition)),
+            JoinOn::Condition(Box::new(condition.into_condition())),
         )
     }
 
+    /// Join with a lateral sub-query, which can reference columns of the preceding tables.
+    /// Not supported by SQLite; building the statement panics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let sub_glyph: DynIden = SeaRc::new(Alias::new("sub_glyph"));
+    /// let query = Query::select()
+    ///     .column(Font::Name)
+    ///     .from(Font::Table)
+    ///     .join_lateral(
+    ///         JoinType::LeftJoin,
+    ///         Query::select()
+    ///             .column(Glyph::Image)
+    ///             .from(Glyph::Table)
+    ///             .and_where(Expr::tbl(Glyph::Table, Glyph::Id).equals(Font::Table, Font::Id))
+    ///             .take(),
+    ///         sub_glyph.clone(),
+    ///         Expr::val(true).into_simple_expr(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `name` FROM `font` LEFT JOIN LATERAL (SELECT `image` FROM `glyph` WHERE `glyph`.`id` = `font`.`id`) AS `sub_glyph` ON TRUE"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "name" FROM "font" LEFT JOIN LATERAL (SELECT "image" FROM "glyph" WHERE "glyph"."id" = "font"."id") AS "sub_glyph" ON TRUE"#
+    /// );
+    /// ```
+    pub fn join_lateral<T, C>(
+        &mut self,
+        join: JoinType,
+        query: SelectStatement,
+        alias: T,
+        condition: C,
+    ) -> &mut Self
+    where
+        T: IntoIden,
+        C: IntoCondition,
+    {
+        self.join.push(JoinExpr {
+            join,
+            table: Box::new(TableRef::SubQuery(query, alias.into_iden())),
+            on: Some(JoinOn::Condition(Box::new(condition.into_condition()))),
+            lateral: true,
+        });
+        self
+    }
+
     fn join_join(&mut self, join: JoinType, table: TableRef, on: JoinOn) -> &mut Self {
         self.join.push(JoinExpr {
             join,
             table: Box::new(table),
             on: Some(on),
+            lateral: false,
         });
         self
     }
@@ -1080,6 +1688,110 @@ impl SelectStatement {
         self
     }
 
+    /// Group by columns with subtotal rows, rolling up from the last column to the grand total.
+    /// Translated to `WITH ROLLUP` in MySQL; not supported by SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::FontId, Char::SizeW])
+    ///     .expr(Func::count(Expr::col(Char::Id)))
+    ///     .from(Char::Table)
+    ///     .group_by_rollup(vec![Char::FontId, Char::SizeW])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `font_id`, `size_w`, COUNT(`id`) FROM `character` GROUP BY `font_id`, `size_w` WITH ROLLUP"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", "size_w", COUNT("id") FROM "character" GROUP BY ROLLUP ("font_id", "size_w")"#
+    /// );
+    /// ```
+    pub fn group_by_rollup<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.grouping = Some(Grouping::Rollup);
+        self.group_by_columns(cols)
+    }
+
+    /// Group by columns with subtotal rows for every combination of the columns.
+    /// Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::FontId, Char::SizeW])
+    ///     .expr(Func::count(Expr::col(Char::Id)))
+    ///     .from(Char::Table)
+    ///     .group_by_cube(vec![Char::FontId, Char::SizeW])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", "size_w", COUNT("id") FROM "character" GROUP BY CUBE ("font_id", "size_w")"#
+    /// );
+    /// ```
+    pub fn group_by_cube<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.grouping = Some(Grouping::Cube);
+        self.group_by_columns(cols)
+    }
+
+    /// Group by each of the sets of columns, an empty set being the grand total.
+    /// Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .columns(vec![Char::FontId, Char::SizeW])
+    ///     .expr(Func::count(Expr::col(Char::Id)))
+    ///     .from(Char::Table)
+    ///     .group_by_grouping_sets(vec![
+    ///         vec![Char::FontId, Char::SizeW],
+    ///         vec![Char::SizeW],
+    ///         vec![],
+    ///     ])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "font_id", "size_w", COUNT("id") FROM "character" GROUP BY GROUPING SETS (("font_id", "size_w"), ("size_w"), ())"#
+    /// );
+    /// ```
+    pub fn group_by_grouping_sets<T, I, S>(&mut self, sets: S) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+        S: IntoIterator<Item = I>,
+    {
+        self.grouping = Some(Grouping::Sets(
+            sets.into_iter()
+                .map(|set| {
+                    set.into_iter()
+                        .map(|col| SimpleExpr::Column(col.into_column_ref()))
+                        .collect()
+                })
+                .collect(),
+        ));
+        self
+    }
+
     /// Having condition, expressed with [`any!`] and [`all!`].
     ///
     /// # Examples
@@ -1268,6 +1980,70 @@ impl SelectStatement {
         self.offset = None;
         self
     }
+
+    /// Keyset pagination, appending the `WHERE` and `ORDER BY` of a page. See [`Keyset`].
+    ///
+    /// Returns [`Error::ColValNumMismatch`] if the cursor and the columns differ in length.
+    pub fn paginate(&mut self, keyset: Keyset) -> Result<&mut Self> {
+        keyset.apply(self)?;
+        Ok(self)
+    }
+
+    /// Combine the result with another select statement. The `ORDER BY`, `LIMIT` and `OFFSET`
+    /// of this statement apply to the combined result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).eq(5))
+    ///     .union(
+    ///         UnionType::All,
+    ///         Query::select()
+    ///             .column(Char::Character)
+    ///             .from(Char::Table)
+    ///             .and_where(Expr::col(Char::FontId).eq(4))
+    ///             .to_owned(),
+    ///     )
+    ///     .order_by(Char::Character, Order::Asc)
+    ///     .limit(10)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = 5 UNION ALL (SELECT `character` FROM `character` WHERE `font_id` = 4) ORDER BY `character` ASC LIMIT 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5 UNION ALL (SELECT "character" FROM "character" WHERE "font_id" = 4) ORDER BY "character" ASC LIMIT 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = 5 UNION ALL SELECT `character` FROM `character` WHERE `font_id` = 4 ORDER BY `character` ASC LIMIT 10"#
+    /// );
+    /// ```
+    pub fn union(&mut self, union_type: UnionType, query: SelectStatement) -> &mut Self {
+        self.unions.push((union_type, query));
+        self
+    }
+
+    /// Combine the result with multiple select statements, see [`SelectStatement::union`].
+    pub fn unions<T>(&mut self, unions: T) -> &mut Self
+    where
+        T: IntoIterator<Item = (UnionType, SelectStatement)>,
+    {
+        self.unions.extend(unions);
+        self
+    }
+
+    /// Prefix this statement with a `WITH` clause, see [`CommonTableExpression`](crate::CommonTableExpression).
+    pub fn with(self, clause: WithClause) -> WithQuery {
+        clause.query(self)
+    }
 }
 
 impl QueryStatementBuilder for SelectStatement {
@@ -1322,6 +2098,12 @@ impl QueryStatementBuilder for SelectStatement {
         query_builder.prepare_select_statement(self, &mut sql, collector);
         sql.result()
     }
+
+    fn check(&self, query_builder: &dyn QueryBuilder) -> Result<()> {
+        let mut check = SupportCheck::new(query_builder);
+        check.visit_select(self);
+        check.finish()
+    }
 }
 
 impl OrderedStatement for SelectStatement {
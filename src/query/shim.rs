@@ -4,6 +4,7 @@ impl_query_statement_builder!(select_statement_builder, SelectStatement);
 impl_query_statement_builder!(insert_statement_builder, InsertStatement);
 impl_query_statement_builder!(update_statement_builder, UpdateStatement);
 impl_query_statement_builder!(delete_statement_builder, DeleteStatement);
+impl_query_statement_builder!(with_query_builder, WithQuery);
 
 impl_ordered_statement!(select_statement_ordered, SelectStatement);
 impl_ordered_statement!(update_statement_ordered, UpdateStatement);
@@ -1,8 +1,10 @@
 use crate::{
     backend::QueryBuilder,
-    prepare::inject_parameters,
+    error::Result,
+    prepare::{inject_parameters, rewrite_parameters, PlaceholderStyle},
     value::{Value, Values},
 };
+use std::collections::BTreeMap;
 
 pub trait QueryStatementBuilder {
     /// Build corresponding SQL statement for certain database backend and return SQL string
@@ -62,6 +64,34 @@ pub trait QueryStatementBuilder {
     }
 
     /// Build corresponding SQL statement for certain database backend and collect query parameters into a vector
+    ///
+    /// The backend is a trait object, so it can be selected at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// fn query_builder(database: &str) -> Box<dyn QueryBuilder> {
+    ///     match database {
+    ///         "mysql" => Box::new(MysqlQueryBuilder),
+    ///         "postgres" => Box::new(PostgresQueryBuilder),
+    ///         _ => Box::new(SqliteQueryBuilder),
+    ///     }
+    /// }
+    ///
+    /// let (query, params) = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Aspect).gt(2))
+    ///     .build_any(query_builder("postgres").as_ref());
+    ///
+    /// assert_eq!(
+    ///     query,
+    ///     r#"SELECT "aspect" FROM "glyph" WHERE "aspect" > $1"#
+    /// );
+    /// assert_eq!(params, Values(vec![2i32.into()]));
+    /// ```
     fn build_any(&self, query_builder: &dyn QueryBuilder) -> (String, Values) {
         let mut values = Vec::new();
         let mut collector = |v| values.push(v);
@@ -69,6 +99,161 @@ pub trait QueryStatementBuilder {
         (sql, Values(values))
     }
 
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query_builders: Vec<Box<dyn QueryBuilder>> = vec![
+    ///     Box::new(MysqlQueryBuilder),
+    ///     Box::new(PostgresQueryBuilder),
+    ///     Box::new(SqliteQueryBuilder),
+    /// ];
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Aspect).gt(2))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query_builders
+    ///         .iter()
+    ///         .map(|query_builder| query.to_string_any(query_builder.as_ref()))
+    ///         .collect::<Vec<_>>(),
+    ///     vec![
+    ///         r#"SELECT `aspect` FROM `glyph` WHERE `aspect` > 2"#,
+    ///         r#"SELECT "aspect" FROM "glyph" WHERE "aspect" > 2"#,
+    ///         r#"SELECT `aspect` FROM `glyph` WHERE `aspect` > 2"#,
+    ///     ]
+    /// );
+    /// ```
+    fn to_string_any(&self, query_builder: &dyn QueryBuilder) -> String {
+        let (sql, values) = self.build_any(query_builder);
+        inject_parameters(&sql, values.0, query_builder)
+    }
+
+    /// Build corresponding SQL statement for certain database backend, writing placeholders in the given
+    /// [`PlaceholderStyle`] instead of the backend's own, and collect query parameters into a vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let (query, params) = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Aspect).gt(2))
+    ///     .and_where(Expr::col(Glyph::Image).like("A%"))
+    ///     .build_with_placeholder(PostgresQueryBuilder, PlaceholderStyle::QuestionMark);
+    ///
+    /// assert_eq!(
+    ///     query,
+    ///     r#"SELECT "aspect" FROM "glyph" WHERE "aspect" > ? AND "image" LIKE ?"#
+    /// );
+    /// assert_eq!(params, Values(vec![2i32.into(), "A%".into()]));
+    /// ```
+    fn build_with_placeholder<T: QueryBuilder>(
+        &self,
+        query_builder: T,
+        style: PlaceholderStyle,
+    ) -> (String, Values) {
+        let (sql, values) = self.build_any(&query_builder);
+        (rewrite_parameters(&sql, &query_builder, style), values)
+    }
+
+    /// Build corresponding SQL statement for certain database backend with named parameters, i.e. the sign
+    /// followed by `p1`, `p2` and so on, and collect query parameters into a map keyed by name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    /// use std::collections::BTreeMap;
+    ///
+    /// let (query, params) = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Aspect).gt(2))
+    ///     .and_where(Expr::col(Glyph::Image).like("A%"))
+    ///     .build_named(MysqlQueryBuilder, ":");
+    ///
+    /// assert_eq!(
+    ///     query,
+    ///     r#"SELECT `aspect` FROM `glyph` WHERE `aspect` > :p1 AND `image` LIKE :p2"#
+    /// );
+    /// assert_eq!(
+    ///     params,
+    ///     BTreeMap::from([
+    ///         ("p1".to_owned(), 2i32.into()),
+    ///         ("p2".to_owned(), "A%".into())
+    ///     ])
+    /// );
+    /// ```
+    fn build_named<T: QueryBuilder>(
+        &self,
+        query_builder: T,
+        sign: &'static str,
+    ) -> (String, BTreeMap<String, Value>) {
+        let (sql, values) =
+            self.build_with_placeholder(query_builder, PlaceholderStyle::Named(sign));
+        let values = values
+            .0
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| (PlaceholderStyle::param_name(index), value))
+            .collect();
+        (sql, values)
+    }
+
+    /// Build corresponding SQL statement for certain database backend and collect query parameters into a vector,
+    /// failing with [`Error::Unsupported`](crate::error::Error::Unsupported) if the statement uses
+    /// a feature the backend does not support
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::Error, tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .full_outer_join(Font::Table, Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.try_build(PostgresQueryBuilder),
+    ///     Ok((
+    ///         r#"SELECT "character" FROM "character" FULL OUTER JOIN "font" ON "character"."font_id" = "font"."id""#
+    ///             .to_owned(),
+    ///         Values(Vec::new())
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     query.try_build(MysqlQueryBuilder),
+    ///     Err(Error::Unsupported {
+    ///         backend: "MySQL",
+    ///         feature: "FULL OUTER JOIN"
+    ///     })
+    /// );
+    /// ```
+    fn try_build<T: QueryBuilder>(&self, query_builder: T) -> Result<(String, Values)> {
+        self.try_build_any(&query_builder)
+    }
+
+    /// Build corresponding SQL statement for certain database backend and collect query parameters into a vector,
+    /// failing if the statement uses a feature the backend does not support
+    fn try_build_any(&self, query_builder: &dyn QueryBuilder) -> Result<(String, Values)> {
+        self.check(query_builder)?;
+        Ok(self.build_any(query_builder))
+    }
+
+    /// Check the statement for features not supported by certain database backend
+    fn check(&self, query_builder: &dyn QueryBuilder) -> Result<()>;
+
     /// Build corresponding SQL statement for certain database backend and collect query parameters
     ///
     /// # Examples
@@ -113,4 +298,94 @@ pub trait QueryStatementBuilder {
         query_builder: &dyn QueryBuilder,
         collector: &mut dyn FnMut(Value),
     ) -> String;
+
+    /// A shorthand to express if ... else ... when constructing the statement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .conditions(
+    ///         true,
+    ///         |x| { x.and_where(Expr::col(Char::FontId).eq(5)); },
+    ///         |x| { x.and_where(Expr::col(Char::FontId).eq(10)); }
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = 5"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "font_id" = 5"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character` FROM `character` WHERE `font_id` = 5"#
+    /// );
+    /// ```
+    fn conditions<T, F>(&mut self, b: bool, if_true: T, if_false: F) -> &mut Self
+    where
+        Self: Sized,
+        T: FnOnce(&mut Self),
+        F: FnOnce(&mut Self),
+    {
+        if b {
+            if_true(self)
+        } else {
+            if_false(self)
+        }
+        self
+    }
+
+    /// Apply a modification to the statement only when the value is present,
+    /// e.g. an optional filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let font_id: Option<i32> = Some(5);
+    /// let variant: Option<&str> = None;
+    ///
+    /// let query = Query::update()
+    ///     .table(Char::Table)
+    ///     .value(Char::Character, "A".into())
+    ///     .apply_if(font_id, |q, v| {
+    ///         q.and_where(Expr::col(Char::FontId).eq(v));
+    ///     })
+    ///     .apply_if(variant, |q, v| {
+    ///         q.and_where(Expr::col(Char::Character).eq(v));
+    ///     })
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"UPDATE `character` SET `character` = 'A' WHERE `font_id` = 5"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "character" SET "character" = 'A' WHERE "font_id" = 5"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE `character` SET `character` = 'A' WHERE `font_id` = 5"#
+    /// );
+    /// ```
+    fn apply_if<T, F>(&mut self, val: Option<T>, if_some: F) -> &mut Self
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self, T),
+    {
+        if let Some(val) = val {
+            if_some(self, val);
+        }
+        self
+    }
 }
@@ -1,11 +1,12 @@
 use crate::{
-    backend::QueryBuilder,
+    backend::{QueryBuilder, SupportCheck},
+    error::*,
     expr::*,
     prepare::*,
-    query::{condition::*, OrderedStatement},
+    query::{condition::*, OrderedStatement, Visitor},
     types::*,
     value::*,
-    Query, QueryStatementBuilder, SelectExpr, SelectStatement,
+    Query, QueryStatementBuilder, ReturningClause, WithClause, WithQuery,
 };
 
 /// Update existing rows in the table
@@ -38,13 +39,15 @@ use crate::{
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateStatement {
     pub(crate) table: Option<Box<TableRef>>,
+    pub(crate) from: Vec<TableRef>,
     pub(crate) values: Vec<(String, Box<SimpleExpr>)>,
     pub(crate) wherei: ConditionHolder,
     pub(crate) orders: Vec<OrderExpr>,
     pub(crate) limit: Option<Value>,
-    pub(crate) returning: Vec<SelectExpr>,
+    pub(crate) returning: Option<ReturningClause>,
 }
 
 impl Default for UpdateStatement {
@@ -58,11 +61,12 @@ impl UpdateStatement {
     pub fn new() -> Self {
         Self {
             table: None,
+            from: Vec::new(),
             values: Vec::new(),
             wherei: ConditionHolder::new(),
             orders: Vec::new(),
             limit: None,
-            returning: Vec::new(),
+            returning: None,
         }
     }
 
@@ -80,6 +84,42 @@ impl UpdateStatement {
         self
     }
 
+    /// Update using rows of other tables, which can be referenced in the values and the
+    /// conditions. Translated to `FROM` in Postgres and SQLite, and to `JOIN` in MySQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::update()
+    ///     .table(Char::Table)
+    ///     .from(Font::Table)
+    ///     .value_expr(Char::Character, Expr::tbl(Font::Table, Font::Name).into_simple_expr())
+    ///     .and_where(Expr::tbl(Font::Table, Font::Id).equals(Char::Table, Char::FontId))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"UPDATE `character` JOIN `font` SET `character` = `font`.`name` WHERE `font`.`id` = `character`.`font_id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"UPDATE "character" SET "character" = "font"."name" FROM "font" WHERE "font"."id" = "character"."font_id""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE `character` SET `character` = `font`.`name` FROM `font` WHERE `font`.`id` = `character`.`font_id`"#
+    /// );
+    /// ```
+    pub fn from<R>(&mut self, tbl_ref: R) -> &mut Self
+    where
+        R: IntoTableRef,
+    {
+        self.from.push(tbl_ref.into_table_ref());
+        self
+    }
+
     #[deprecated(
         since = "0.5.0",
         note = "Please use the UpdateStatement::table function instead"
@@ -217,13 +257,45 @@ impl UpdateStatement {
         self
     }
 
-    /// Limit number of updated rows.
+    /// Limit number of updated rows. Together with `ORDER BY`, supported by MySQL and by
+    /// SQLite built with `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`. For Postgres, `build` panics
+    /// and `try_build` returns [`Error::Unsupported`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::Error, tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(Glyph::Aspect, 0.into())
+    ///     .and_where(Expr::col(Glyph::Image).like("A%"))
+    ///     .order_by(Glyph::Id, Order::Desc)
+    ///     .limit(10)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `aspect` = 0 WHERE `image` LIKE 'A%' ORDER BY `id` DESC LIMIT 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"UPDATE `glyph` SET `aspect` = 0 WHERE `image` LIKE 'A%' ORDER BY `id` DESC LIMIT 10"#
+    /// );
+    /// assert_eq!(
+    ///     query.try_build(PostgresQueryBuilder),
+    ///     Err(Error::Unsupported {
+    ///         backend: "Postgres",
+    ///         feature: "ORDER BY and LIMIT in UPDATE"
+    ///     })
+    /// );
+    /// ```
     pub fn limit(&mut self, limit: u64) -> &mut Self {
         self.limit = Some(Value::BigUnsigned(Some(limit)));
         self
     }
 
-    /// RETURNING expressions. Postgres only.
+    /// RETURNING expressions. Postgres and SQLite only.
     ///
     /// ```
     /// use sea_query::{*, tests_cfg::*};
@@ -246,15 +318,18 @@ impl UpdateStatement {
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE `id` = 1"#
+    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE `id` = 1 RETURNING `id`"#
     /// );
     /// ```
-    pub fn returning(&mut self, select: SelectStatement) -> &mut Self {
-        self.returning = select.selects;
+    pub fn returning<R>(&mut self, returning: R) -> &mut Self
+    where
+        R: Into<ReturningClause>,
+    {
+        self.returning = Some(returning.into());
         self
     }
 
-    /// RETURNING a column after update. Postgres only.
+    /// RETURNING a column after update. Postgres and SQLite only.
     /// Wrapper over [`UpdateStatement::returning()`].
     ///
     /// ```
@@ -279,14 +354,28 @@ impl UpdateStatement {
     /// );
     /// assert_eq!(
     ///     query.to_string(SqliteQueryBuilder),
-    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE `id` = 1"#
+    ///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '235m' WHERE `id` = 1 RETURNING `id`"#
     /// );
     /// ```
     pub fn returning_col<C>(&mut self, col: C) -> &mut Self
     where
         C: IntoIden,
     {
-        self.returning(Query::select().column(col.into_iden()).take())
+        self.returning(Query::returning().column(col.into_iden()))
+    }
+
+    /// RETURNING all columns, i.e. `RETURNING *`. Postgres and SQLite only.
+    ///
+    /// # Examples
+    ///
+    /// See [`UpdateStatement::returning()`].
+    pub fn returning_all(&mut self) -> &mut Self {
+        self.returning(Query::returning().all())
+    }
+
+    /// Prefix this statement with a `WITH` clause, see [`CommonTableExpression`](crate::CommonTableExpression).
+    pub fn with(self, clause: WithClause) -> WithQuery {
+        clause.query(self)
     }
 }
 
@@ -347,6 +436,12 @@ impl QueryStatementBuilder for UpdateStatement {
         query_builder.prepare_update_statement(self, &mut sql, collector);
         sql.result()
     }
+
+    fn check(&self, query_builder: &dyn QueryBuilder) -> Result<()> {
+        let mut check = SupportCheck::new(query_builder);
+        check.visit_update(self);
+        check.finish()
+    }
 }
 
 impl OrderedStatement for UpdateStatement {
@@ -0,0 +1,472 @@
+use crate::{expr::*, func::*, query::*, types::*, value::*};
+
+macro_rules! impl_visitor {
+    ( $(#[$attr: meta])* $trait_name: ident, $($mutability: tt)? ) => {
+        $(#[$attr])*
+        ///
+        /// Each `visit_*` method is called on the corresponding node, and by default walks into
+        /// its children with the `walk_*` method of the same name. Override a `visit_*` method
+        /// to inspect the node, and call the `walk_*` method to keep going deeper.
+        pub trait $trait_name {
+            /// Visit a [`QueryStatement`]
+            fn visit_query(&mut self, query: &$($mutability)? QueryStatement) {
+                self.walk_query(query);
+            }
+
+            /// Visit a [`SelectStatement`], including sub-queries
+            fn visit_select(&mut self, select: &$($mutability)? SelectStatement) {
+                self.walk_select(select);
+            }
+
+            /// Visit an [`InsertStatement`]
+            fn visit_insert(&mut self, insert: &$($mutability)? InsertStatement) {
+                self.walk_insert(insert);
+            }
+
+            /// Visit an [`UpdateStatement`]
+            fn visit_update(&mut self, update: &$($mutability)? UpdateStatement) {
+                self.walk_update(update);
+            }
+
+            /// Visit a [`DeleteStatement`]
+            fn visit_delete(&mut self, delete: &$($mutability)? DeleteStatement) {
+                self.walk_delete(delete);
+            }
+
+            /// Visit a [`WithQuery`]
+            fn visit_with_query(&mut self, query: &$($mutability)? WithQuery) {
+                self.walk_with_query(query);
+            }
+
+            /// Visit a [`TableRef`]
+            fn visit_table_ref(&mut self, table: &$($mutability)? TableRef) {
+                self.walk_table_ref(table);
+            }
+
+            /// Visit a [`ColumnRef`]
+            fn visit_column_ref(&mut self, _column: &$($mutability)? ColumnRef) {}
+
+            /// Visit a [`SimpleExpr`]
+            fn visit_expr(&mut self, expr: &$($mutability)? SimpleExpr) {
+                self.walk_expr(expr);
+            }
+
+            /// Visit a [`Value`]
+            fn visit_value(&mut self, _value: &$($mutability)? Value) {}
+
+            /// Walk into the statement of a [`QueryStatement`]
+            fn walk_query(&mut self, query: &$($mutability)? QueryStatement) {
+                match query {
+                    QueryStatement::Select(select) => self.visit_select(select),
+                    QueryStatement::Insert(insert) => self.visit_insert(insert),
+                    QueryStatement::Update(update) => self.visit_update(update),
+                    QueryStatement::Delete(delete) => self.visit_delete(delete),
+                }
+            }
+
+            /// Walk into the children of a [`SelectStatement`]
+            fn walk_select(&mut self, select: &$($mutability)? SelectStatement) {
+                self.walk_exprs(&$($mutability)? select.distinct_on);
+                for select_expr in &$($mutability)? select.selects {
+                    self.walk_select_expr(select_expr);
+                }
+                if let Some(table) = &$($mutability)? select.from {
+                    self.visit_table_ref(table);
+                }
+                if let Some(sample) = &$($mutability)? select.sample {
+                    self.visit_value(sample);
+                }
+                for join in &$($mutability)? select.join {
+                    self.visit_table_ref(&$($mutability)? join.table);
+                    match &$($mutability)? join.on {
+                        Some(JoinOn::Condition(condition)) => self.walk_condition(condition),
+                        Some(JoinOn::Columns(exprs)) => self.walk_exprs(exprs),
+                        None => {}
+                    }
+                }
+                self.walk_condition_holder(&$($mutability)? select.wherei);
+                self.walk_exprs(&$($mutability)? select.groups);
+                if let Some(Grouping::Sets(sets)) = &$($mutability)? select.grouping {
+                    for exprs in sets {
+                        self.walk_exprs(exprs);
+                    }
+                }
+                self.walk_condition_holder(&$($mutability)? select.having);
+                self.walk_order_exprs(&$($mutability)? select.orders);
+                if let Some(limit) = &$($mutability)? select.limit {
+                    self.visit_value(limit);
+                }
+                if let Some(offset) = &$($mutability)? select.offset {
+                    self.visit_value(offset);
+                }
+                for (_, window) in &$($mutability)? select.windows {
+                    self.walk_window(window);
+                }
+                for (_, union) in &$($mutability)? select.unions {
+                    self.visit_select(union);
+                }
+            }
+
+            /// Walk into the children of an [`InsertStatement`]
+            fn walk_insert(&mut self, insert: &$($mutability)? InsertStatement) {
+                if let Some(table) = &$($mutability)? insert.table {
+                    self.visit_table_ref(table);
+                }
+                match &$($mutability)? insert.source {
+                    Some(InsertValueSource::Values(rows)) => {
+                        for row in rows {
+                            self.walk_exprs(row);
+                        }
+                    }
+                    Some(InsertValueSource::Select(select)) => self.visit_select(select),
+                    None => {}
+                }
+                if let Some(on_conflict) = &$($mutability)? insert.on_conflict {
+                    self.walk_condition_holder(&$($mutability)? on_conflict.target_where);
+                    if let Some(OnConflictAction::Update(updates)) = &$($mutability)? on_conflict.action {
+                        for update in updates {
+                            if let OnConflictUpdate::Value(_, expr) = update {
+                                self.visit_expr(expr);
+                            }
+                        }
+                    }
+                    self.walk_condition_holder(&$($mutability)? on_conflict.action_where);
+                }
+                self.walk_returning(&$($mutability)? insert.returning);
+            }
+
+            /// Walk into the children of an [`UpdateStatement`]
+            fn walk_update(&mut self, update: &$($mutability)? UpdateStatement) {
+                if let Some(table) = &$($mutability)? update.table {
+                    self.visit_table_ref(table);
+                }
+                for (_, expr) in &$($mutability)? update.values {
+                    self.visit_expr(expr);
+                }
+                for table in &$($mutability)? update.from {
+                    self.visit_table_ref(table);
+                }
+                self.walk_condition_holder(&$($mutability)? update.wherei);
+                self.walk_order_exprs(&$($mutability)? update.orders);
+                if let Some(limit) = &$($mutability)? update.limit {
+                    self.visit_value(limit);
+                }
+                self.walk_returning(&$($mutability)? update.returning);
+            }
+
+            /// Walk into the children of a [`DeleteStatement`]
+            fn walk_delete(&mut self, delete: &$($mutability)? DeleteStatement) {
+                if let Some(table) = &$($mutability)? delete.table {
+                    self.visit_table_ref(table);
+                }
+                for table in &$($mutability)? delete.using {
+                    self.visit_table_ref(table);
+                }
+                self.walk_condition_holder(&$($mutability)? delete.wherei);
+                self.walk_order_exprs(&$($mutability)? delete.orders);
+                if let Some(limit) = &$($mutability)? delete.limit {
+                    self.visit_value(limit);
+                }
+                self.walk_returning(&$($mutability)? delete.returning);
+            }
+
+            /// Walk into the common table expressions and the statement of a [`WithQuery`]
+            fn walk_with_query(&mut self, query: &$($mutability)? WithQuery) {
+                for cte in &$($mutability)? query.with_clause.cte_expressions {
+                    if let Some(query) = &$($mutability)? cte.query {
+                        self.visit_query(query);
+                    }
+                    if let Some((_, select)) = &$($mutability)? cte.recursive_query {
+                        self.visit_select(select);
+                    }
+                }
+                self.visit_query(&$($mutability)? query.query);
+            }
+
+            /// Walk into the sub-query or values of a [`TableRef`]
+            fn walk_table_ref(&mut self, table: &$($mutability)? TableRef) {
+                match table {
+                    TableRef::SubQuery(select, _) => self.visit_select(select),
+                    TableRef::ValuesList(rows, _, _) => {
+                        for row in rows {
+                            for value in &$($mutability)? row.0 {
+                                self.visit_value(value);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            /// Walk into the children of a [`SimpleExpr`]
+            fn walk_expr(&mut self, expr: &$($mutability)? SimpleExpr) {
+                match expr {
+                    SimpleExpr::Column(column) => self.visit_column_ref(column),
+                    SimpleExpr::Unary(_, expr) => self.visit_expr(expr),
+                    SimpleExpr::FunctionCall(func, args) => {
+                        if let Function::StringAgg(_, orders) = func {
+                            self.walk_order_exprs(orders);
+                        }
+                        self.walk_exprs(args);
+                    }
+                    SimpleExpr::Binary(left, _, right) => {
+                        self.visit_expr(left);
+                        self.visit_expr(right);
+                    }
+                    SimpleExpr::SubQuery(select) => self.visit_select(select),
+                    SimpleExpr::Value(value) => self.visit_value(value),
+                    SimpleExpr::Values(values) | SimpleExpr::CustomWithValues(_, values) => {
+                        for value in values {
+                            self.visit_value(value);
+                        }
+                    }
+                    SimpleExpr::Tuple(exprs) => self.walk_exprs(exprs),
+                    SimpleExpr::Cast(expr, _) => self.visit_expr(expr),
+                    #[cfg(feature = "backend-postgres")]
+                    SimpleExpr::Subscript(expr, index) => {
+                        self.visit_expr(expr);
+                        self.visit_expr(index);
+                    }
+                    SimpleExpr::FullTextMatch(exprs, query, _) => {
+                        self.walk_exprs(exprs);
+                        self.visit_expr(query);
+                    }
+                    SimpleExpr::Excluded(_) | SimpleExpr::Custom(_) | SimpleExpr::Keyword(_) => {}
+                }
+            }
+
+            #[doc(hidden)]
+            fn walk_exprs(&mut self, exprs: &$($mutability)? [SimpleExpr]) {
+                for expr in exprs {
+                    self.visit_expr(expr);
+                }
+            }
+
+            #[doc(hidden)]
+            fn walk_select_expr(&mut self, select_expr: &$($mutability)? SelectExpr) {
+                self.visit_expr(&$($mutability)? select_expr.expr);
+                if let Some(WindowSelectType::Query(window)) = &$($mutability)? select_expr.window {
+                    self.walk_window(window);
+                }
+            }
+
+            #[doc(hidden)]
+            fn walk_window(&mut self, window: &$($mutability)? WindowStatement) {
+                self.walk_exprs(&$($mutability)? window.partition_by);
+                self.walk_order_exprs(&$($mutability)? window.order_by);
+            }
+
+            #[doc(hidden)]
+            fn walk_order_exprs(&mut self, orders: &$($mutability)? [OrderExpr]) {
+                for order in orders {
+                    self.visit_expr(&$($mutability)? order.expr);
+                    if let Some(values) = &$($mutability)? order.field {
+                        for value in &$($mutability)? values.0 {
+                            self.visit_value(value);
+                        }
+                    }
+                }
+            }
+
+            #[doc(hidden)]
+            fn walk_condition_holder(&mut self, holder: &$($mutability)? ConditionHolder) {
+                match &$($mutability)? holder.contents {
+                    ConditionHolderContents::Empty => {}
+                    ConditionHolderContents::Chain(chain) => {
+                        for oper in chain {
+                            match oper {
+                                LogicalChainOper::And(expr) | LogicalChainOper::Or(expr) => {
+                                    self.visit_expr(expr)
+                                }
+                            }
+                        }
+                    }
+                    ConditionHolderContents::Condition(condition) => self.walk_condition(condition),
+                }
+            }
+
+            #[doc(hidden)]
+            fn walk_condition(&mut self, condition: &$($mutability)? Condition) {
+                for expr in &$($mutability)? condition.conditions {
+                    match expr {
+                        ConditionExpression::Condition(condition) => self.walk_condition(condition),
+                        ConditionExpression::SimpleExpr(expr) => self.visit_expr(expr),
+                    }
+                }
+            }
+
+            #[doc(hidden)]
+            fn walk_returning(&mut self, returning: &$($mutability)? Option<ReturningClause>) {
+                if let Some(ReturningClause::Exprs(select_exprs)) = returning {
+                    for select_expr in select_exprs {
+                        self.walk_select_expr(select_expr);
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_visitor!(
+    /// Walk the expressions, table references and values of a query statement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// #[derive(Default)]
+    /// struct Columns(Vec<String>);
+    ///
+    /// impl Visitor for Columns {
+    ///     fn visit_column_ref(&mut self, column: &ColumnRef) {
+    ///         if let ColumnRef::Column(name) | ColumnRef::TableColumn(_, name) = column {
+    ///             self.0.push(name.to_string());
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).in_subquery(
+    ///         Query::select()
+    ///             .column(Font::Id)
+    ///             .from(Font::Table)
+    ///             .and_where(Expr::col(Font::Name).eq("Arial"))
+    ///             .take(),
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// let mut columns = Columns::default();
+    /// columns.visit_select(&query);
+    ///
+    /// assert_eq!(columns.0, vec!["character", "font_id", "id", "name"]);
+    /// ```
+    Visitor,
+);
+
+impl_visitor!(
+    /// Walk the expressions, table references and values of a query statement, with mutable
+    /// access to rewrite them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// struct TenantFilter(i32);
+    ///
+    /// impl VisitorMut for TenantFilter {
+    ///     fn visit_select(&mut self, select: &mut SelectStatement) {
+    ///         select.and_where(Expr::col(Alias::new("tenant_id")).eq(self.0));
+    ///         self.walk_select(select);
+    ///     }
+    /// }
+    ///
+    /// let mut query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::FontId).in_subquery(
+    ///         Query::select().column(Font::Id).from(Font::Table).take(),
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// TenantFilter(7).visit_select(&mut query);
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     [
+    ///         r#"SELECT "character" FROM "character""#,
+    ///         r#"WHERE "font_id" IN (SELECT "id" FROM "font" WHERE "tenant_id" = 7)"#,
+    ///         r#"AND "tenant_id" = 7"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    VisitorMut,
+    mut
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests_cfg::*, *};
+
+    #[derive(Default)]
+    struct Collector {
+        tables: Vec<String>,
+        values: Vec<Value>,
+    }
+
+    impl Visitor for Collector {
+        fn visit_table_ref(&mut self, table: &TableRef) {
+            if let TableRef::Table(name) = table {
+                self.tables.push(name.to_string());
+            }
+            self.walk_table_ref(table);
+        }
+
+        fn visit_value(&mut self, value: &Value) {
+            self.values.push(value.clone());
+        }
+    }
+
+    #[test]
+    fn test_visit_insert() {
+        let query = Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Aspect, Glyph::Image])
+            .values_panic(vec![
+                2.into(),
+                "24B0E11951B03B07F8300FD003983F03F0780060".into(),
+            ])
+            .on_conflict(
+                OnConflict::column(Glyph::Id)
+                    .update_value(Glyph::Aspect, Expr::value(3))
+                    .to_owned(),
+            )
+            .to_owned();
+
+        let mut collector = Collector::default();
+        collector.visit_insert(&query);
+
+        assert_eq!(collector.tables, vec!["glyph"]);
+        assert_eq!(
+            collector.values,
+            vec![
+                2.into(),
+                "24B0E11951B03B07F8300FD003983F03F0780060".into(),
+                3.into()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_visit_with_query() {
+        let cte = CommonTableExpression::new()
+            .query(
+                Query::update()
+                    .table(Glyph::Table)
+                    .value(Glyph::Aspect, 1.into())
+                    .and_where(Expr::col(Glyph::Id).eq(2))
+                    .to_owned(),
+            )
+            .table_name(Alias::new("cte"))
+            .to_owned();
+        let query = Query::delete()
+            .from_table(Font::Table)
+            .and_where(Expr::col(Font::Id).gt(3))
+            .limit(4)
+            .to_owned()
+            .with(WithClause::new().cte(cte).to_owned());
+
+        let mut collector = Collector::default();
+        collector.visit_with_query(&query);
+
+        assert_eq!(collector.tables, vec!["glyph", "font"]);
+        assert_eq!(
+            collector.values,
+            vec![1.into(), 2.into(), 3.into(), 4u64.into()]
+        );
+    }
+}
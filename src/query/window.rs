@@ -0,0 +1,138 @@
+use crate::{expr::*, query::OrderedStatement, types::*};
+
+/// Window expression
+///
+/// # References:
+///
+/// 1. <https://dev.mysql.com/doc/refman/8.0/en/window-function-descriptions.html>
+/// 2. <https://www.sqlite.org/windowfunctions.html>
+/// 3. <https://www.postgresql.org/docs/current/tutorial-window.html>
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowStatement {
+    pub(crate) partition_by: Vec<SimpleExpr>,
+    pub(crate) order_by: Vec<OrderExpr>,
+    pub(crate) frame: Option<FrameClause>,
+}
+
+/// Window select type: either referencing a named window or an inline window specification
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowSelectType {
+    /// Name in [`SelectStatement`](crate::SelectStatement) `WINDOW` clause
+    Name(#[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden),
+    /// Inline window specification
+    Query(WindowStatement),
+}
+
+/// Frame type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrameType {
+    Range,
+    Rows,
+}
+
+/// Frame bound
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Frame {
+    UnboundedPreceding,
+    Preceding(u32),
+    CurrentRow,
+    Following(u32),
+    UnboundedFollowing,
+}
+
+/// Frame clause
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameClause {
+    pub(crate) r#type: FrameType,
+    pub(crate) start: Frame,
+    pub(crate) end: Option<Frame>,
+}
+
+impl Default for WindowStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindowStatement {
+    /// Construct a new [`WindowStatement`]
+    pub fn new() -> Self {
+        Self {
+            partition_by: Vec::new(),
+            order_by: Vec::new(),
+            frame: None,
+        }
+    }
+
+    /// Take the ownership of data in the current [`WindowStatement`]
+    pub fn take(&mut self) -> Self {
+        Self {
+            partition_by: std::mem::take(&mut self.partition_by),
+            order_by: std::mem::take(&mut self.order_by),
+            frame: self.frame.take(),
+        }
+    }
+
+    /// Construct a new [`WindowStatement`] with `PARTITION BY` column
+    pub fn partition_by<T>(col: T) -> Self
+    where
+        T: IntoColumnRef,
+    {
+        let mut window = Self::new();
+        window.add_partition_by(SimpleExpr::Column(col.into_column_ref()));
+        window
+    }
+
+    /// Construct a new [`WindowStatement`] with `PARTITION BY` custom string
+    pub fn partition_by_custom<T>(col: T) -> Self
+    where
+        T: ToString,
+    {
+        let mut window = Self::new();
+        window.add_partition_by(SimpleExpr::Custom(col.to_string()));
+        window
+    }
+
+    /// Add `PARTITION BY` [`SimpleExpr`]
+    pub fn add_partition_by<T>(&mut self, expr: T) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.partition_by.push(expr.into());
+        self
+    }
+
+    /// Frame clause with only a start bound, e.g. `ROWS UNBOUNDED PRECEDING`
+    pub fn frame_start(&mut self, r#type: FrameType, start: Frame) -> &mut Self {
+        self.frame(r#type, start, None)
+    }
+
+    /// Frame clause with both bounds, e.g. `ROWS BETWEEN 1 PRECEDING AND CURRENT ROW`
+    pub fn frame_between(&mut self, r#type: FrameType, start: Frame, end: Frame) -> &mut Self {
+        self.frame(r#type, start, Some(end))
+    }
+
+    /// Frame clause
+    pub fn frame(&mut self, r#type: FrameType, start: Frame, end: Option<Frame>) -> &mut Self {
+        self.frame = Some(FrameClause { r#type, start, end });
+        self
+    }
+}
+
+impl OrderedStatement for WindowStatement {
+    fn add_order_by(&mut self, order: OrderExpr) -> &mut Self {
+        self.order_by.push(order);
+        self
+    }
+}
+
+impl From<WindowStatement> for WindowSelectType {
+    fn from(window: WindowStatement) -> Self {
+        WindowSelectType::Query(window)
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{
+    backend::{QueryBuilder, SupportCheck},
+    error::*,
+    prepare::*,
+    types::*,
+    value::*,
+    DeleteStatement, InsertStatement, QueryStatement, QueryStatementBuilder, SelectStatement,
+    UnionType, UpdateStatement, Visitor,
+};
+
+/// A table definition inside a `WITH` clause ([`WithClause`]).
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let cte = CommonTableExpression::new()
+///     .table_name(Alias::new("cte"))
+///     .columns(vec![Alias::new("id"), Alias::new("name")])
+///     .query(
+///         Query::select()
+///             .columns(vec![Font::Id, Font::Name])
+///             .from(Font::Table)
+///             .to_owned(),
+///     )
+///     .to_owned();
+///
+/// let query = Query::select()
+///     .column(Alias::new("name"))
+///     .from(Alias::new("cte"))
+///     .to_owned()
+///     .with(WithClause::new().cte(cte).to_owned());
+///
+/// assert_eq!(
+///     query.to_string(MysqlQueryBuilder),
+///     r#"WITH `cte` (`id`, `name`) AS (SELECT `id`, `name` FROM `font`) SELECT `name` FROM `cte`"#
+/// );
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"WITH "cte" ("id", "name") AS (SELECT "id", "name" FROM "font") SELECT "name" FROM "cte""#
+/// );
+/// assert_eq!(
+///     query.to_string(SqliteQueryBuilder),
+///     r#"WITH `cte` (`id`, `name`) AS (SELECT `id`, `name` FROM `font`) SELECT `name` FROM `cte`"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommonTableExpression {
+    #[cfg_attr(
+        feature = "with-serde",
+        serde(with = "crate::types::iden_serde::option")
+    )]
+    pub(crate) table_name: Option<DynIden>,
+    #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde::vec"))]
+    pub(crate) cols: Vec<DynIden>,
+    pub(crate) query: Option<Box<QueryStatement>>,
+    pub(crate) recursive_query: Option<(UnionType, Box<SelectStatement>)>,
+}
+
+impl CommonTableExpression {
+    /// Construct a new [`CommonTableExpression`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the name of the table to be referenced by the main query.
+    pub fn table_name<T>(&mut self, table_name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.table_name = Some(table_name.into_iden());
+        self
+    }
+
+    /// Add a column alias.
+    pub fn column<C>(&mut self, col: C) -> &mut Self
+    where
+        C: IntoIden,
+    {
+        self.cols.push(col.into_iden());
+        self
+    }
+
+    /// Add column aliases.
+    pub fn columns<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.cols
+            .extend(cols.into_iter().map(|col| col.into_iden()));
+        self
+    }
+
+    /// Set the query generating the rows of this table.
+    pub fn query<Q>(&mut self, query: Q) -> &mut Self
+    where
+        Q: Into<QueryStatement>,
+    {
+        self.query = Some(Box::new(query.into()));
+        self
+    }
+
+    /// Set a base query and a recursive query joined by `UNION` or `UNION ALL`.
+    /// The recursive query can reference this table by its name. Use it together
+    /// with [`WithClause::recursive`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Alias::new("n"))
+    ///     .from(Alias::new("cnt"))
+    ///     .to_owned()
+    ///     .with(
+    ///         Query::with()
+    ///             .recursive(true)
+    ///             .cte(
+    ///                 CommonTableExpression::new()
+    ///                     .table_name(Alias::new("cnt"))
+    ///                     .column(Alias::new("n"))
+    ///                     .recursive_query(
+    ///                         Query::select().expr(Expr::val(1)).to_owned(),
+    ///                         UnionType::All,
+    ///                         Query::select()
+    ///                             .expr(Expr::col(Alias::new("n")).add(1))
+    ///                             .from(Alias::new("cnt"))
+    ///                             .and_where(Expr::col(Alias::new("n")).lt(10))
+    ///                             .to_owned(),
+    ///                     )
+    ///                     .to_owned(),
+    ///             )
+    ///             .to_owned(),
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"WITH RECURSIVE `cnt` (`n`) AS (SELECT 1 UNION ALL SELECT `n` + 1 FROM `cnt` WHERE `n` < 10) SELECT `n` FROM `cnt`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"WITH RECURSIVE "cnt" ("n") AS (SELECT 1 UNION ALL SELECT "n" + 1 FROM "cnt" WHERE "n" < 10) SELECT "n" FROM "cnt""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"WITH RECURSIVE `cnt` (`n`) AS (SELECT 1 UNION ALL SELECT `n` + 1 FROM `cnt` WHERE `n` < 10) SELECT `n` FROM `cnt`"#
+    /// );
+    /// ```
+    pub fn recursive_query(
+        &mut self,
+        base: SelectStatement,
+        union_type: UnionType,
+        recursive: SelectStatement,
+    ) -> &mut Self {
+        self.query = Some(Box::new(base.into()));
+        self.recursive_query = Some((union_type, Box::new(recursive)));
+        self
+    }
+}
+
+/// A `WITH` clause, attached to a statement with `with` (e.g. [`SelectStatement::with`]) or
+/// [`WithClause::query`] to form a [`WithQuery`].
+///
+/// # Examples
+///
+/// See [`CommonTableExpression`]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithClause {
+    pub(crate) recursive: bool,
+    pub(crate) cte_expressions: Vec<CommonTableExpression>,
+}
+
+impl WithClause {
+    /// Construct a new [`WithClause`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark this clause as `WITH RECURSIVE`, required by MySQL and Postgres
+    /// when any of its tables references itself.
+    pub fn recursive(&mut self, recursive: bool) -> &mut Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Add a [`CommonTableExpression`] to this `WITH` clause.
+    pub fn cte(&mut self, cte: CommonTableExpression) -> &mut Self {
+        self.cte_expressions.push(cte);
+        self
+    }
+
+    /// Attach the main query, which can reference the tables defined in this clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = WithClause::new()
+    ///     .cte(
+    ///         CommonTableExpression::new()
+    ///             .table_name(Alias::new("bold_font"))
+    ///             .query(
+    ///                 Query::select()
+    ///                     .column(Font::Id)
+    ///                     .from(Font::Table)
+    ///                     .and_where(Expr::col(Font::Variant).eq("Bold"))
+    ///                     .to_owned(),
+    ///             )
+    ///             .to_owned(),
+    ///     )
+    ///     .query(
+    ///         Query::delete()
+    ///             .from_table(Char::Table)
+    ///             .and_where(Expr::col(Char::FontId).in_subquery(
+    ///                 Query::select()
+    ///                     .column(Alias::new("id"))
+    ///                     .from(Alias::new("bold_font"))
+    ///                     .to_owned(),
+    ///             ))
+    ///             .to_owned(),
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"WITH `bold_font` AS (SELECT `id` FROM `font` WHERE `variant` = 'Bold') DELETE FROM `character` WHERE `font_id` IN (SELECT `id` FROM `bold_font`)"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"WITH "bold_font" AS (SELECT "id" FROM "font" WHERE "variant" = 'Bold') DELETE FROM "character" WHERE "font_id" IN (SELECT "id" FROM "bold_font")"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"WITH `bold_font` AS (SELECT `id` FROM `font` WHERE `variant` = 'Bold') DELETE FROM `character` WHERE `font_id` IN (SELECT `id` FROM `bold_font`)"#
+    /// );
+    /// ```
+    pub fn query<Q>(&self, query: Q) -> WithQuery
+    where
+        Q: Into<QueryStatement>,
+    {
+        WithQuery {
+            with_clause: self.clone(),
+            query: Box::new(query.into()),
+        }
+    }
+}
+
+/// A statement prefixed by a [`WithClause`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WithQuery {
+    pub(crate) with_clause: WithClause,
+    pub(crate) query: Box<QueryStatement>,
+}
+
+impl QueryStatementBuilder for WithQuery {
+    fn build_collect<T: QueryBuilder>(
+        &self,
+        query_builder: T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        query_builder.prepare_with_query(self, &mut sql, collector);
+        sql.result()
+    }
+
+    fn build_collect_any(
+        &self,
+        query_builder: &dyn QueryBuilder,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        query_builder.prepare_with_query(self, &mut sql, collector);
+        sql.result()
+    }
+
+    fn check(&self, query_builder: &dyn QueryBuilder) -> Result<()> {
+        let mut check = SupportCheck::new(query_builder);
+        check.visit_with_query(self);
+        check.finish()
+    }
+}
+
+impl From<SelectStatement> for QueryStatement {
+    fn from(statement: SelectStatement) -> Self {
+        QueryStatement::Select(statement)
+    }
+}
+
+impl From<InsertStatement> for QueryStatement {
+    fn from(statement: InsertStatement) -> Self {
+        QueryStatement::Insert(statement)
+    }
+}
+
+impl From<UpdateStatement> for QueryStatement {
+    fn from(statement: UpdateStatement) -> Self {
+        QueryStatement::Update(statement)
+    }
+}
+
+impl From<DeleteStatement> for QueryStatement {
+    fn from(statement: DeleteStatement) -> Self {
+        QueryStatement::Delete(statement)
+    }
+}
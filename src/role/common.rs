@@ -0,0 +1,20 @@
+/// Option of a role
+#[derive(Debug, Clone)]
+pub enum RoleOption {
+    /// Whether the role may log in. Postgres only.
+    Login(bool),
+    /// Postgres only
+    Superuser(bool),
+    /// Postgres only
+    CreateDb(bool),
+    /// Postgres only
+    CreateRole(bool),
+    /// Whether the role inherits the privileges of its groups. Postgres only.
+    Inherit(bool),
+    /// Maximum number of concurrent connections
+    ConnectionLimit(i32),
+    /// Plain text password, i.e. `IDENTIFIED BY` in MySQL
+    Password(String),
+    /// Timestamp after which the password expires. Postgres only.
+    ValidUntil(String),
+}
@@ -0,0 +1,120 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, RoleOption, SchemaStatementBuilder};
+
+/// Create a role or a user. Not supported by Sqlite.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let role = Role::create()
+///     .name(Alias::new("app"))
+///     .login()
+///     .password("s3cret")
+///     .to_owned();
+///
+/// assert_eq!(
+///     role.to_string(PostgresQueryBuilder),
+///     r#"CREATE ROLE "app" LOGIN PASSWORD 's3cret'"#
+/// );
+///
+/// let user = User::create()
+///     .if_not_exists()
+///     .name(Alias::new("app"))
+///     .host("localhost")
+///     .password("s3cret")
+///     .to_owned();
+///
+/// assert_eq!(
+///     user.to_string(MysqlQueryBuilder),
+///     r#"CREATE USER IF NOT EXISTS `app`@'localhost' IDENTIFIED BY 's3cret'"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RoleCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) host: Option<String>,
+    pub(crate) user: bool,
+    pub(crate) if_not_exists: bool,
+    pub(crate) options: Vec<RoleOption>,
+}
+
+impl Default for RoleCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoleCreateStatement {
+    /// Construct a new [`RoleCreateStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            host: None,
+            user: false,
+            if_not_exists: false,
+            options: Vec::new(),
+        }
+    }
+
+    /// Set role name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the host the account connects from. MySQL only.
+    pub fn host(&mut self, host: &str) -> &mut Self {
+        self.host = Some(host.to_owned());
+        self
+    }
+
+    /// Create role if it does not exist. Not supported by Postgres.
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Allow the role to log in. Postgres only.
+    pub fn login(&mut self) -> &mut Self {
+        self.option(RoleOption::Login(true))
+    }
+
+    /// Set the password of the role
+    pub fn password(&mut self, password: &str) -> &mut Self {
+        self.option(RoleOption::Password(password.to_owned()))
+    }
+
+    /// Add a role option
+    pub fn option(&mut self, option: RoleOption) -> &mut Self {
+        self.options.push(option);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            host: self.host.take(),
+            user: self.user,
+            if_not_exists: self.if_not_exists,
+            options: std::mem::take(&mut self.options),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for RoleCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_role_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_role_create_statement(self, &mut sql);
+        sql.result()
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Drop a role or a user. Not supported by Sqlite.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let role = Role::drop()
+///     .if_exists()
+///     .name(Alias::new("app"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     role.to_string(PostgresQueryBuilder),
+///     r#"DROP ROLE IF EXISTS "app""#
+/// );
+///
+/// let user = User::drop()
+///     .name(Alias::new("app"))
+///     .host("localhost")
+///     .to_owned();
+///
+/// assert_eq!(
+///     user.to_string(MysqlQueryBuilder),
+///     r#"DROP USER `app`@'localhost'"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RoleDropStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) host: Option<String>,
+    pub(crate) user: bool,
+    pub(crate) if_exists: bool,
+}
+
+impl Default for RoleDropStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoleDropStatement {
+    /// Construct a new [`RoleDropStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            host: None,
+            user: false,
+            if_exists: false,
+        }
+    }
+
+    /// Set role name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the host the account connects from. MySQL only.
+    pub fn host(&mut self, host: &str) -> &mut Self {
+        self.host = Some(host.to_owned());
+        self
+    }
+
+    /// Drop role only if it exists
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+impl SchemaStatementBuilder for RoleDropStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_role_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_role_drop_statement(self, &mut sql);
+        sql.result()
+    }
+}
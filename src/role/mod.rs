@@ -0,0 +1,58 @@
+//! Role and user management statements.
+//!
+//! # Usage
+//!
+//! - Role Create, see [`RoleCreateStatement`]
+//! - Role Drop, see [`RoleDropStatement`]
+
+mod common;
+mod create;
+mod drop;
+mod shim;
+
+pub use common::*;
+pub use create::*;
+pub use drop::*;
+
+/// Shorthand for constructing any role statement
+#[derive(Debug, Clone)]
+pub struct Role;
+
+/// Shorthand for constructing any user statement, i.e. a role allowed to log in
+#[derive(Debug, Clone)]
+pub struct User;
+
+/// All available types of role statement
+#[derive(Debug, Clone)]
+pub enum RoleStatement {
+    Create(RoleCreateStatement),
+    Drop(RoleDropStatement),
+}
+
+impl Role {
+    /// Construct role [`RoleCreateStatement`]
+    pub fn create() -> RoleCreateStatement {
+        RoleCreateStatement::new()
+    }
+
+    /// Construct role [`RoleDropStatement`]
+    pub fn drop() -> RoleDropStatement {
+        RoleDropStatement::new()
+    }
+}
+
+impl User {
+    /// Construct user [`RoleCreateStatement`]
+    pub fn create() -> RoleCreateStatement {
+        let mut create = RoleCreateStatement::new();
+        create.user = true;
+        create
+    }
+
+    /// Construct user [`RoleDropStatement`]
+    pub fn drop() -> RoleDropStatement {
+        let mut drop = RoleDropStatement::new();
+        drop.user = true;
+        drop
+    }
+}
@@ -0,0 +1,4 @@
+use crate::impl_schema_statement_builder;
+
+impl_schema_statement_builder!(role_create_statement_builder, RoleCreateStatement);
+impl_schema_statement_builder!(role_drop_statement_builder, RoleDropStatement);
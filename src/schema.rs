@@ -1,12 +1,20 @@
 //! Schema definition & alternations statements
 
-use crate::{backend::SchemaBuilder, ForeignKeyStatement, IndexStatement, TableStatement};
+use crate::{
+    backend::SchemaBuilder, error::Result, DatabaseStatement, ForeignKeyStatement, IndexStatement,
+    RoleStatement, SequenceStatement, TableStatement, TriggerStatement, ViewStatement,
+};
 
 #[derive(Debug, Clone)]
 pub enum SchemaStatement {
     TableStatement(TableStatement),
     IndexStatement(IndexStatement),
     ForeignKeyStatement(ForeignKeyStatement),
+    ViewStatement(ViewStatement),
+    SequenceStatement(SequenceStatement),
+    TriggerStatement(TriggerStatement),
+    DatabaseStatement(DatabaseStatement),
+    RoleStatement(RoleStatement),
 }
 
 pub trait SchemaStatementBuilder {
@@ -20,4 +28,27 @@ pub trait SchemaStatementBuilder {
     fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String {
         self.build(schema_builder)
     }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    fn to_string_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        self.build_any(schema_builder)
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string,
+    /// failing if the statement uses a feature the backend does not support
+    fn try_build<T: SchemaBuilder>(&self, schema_builder: T) -> Result<String> {
+        self.try_build_any(&schema_builder)
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string,
+    /// failing if the statement uses a feature the backend does not support
+    fn try_build_any(&self, schema_builder: &dyn SchemaBuilder) -> Result<String> {
+        self.check_support(schema_builder)?;
+        Ok(self.build_any(schema_builder))
+    }
+
+    /// Check the statement for features not supported by certain database backend
+    fn check_support(&self, _schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        Ok(())
+    }
 }
@@ -0,0 +1,126 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder, SequenceOption};
+
+/// Alter a sequence. Postgres only.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let sequence = Sequence::alter()
+///     .name(Alias::new("glyph_id_seq"))
+///     .increment_by(10)
+///     .restart_with(1000)
+///     .to_owned();
+///
+/// assert_eq!(
+///     sequence.to_string(PostgresQueryBuilder),
+///     r#"ALTER SEQUENCE "glyph_id_seq" INCREMENT BY 10 RESTART WITH 1000"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequenceAlterStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) options: Vec<SequenceOption>,
+}
+
+impl Default for SequenceAlterStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SequenceAlterStatement {
+    /// Construct a new [`SequenceAlterStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            options: Vec::new(),
+        }
+    }
+
+    /// Set sequence name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the step between values, negative for a descending sequence
+    pub fn increment_by(&mut self, increment: i64) -> &mut Self {
+        self.opt(SequenceOption::IncrementBy(increment))
+    }
+
+    /// Set the minimum value
+    pub fn min_value(&mut self, value: i64) -> &mut Self {
+        self.opt(SequenceOption::MinValue(Some(value)))
+    }
+
+    /// Use the default minimum value
+    pub fn no_min_value(&mut self) -> &mut Self {
+        self.opt(SequenceOption::MinValue(None))
+    }
+
+    /// Set the maximum value
+    pub fn max_value(&mut self, value: i64) -> &mut Self {
+        self.opt(SequenceOption::MaxValue(Some(value)))
+    }
+
+    /// Use the default maximum value
+    pub fn no_max_value(&mut self) -> &mut Self {
+        self.opt(SequenceOption::MaxValue(None))
+    }
+
+    /// Set the value used by a later [`SequenceAlterStatement::restart`]
+    pub fn start_with(&mut self, value: i64) -> &mut Self {
+        self.opt(SequenceOption::StartWith(value))
+    }
+
+    /// Reset the current value to the start value
+    pub fn restart(&mut self) -> &mut Self {
+        self.opt(SequenceOption::Restart(None))
+    }
+
+    /// Set the current value, returned by the next call of `nextval`
+    pub fn restart_with(&mut self, value: i64) -> &mut Self {
+        self.opt(SequenceOption::Restart(Some(value)))
+    }
+
+    /// Set the number of values preallocated
+    pub fn cache(&mut self, cache: i64) -> &mut Self {
+        self.opt(SequenceOption::Cache(cache))
+    }
+
+    /// Set whether to wrap around after reaching a bound
+    pub fn cycle(&mut self, cycle: bool) -> &mut Self {
+        self.opt(SequenceOption::Cycle(cycle))
+    }
+
+    fn opt(&mut self, option: SequenceOption) -> &mut Self {
+        self.options.push(option);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            options: std::mem::take(&mut self.options),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for SequenceAlterStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_sequence_alter_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_sequence_alter_statement(self, &mut sql);
+        sql.result()
+    }
+}
@@ -0,0 +1,16 @@
+/// Option of a sequence
+#[derive(Debug, Clone)]
+pub enum SequenceOption {
+    IncrementBy(i64),
+    /// Lower bound, or `NO MINVALUE` for the default of the direction
+    MinValue(Option<i64>),
+    /// Upper bound, or `NO MAXVALUE` for the default of the direction
+    MaxValue(Option<i64>),
+    StartWith(i64),
+    /// Set the current value, or reset it to the start value. Alter only.
+    Restart(Option<i64>),
+    /// Number of values preallocated for faster access
+    Cache(i64),
+    /// Whether to wrap around after reaching a bound, instead of failing
+    Cycle(bool),
+}
@@ -0,0 +1,130 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder, SequenceOption};
+
+/// Create a sequence. Postgres only.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let sequence = Sequence::create()
+///     .if_not_exists()
+///     .name(Alias::new("glyph_id_seq"))
+///     .increment_by(1)
+///     .min_value(1)
+///     .no_max_value()
+///     .start_with(100)
+///     .cache(10)
+///     .cycle(false)
+///     .to_owned();
+///
+/// assert_eq!(
+///     sequence.to_string(PostgresQueryBuilder),
+///     r#"CREATE SEQUENCE IF NOT EXISTS "glyph_id_seq" INCREMENT BY 1 MINVALUE 1 NO MAXVALUE START WITH 100 CACHE 10 NO CYCLE"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequenceCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) options: Vec<SequenceOption>,
+}
+
+impl Default for SequenceCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SequenceCreateStatement {
+    /// Construct a new [`SequenceCreateStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            if_not_exists: false,
+            options: Vec::new(),
+        }
+    }
+
+    /// Create sequence if it does not exist
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set sequence name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the step between values, negative for a descending sequence
+    pub fn increment_by(&mut self, increment: i64) -> &mut Self {
+        self.opt(SequenceOption::IncrementBy(increment))
+    }
+
+    /// Set the minimum value
+    pub fn min_value(&mut self, value: i64) -> &mut Self {
+        self.opt(SequenceOption::MinValue(Some(value)))
+    }
+
+    /// Use the default minimum value
+    pub fn no_min_value(&mut self) -> &mut Self {
+        self.opt(SequenceOption::MinValue(None))
+    }
+
+    /// Set the maximum value
+    pub fn max_value(&mut self, value: i64) -> &mut Self {
+        self.opt(SequenceOption::MaxValue(Some(value)))
+    }
+
+    /// Use the default maximum value
+    pub fn no_max_value(&mut self) -> &mut Self {
+        self.opt(SequenceOption::MaxValue(None))
+    }
+
+    /// Set the first value
+    pub fn start_with(&mut self, value: i64) -> &mut Self {
+        self.opt(SequenceOption::StartWith(value))
+    }
+
+    /// Set the number of values preallocated
+    pub fn cache(&mut self, cache: i64) -> &mut Self {
+        self.opt(SequenceOption::Cache(cache))
+    }
+
+    /// Set whether to wrap around after reaching a bound
+    pub fn cycle(&mut self, cycle: bool) -> &mut Self {
+        self.opt(SequenceOption::Cycle(cycle))
+    }
+
+    fn opt(&mut self, option: SequenceOption) -> &mut Self {
+        self.options.push(option);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            if_not_exists: self.if_not_exists,
+            options: std::mem::take(&mut self.options),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for SequenceCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_sequence_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_sequence_create_statement(self, &mut sql);
+        sql.result()
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Drop sequences. Postgres only.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let sequence = Sequence::drop()
+///     .if_exists()
+///     .name(Alias::new("glyph_id_seq"))
+///     .name(Alias::new("font_id_seq"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     sequence.to_string(PostgresQueryBuilder),
+///     r#"DROP SEQUENCE IF EXISTS "glyph_id_seq", "font_id_seq""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequenceDropStatement {
+    pub(crate) names: Vec<DynIden>,
+    pub(crate) if_exists: bool,
+}
+
+impl Default for SequenceDropStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SequenceDropStatement {
+    /// Construct a new [`SequenceDropStatement`]
+    pub fn new() -> Self {
+        Self {
+            names: Vec::new(),
+            if_exists: false,
+        }
+    }
+
+    /// Add a sequence to drop
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.names.push(name.into_iden());
+        self
+    }
+
+    /// Drop sequences only if they exist
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+impl SchemaStatementBuilder for SequenceDropStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_sequence_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_sequence_drop_statement(self, &mut sql);
+        sql.result()
+    }
+}
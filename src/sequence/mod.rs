@@ -0,0 +1,47 @@
+//! Sequence definition & alternations statements.
+//!
+//! # Usage
+//!
+//! - Sequence Create, see [`SequenceCreateStatement`]
+//! - Sequence Alter, see [`SequenceAlterStatement`]
+//! - Sequence Drop, see [`SequenceDropStatement`]
+
+mod alter;
+mod common;
+mod create;
+mod drop;
+mod shim;
+
+pub use alter::*;
+pub use common::*;
+pub use create::*;
+pub use drop::*;
+
+/// Shorthand for constructing any sequence statement
+#[derive(Debug, Clone)]
+pub struct Sequence;
+
+/// All available types of sequence statement
+#[derive(Debug, Clone)]
+pub enum SequenceStatement {
+    Create(SequenceCreateStatement),
+    Alter(SequenceAlterStatement),
+    Drop(SequenceDropStatement),
+}
+
+impl Sequence {
+    /// Construct sequence [`SequenceCreateStatement`]
+    pub fn create() -> SequenceCreateStatement {
+        SequenceCreateStatement::new()
+    }
+
+    /// Construct sequence [`SequenceAlterStatement`]
+    pub fn alter() -> SequenceAlterStatement {
+        SequenceAlterStatement::new()
+    }
+
+    /// Construct sequence [`SequenceDropStatement`]
+    pub fn drop() -> SequenceDropStatement {
+        SequenceDropStatement::new()
+    }
+}
@@ -0,0 +1,5 @@
+use crate::impl_schema_statement_builder;
+
+impl_schema_statement_builder!(sequence_create_statement_builder, SequenceCreateStatement);
+impl_schema_statement_builder!(sequence_alter_statement_builder, SequenceAlterStatement);
+impl_schema_statement_builder!(sequence_drop_statement_builder, SequenceDropStatement);
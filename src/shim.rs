@@ -17,6 +17,10 @@ macro_rules! impl_schema_statement_builder {
                 pub fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
                     <Self as SchemaStatementBuilder>::build_any(self, schema_builder)
                 }
+
+                pub fn to_string_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+                    <Self as SchemaStatementBuilder>::to_string_any(self, schema_builder)
+                }
             }
         }
     };
@@ -27,7 +31,11 @@ macro_rules! impl_query_statement_builder {
     ( $mod_name: ident, $struct_name: ident ) => {
         mod $mod_name {
 
-            use crate::{$struct_name, QueryBuilder, QueryStatementBuilder, Values};
+            use crate::{
+                error::Result, $struct_name, PlaceholderStyle, QueryBuilder, QueryStatementBuilder,
+                Value, Values,
+            };
+            use std::collections::BTreeMap;
 
             impl $struct_name {
                 pub fn to_string<T: QueryBuilder>(&self, query_builder: T) -> String {
@@ -41,6 +49,59 @@ macro_rules! impl_query_statement_builder {
                 pub fn build_any(&self, query_builder: &dyn QueryBuilder) -> (String, Values) {
                     <Self as QueryStatementBuilder>::build_any(self, query_builder)
                 }
+
+                pub fn to_string_any(&self, query_builder: &dyn QueryBuilder) -> String {
+                    <Self as QueryStatementBuilder>::to_string_any(self, query_builder)
+                }
+
+                pub fn build_with_placeholder<T: QueryBuilder>(
+                    &self,
+                    query_builder: T,
+                    style: PlaceholderStyle,
+                ) -> (String, Values) {
+                    <Self as QueryStatementBuilder>::build_with_placeholder(
+                        self,
+                        query_builder,
+                        style,
+                    )
+                }
+
+                pub fn build_named<T: QueryBuilder>(
+                    &self,
+                    query_builder: T,
+                    sign: &'static str,
+                ) -> (String, BTreeMap<String, Value>) {
+                    <Self as QueryStatementBuilder>::build_named(self, query_builder, sign)
+                }
+
+                pub fn try_build<T: QueryBuilder>(
+                    &self,
+                    query_builder: T,
+                ) -> Result<(String, Values)> {
+                    <Self as QueryStatementBuilder>::try_build(self, query_builder)
+                }
+
+                pub fn try_build_any(
+                    &self,
+                    query_builder: &dyn QueryBuilder,
+                ) -> Result<(String, Values)> {
+                    <Self as QueryStatementBuilder>::try_build_any(self, query_builder)
+                }
+
+                pub fn conditions<T, F>(&mut self, b: bool, if_true: T, if_false: F) -> &mut Self
+                where
+                    T: FnOnce(&mut Self),
+                    F: FnOnce(&mut Self),
+                {
+                    <Self as QueryStatementBuilder>::conditions(self, b, if_true, if_false)
+                }
+
+                pub fn apply_if<T, F>(&mut self, val: Option<T>, if_some: F) -> &mut Self
+                where
+                    F: FnOnce(&mut Self, T),
+                {
+                    <Self as QueryStatementBuilder>::apply_if(self, val, if_some)
+                }
             }
         }
     };
@@ -85,7 +146,7 @@ macro_rules! impl_ordered_statement {
         #[allow(deprecated)]
         mod $mod_name {
 
-            use crate::{OrderedStatement, IntoColumnRef, IntoIden, Order, SimpleExpr, $struct_name};
+            use crate::{OrderedStatement, IntoColumnRef, IntoIden, NullOrdering, Order, SimpleExpr, Value, $struct_name};
 
             impl $struct_name {
                 pub fn order_by<T>(&mut self, col: T, order: Order) -> &mut Self
@@ -107,6 +168,11 @@ macro_rules! impl_ordered_statement {
                     <Self as OrderedStatement>::order_by_expr(self, expr, order)
                 }
 
+                pub fn order_by_field<T>(&mut self, col: T, values: Vec<Value>) -> &mut Self
+                    where T: IntoColumnRef {
+                    <Self as OrderedStatement>::order_by_field(self, col, values)
+                }
+
                 pub fn order_by_customs<T>(&mut self, cols: Vec<(T, Order)>) -> &mut Self
                     where T: ToString {
                     <Self as OrderedStatement>::order_by_customs(self, cols)
@@ -117,6 +183,15 @@ macro_rules! impl_ordered_statement {
                     <Self as OrderedStatement>::order_by_columns(self, cols)
                 }
 
+                pub fn order_by_with_nulls<T>(&mut self, col: T, order: Order, nulls: NullOrdering) -> &mut Self
+                    where T: IntoColumnRef {
+                    <Self as OrderedStatement>::order_by_with_nulls(self, col, order, nulls)
+                }
+
+                pub fn order_by_expr_with_nulls(&mut self, expr: SimpleExpr, order: Order, nulls: NullOrdering) -> &mut Self {
+                    <Self as OrderedStatement>::order_by_expr_with_nulls(self, expr, order, nulls)
+                }
+
                 #[deprecated(
                     since = "0.9.0",
                     note = "Please use the [`OrderedStatement::order_by_columns`] with a tuple as [`ColumnRef`]"
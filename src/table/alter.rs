@@ -1,7 +1,14 @@
-use crate::{backend::SchemaBuilder, prepare::*, types::*, ColumnDef, SchemaStatementBuilder};
+use crate::{
+    backend::SchemaBuilder, error::Result, expr::*, prepare::*, types::*, ColumnDef,
+    SchemaStatementBuilder,
+};
 
 /// Alter a table
 ///
+/// Alterations can be chained, and are applied in a single statement. Backends not supporting
+/// this get several statements separated by `;`: Sqlite takes one alteration per statement,
+/// and Postgres cannot rename a column along with other alterations.
+///
 /// # Examples
 ///
 /// ```
@@ -24,11 +31,30 @@ use crate::{backend::SchemaBuilder, prepare::*, types::*, ColumnDef, SchemaState
 ///     table.to_string(SqliteQueryBuilder),
 ///     r#"ALTER TABLE `font` ADD COLUMN `new_col` integer NOT NULL DEFAULT 100"#,
 /// );
+///
+/// let table = Table::alter()
+///     .table(Font::Table)
+///     .add_column(ColumnDef::new(Alias::new("new_col")).integer())
+///     .rename_column(Font::Variant, Alias::new("style"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     table.to_string(MysqlQueryBuilder),
+///     r#"ALTER TABLE `font` ADD COLUMN `new_col` int, RENAME COLUMN `variant` TO `style`"#
+/// );
+/// assert_eq!(
+///     table.to_string(PostgresQueryBuilder),
+///     r#"ALTER TABLE "font" ADD COLUMN "new_col" integer; ALTER TABLE "font" RENAME COLUMN "variant" TO "style""#
+/// );
+/// assert_eq!(
+///     table.to_string(SqliteQueryBuilder),
+///     r#"ALTER TABLE `font` ADD COLUMN `new_col` integer; ALTER TABLE `font` RENAME COLUMN `variant` TO `style`"#
+/// );
 /// ```
 #[derive(Debug, Clone)]
 pub struct TableAlterStatement {
     pub(crate) table: Option<DynIden>,
-    pub(crate) alter_option: Option<TableAlterOption>,
+    pub(crate) options: Vec<TableAlterOption>,
 }
 
 /// All available table alter options
@@ -36,8 +62,15 @@ pub struct TableAlterStatement {
 pub enum TableAlterOption {
     AddColumn(ColumnDef),
     ModifyColumn(ColumnDef),
+    /// Modify a column, converting the existing values with an expression
+    ModifyColumnUsing(ColumnDef, SimpleExpr),
     RenameColumn(DynIden, DynIden),
+    /// Rename a column, restating its definition
+    ChangeColumn(DynIden, ColumnDef),
     DropColumn(DynIden),
+    DropColumnDefault(DynIden),
+    AddCheckConstraint(DynIden, SimpleExpr),
+    DropConstraint(DynIden),
 }
 
 impl Default for TableAlterStatement {
@@ -51,7 +84,7 @@ impl TableAlterStatement {
     pub fn new() -> Self {
         Self {
             table: None,
-            alter_option: None,
+            options: Vec::new(),
         }
     }
 
@@ -123,6 +156,47 @@ impl TableAlterStatement {
         self.alter_option(TableAlterOption::ModifyColumn(column_def.take()))
     }
 
+    /// Modify a column in an existing table, computing the new values from the old ones with
+    /// `USING` in Postgres. MySQL converts the values implicitly and ignores the expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .modify_column_using(
+    ///         ColumnDef::new(Font::Variant).json_binary().not_null(),
+    ///         Expr::cast_as(Expr::col(Font::Variant).into(), Alias::new("jsonb")).into(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `font` MODIFY COLUMN `variant` json NOT NULL"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     vec![
+    ///         r#"ALTER TABLE "font""#,
+    ///             r#"ALTER COLUMN "variant" TYPE jsonb USING CAST("variant" AS jsonb),"#,
+    ///             r#"ALTER COLUMN "variant" SET NOT NULL"#,
+    ///     ].join(" ")
+    /// );
+    /// // Sqlite not support modifying table column
+    /// ```
+    pub fn modify_column_using(
+        &mut self,
+        column_def: &mut ColumnDef,
+        using: SimpleExpr,
+    ) -> &mut Self {
+        self.alter_option(TableAlterOption::ModifyColumnUsing(
+            column_def.take(),
+            using,
+        ))
+    }
+
     /// Rename a column in an existing table
     ///
     /// # Examples
@@ -159,6 +233,46 @@ impl TableAlterStatement {
         ))
     }
 
+    /// Rename a column in an existing table, giving its full definition under the new name.
+    /// MySQL uses `CHANGE COLUMN`, which works before MySQL 8.0 and needs the definition
+    /// to keep the column type and attributes. Other backends only rename the column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .change_column(
+    ///         Font::Variant,
+    ///         ColumnDef::new(Alias::new("style")).string().not_null(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `font` CHANGE COLUMN `variant` `style` varchar(255) NOT NULL"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "font" RENAME COLUMN "variant" TO "style""#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     r#"ALTER TABLE `font` RENAME COLUMN `variant` TO `style`"#
+    /// );
+    /// ```
+    pub fn change_column<T>(&mut self, from_name: T, column_def: &mut ColumnDef) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.alter_option(TableAlterOption::ChangeColumn(
+            from_name.into_iden(),
+            column_def.take(),
+        ))
+    }
+
     /// Add a column to existing table
     ///
     /// # Examples
@@ -188,15 +302,108 @@ impl TableAlterStatement {
         self.alter_option(TableAlterOption::DropColumn(SeaRc::new(col_name)))
     }
 
+    /// Drop the default value of a column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .drop_column_default(Font::Name)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `font` ALTER COLUMN `name` DROP DEFAULT"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "font" ALTER COLUMN "name" DROP DEFAULT"#
+    /// );
+    /// // Sqlite not support modifying table column
+    /// ```
+    pub fn drop_column_default<T>(&mut self, col_name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.alter_option(TableAlterOption::DropColumnDefault(col_name.into_iden()))
+    }
+
+    /// Add a named `CHECK` constraint to an existing table
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .add_check_constraint(
+    ///         Alias::new("font_name_not_empty"),
+    ///         Expr::col(Font::Name).ne(""),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `font` ADD CONSTRAINT `font_name_not_empty` CHECK (`name` <> '')"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "font" ADD CONSTRAINT "font_name_not_empty" CHECK ("name" <> '')"#
+    /// );
+    /// // Sqlite not support adding table constraint
+    /// ```
+    pub fn add_check_constraint<T>(&mut self, name: T, check: SimpleExpr) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.alter_option(TableAlterOption::AddCheckConstraint(
+            name.into_iden(),
+            check,
+        ))
+    }
+
+    /// Drop a named constraint from an existing table
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .drop_constraint(Alias::new("font_name_not_empty"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `font` DROP CONSTRAINT `font_name_not_empty`"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "font" DROP CONSTRAINT "font_name_not_empty""#
+    /// );
+    /// // Sqlite not support dropping table constraint
+    /// ```
+    pub fn drop_constraint<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.alter_option(TableAlterOption::DropConstraint(name.into_iden()))
+    }
+
     fn alter_option(&mut self, alter_option: TableAlterOption) -> &mut Self {
-        self.alter_option = Some(alter_option);
+        self.options.push(alter_option);
         self
     }
 
     pub fn take(&mut self) -> Self {
         Self {
             table: self.table.take(),
-            alter_option: self.alter_option.take(),
+            options: std::mem::take(&mut self.options),
         }
     }
 }
@@ -213,4 +420,19 @@ impl SchemaStatementBuilder for TableAlterStatement {
         schema_builder.prepare_table_alter_statement(self, &mut sql);
         sql.result()
     }
+
+    fn check_support(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        for option in self.options.iter() {
+            match option {
+                TableAlterOption::AddColumn(column_def)
+                | TableAlterOption::ModifyColumn(column_def)
+                | TableAlterOption::ModifyColumnUsing(column_def, _)
+                | TableAlterOption::ChangeColumn(_, column_def) => {
+                    schema_builder.check_column_def(column_def)?
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{types::*, value::*};
+use crate::{expr::*, types::*, value::*};
 
 /// Specification of a table column
 #[derive(Debug, Clone)]
@@ -10,7 +10,8 @@ pub struct ColumnDef {
 }
 
 /// All column types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColumnType {
     Char(Option<u32>),
     String(Option<u32>),
@@ -33,21 +34,90 @@ pub enum ColumnType {
     Json,
     JsonBinary,
     Uuid,
-    Custom(DynIden),
+    Inet,
+    Cidr,
+    MacAddr,
+    Enum(
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde::vec"))]
+        Vec<DynIden>,
+    ),
+    Array(Box<ColumnType>),
+    Custom(#[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden),
 }
 
 /// All column specification keywords
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ColumnSpec {
     Null,
     NotNull,
-    Default(Value),
+    Default(SimpleExpr),
     AutoIncrement,
+    Identity(Identity),
     UniqueKey,
     PrimaryKey,
+    Check(SimpleExpr),
+    Generated(SimpleExpr, GeneratedStorage),
     Extra(String),
 }
 
+/// Identity of a column, see [`ColumnDef::identity`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Identity {
+    pub(crate) always: bool,
+    pub(crate) start: Option<i64>,
+    pub(crate) increment: Option<i64>,
+}
+
+/// Storage of a generated column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratedStorage {
+    /// Computed when written and stored like a normal column
+    Stored,
+    /// Computed when read. Not supported by Postgres.
+    Virtual,
+}
+
+impl Identity {
+    /// Values are always generated; explicit values are rejected unless overridden.
+    /// Only distinguished by Postgres.
+    pub fn always() -> Self {
+        Self {
+            always: true,
+            ..Default::default()
+        }
+    }
+
+    /// Values are generated unless given explicitly
+    pub fn by_default() -> Self {
+        Self::default()
+    }
+
+    /// Set the first generated value. Not supported by SQLite.
+    pub fn start(&mut self, start: i64) -> &mut Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Set the step between generated values. Only supported by Postgres.
+    pub fn increment(&mut self, increment: i64) -> &mut Self {
+        self.increment = Some(increment);
+        self
+    }
+
+    pub fn is_always(&self) -> bool {
+        self.always
+    }
+
+    pub fn get_start(&self) -> Option<i64> {
+        self.start
+    }
+
+    pub fn get_increment(&self) -> Option<i64> {
+        self.increment
+    }
+}
+
 impl ColumnDef {
     /// Construct a table column
     pub fn new<T: 'static>(name: T) -> Self
@@ -73,7 +143,65 @@ impl ColumnDef {
     where
         T: Into<Value>,
     {
-        self.spec.push(ColumnSpec::Default(value.into()));
+        self.spec
+            .push(ColumnSpec::Default(SimpleExpr::Value(value.into())));
+        self
+    }
+
+    /// Set default of a column to an expression, e.g. a function call
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(
+    ///         ColumnDef::new(Alias::new("created_at"))
+    ///             .timestamp()
+    ///             .default_expr(Expr::current_timestamp()),
+    ///     )
+    ///     .col(
+    ///         ColumnDef::new(Alias::new("token"))
+    ///             .string()
+    ///             .default_expr(Func::cust(Alias::new("LOWER")).args(vec![Expr::val("ABC")])),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE `glyph` ("#,
+    ///             r#"`created_at` timestamp DEFAULT CURRENT_TIMESTAMP,"#,
+    ///             r#"`token` varchar(255) DEFAULT (LOWER('ABC'))"#,
+    ///         r#")"#,
+    ///     ].join(" ")
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE "glyph" ("#,
+    ///             r#""created_at" timestamp DEFAULT CURRENT_TIMESTAMP,"#,
+    ///             r#""token" varchar DEFAULT (LOWER('ABC'))"#,
+    ///         r#")"#,
+    ///     ].join(" ")
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE `glyph` ("#,
+    ///             r#"`created_at` text DEFAULT CURRENT_TIMESTAMP,"#,
+    ///             r#"`token` text DEFAULT (LOWER('ABC'))"#,
+    ///         r#")"#,
+    ///     ].join(" ")
+    /// );
+    /// ```
+    pub fn default_expr<T>(&mut self, expr: T) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.spec.push(ColumnSpec::Default(expr.into()));
         self
     }
 
@@ -83,6 +211,41 @@ impl ColumnDef {
         self
     }
 
+    /// Set column as an identity column, generating its values from a sequence.
+    ///
+    /// Translated to `GENERATED ... AS IDENTITY` in Postgres, `AUTO_INCREMENT` in MySQL, where the
+    /// start value becomes a table option, and `AUTOINCREMENT` in SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(
+    ///         ColumnDef::new(Glyph::Id)
+    ///             .integer()
+    ///             .not_null()
+    ///             .identity(Identity::always().start(100).to_owned())
+    ///             .primary_key(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `glyph` ( `id` int NOT NULL AUTO_INCREMENT PRIMARY KEY ) AUTO_INCREMENT=100"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "glyph" ( "id" integer NOT NULL GENERATED ALWAYS AS IDENTITY (START WITH 100) PRIMARY KEY )"#
+    /// );
+    /// ```
+    pub fn identity(&mut self, identity: Identity) -> &mut Self {
+        self.spec.push(ColumnSpec::Identity(identity));
+        self
+    }
+
     /// Set column unique constraint
     pub fn unique_key(&mut self) -> &mut Self {
         self.spec.push(ColumnSpec::UniqueKey);
@@ -95,6 +258,101 @@ impl ColumnDef {
         self
     }
 
+    /// Add a `CHECK` constraint to the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(
+    ///         ColumnDef::new(Glyph::Aspect)
+    ///             .double()
+    ///             .not_null()
+    ///             .check(Expr::col(Glyph::Aspect).gt(0)),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `glyph` ( `aspect` double NOT NULL CHECK (`aspect` > 0) )"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "glyph" ( "aspect" double precision NOT NULL CHECK ("aspect" > 0) )"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE TABLE `glyph` ( `aspect` real NOT NULL CHECK (`aspect` > 0) )"#
+    /// );
+    /// ```
+    pub fn check<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.spec.push(ColumnSpec::Check(value.into()));
+        self
+    }
+
+    /// Compute the column from other columns of the row, i.e. `GENERATED ALWAYS AS (...)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Char::Table)
+    ///     .col(ColumnDef::new(Char::SizeW).integer().not_null())
+    ///     .col(ColumnDef::new(Char::SizeH).integer().not_null())
+    ///     .col(
+    ///         ColumnDef::new(Alias::new("size_sum"))
+    ///             .integer()
+    ///             .generated(Expr::col(Char::SizeW).into_simple_expr().add(Expr::col(Char::SizeH)), GeneratedStorage::Stored),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE `character` ("#,
+    ///             r#"`size_w` int NOT NULL,"#,
+    ///             r#"`size_h` int NOT NULL,"#,
+    ///             r#"`size_sum` int GENERATED ALWAYS AS (`size_w` + `size_h`) STORED"#,
+    ///         r#")"#,
+    ///     ].join(" ")
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE "character" ("#,
+    ///             r#""size_w" integer NOT NULL,"#,
+    ///             r#""size_h" integer NOT NULL,"#,
+    ///             r#""size_sum" integer GENERATED ALWAYS AS ("size_w" + "size_h") STORED"#,
+    ///         r#")"#,
+    ///     ].join(" ")
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE `character` ("#,
+    ///             r#"`size_w` integer NOT NULL,"#,
+    ///             r#"`size_h` integer NOT NULL,"#,
+    ///             r#"`size_sum` integer GENERATED ALWAYS AS (`size_w` + `size_h`) STORED"#,
+    ///         r#")"#,
+    ///     ].join(" ")
+    /// );
+    /// ```
+    pub fn generated<T>(&mut self, expr: T, storage: GeneratedStorage) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.spec.push(ColumnSpec::Generated(expr.into(), storage));
+        self
+    }
+
     /// Set column type as char with custom length
     pub fn char_len(&mut self, length: u32) -> &mut Self {
         self.types = Some(ColumnType::Char(Some(length)));
@@ -315,6 +573,94 @@ impl ColumnDef {
         self
     }
 
+    /// Set column type as inet.
+    /// On MySQL, this is equivalent to `varchar(43)`. On SQLite, this is equivalent to `text`.
+    pub fn inet(&mut self) -> &mut Self {
+        self.types = Some(ColumnType::Inet);
+        self
+    }
+
+    /// Set column type as cidr.
+    /// On MySQL, this is equivalent to `varchar(43)`. On SQLite, this is equivalent to `text`.
+    pub fn cidr(&mut self) -> &mut Self {
+        self.types = Some(ColumnType::Cidr);
+        self
+    }
+
+    /// Set column type as macaddr.
+    /// On MySQL, this is equivalent to `varchar(17)`. On SQLite, this is equivalent to `text`.
+    pub fn mac_address(&mut self) -> &mut Self {
+        self.types = Some(ColumnType::MacAddr);
+        self
+    }
+
+    /// Set column type as array of the given element type. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(ColumnDef::new(Alias::new("tags")).array(ColumnType::Text).not_null())
+    ///     .col(ColumnDef::new(Alias::new("matrix")).array(ColumnType::Array(Box::new(ColumnType::Integer(None)))))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "glyph" ( "tags" text[] NOT NULL, "matrix" integer[][] )"#
+    /// );
+    /// ```
+    pub fn array(&mut self, elem_type: ColumnType) -> &mut Self {
+        self.types = Some(ColumnType::Array(Box::new(elem_type)));
+        self
+    }
+
+    /// Set column type as enum with the given name and variants. Uses the enum type created by
+    /// [`Type::create`](crate::extension::postgres::Type::create) in Postgres, and a string
+    /// checked against the variants in MySQL and SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Font::Table)
+    ///     .col(
+    ///         ColumnDef::new(Font::Variant)
+    ///             .enumeration(Alias::new("font_variant"), vec![Alias::new("regular"), Alias::new("bold")])
+    ///             .not_null(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `font` ( `variant` varchar(255) CHECK (`variant` IN ('regular', 'bold')) NOT NULL )"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "font" ( "variant" "font_variant" NOT NULL )"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE TABLE `font` ( `variant` text CHECK (`variant` IN ('regular', 'bold')) NOT NULL )"#
+    /// );
+    /// ```
+    pub fn enumeration<N, S, V>(&mut self, name: N, variants: V) -> &mut Self
+    where
+        N: IntoIden,
+        S: IntoIden,
+        V: IntoIterator<Item = S>,
+    {
+        self.types = Some(ColumnType::Enum(
+            name.into_iden(),
+            variants.into_iter().map(IntoIden::into_iden).collect(),
+        ));
+        self
+    }
+
     /// Use a custom type on this column.
     pub fn custom<T: 'static>(&mut self, n: T) -> &mut Self
     where
@@ -1,6 +1,6 @@
 use crate::{
-    backend::SchemaBuilder, foreign_key::*, index::*, prepare::*, types::*, ColumnDef,
-    SchemaStatementBuilder,
+    backend::SchemaBuilder, error::Result, expr::*, foreign_key::*, index::*, prepare::*, types::*,
+    ColumnDef, SchemaStatementBuilder, SelectStatement,
 };
 
 /// Create a table
@@ -82,9 +82,15 @@ pub struct TableCreateStatement {
     pub(crate) columns: Vec<ColumnDef>,
     pub(crate) options: Vec<TableOpt>,
     pub(crate) partitions: Vec<TablePartition>,
+    pub(crate) partition_of: Option<(DynIden, PartitionValues)>,
     pub(crate) indexes: Vec<IndexCreateStatement>,
     pub(crate) foreign_keys: Vec<ForeignKeyCreateStatement>,
+    pub(crate) checks: Vec<SimpleExpr>,
+    pub(crate) query: Option<Box<SelectStatement>>,
+    pub(crate) with_no_data: bool,
     pub(crate) if_not_exists: bool,
+    pub(crate) temporary: bool,
+    pub(crate) unlogged: bool,
 }
 
 /// All available table options
@@ -93,11 +99,82 @@ pub enum TableOpt {
     Engine(String),
     Collate(String),
     CharacterSet(String),
+    /// Sorting key of the table engine. ClickHouse only.
+    OrderBy(Vec<DynIden>),
 }
 
 /// All available table partition options
 #[derive(Debug, Clone)]
-pub enum TablePartition {}
+pub enum TablePartition {
+    /// `PARTITION BY` the columns
+    By(PartitionType, Vec<DynIden>),
+    /// A named partition defined along with the table. MySQL only.
+    Definition(DynIden, PartitionValues),
+}
+
+/// Partitioning method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionType {
+    Range,
+    List,
+    Hash,
+}
+
+/// Bound of the rows stored in a partition
+#[derive(Debug, Clone)]
+pub enum PartitionValues {
+    /// `VALUES LESS THAN (..)`. MySQL only.
+    LessThan(Vec<SimpleExpr>),
+    /// `FOR VALUES FROM (..) TO (..)`. Postgres only.
+    FromTo(Vec<SimpleExpr>, Vec<SimpleExpr>),
+    /// `VALUES IN (..)`
+    In(Vec<SimpleExpr>),
+    /// `FOR VALUES WITH (MODULUS m, REMAINDER r)`. Postgres only.
+    Modulus(u32, u32),
+    /// `DEFAULT`, the rows not fitting any other partition. Postgres only.
+    Default,
+}
+
+impl PartitionValues {
+    /// Rows with partition key less than the values.
+    /// Use `Expr::cust("MAXVALUE")` for an unbounded partition.
+    pub fn less_than<T, I>(values: I) -> Self
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        Self::LessThan(values.into_iter().map(Into::into).collect())
+    }
+
+    /// Rows with partition key from the lower values (inclusive) to the upper values (exclusive).
+    /// Use `Expr::cust("MINVALUE")` and `Expr::cust("MAXVALUE")` for unbounded ends.
+    pub fn from_to<T, I, U, J>(from: I, to: J) -> Self
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+        U: Into<SimpleExpr>,
+        J: IntoIterator<Item = U>,
+    {
+        Self::FromTo(
+            from.into_iter().map(Into::into).collect(),
+            to.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    /// Rows with partition key in the list of values
+    pub fn list<T, I>(values: I) -> Self
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        Self::In(values.into_iter().map(Into::into).collect())
+    }
+
+    /// Rows with hash of partition key modulo `modulus` equal to `remainder`
+    pub fn modulus(modulus: u32, remainder: u32) -> Self {
+        Self::Modulus(modulus, remainder)
+    }
+}
 
 impl Default for TableCreateStatement {
     fn default() -> Self {
@@ -113,9 +190,15 @@ impl TableCreateStatement {
             columns: Vec::new(),
             options: Vec::new(),
             partitions: Vec::new(),
+            partition_of: None,
             indexes: Vec::new(),
             foreign_keys: Vec::new(),
+            checks: Vec::new(),
+            query: None,
+            with_no_data: false,
             if_not_exists: false,
+            temporary: false,
+            unlogged: false,
         }
     }
 
@@ -134,6 +217,130 @@ impl TableCreateStatement {
         self
     }
 
+    /// Create a temporary table, dropped at the end of the session
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Alias::new("staging"))
+    ///     .temporary()
+    ///     .as_select(
+    ///         Query::select()
+    ///             .columns(vec![Glyph::Id, Glyph::Image])
+    ///             .from(Glyph::Table)
+    ///             .and_where(Expr::col(Glyph::Aspect).gt(2))
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TEMPORARY TABLE `staging` AS SELECT `id`, `image` FROM `glyph` WHERE `aspect` > 2"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TEMPORARY TABLE "staging" AS SELECT "id", "image" FROM "glyph" WHERE "aspect" > 2"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE TEMPORARY TABLE `staging` AS SELECT `id`, `image` FROM `glyph` WHERE `aspect` > 2"#
+    /// );
+    /// ```
+    pub fn temporary(&mut self) -> &mut Self {
+        self.temporary = true;
+        self
+    }
+
+    /// Create an unlogged table, not written to the write-ahead log. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Alias::new("staging"))
+    ///     .unlogged()
+    ///     .col(ColumnDef::new(Glyph::Id).integer())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE UNLOGGED TABLE "staging" ( "id" integer )"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `staging` ( `id` int )"#
+    /// );
+    /// ```
+    pub fn unlogged(&mut self) -> &mut Self {
+        self.unlogged = true;
+        self
+    }
+
+    /// Fill the table with the rows of a query, i.e. `CREATE TABLE ... AS SELECT`.
+    /// The columns of the table are those of the query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Alias::new("font_snapshot"))
+    ///     .as_select(
+    ///         Query::select()
+    ///             .columns(vec![Font::Id, Font::Name])
+    ///             .from(Font::Table)
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `font_snapshot` AS SELECT `id`, `name` FROM `font`"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "font_snapshot" AS SELECT "id", "name" FROM "font""#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE TABLE `font_snapshot` AS SELECT `id`, `name` FROM `font`"#
+    /// );
+    /// ```
+    pub fn as_select(&mut self, query: SelectStatement) -> &mut Self {
+        self.query = Some(Box::new(query));
+        self
+    }
+
+    /// Copy only the structure of the query in [`TableCreateStatement::as_select`], without
+    /// its rows, i.e. `WITH NO DATA`. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Alias::new("font_snapshot"))
+    ///     .as_select(Query::select().column(Font::Name).from(Font::Table).to_owned())
+    ///     .with_no_data()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "font_snapshot" AS SELECT "name" FROM "font" WITH NO DATA"#
+    /// );
+    /// ```
+    pub fn with_no_data(&mut self) -> &mut Self {
+        self.with_no_data = true;
+        self
+    }
+
     /// Set table name
     pub fn table<T: 'static>(&mut self, table: T) -> &mut Self
     where
@@ -240,6 +447,56 @@ impl TableCreateStatement {
         self
     }
 
+    /// Add a `CHECK` constraint over the columns of the table
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Char::Table)
+    ///     .col(ColumnDef::new(Char::SizeW).integer().not_null())
+    ///     .col(ColumnDef::new(Char::SizeH).integer().not_null())
+    ///     .check(Expr::col(Char::SizeW).into_simple_expr().less_than(Expr::col(Char::SizeH)))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE `character` ("#,
+    ///             r#"`size_w` int NOT NULL,"#,
+    ///             r#"`size_h` int NOT NULL,"#,
+    ///             r#"CHECK (`size_w` < `size_h`)"#,
+    ///         r#")"#,
+    ///     ].join(" ")
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE "character" ("#,
+    ///             r#""size_w" integer NOT NULL,"#,
+    ///             r#""size_h" integer NOT NULL,"#,
+    ///             r#"CHECK ("size_w" < "size_h")"#,
+    ///         r#")"#,
+    ///     ].join(" ")
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE `character` ("#,
+    ///             r#"`size_w` integer NOT NULL,"#,
+    ///             r#"`size_h` integer NOT NULL,"#,
+    ///             r#"CHECK (`size_w` < `size_h`)"#,
+    ///         r#")"#,
+    ///     ].join(" ")
+    /// );
+    /// ```
+    pub fn check(&mut self, value: SimpleExpr) -> &mut Self {
+        self.checks.push(value);
+        self
+    }
+
     /// Set database engine. MySQL only.
     pub fn engine(&mut self, string: &str) -> &mut Self {
         self.opt(TableOpt::Engine(string.into()));
@@ -258,12 +515,136 @@ impl TableCreateStatement {
         self
     }
 
+    /// Set the sorting key of the table engine, `tuple()` if no column is given. ClickHouse only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(ColumnDef::new(Glyph::Id).integer().not_null())
+    ///     .col(ColumnDef::new(Glyph::Aspect).double().not_null())
+    ///     .engine("MergeTree")
+    ///     .order_by(vec![Glyph::Id])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(ClickHouseQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE `glyph` ("#,
+    ///             r#"`id` Int32 NOT NULL,"#,
+    ///             r#"`aspect` Float64 NOT NULL"#,
+    ///         r#") ENGINE = MergeTree ORDER BY (`id`)"#,
+    ///     ].join(" ")
+    /// );
+    /// ```
+    pub fn order_by<T, I>(&mut self, columns: I) -> &mut Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.opt(TableOpt::OrderBy(
+            columns.into_iter().map(|col| col.into_iden()).collect(),
+        ))
+    }
+
     fn opt(&mut self, option: TableOpt) -> &mut Self {
         self.options.push(option);
         self
     }
 
-    #[allow(dead_code)]
+    /// Partition the table by the columns
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Char::Table)
+    ///     .col(ColumnDef::new(Char::Id).integer().not_null())
+    ///     .col(ColumnDef::new(Char::FontSize).integer().not_null())
+    ///     .partition_by(PartitionType::Range, vec![Char::FontSize])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE "character" ("#,
+    ///             r#""id" integer NOT NULL,"#,
+    ///             r#""font_size" integer NOT NULL"#,
+    ///         r#") PARTITION BY RANGE ("font_size")"#,
+    ///     ].join(" ")
+    /// );
+    ///
+    /// let table = table
+    ///     .to_owned()
+    ///     .add_partition(Alias::new("small"), PartitionValues::less_than(vec![Expr::val(12)]))
+    ///     .add_partition(Alias::new("large"), PartitionValues::less_than(vec![Expr::cust("MAXVALUE")]))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE `character` ("#,
+    ///             r#"`id` int NOT NULL,"#,
+    ///             r#"`font_size` int NOT NULL"#,
+    ///         r#") PARTITION BY RANGE COLUMNS (`font_size`) ("#,
+    ///             r#"PARTITION `small` VALUES LESS THAN (12),"#,
+    ///             r#"PARTITION `large` VALUES LESS THAN (MAXVALUE)"#,
+    ///         r#")"#,
+    ///     ].join(" ")
+    /// );
+    /// ```
+    pub fn partition_by<T, I>(&mut self, r#type: PartitionType, columns: I) -> &mut Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.partition(TablePartition::By(
+            r#type,
+            columns.into_iter().map(IntoIden::into_iden).collect(),
+        ))
+    }
+
+    /// Define a partition of a table partitioned with [`TableCreateStatement::partition_by`]. MySQL only.
+    pub fn add_partition<T>(&mut self, name: T, values: PartitionValues) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.partition(TablePartition::Definition(name.into_iden(), values))
+    }
+
+    /// Create the table as a partition of a parent table. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let table = Table::create()
+    ///     .table(Alias::new("character_small"))
+    ///     .partition_of(
+    ///         Char::Table,
+    ///         PartitionValues::from_to(vec![Expr::cust("MINVALUE")], vec![Expr::val(12)]),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "character_small" PARTITION OF "character" FOR VALUES FROM (MINVALUE) TO (12)"#
+    /// );
+    /// ```
+    pub fn partition_of<T>(&mut self, parent: T, values: PartitionValues) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.partition_of = Some((parent.into_iden(), values));
+        self
+    }
+
     fn partition(&mut self, partition: TablePartition) -> &mut Self {
         self.partitions.push(partition);
         self
@@ -291,9 +672,15 @@ impl TableCreateStatement {
             columns: std::mem::take(&mut self.columns),
             options: std::mem::take(&mut self.options),
             partitions: std::mem::take(&mut self.partitions),
+            partition_of: self.partition_of.take(),
             indexes: std::mem::take(&mut self.indexes),
             foreign_keys: std::mem::take(&mut self.foreign_keys),
+            checks: std::mem::take(&mut self.checks),
+            query: self.query.take(),
+            with_no_data: self.with_no_data,
             if_not_exists: self.if_not_exists,
+            temporary: self.temporary,
+            unlogged: self.unlogged,
         }
     }
 }
@@ -310,4 +697,11 @@ impl SchemaStatementBuilder for TableCreateStatement {
         schema_builder.prepare_table_create_statement(self, &mut sql);
         sql.result()
     }
+
+    fn check_support(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        for column_def in self.columns.iter() {
+            schema_builder.check_column_def(column_def)?;
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,314 @@
+use crate::{
+    backend::SchemaBuilder,
+    error::{Error, Result},
+    types::*,
+    ColumnDef, ColumnSpec, ForeignKeyCreateStatement, ForeignKeyDropStatement, ForeignKeyStatement,
+    IndexCreateStatement, IndexDropStatement, IndexStatement, SchemaStatement, TableAlterOption,
+    TableAlterStatement, TableCreateStatement, TableStatement,
+};
+
+/// Statements migrating a table from its current definition to the desired one: foreign keys
+/// and indexes which are gone or changed are dropped, columns are added, modified and dropped
+/// with a single [`TableAlterStatement`], then new or changed indexes and foreign keys are
+/// created.
+///
+/// Columns, indexes and foreign keys are matched by name, so a renamed column is dropped and
+/// added again; indexes and foreign keys without a name are matched by definition and can only
+/// be added. The primary key, check constraints and table options are not compared.
+///
+/// A modified column which was `NOT NULL` and is no longer is restated with `NULL`, and its
+/// default is dropped if it no longer has one, as Postgres keeps both when altering a column.
+/// The primary key, unique key, auto increment and identity of a modified column are not
+/// restated, as they cannot be added again to an existing column; on MySQL, restating a column
+/// replaces its definition, so an auto increment column loses its auto increment.
+///
+/// Sqlite can neither modify a column nor add or drop a foreign key with `ALTER TABLE`; give
+/// the alterations to a [`TableRebuildStatement`](crate::TableRebuildStatement) instead.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let current = Table::create()
+///     .table(Font::Table)
+///     .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+///     .col(ColumnDef::new(Font::Name).string())
+///     .col(ColumnDef::new(Font::Variant).string())
+///     .to_owned();
+///
+/// let desired = Table::create()
+///     .table(Font::Table)
+///     .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+///     .col(ColumnDef::new(Font::Name).string().not_null())
+///     .col(ColumnDef::new(Font::Language).string())
+///     .index(Index::create().name("idx-font-name").col(Font::Name))
+///     .to_owned();
+///
+/// let diff = TableDiff::new(&current, &desired).unwrap();
+///
+/// assert_eq!(
+///     diff.build(MysqlQueryBuilder),
+///     vec![
+///         "ALTER TABLE `font` MODIFY COLUMN `name` varchar(255) NOT NULL, ADD COLUMN `language` varchar(255), DROP COLUMN `variant`",
+///         "CREATE INDEX `idx-font-name` ON `font` (`name`)",
+///     ]
+/// );
+/// assert_eq!(
+///     diff.build(PostgresQueryBuilder),
+///     vec![
+///         [
+///             r#"ALTER TABLE "font""#,
+///             r#"ALTER COLUMN "name" TYPE varchar, ALTER COLUMN "name" SET NOT NULL,"#,
+///             r#"ADD COLUMN "language" varchar,"#,
+///             r#"DROP COLUMN "variant""#,
+///         ]
+///         .join(" "),
+///         r#"CREATE INDEX "idx-font-name" ON "font" ("name")"#.to_owned(),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TableDiff {
+    pub(crate) drop_foreign_keys: Vec<ForeignKeyDropStatement>,
+    pub(crate) drop_indexes: Vec<IndexDropStatement>,
+    pub(crate) alter: TableAlterStatement,
+    pub(crate) create_indexes: Vec<IndexCreateStatement>,
+    pub(crate) create_foreign_keys: Vec<ForeignKeyCreateStatement>,
+}
+
+impl TableDiff {
+    /// Compare the current definition of a table with the desired one, failing with
+    /// [`Error::NoTableName`] if neither definition has a table name
+    pub fn new(current: &TableCreateStatement, desired: &TableCreateStatement) -> Result<Self> {
+        let table = match desired.table.as_ref().or(current.table.as_ref()) {
+            Some(table) => table.clone(),
+            None => return Err(Error::NoTableName),
+        };
+        let mut diff = Self {
+            drop_foreign_keys: Vec::new(),
+            drop_indexes: Vec::new(),
+            alter: TableAlterStatement::new(),
+            create_indexes: Vec::new(),
+            create_foreign_keys: Vec::new(),
+        };
+        diff.alter.table = Some(table.clone());
+
+        for column in desired.columns.iter() {
+            match find_column(current, &column.name) {
+                None => diff
+                    .alter
+                    .options
+                    .push(TableAlterOption::AddColumn(column.clone())),
+                Some(existing) if !same_column(existing, column) => {
+                    let mut modified = column.clone();
+                    modified.spec.retain(|spec| {
+                        !matches!(
+                            spec,
+                            ColumnSpec::PrimaryKey
+                                | ColumnSpec::UniqueKey
+                                | ColumnSpec::AutoIncrement
+                                | ColumnSpec::Identity(_)
+                        )
+                    });
+                    if has_spec(existing, |spec| matches!(spec, ColumnSpec::NotNull))
+                        && !has_spec(column, |spec| {
+                            matches!(
+                                spec,
+                                ColumnSpec::Null | ColumnSpec::NotNull | ColumnSpec::PrimaryKey
+                            )
+                        })
+                    {
+                        modified.spec.push(ColumnSpec::Null);
+                    }
+                    diff.alter
+                        .options
+                        .push(TableAlterOption::ModifyColumn(modified));
+                    let has_default =
+                        |column| has_spec(column, |spec| matches!(spec, ColumnSpec::Default(_)));
+                    if has_default(existing) && !has_default(column) {
+                        diff.alter
+                            .options
+                            .push(TableAlterOption::DropColumnDefault(column.name.clone()));
+                    }
+                }
+                Some(_) => (),
+            }
+        }
+        for column in current.columns.iter() {
+            if find_column(desired, &column.name).is_none() {
+                diff.alter
+                    .options
+                    .push(TableAlterOption::DropColumn(column.name.clone()));
+            }
+        }
+
+        let indexes = |create: &TableCreateStatement| -> Vec<IndexCreateStatement> {
+            create
+                .indexes
+                .iter()
+                .filter(|index| !index.primary)
+                .map(|index| {
+                    let mut index = index.clone();
+                    index.table = Some(table.clone());
+                    index
+                })
+                .collect()
+        };
+        let (current_indexes, desired_indexes) = (indexes(current), indexes(desired));
+        for index in current_indexes.iter() {
+            if let Some(name) = &index.index.name {
+                if !desired_indexes.iter().any(|other| index == other) {
+                    let mut drop = IndexDropStatement::new();
+                    drop.table = Some(table.clone());
+                    drop.name(name);
+                    diff.drop_indexes.push(drop);
+                }
+            }
+        }
+        for index in desired_indexes {
+            if !current_indexes.iter().any(|other| &index == other) {
+                diff.create_indexes.push(index);
+            }
+        }
+
+        let foreign_keys = |create: &TableCreateStatement| -> Vec<ForeignKeyCreateStatement> {
+            create
+                .foreign_keys
+                .iter()
+                .map(|foreign_key| {
+                    let mut foreign_key = foreign_key.clone();
+                    foreign_key.foreign_key.table = Some(table.clone());
+                    foreign_key
+                })
+                .collect()
+        };
+        let (current_foreign_keys, desired_foreign_keys) =
+            (foreign_keys(current), foreign_keys(desired));
+        for foreign_key in current_foreign_keys.iter() {
+            if let Some(name) = &foreign_key.foreign_key.name {
+                if !desired_foreign_keys
+                    .iter()
+                    .any(|other| foreign_key == other)
+                {
+                    let mut drop = ForeignKeyDropStatement::new();
+                    drop.table = Some(table.clone());
+                    drop.name(name);
+                    diff.drop_foreign_keys.push(drop);
+                }
+            }
+        }
+        for foreign_key in desired_foreign_keys {
+            if !current_foreign_keys
+                .iter()
+                .any(|other| &foreign_key == other)
+            {
+                diff.create_foreign_keys.push(foreign_key);
+            }
+        }
+
+        Ok(diff)
+    }
+
+    /// Whether the current definition already matches the desired one
+    pub fn is_empty(&self) -> bool {
+        self.drop_foreign_keys.is_empty()
+            && self.drop_indexes.is_empty()
+            && self.alter.options.is_empty()
+            && self.create_indexes.is_empty()
+            && self.create_foreign_keys.is_empty()
+    }
+
+    /// The statements of the migration, to be executed in order
+    pub fn statements(&self) -> Vec<SchemaStatement> {
+        let mut statements = Vec::new();
+        statements.extend(self.drop_foreign_keys.iter().map(|drop| {
+            SchemaStatement::ForeignKeyStatement(ForeignKeyStatement::Drop(drop.clone()))
+        }));
+        statements.extend(
+            self.drop_indexes
+                .iter()
+                .map(|drop| SchemaStatement::IndexStatement(IndexStatement::Drop(drop.clone()))),
+        );
+        if !self.alter.options.is_empty() {
+            statements.push(SchemaStatement::TableStatement(TableStatement::Alter(
+                self.alter.clone(),
+            )));
+        }
+        statements.extend(
+            self.create_indexes.iter().map(|create| {
+                SchemaStatement::IndexStatement(IndexStatement::Create(create.clone()))
+            }),
+        );
+        statements.extend(self.create_foreign_keys.iter().map(|create| {
+            SchemaStatement::ForeignKeyStatement(ForeignKeyStatement::Create(create.clone()))
+        }));
+        statements
+    }
+
+    /// Build the statements of the migration, to be executed in order
+    pub fn build<T: SchemaBuilder>(&self, schema_builder: T) -> Vec<String> {
+        self.build_any(&schema_builder)
+    }
+
+    /// Build the statements of the migration, to be executed in order
+    pub fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> Vec<String> {
+        let mut statements = Vec::new();
+        statements.extend(
+            self.drop_foreign_keys
+                .iter()
+                .map(|drop| drop.build_any(schema_builder)),
+        );
+        statements.extend(
+            self.drop_indexes
+                .iter()
+                .map(|drop| drop.build_any(schema_builder)),
+        );
+        if !self.alter.options.is_empty() {
+            statements.push(self.alter.build_any(schema_builder));
+        }
+        statements.extend(
+            self.create_indexes
+                .iter()
+                .map(|create| create.build_any(schema_builder)),
+        );
+        statements.extend(
+            self.create_foreign_keys
+                .iter()
+                .map(|create| create.build_any(schema_builder)),
+        );
+        statements
+    }
+
+    /// Build the statements of the migration, separated by `;`
+    pub fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        self.build(schema_builder).join("; ")
+    }
+}
+
+impl TableCreateStatement {
+    /// Compute the statements migrating this table to the desired definition, see [`TableDiff`]
+    pub fn diff(&self, desired: &TableCreateStatement) -> Result<TableDiff> {
+        TableDiff::new(self, desired)
+    }
+}
+
+fn find_column<'a>(create: &'a TableCreateStatement, name: &DynIden) -> Option<&'a ColumnDef> {
+    let name = name.to_string();
+    create
+        .columns
+        .iter()
+        .find(|column| column.name.to_string() == name)
+}
+
+fn has_spec(column: &ColumnDef, predicate: impl Fn(&ColumnSpec) -> bool) -> bool {
+    column.spec.iter().any(predicate)
+}
+
+/// Columns have the same type and the same specifications, in any order
+fn same_column(a: &ColumnDef, b: &ColumnDef) -> bool {
+    a.types == b.types
+        && a.spec.len() == b.spec.len()
+        && a.spec.iter().all(|spec| b.spec.contains(spec))
+        && b.spec.iter().all(|spec| a.spec.contains(spec))
+}
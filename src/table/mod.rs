@@ -7,13 +7,17 @@
 //! - Table Drop, see [`TableDropStatement`]
 //! - Table Rename, see [`TableRenameStatement`]
 //! - Table Truncate, see [`TableTruncateStatement`]
+//! - Table Rebuild, see [`TableRebuildStatement`]
+//! - Table Diff, see [`TableDiff`]
 
 use crate::SchemaBuilder;
 
 mod alter;
 mod column;
 mod create;
+mod diff;
 mod drop;
+mod rebuild;
 mod rename;
 mod shim;
 mod truncate;
@@ -21,7 +25,9 @@ mod truncate;
 pub use alter::*;
 pub use column::*;
 pub use create::*;
+pub use diff::*;
 pub use drop::*;
+pub use rebuild::*;
 pub use rename::*;
 pub use truncate::*;
 
@@ -99,4 +105,9 @@ impl TableStatement {
             Self::Truncate(stat) => stat.to_string(table_builder),
         }
     }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string_any(&self, table_builder: &dyn SchemaBuilder) -> String {
+        self.build_any(table_builder)
+    }
 }
@@ -0,0 +1,197 @@
+use crate::{
+    backend::GenericBuilder, expr::*, prepare::*, types::*, ColumnDef, ColumnSpec,
+    ForeignKeyCreateStatement, InsertStatement, Query, TableAlterOption, TableAlterStatement,
+    TableCreateStatement, TableDropStatement, TableRenameStatement,
+};
+
+/// Apply alterations Sqlite cannot do with `ALTER TABLE`, e.g. modifying a column or adding a
+/// foreign key, by rebuilding the table: the table is renamed, created again with the altered
+/// definition, filled with the rows of the renamed table, which is then dropped.
+///
+/// The current definition of the table has to be given, as Sqlite cannot alter it in place.
+/// Foreign key enforcement should be turned off while rebuilding a referenced table.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let font = Table::create()
+///     .table(Font::Table)
+///     .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+///     .col(ColumnDef::new(Font::Name).string())
+///     .col(ColumnDef::new(Font::Variant).string())
+///     .to_owned();
+///
+/// let rebuild = TableRebuildStatement::new(&font)
+///     .alter(
+///         Table::alter()
+///             .modify_column(ColumnDef::new(Font::Name).string().not_null())
+///             .drop_column(Font::Variant),
+///     )
+///     .to_owned();
+///
+/// assert_eq!(
+///     rebuild.build(SqliteQueryBuilder),
+///     vec![
+///         r#"ALTER TABLE `font` RENAME TO `font_old`"#,
+///         r#"CREATE TABLE `font` ( `id` integer NOT NULL PRIMARY KEY, `name` text NOT NULL )"#,
+///         r#"INSERT INTO `font` (`id`, `name`) SELECT `id`, `name` FROM `font_old`"#,
+///         r#"DROP TABLE `font_old`"#,
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TableRebuildStatement {
+    pub(crate) table: TableCreateStatement,
+    pub(crate) options: Vec<TableAlterOption>,
+    pub(crate) foreign_keys: Vec<ForeignKeyCreateStatement>,
+}
+
+impl TableRebuildStatement {
+    /// Construct a rebuild of the table with this current definition
+    pub fn new(table: &TableCreateStatement) -> Self {
+        Self {
+            table: table.clone(),
+            options: Vec::new(),
+            foreign_keys: Vec::new(),
+        }
+    }
+
+    /// Apply the alterations of a [`TableAlterStatement`]; its table name is ignored
+    pub fn alter(&mut self, alter: &mut TableAlterStatement) -> &mut Self {
+        self.options.append(&mut alter.options);
+        self
+    }
+
+    /// Add a foreign key to the table
+    pub fn add_foreign_key(&mut self, foreign_key: &mut ForeignKeyCreateStatement) -> &mut Self {
+        self.foreign_keys.push(foreign_key.take());
+        self
+    }
+
+    /// Build the statements rebuilding the table, to be executed in order
+    pub fn build<T: GenericBuilder>(&self, builder: T) -> Vec<String> {
+        let table = match &self.table.table {
+            Some(table) => table.clone(),
+            None => panic!("No table name found"),
+        };
+        let old_table: DynIden = SeaRc::new(Alias::new(&format!("{}_old", table.to_string())));
+
+        let mut create = self.table.clone();
+        // Columns of the new table, each with the expression on the old table filling it
+        let mut columns: Vec<(DynIden, Option<SimpleExpr>)> = create
+            .columns
+            .iter()
+            .map(|column| {
+                let source = Expr::col(column.name.clone()).into_simple_expr();
+                (column.name.clone(), Some(source))
+            })
+            .collect();
+        for option in self.options.iter() {
+            match option {
+                TableAlterOption::AddColumn(column_def) => {
+                    create.columns.push(column_def.clone());
+                    columns.push((column_def.name.clone(), None));
+                }
+                TableAlterOption::ModifyColumn(column_def) => {
+                    let i = Self::column_index(&create, &column_def.name);
+                    create.columns[i] = column_def.clone();
+                }
+                TableAlterOption::ModifyColumnUsing(column_def, using) => {
+                    let i = Self::column_index(&create, &column_def.name);
+                    create.columns[i] = column_def.clone();
+                    columns[i].1 = Some(using.clone());
+                }
+                TableAlterOption::RenameColumn(from_name, to_name) => {
+                    let i = Self::column_index(&create, from_name);
+                    create.columns[i].name = to_name.clone();
+                    columns[i].0 = to_name.clone();
+                }
+                TableAlterOption::ChangeColumn(from_name, column_def) => {
+                    let i = Self::column_index(&create, from_name);
+                    create.columns[i] = column_def.clone();
+                    columns[i].0 = column_def.name.clone();
+                }
+                TableAlterOption::DropColumn(column_name) => {
+                    let i = Self::column_index(&create, column_name);
+                    create.columns.remove(i);
+                    columns.remove(i);
+                }
+                TableAlterOption::DropColumnDefault(column_name) => {
+                    let i = Self::column_index(&create, column_name);
+                    create.columns[i]
+                        .spec
+                        .retain(|spec| !matches!(spec, ColumnSpec::Default(_)));
+                }
+                TableAlterOption::AddCheckConstraint(_, check) => {
+                    create.checks.push(check.clone());
+                }
+                TableAlterOption::DropConstraint(name) => {
+                    let name = name.to_string();
+                    let (indexes, foreign_keys) = (create.indexes.len(), create.foreign_keys.len());
+                    create
+                        .indexes
+                        .retain(|index| index.index.name.as_ref() != Some(&name));
+                    create
+                        .foreign_keys
+                        .retain(|foreign_key| foreign_key.foreign_key.name.as_ref() != Some(&name));
+                    if indexes == create.indexes.len() && foreign_keys == create.foreign_keys.len()
+                    {
+                        panic!("Constraint {} not found", name);
+                    }
+                }
+            }
+        }
+        create
+            .foreign_keys
+            .extend(self.foreign_keys.iter().cloned());
+
+        let (columns, sources): (Vec<_>, Vec<_>) = columns
+            .into_iter()
+            .filter_map(|(column, source)| source.map(|source| (column, source)))
+            .unzip();
+        let copy = InsertStatement::new()
+            .into_table(table.clone())
+            .columns(columns)
+            .select_from(
+                Query::select()
+                    .exprs(sources)
+                    .from(old_table.clone())
+                    .to_owned(),
+            )
+            .unwrap()
+            .to_owned();
+        let (copy, values) = copy.build_any(&builder);
+
+        vec![
+            TableRenameStatement {
+                from_name: Some(table),
+                to_name: Some(old_table.clone()),
+            }
+            .build_any(&builder),
+            create.build_any(&builder),
+            inject_parameters(&copy, values.0, &builder),
+            TableDropStatement {
+                tables: vec![old_table],
+                options: Vec::new(),
+                if_exists: false,
+            }
+            .build_any(&builder),
+        ]
+    }
+
+    /// Build the statements rebuilding the table, separated by `;`
+    pub fn to_string<T: GenericBuilder>(&self, builder: T) -> String {
+        self.build(builder).join("; ")
+    }
+
+    fn column_index(create: &TableCreateStatement, name: &DynIden) -> usize {
+        let name = name.to_string();
+        create
+            .columns
+            .iter()
+            .position(|column: &ColumnDef| column.name.to_string() == name)
+            .unwrap_or_else(|| panic!("Column {} not found", name))
+    }
+}
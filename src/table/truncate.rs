@@ -1,6 +1,6 @@
-use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder};
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder, TableDropOpt};
 
-/// Drop a table
+/// Truncate a table. Sqlite falls back to `DELETE FROM`.
 ///
 /// # Examples
 ///
@@ -21,12 +21,38 @@ use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder
 /// );
 /// assert_eq!(
 ///     table.to_string(SqliteQueryBuilder),
-///     r#"TRUNCATE TABLE `font`"#
+///     r#"DELETE FROM `font`"#
+/// );
+/// ```
+///
+/// Postgres truncates several tables at once
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let table = Table::truncate()
+///     .table(Glyph::Table)
+///     .table(Char::Table)
+///     .restart_identity()
+///     .cascade()
+///     .to_owned();
+///
+/// assert_eq!(
+///     table.to_string(PostgresQueryBuilder),
+///     r#"TRUNCATE TABLE "glyph", "character" RESTART IDENTITY CASCADE"#
 /// );
 /// ```
 #[derive(Debug, Clone)]
 pub struct TableTruncateStatement {
-    pub(crate) table: Option<DynIden>,
+    pub(crate) tables: Vec<DynIden>,
+    pub(crate) identity: Option<TableTruncateIdentity>,
+    pub(crate) options: Vec<TableDropOpt>,
+}
+
+/// What to do with the sequences owned by the truncated tables
+#[derive(Debug, Clone)]
+pub enum TableTruncateIdentity {
+    Restart,
+    Continue,
 }
 
 impl Default for TableTruncateStatement {
@@ -38,21 +64,51 @@ impl Default for TableTruncateStatement {
 impl TableTruncateStatement {
     /// Construct truncate table statement
     pub fn new() -> Self {
-        Self { table: None }
+        Self {
+            tables: Vec::new(),
+            identity: None,
+            options: Vec::new(),
+        }
     }
 
-    /// Set table name
+    /// Set table name. Only Postgres truncates more than one table at once.
     pub fn table<T: 'static>(&mut self, table: T) -> &mut Self
     where
         T: Iden,
     {
-        self.table = Some(SeaRc::new(table));
+        self.tables.push(SeaRc::new(table));
+        self
+    }
+
+    /// Reset the sequences owned by the truncated tables. Postgres only.
+    pub fn restart_identity(&mut self) -> &mut Self {
+        self.identity = Some(TableTruncateIdentity::Restart);
+        self
+    }
+
+    /// Keep the sequences owned by the truncated tables unchanged. Postgres only.
+    pub fn continue_identity(&mut self) -> &mut Self {
+        self.identity = Some(TableTruncateIdentity::Continue);
+        self
+    }
+
+    /// Truncate option restrict. Postgres only.
+    pub fn restrict(&mut self) -> &mut Self {
+        self.options.push(TableDropOpt::Restrict);
+        self
+    }
+
+    /// Truncate option cascade. Postgres only.
+    pub fn cascade(&mut self) -> &mut Self {
+        self.options.push(TableDropOpt::Cascade);
         self
     }
 
     pub fn take(&mut self) -> Self {
         Self {
-            table: self.table.take(),
+            tables: std::mem::take(&mut self.tables),
+            identity: self.identity.take(),
+            options: std::mem::take(&mut self.options),
         }
     }
 }
@@ -0,0 +1,78 @@
+use crate::{
+    backend::TransactionBuilder, prepare::*, TransactionAccessMode, TransactionIsolationLevel,
+};
+
+/// Start a transaction
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let begin = Transaction::begin()
+///     .isolation_level(TransactionIsolationLevel::Serializable)
+///     .access_mode(TransactionAccessMode::ReadOnly)
+///     .to_owned();
+///
+/// assert_eq!(
+///     begin.to_string(PostgresQueryBuilder),
+///     r#"BEGIN ISOLATION LEVEL SERIALIZABLE, READ ONLY"#
+/// );
+///
+/// let begin = Transaction::begin()
+///     .access_mode(TransactionAccessMode::ReadOnly)
+///     .to_owned();
+///
+/// assert_eq!(
+///     begin.to_string(MysqlQueryBuilder),
+///     r#"START TRANSACTION READ ONLY"#
+/// );
+/// assert_eq!(
+///     Transaction::begin().to_string(SqliteQueryBuilder),
+///     r#"BEGIN"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TransactionBeginStatement {
+    pub(crate) isolation_level: Option<TransactionIsolationLevel>,
+    pub(crate) access_mode: Option<TransactionAccessMode>,
+}
+
+impl TransactionBeginStatement {
+    /// Construct a new [`TransactionBeginStatement`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set isolation level. Postgres only, use [`TransactionSetStatement`] for MySQL.
+    pub fn isolation_level(&mut self, level: TransactionIsolationLevel) -> &mut Self {
+        self.isolation_level = Some(level);
+        self
+    }
+
+    /// Set access mode. Not supported by Sqlite.
+    pub fn access_mode(&mut self, mode: TransactionAccessMode) -> &mut Self {
+        self.access_mode = Some(mode);
+        self
+    }
+
+    pub fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        self.build_any(&transaction_builder)
+    }
+
+    pub fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_begin_statement(self, &mut sql);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        self.build(transaction_builder)
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        self.build_any(transaction_builder)
+    }
+}
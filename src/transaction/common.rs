@@ -0,0 +1,15 @@
+/// Isolation level of a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionIsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+/// Access mode of a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionAccessMode {
+    ReadOnly,
+    ReadWrite,
+}
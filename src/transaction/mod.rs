@@ -0,0 +1,136 @@
+//! Transaction control statements.
+//!
+//! # Usage
+//!
+//! - Transaction Begin, see [`TransactionBeginStatement`]
+//! - Transaction Set, see [`TransactionSetStatement`]
+//! - Commit, rollback and savepoints, see [`TransactionStatement`]
+
+use crate::{backend::TransactionBuilder, prepare::*, types::*};
+
+mod begin;
+mod common;
+mod set;
+
+pub use begin::*;
+pub use common::*;
+pub use set::*;
+
+/// Shorthand for constructing any transaction statement
+#[derive(Debug, Clone)]
+pub struct Transaction;
+
+/// All available types of transaction statement
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// assert_eq!(
+///     Transaction::savepoint(Alias::new("before_import")).to_string(PostgresQueryBuilder),
+///     r#"SAVEPOINT "before_import""#
+/// );
+/// assert_eq!(
+///     Transaction::rollback_to(Alias::new("before_import")).to_string(MysqlQueryBuilder),
+///     r#"ROLLBACK TO SAVEPOINT `before_import`"#
+/// );
+/// assert_eq!(
+///     Transaction::release(Alias::new("before_import")).to_string(SqliteQueryBuilder),
+///     r#"RELEASE SAVEPOINT `before_import`"#
+/// );
+/// assert_eq!(
+///     Transaction::commit().to_string(SqliteQueryBuilder),
+///     r#"COMMIT"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub enum TransactionStatement {
+    Begin(TransactionBeginStatement),
+    Set(TransactionSetStatement),
+    Commit,
+    /// Roll back the transaction, or to a savepoint
+    Rollback(Option<DynIden>),
+    Savepoint(DynIden),
+    ReleaseSavepoint(DynIden),
+}
+
+impl Transaction {
+    /// Construct transaction [`TransactionBeginStatement`]
+    pub fn begin() -> TransactionBeginStatement {
+        TransactionBeginStatement::new()
+    }
+
+    /// Construct transaction [`TransactionSetStatement`]
+    pub fn set() -> TransactionSetStatement {
+        TransactionSetStatement::new()
+    }
+
+    /// Construct `COMMIT`
+    pub fn commit() -> TransactionStatement {
+        TransactionStatement::Commit
+    }
+
+    /// Construct `ROLLBACK`
+    pub fn rollback() -> TransactionStatement {
+        TransactionStatement::Rollback(None)
+    }
+
+    /// Construct `ROLLBACK TO SAVEPOINT`
+    pub fn rollback_to<T>(savepoint: T) -> TransactionStatement
+    where
+        T: IntoIden,
+    {
+        TransactionStatement::Rollback(Some(savepoint.into_iden()))
+    }
+
+    /// Construct `SAVEPOINT`
+    pub fn savepoint<T>(savepoint: T) -> TransactionStatement
+    where
+        T: IntoIden,
+    {
+        TransactionStatement::Savepoint(savepoint.into_iden())
+    }
+
+    /// Construct `RELEASE SAVEPOINT`
+    pub fn release<T>(savepoint: T) -> TransactionStatement
+    where
+        T: IntoIden,
+    {
+        TransactionStatement::ReleaseSavepoint(savepoint.into_iden())
+    }
+}
+
+impl TransactionStatement {
+    pub fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        self.build_any(&transaction_builder)
+    }
+
+    pub fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_statement(self, &mut sql);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        self.build(transaction_builder)
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        self.build_any(transaction_builder)
+    }
+}
+
+impl From<TransactionBeginStatement> for TransactionStatement {
+    fn from(begin: TransactionBeginStatement) -> Self {
+        Self::Begin(begin)
+    }
+}
+
+impl From<TransactionSetStatement> for TransactionStatement {
+    fn from(set: TransactionSetStatement) -> Self {
+        Self::Set(set)
+    }
+}
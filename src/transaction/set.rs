@@ -0,0 +1,69 @@
+use crate::{
+    backend::TransactionBuilder, prepare::*, TransactionAccessMode, TransactionIsolationLevel,
+};
+
+/// Set the characteristics of a transaction. Not supported by Sqlite.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let set = Transaction::set()
+///     .isolation_level(TransactionIsolationLevel::RepeatableRead)
+///     .access_mode(TransactionAccessMode::ReadWrite)
+///     .to_owned();
+///
+/// assert_eq!(
+///     set.to_string(MysqlQueryBuilder),
+///     r#"SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ WRITE"#
+/// );
+/// assert_eq!(
+///     set.to_string(PostgresQueryBuilder),
+///     r#"SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ WRITE"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TransactionSetStatement {
+    pub(crate) isolation_level: Option<TransactionIsolationLevel>,
+    pub(crate) access_mode: Option<TransactionAccessMode>,
+}
+
+impl TransactionSetStatement {
+    /// Construct a new [`TransactionSetStatement`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set isolation level
+    pub fn isolation_level(&mut self, level: TransactionIsolationLevel) -> &mut Self {
+        self.isolation_level = Some(level);
+        self
+    }
+
+    /// Set access mode
+    pub fn access_mode(&mut self, mode: TransactionAccessMode) -> &mut Self {
+        self.access_mode = Some(mode);
+        self
+    }
+
+    pub fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        self.build_any(&transaction_builder)
+    }
+
+    pub fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_set_statement(self, &mut sql);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        self.build(transaction_builder)
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        self.build_any(transaction_builder)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{types::*, QueryStatement};
+
+/// Time a trigger fires relative to its event
+#[derive(Debug, Clone)]
+pub enum TriggerTiming {
+    Before,
+    After,
+    /// Replace the event, on a view. Not supported by MySQL.
+    InsteadOf,
+}
+
+/// Event firing a trigger
+#[derive(Debug, Clone)]
+pub enum TriggerEvent {
+    Insert,
+    /// Update, restricted to the listed columns if any
+    Update(Vec<DynIden>),
+    Delete,
+    /// Postgres only
+    Truncate,
+}
+
+/// Whether a trigger fires once per affected row or once per statement
+#[derive(Debug, Clone)]
+pub enum TriggerScope {
+    Row,
+    /// Postgres only
+    Statement,
+}
+
+/// Action run by a trigger
+#[derive(Debug, Clone)]
+pub enum TriggerAction {
+    /// Call a trigger function. Postgres only.
+    Function(DynIden),
+    /// Run statements. Not supported by Postgres.
+    Body(Vec<QueryStatement>),
+}
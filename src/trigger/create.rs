@@ -0,0 +1,187 @@
+use crate::{
+    backend::SchemaBuilder, expr::*, prepare::*, types::*, QueryStatement, SchemaStatementBuilder,
+    TriggerAction, TriggerEvent, TriggerScope, TriggerTiming,
+};
+
+/// Create a trigger on a table
+///
+/// # Examples
+///
+/// Postgres calls a trigger function
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let trigger = Trigger::create()
+///     .name(Alias::new("glyph_audit"))
+///     .timing(TriggerTiming::After)
+///     .event(TriggerEvent::Insert)
+///     .event(TriggerEvent::Update(vec![Glyph::Image.into_iden()]))
+///     .table(Glyph::Table)
+///     .for_each_row()
+///     .when(Expr::cust("NEW.aspect > 0"))
+///     .execute_function(Alias::new("audit_glyph"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     trigger.to_string(PostgresQueryBuilder),
+///     r#"CREATE TRIGGER "glyph_audit" AFTER INSERT OR UPDATE OF "image" ON "glyph" FOR EACH ROW WHEN (NEW.aspect > 0) EXECUTE FUNCTION "audit_glyph"()"#
+/// );
+/// ```
+///
+/// MySQL and SQLite run statements
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let trigger = Trigger::create()
+///     .name(Alias::new("glyph_touch"))
+///     .timing(TriggerTiming::After)
+///     .event(TriggerEvent::Delete)
+///     .table(Glyph::Table)
+///     .for_each_row()
+///     .statement(
+///         Query::delete()
+///             .from_table(Char::Table)
+///             .and_where(
+///                 Expr::col(Char::FontId)
+///                     .into_simple_expr()
+///                     .equals(Expr::cust("OLD.id")),
+///             )
+///             .to_owned(),
+///     )
+///     .to_owned();
+///
+/// assert_eq!(
+///     trigger.to_string(MysqlQueryBuilder),
+///     r#"CREATE TRIGGER `glyph_touch` AFTER DELETE ON `glyph` FOR EACH ROW DELETE FROM `character` WHERE `font_id` = OLD.id"#
+/// );
+/// assert_eq!(
+///     trigger.to_string(SqliteQueryBuilder),
+///     r#"CREATE TRIGGER `glyph_touch` AFTER DELETE ON `glyph` FOR EACH ROW BEGIN DELETE FROM `character` WHERE `font_id` = OLD.id; END"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TriggerCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) table: Option<DynIden>,
+    pub(crate) timing: Option<TriggerTiming>,
+    pub(crate) events: Vec<TriggerEvent>,
+    pub(crate) scope: Option<TriggerScope>,
+    pub(crate) when: Option<SimpleExpr>,
+    pub(crate) action: Option<TriggerAction>,
+}
+
+impl Default for TriggerCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TriggerCreateStatement {
+    /// Construct a new [`TriggerCreateStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            table: None,
+            timing: None,
+            events: Vec::new(),
+            scope: None,
+            when: None,
+            action: None,
+        }
+    }
+
+    /// Set trigger name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set target table
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.table = Some(table.into_iden());
+        self
+    }
+
+    /// Set when the trigger fires relative to its events
+    pub fn timing(&mut self, timing: TriggerTiming) -> &mut Self {
+        self.timing = Some(timing);
+        self
+    }
+
+    /// Add an event firing the trigger. MySQL and SQLite allow a single event.
+    pub fn event(&mut self, event: TriggerEvent) -> &mut Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Fire once per affected row
+    pub fn for_each_row(&mut self) -> &mut Self {
+        self.scope = Some(TriggerScope::Row);
+        self
+    }
+
+    /// Fire once per statement. Postgres only.
+    pub fn for_each_statement(&mut self) -> &mut Self {
+        self.scope = Some(TriggerScope::Statement);
+        self
+    }
+
+    /// Fire only when the condition holds. Not supported by MySQL.
+    pub fn when(&mut self, condition: SimpleExpr) -> &mut Self {
+        self.when = Some(condition);
+        self
+    }
+
+    /// Call a trigger function. Postgres only.
+    pub fn execute_function<T>(&mut self, function: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.action = Some(TriggerAction::Function(function.into_iden()));
+        self
+    }
+
+    /// Add a statement to the body of the trigger. Not supported by Postgres.
+    pub fn statement<Q>(&mut self, statement: Q) -> &mut Self
+    where
+        Q: Into<QueryStatement>,
+    {
+        match &mut self.action {
+            Some(TriggerAction::Body(statements)) => statements.push(statement.into()),
+            _ => self.action = Some(TriggerAction::Body(vec![statement.into()])),
+        }
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            table: self.table.take(),
+            timing: self.timing.take(),
+            events: std::mem::take(&mut self.events),
+            scope: self.scope.take(),
+            when: self.when.take(),
+            action: self.action.take(),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for TriggerCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_trigger_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_trigger_create_statement(self, &mut sql);
+        sql.result()
+    }
+}
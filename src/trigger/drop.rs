@@ -0,0 +1,89 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Drop a trigger
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let trigger = Trigger::drop()
+///     .if_exists()
+///     .name(Alias::new("glyph_audit"))
+///     .table(Glyph::Table)
+///     .to_owned();
+///
+/// assert_eq!(
+///     trigger.to_string(MysqlQueryBuilder),
+///     r#"DROP TRIGGER IF EXISTS `glyph_audit`"#
+/// );
+/// assert_eq!(
+///     trigger.to_string(PostgresQueryBuilder),
+///     r#"DROP TRIGGER IF EXISTS "glyph_audit" ON "glyph""#
+/// );
+/// assert_eq!(
+///     trigger.to_string(SqliteQueryBuilder),
+///     r#"DROP TRIGGER IF EXISTS `glyph_audit`"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TriggerDropStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) table: Option<DynIden>,
+    pub(crate) if_exists: bool,
+}
+
+impl Default for TriggerDropStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TriggerDropStatement {
+    /// Construct a new [`TriggerDropStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            table: None,
+            if_exists: false,
+        }
+    }
+
+    /// Set trigger name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set target table, required by Postgres
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.table = Some(table.into_iden());
+        self
+    }
+
+    /// Drop trigger only if it exists
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+impl SchemaStatementBuilder for TriggerDropStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_trigger_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_trigger_drop_statement(self, &mut sql);
+        sql.result()
+    }
+}
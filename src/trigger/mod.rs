@@ -0,0 +1,38 @@
+//! Trigger definition statements.
+//!
+//! # Usage
+//!
+//! - Trigger Create, see [`TriggerCreateStatement`]
+//! - Trigger Drop, see [`TriggerDropStatement`]
+
+mod common;
+mod create;
+mod drop;
+mod shim;
+
+pub use common::*;
+pub use create::*;
+pub use drop::*;
+
+/// Shorthand for constructing any trigger statement
+#[derive(Debug, Clone)]
+pub struct Trigger;
+
+/// All available types of trigger statement
+#[derive(Debug, Clone)]
+pub enum TriggerStatement {
+    Create(TriggerCreateStatement),
+    Drop(TriggerDropStatement),
+}
+
+impl Trigger {
+    /// Construct trigger [`TriggerCreateStatement`]
+    pub fn create() -> TriggerCreateStatement {
+        TriggerCreateStatement::new()
+    }
+
+    /// Construct trigger [`TriggerDropStatement`]
+    pub fn drop() -> TriggerDropStatement {
+        TriggerDropStatement::new()
+    }
+}
@@ -0,0 +1,4 @@
+use crate::impl_schema_statement_builder;
+
+impl_schema_statement_builder!(trigger_create_statement_builder, TriggerCreateStatement);
+impl_schema_statement_builder!(trigger_drop_statement_builder, TriggerDropStatement);
@@ -1,6 +1,6 @@
 //! Base types used throughout sea-query.
 
-use crate::{expr::*, query::*};
+use crate::{expr::*, query::*, table::ColumnType, value::*};
 use std::fmt;
 
 #[cfg(not(feature = "thread-safe"))]
@@ -57,11 +57,22 @@ impl fmt::Debug for dyn Iden {
     }
 }
 
+/// Identifiers are equal if they have the same name
+impl PartialEq for dyn Iden {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
 /// Column references
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColumnRef {
-    Column(DynIden),
-    TableColumn(DynIden, DynIden),
+    Column(#[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden),
+    TableColumn(
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+    ),
 }
 
 pub trait IntoColumnRef {
@@ -70,13 +81,46 @@ pub trait IntoColumnRef {
 
 /// Table references
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableRef {
-    Table(DynIden),
-    SchemaTable(DynIden, DynIden),
-    TableAlias(DynIden, DynIden),
-    SchemaTableAlias(DynIden, DynIden, DynIden),
-    SubQuery(SelectStatement, DynIden),
+    Table(#[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden),
+    SchemaTable(
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+    ),
+    TableAlias(
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+    ),
+    SchemaTableAlias(
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+    ),
+    /// Table qualified by database (or project) and schema (or dataset)
+    DatabaseSchemaTable(
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+    ),
+    DatabaseSchemaTableAlias(
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+    ),
+    SubQuery(
+        SelectStatement,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+    ),
+    /// `VALUES` list with alias and column names
+    ValuesList(
+        Vec<Values>,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden,
+        #[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde::vec"))]
+        Vec<DynIden>,
+    ),
 }
 
 pub trait IntoTableRef {
@@ -85,12 +129,20 @@ pub trait IntoTableRef {
 
 /// Unary operator
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnOper {
     Not,
+    Exists,
+    Any,
+    All,
+    /// `DISTINCT` modifier of an aggregate function argument
+    Distinct,
+    BitNot,
 }
 
 /// Binary operator
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BinOper {
     And,
     Or,
@@ -98,6 +150,8 @@ pub enum BinOper {
     NotLike,
     Is,
     IsNot,
+    IsDistinctFrom,
+    IsNotDistinctFrom,
     In,
     NotIn,
     Between,
@@ -112,16 +166,42 @@ pub enum BinOper {
     Sub,
     Mul,
     Div,
+    BitAnd,
+    BitOr,
+    BitXor,
+    LShift,
+    RShift,
+    Concat,
+    Escape,
+    Regexp,
+    NotRegexp,
+    IRegexp,
+    NotIRegexp,
+    GetJsonField,
+    CastJsonField,
     #[cfg(feature = "backend-postgres")]
     Matches,
     #[cfg(feature = "backend-postgres")]
     Contains,
     #[cfg(feature = "backend-postgres")]
     Contained,
+    #[cfg(feature = "backend-postgres")]
+    GetJsonPath,
+    #[cfg(feature = "backend-postgres")]
+    CastJsonPath,
+    #[cfg(feature = "backend-postgres")]
+    HasKey,
+    #[cfg(feature = "backend-postgres")]
+    Overlap,
+    #[cfg(feature = "backend-postgres")]
+    ILike,
+    #[cfg(feature = "backend-postgres")]
+    NotILike,
 }
 
 /// Logical chain operator
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LogicalChainOper {
     And(SimpleExpr),
     Or(SimpleExpr),
@@ -129,47 +209,171 @@ pub enum LogicalChainOper {
 
 /// Join types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JoinType {
     Join,
+    CrossJoin,
     InnerJoin,
     LeftJoin,
     RightJoin,
+    FullOuterJoin,
 }
 
 /// Order expression
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderExpr {
     pub(crate) expr: SimpleExpr,
     pub(crate) order: Order,
+    pub(crate) nulls: Option<NullOrdering>,
+    pub(crate) field: Option<Values>,
 }
 
 /// Join on types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JoinOn {
-    Condition(Box<SimpleExpr>),
+    Condition(Box<Condition>),
     Columns(Vec<SimpleExpr>),
 }
 
+/// Target type of a `CAST` expression
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CastType {
+    /// Column type, translated to a cast target supported by the backend
+    Column(ColumnType),
+    /// Type name as is
+    Custom(#[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden),
+}
+
 /// Ordering options
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Order {
     Asc,
     Desc,
 }
 
+/// Ordering of nulls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NullOrdering {
+    First,
+    Last,
+}
+
+/// Full text search mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SearchMode {
+    /// Match words of the query, without operators
+    NaturalLanguage,
+    /// Query with operators, e.g. `+a -b` in MySQL or `a & !b` in Postgres
+    Boolean,
+}
+
 /// Helper for create name alias
 #[derive(Debug, Clone)]
 pub struct Alias(String);
 
+/// Helper for create name alias written as is, without quotes, e.g. `CURRENT_TIMESTAMP`
+#[derive(Debug, Clone)]
+pub struct UnquotedAlias(String);
+
 /// Null Alias
 #[derive(Debug, Copy, Clone)]
 pub struct NullAlias;
 
 /// Common SQL Keywords
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Keyword {
     Null,
-    Custom(DynIden),
+    Default,
+    CurrentTimestamp,
+    CurrentDate,
+    CurrentTime,
+    Custom(#[cfg_attr(feature = "with-serde", serde(with = "crate::types::iden_serde"))] DynIden),
+}
+
+/// (De)serialize identifiers by name; deserialized identifiers are [`Alias`]
+#[cfg(feature = "with-serde")]
+pub(crate) mod iden_serde {
+    use super::{Alias, DynIden, SeaRc};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    fn alias(name: String) -> DynIden {
+        SeaRc::new(Alias(name))
+    }
+
+    pub fn serialize<S: Serializer>(iden: &DynIden, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&iden.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DynIden, D::Error> {
+        String::deserialize(deserializer).map(alias)
+    }
+
+    pub mod vec {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            idens: &[DynIden],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(idens.iter().map(|iden| iden.to_string()))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<DynIden>, D::Error> {
+            Vec::<String>::deserialize(deserializer)
+                .map(|names| names.into_iter().map(alias).collect())
+        }
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            iden: &Option<DynIden>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            iden.as_ref()
+                .map(|iden| iden.to_string())
+                .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<DynIden>, D::Error> {
+            Option::<String>::deserialize(deserializer).map(|name| name.map(alias))
+        }
+    }
+
+    /// Identifiers paired with the items they name
+    pub mod vec_named {
+        use super::*;
+
+        pub fn serialize<S: Serializer, T: Serialize>(
+            named: &[(DynIden, T)],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(named.iter().map(|(iden, item)| (iden.to_string(), item)))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<(DynIden, T)>, D::Error> {
+            Vec::<(String, T)>::deserialize(deserializer).map(|named| {
+                named
+                    .into_iter()
+                    .map(|(name, item)| (alias(name), item))
+                    .collect()
+            })
+        }
+    }
 }
 
 // Impl begins
@@ -189,6 +393,18 @@ impl IntoIden for DynIden {
     }
 }
 
+impl IntoIden for &str {
+    fn into_iden(self) -> DynIden {
+        SeaRc::new(Alias::new(self))
+    }
+}
+
+impl IntoIden for String {
+    fn into_iden(self) -> DynIden {
+        SeaRc::new(Alias(self))
+    }
+}
+
 impl<I> IdenList for I
 where
     I: IntoIden,
@@ -275,6 +491,17 @@ where
     }
 }
 
+impl<S: 'static, T: 'static, U: 'static> IntoTableRef for (S, T, U)
+where
+    S: IntoIden,
+    T: IntoIden,
+    U: IntoIden,
+{
+    fn into_table_ref(self) -> TableRef {
+        TableRef::DatabaseSchemaTable(self.0.into_iden(), self.1.into_iden(), self.2.into_iden())
+    }
+}
+
 impl TableRef {
     /// Add or replace the current alias
     pub fn alias<A: 'static>(self, alias: A) -> Self
@@ -290,9 +517,84 @@ impl TableRef {
             Self::SchemaTableAlias(schema, table, _) => {
                 Self::SchemaTableAlias(schema, table, alias.into_iden())
             }
+            Self::DatabaseSchemaTable(database, schema, table) => {
+                Self::DatabaseSchemaTableAlias(database, schema, table, alias.into_iden())
+            }
+            Self::DatabaseSchemaTableAlias(database, schema, table, _) => {
+                Self::DatabaseSchemaTableAlias(database, schema, table, alias.into_iden())
+            }
             Self::SubQuery(statement, _) => Self::SubQuery(statement, alias.into_iden()),
+            Self::ValuesList(rows, _, cols) => Self::ValuesList(rows, alias.into_iden(), cols),
         }
     }
+
+    /// Construct a table from a `VALUES` list, with an alias and names for its columns.
+    /// SQLite does not support naming the columns of a `VALUES` list; it is translated to
+    /// a `UNION ALL` of `SELECT`s instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column((Char::Table, Char::Character))
+    ///     .column((Alias::new("v"), Alias::new("label")))
+    ///     .from(Char::Table)
+    ///     .join(
+    ///         JoinType::InnerJoin,
+    ///         TableRef::values_list(
+    ///             vec![
+    ///                 Values(vec![1.into(), "a".into()]),
+    ///                 Values(vec![2.into(), "b".into()]),
+    ///             ],
+    ///             Alias::new("v"),
+    ///             vec![Alias::new("id"), Alias::new("label")],
+    ///         ),
+    ///         Expr::tbl(Char::Table, Char::Id).equals(Alias::new("v"), Alias::new("id")),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT `character`.`character`, `v`.`label` FROM `character` INNER JOIN (VALUES ROW(1, 'a'), ROW(2, 'b')) AS `v` (`id`, `label`) ON `character`.`id` = `v`.`id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "character"."character", "v"."label" FROM "character" INNER JOIN (VALUES (1, 'a'), (2, 'b')) AS "v" ("id", "label") ON "character"."id" = "v"."id""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT `character`.`character`, `v`.`label` FROM `character` INNER JOIN (SELECT 1 AS `id`, 'a' AS `label` UNION ALL SELECT 2, 'b') AS `v` ON `character`.`id` = `v`.`id`"#
+    /// );
+    /// ```
+    pub fn values_list<A, C, I>(rows: Vec<Values>, alias: A, cols: I) -> Self
+    where
+        A: IntoIden,
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+    {
+        Self::ValuesList(
+            rows,
+            alias.into_iden(),
+            cols.into_iter().map(|col| col.into_iden()).collect(),
+        )
+    }
+}
+
+impl From<ColumnType> for CastType {
+    fn from(column_type: ColumnType) -> Self {
+        CastType::Column(column_type)
+    }
+}
+
+impl<T: 'static> From<T> for CastType
+where
+    T: IntoIden,
+{
+    fn from(name: T) -> Self {
+        CastType::Custom(name.into_iden())
+    }
 }
 
 impl Alias {
@@ -307,6 +609,22 @@ impl Iden for Alias {
     }
 }
 
+impl UnquotedAlias {
+    pub fn new(n: &str) -> Self {
+        Self(n.to_owned())
+    }
+}
+
+impl Iden for UnquotedAlias {
+    fn prepare(&self, s: &mut dyn fmt::Write, _q: char) {
+        self.unquoted(s);
+    }
+
+    fn unquoted(&self, s: &mut dyn fmt::Write) {
+        write!(s, "{}", self.0).unwrap();
+    }
+}
+
 impl NullAlias {
     pub fn new() -> Self {
         Self
@@ -376,4 +694,71 @@ mod tests {
             r#"SELECT "hel""""lo""#
         );
     }
+
+    #[test]
+    fn test_string_identifier() {
+        let query = Query::select()
+            .column(("glyph", String::from("hel`lo")))
+            .from("glyph")
+            .to_owned();
+
+        #[cfg(feature = "backend-mysql")]
+        assert_eq!(
+            query.to_string(MysqlQueryBuilder),
+            r#"SELECT `glyph`.`hel``lo` FROM `glyph`"#
+        );
+        #[cfg(feature = "backend-postgres")]
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            r#"SELECT "glyph"."hel`lo" FROM "glyph""#
+        );
+    }
+
+    #[test]
+    fn test_unquoted_identifier() {
+        let query = Query::select()
+            .column(UnquotedAlias::new("CURRENT_DATE"))
+            .to_owned();
+
+        #[cfg(feature = "backend-mysql")]
+        assert_eq!(query.to_string(MysqlQueryBuilder), r#"SELECT CURRENT_DATE"#);
+        #[cfg(feature = "backend-sqlite")]
+        assert_eq!(
+            query.to_string(SqliteQueryBuilder),
+            r#"SELECT CURRENT_DATE"#
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "with-serde", feature = "with-json"))]
+    fn test_serde_round_trip() {
+        use crate::tests_cfg::*;
+
+        let query = Query::select()
+            .column((Char::Table, Char::Character))
+            .expr_as(Func::count(Expr::col(Char::Id)), Alias::new("count"))
+            .from(Char::Table)
+            .and_where(Expr::col(Char::SizeW).is_in(vec![3, 4]))
+            .and_where(Expr::col(Char::Character).like("A%"))
+            .group_by_col(Char::Character)
+            .window(
+                Alias::new("w"),
+                WindowStatement::partition_by(Char::FontSize),
+            )
+            .order_by(Char::Character, Order::Desc)
+            .limit(10)
+            .to_owned();
+
+        let json = serde_json::to_string(&query).unwrap();
+        let restored: SelectStatement = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            restored.build(PostgresQueryBuilder),
+            query.build(PostgresQueryBuilder)
+        );
+        assert_eq!(
+            restored.to_string(MysqlQueryBuilder),
+            query.to_string(MysqlQueryBuilder)
+        );
+    }
 }
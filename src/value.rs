@@ -1,4 +1,6 @@
 //! Container for all SQL value types.
+use crate::error::{Error, Result};
+use std::convert::TryFrom;
 use std::fmt::Write;
 
 #[cfg(feature = "with-json")]
@@ -7,7 +9,10 @@ use serde_json::Value as Json;
 use std::str::from_utf8;
 
 #[cfg(feature = "with-chrono")]
-use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+#[cfg(feature = "with-time")]
+use time::{OffsetDateTime, PrimitiveDateTime};
 
 #[cfg(feature = "with-rust_decimal")]
 use rust_decimal::Decimal;
@@ -18,10 +23,17 @@ use bigdecimal::BigDecimal;
 #[cfg(feature = "with-uuid")]
 use uuid::Uuid;
 
+#[cfg(feature = "with-ipnetwork")]
+use ipnetwork::IpNetwork;
+
+#[cfg(feature = "with-mac_address")]
+use mac_address::MacAddress;
+
 /// Value variants
 ///
 /// We want Value to be exactly 1 pointer sized, so anything larger should be boxed.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Value {
     Bool(Option<bool>),
     TinyInt(Option<i8>),
@@ -39,6 +51,9 @@ pub enum Value {
     #[allow(clippy::box_vec)]
     Bytes(Option<Box<Vec<u8>>>),
 
+    #[allow(clippy::box_vec)]
+    Array(Option<Box<Vec<Value>>>),
+
     #[cfg(feature = "with-json")]
     #[cfg_attr(docsrs, doc(cfg(feature = "with-json")))]
     Json(Option<Box<Json>>),
@@ -55,10 +70,34 @@ pub enum Value {
     #[cfg_attr(docsrs, doc(cfg(feature = "with-chrono")))]
     DateTime(Option<Box<NaiveDateTime>>),
 
+    #[cfg(feature = "with-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-chrono")))]
+    DateTimeUtc(Option<Box<DateTime<Utc>>>),
+
+    #[cfg(feature = "with-chrono")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-chrono")))]
+    DateTimeLocal(Option<Box<DateTime<Local>>>),
+
     #[cfg(feature = "with-chrono")]
     #[cfg_attr(docsrs, doc(cfg(feature = "with-chrono")))]
     DateTimeWithTimeZone(Option<Box<DateTime<FixedOffset>>>),
 
+    #[cfg(feature = "with-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-time")))]
+    TimeDate(Option<Box<time::Date>>),
+
+    #[cfg(feature = "with-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-time")))]
+    TimeTime(Option<Box<time::Time>>),
+
+    #[cfg(feature = "with-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-time")))]
+    TimeDateTime(Option<Box<PrimitiveDateTime>>),
+
+    #[cfg(feature = "with-time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-time")))]
+    TimeDateTimeWithTimeZone(Option<Box<OffsetDateTime>>),
+
     #[cfg(feature = "with-uuid")]
     #[cfg_attr(docsrs, doc(cfg(feature = "with-uuid")))]
     Uuid(Option<Box<Uuid>>),
@@ -70,10 +109,23 @@ pub enum Value {
     #[cfg(feature = "with-bigdecimal")]
     #[cfg_attr(docsrs, doc(cfg(feature = "with-bigdecimal")))]
     BigDecimal(Option<Box<BigDecimal>>),
+
+    #[cfg(feature = "with-ipnetwork")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-ipnetwork")))]
+    IpNetwork(Option<Box<IpNetwork>>),
+
+    #[cfg(feature = "with-mac_address")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "with-mac_address")))]
+    MacAddress(Option<Box<MacAddress>>),
 }
 
-pub trait ValueType: ValueTypeDefault {
-    fn unwrap(v: Value) -> Self;
+/// Conversion from [`Value`] into a Rust type.
+pub trait ValueType: ValueTypeDefault + Sized {
+    fn try_unwrap(v: Value) -> Result<Self>;
+
+    fn unwrap(v: Value) -> Self {
+        Self::try_unwrap(v).unwrap_or_else(|e| panic!("{}", e))
+    }
 
     fn type_name() -> &'static str;
 }
@@ -83,6 +135,7 @@ pub trait ValueTypeDefault {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Values(pub Vec<Value>);
 
 #[derive(Debug, PartialEq)]
@@ -103,6 +156,92 @@ impl Value {
     {
         T::unwrap(self)
     }
+
+    /// Extract the inner value, returning [`Error::TypeMismatch`] instead of panicking
+    /// if the variant does not match the requested type.
+    pub fn try_unwrap<T>(self) -> Result<T>
+    where
+        T: ValueType,
+    {
+        T::try_unwrap(self)
+    }
+
+    fn type_mismatch<T>(&self) -> Error
+    where
+        T: ValueType,
+    {
+        self.variant_mismatch(T::type_name())
+    }
+
+    fn variant_mismatch(&self, expected: &'static str) -> Error {
+        Error::TypeMismatch {
+            expected,
+            got: self.variant_name().to_owned(),
+        }
+    }
+
+    fn variant_name(&self) -> &'static str {
+        match self {
+            Self::Bool(_) => "Bool",
+            Self::TinyInt(_) => "TinyInt",
+            Self::SmallInt(_) => "SmallInt",
+            Self::Int(_) => "Int",
+            Self::BigInt(_) => "BigInt",
+            Self::TinyUnsigned(_) => "TinyUnsigned",
+            Self::SmallUnsigned(_) => "SmallUnsigned",
+            Self::Unsigned(_) => "Unsigned",
+            Self::BigUnsigned(_) => "BigUnsigned",
+            Self::Float(_) => "Float",
+            Self::Double(_) => "Double",
+            Self::String(_) => "String",
+            Self::Bytes(_) => "Bytes",
+            Self::Array(_) => "Array",
+            #[cfg(feature = "with-json")]
+            Self::Json(_) => "Json",
+            #[cfg(feature = "with-chrono")]
+            Self::Date(_) => "Date",
+            #[cfg(feature = "with-chrono")]
+            Self::Time(_) => "Time",
+            #[cfg(feature = "with-chrono")]
+            Self::DateTime(_) => "DateTime",
+            #[cfg(feature = "with-chrono")]
+            Self::DateTimeUtc(_) => "DateTimeUtc",
+            #[cfg(feature = "with-chrono")]
+            Self::DateTimeLocal(_) => "DateTimeLocal",
+            #[cfg(feature = "with-chrono")]
+            Self::DateTimeWithTimeZone(_) => "DateTimeWithTimeZone",
+            #[cfg(feature = "with-time")]
+            Self::TimeDate(_) => "TimeDate",
+            #[cfg(feature = "with-time")]
+            Self::TimeTime(_) => "TimeTime",
+            #[cfg(feature = "with-time")]
+            Self::TimeDateTime(_) => "TimeDateTime",
+            #[cfg(feature = "with-time")]
+            Self::TimeDateTimeWithTimeZone(_) => "TimeDateTimeWithTimeZone",
+            #[cfg(feature = "with-uuid")]
+            Self::Uuid(_) => "Uuid",
+            #[cfg(feature = "with-rust_decimal")]
+            Self::Decimal(_) => "Decimal",
+            #[cfg(feature = "with-bigdecimal")]
+            Self::BigDecimal(_) => "BigDecimal",
+            #[cfg(feature = "with-ipnetwork")]
+            Self::IpNetwork(_) => "IpNetwork",
+            #[cfg(feature = "with-mac_address")]
+            Self::MacAddress(_) => "MacAddress",
+        }
+    }
+}
+
+macro_rules! value_try_from {
+    ( $type: ty ) => {
+        impl TryFrom<Value> for $type {
+            type Error = Error;
+
+            fn try_from(v: Value) -> Result<Self> {
+                <$type as ValueType>::try_unwrap(v)
+            }
+        }
+    };
 }
 
 macro_rules! type_to_value {
@@ -120,10 +259,10 @@ macro_rules! type_to_value {
         }
 
         impl ValueType for $type {
-            fn unwrap(v: Value) -> Self {
+            fn try_unwrap(v: Value) -> Result<Self> {
                 match v {
-                    Value::$name(Some(x)) => x,
-                    _ => panic!("type error"),
+                    Value::$name(Some(x)) => Ok(x),
+                    _ => Err(v.type_mismatch::<Self>()),
                 }
             }
 
@@ -142,10 +281,10 @@ macro_rules! type_to_value {
         }
 
         impl ValueType for Option<$type> {
-            fn unwrap(v: Value) -> Self {
+            fn try_unwrap(v: Value) -> Result<Self> {
                 match v {
-                    Value::$name(x) => x,
-                    _ => panic!("type error"),
+                    Value::$name(x) => Ok(x),
+                    _ => Err(v.type_mismatch::<Self>()),
                 }
             }
 
@@ -159,6 +298,9 @@ macro_rules! type_to_value {
                 Default::default()
             }
         }
+
+        value_try_from!($type);
+        value_try_from!(Option<$type>);
     };
 }
 
@@ -179,11 +321,17 @@ macro_rules! type_to_box_value {
             }
         }
 
+        box_value_type!($type, $name);
+    };
+}
+
+macro_rules! box_value_type {
+    ( $type: ty, $name: ident ) => {
         impl ValueType for $type {
-            fn unwrap(v: Value) -> Self {
+            fn try_unwrap(v: Value) -> Result<Self> {
                 match v {
-                    Value::$name(Some(x)) => *x,
-                    _ => panic!("type error"),
+                    Value::$name(Some(x)) => Ok(*x),
+                    _ => Err(v.type_mismatch::<Self>()),
                 }
             }
 
@@ -193,11 +341,11 @@ macro_rules! type_to_box_value {
         }
 
         impl ValueType for Option<$type> {
-            fn unwrap(v: Value) -> Self {
+            fn try_unwrap(v: Value) -> Result<Self> {
                 match v {
-                    Value::$name(Some(x)) => Some(*x),
-                    Value::$name(None) => None,
-                    _ => panic!("type error"),
+                    Value::$name(Some(x)) => Ok(Some(*x)),
+                    Value::$name(None) => Ok(None),
+                    _ => Err(v.type_mismatch::<Self>()),
                 }
             }
 
@@ -211,6 +359,74 @@ macro_rules! type_to_box_value {
                 Default::default()
             }
         }
+
+        value_try_from!($type);
+        value_try_from!(Option<$type>);
+    };
+}
+
+macro_rules! type_to_array_value {
+    ( $type: ty ) => {
+        impl From<Vec<$type>> for Value {
+            fn from(x: Vec<$type>) -> Value {
+                Value::Array(Some(Box::new(x.into_iter().map(Into::into).collect())))
+            }
+        }
+
+        impl From<Option<Vec<$type>>> for Value {
+            fn from(x: Option<Vec<$type>>) -> Value {
+                match x {
+                    Some(v) => From::<Vec<$type>>::from(v),
+                    None => Value::Array(None),
+                }
+            }
+        }
+
+        impl ValueType for Vec<$type> {
+            fn try_unwrap(v: Value) -> Result<Self> {
+                match v {
+                    Value::Array(Some(x)) => x.into_iter().map(Value::try_unwrap).collect(),
+                    _ => Err(v.type_mismatch::<Self>()),
+                }
+            }
+
+            fn type_name() -> &'static str {
+                concat!("Vec<", stringify!($type), ">")
+            }
+        }
+
+        impl ValueTypeDefault for Vec<$type> {
+            fn default() -> Self {
+                Default::default()
+            }
+        }
+
+        impl ValueType for Option<Vec<$type>> {
+            fn try_unwrap(v: Value) -> Result<Self> {
+                match v {
+                    Value::Array(Some(x)) => x
+                        .into_iter()
+                        .map(Value::try_unwrap)
+                        .collect::<Result<_>>()
+                        .map(Some),
+                    Value::Array(None) => Ok(None),
+                    _ => Err(v.type_mismatch::<Self>()),
+                }
+            }
+
+            fn type_name() -> &'static str {
+                concat!("Option<Vec<", stringify!($type), ">>")
+            }
+        }
+
+        impl ValueTypeDefault for Option<Vec<$type>> {
+            fn default() -> Self {
+                Default::default()
+            }
+        }
+
+        value_try_from!(Vec<$type>);
+        value_try_from!(Option<Vec<$type>>);
     };
 }
 
@@ -254,6 +470,19 @@ impl_value_type_default!(Vec<u8>);
 type_to_box_value!(String, String);
 impl_value_type_default!(String);
 
+// `Vec<u8>` is treated as `Value::Bytes`, so it is not listed here
+type_to_array_value!(bool);
+type_to_array_value!(i8);
+type_to_array_value!(i16);
+type_to_array_value!(i32);
+type_to_array_value!(i64);
+type_to_array_value!(u16);
+type_to_array_value!(u32);
+type_to_array_value!(u64);
+type_to_array_value!(f32);
+type_to_array_value!(f64);
+type_to_array_value!(String);
+
 #[cfg(feature = "with-json")]
 #[cfg_attr(docsrs, doc(cfg(feature = "with-json")))]
 mod with_json {
@@ -261,6 +490,7 @@ mod with_json {
 
     type_to_box_value!(Json, Json);
     impl_value_type_default!(Json);
+    type_to_array_value!(Json);
 }
 
 #[cfg(feature = "with-chrono")]
@@ -291,24 +521,68 @@ mod with_chrono {
         }
     }
 
-    impl ValueTypeDefault for DateTime<FixedOffset> {
-        fn default() -> Self {
-            DateTime::from_utc(NaiveDateTime::from_timestamp(0, 0), FixedOffset::east(0))
-        }
-    }
+    /// `DateTime<Utc>` and `DateTime<Local>` are read from their own variants as well as from
+    /// `Value::DateTimeWithTimeZone`
+    macro_rules! date_time_value_type {
+        ( $tz: ident, $name: ident ) => {
+            impl ValueType for DateTime<$tz> {
+                fn try_unwrap(v: Value) -> Result<Self> {
+                    match v {
+                        Value::$name(Some(x)) => Ok(*x),
+                        Value::DateTimeWithTimeZone(Some(x)) => Ok(x.with_timezone(&$tz)),
+                        _ => Err(v.type_mismatch::<Self>()),
+                    }
+                }
 
-    impl ValueTypeDefault for Option<DateTime<FixedOffset>> {
-        fn default() -> Self {
-            Default::default()
-        }
+                fn type_name() -> &'static str {
+                    concat!("DateTime<", stringify!($tz), ">")
+                }
+            }
+
+            impl ValueType for Option<DateTime<$tz>> {
+                fn try_unwrap(v: Value) -> Result<Self> {
+                    match v {
+                        Value::$name(Some(x)) => Ok(Some(*x)),
+                        Value::DateTimeWithTimeZone(Some(x)) => Ok(Some(x.with_timezone(&$tz))),
+                        Value::$name(None) | Value::DateTimeWithTimeZone(None) => Ok(None),
+                        _ => Err(v.type_mismatch::<Self>()),
+                    }
+                }
+
+                fn type_name() -> &'static str {
+                    concat!("Option<DateTime<", stringify!($tz), ">>")
+                }
+            }
+
+            impl ValueTypeDefault for Option<DateTime<$tz>> {
+                fn default() -> Self {
+                    Default::default()
+                }
+            }
+
+            value_try_from!(DateTime<$tz>);
+            value_try_from!(Option<DateTime<$tz>>);
+        };
     }
 
+    date_time_value_type!(Utc, DateTimeUtc);
+    date_time_value_type!(Local, DateTimeLocal);
+    box_value_type!(DateTime<FixedOffset>, DateTimeWithTimeZone);
+    type_to_array_value!(NaiveDate);
+    type_to_array_value!(NaiveTime);
+    type_to_array_value!(NaiveDateTime);
+    type_to_array_value!(DateTime<Utc>);
+    type_to_array_value!(DateTime<Local>);
+    type_to_array_value!(DateTime<FixedOffset>);
+
+    /// Any time zone is stored with its offset, `Value::DateTimeUtc` and `Value::DateTimeLocal`
+    /// are constructed explicitly
     impl<Tz> From<DateTime<Tz>> for Value
     where
         Tz: TimeZone,
     {
         fn from(x: DateTime<Tz>) -> Value {
-            let v = DateTime::<FixedOffset>::from_utc(x.naive_utc(), x.offset().fix());
+            let v = x.with_timezone(&x.offset().fix());
             Value::DateTimeWithTimeZone(Some(Box::new(v)))
         }
     }
@@ -325,29 +599,82 @@ mod with_chrono {
         }
     }
 
-    impl ValueType for DateTime<FixedOffset> {
-        fn unwrap(v: Value) -> Self {
-            match v {
-                Value::DateTimeWithTimeZone(Some(x)) => *x,
-                _ => panic!("type error"),
-            }
+    impl ValueTypeDefault for DateTime<Utc> {
+        fn default() -> Self {
+            Utc.timestamp_opt(0, 0).unwrap()
         }
+    }
 
-        fn type_name() -> &'static str {
-            stringify!(DateTime<FixedOffset>)
+    impl ValueTypeDefault for DateTime<Local> {
+        fn default() -> Self {
+            Local.timestamp_opt(0, 0).unwrap()
         }
     }
 
-    impl ValueType for Option<DateTime<FixedOffset>> {
-        fn unwrap(v: Value) -> Self {
-            match v {
-                Value::DateTimeWithTimeZone(Some(x)) => Some(*x),
-                _ => panic!("type error"),
-            }
+    impl ValueTypeDefault for DateTime<FixedOffset> {
+        fn default() -> Self {
+            FixedOffset::east_opt(0)
+                .unwrap()
+                .timestamp_opt(0, 0)
+                .unwrap()
         }
+    }
+}
+
+#[cfg(feature = "with-time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-time")))]
+pub mod time_format {
+    use time::format_description::FormatItem;
+    use time::macros::format_description;
+
+    pub static FORMAT_DATE: &[FormatItem<'static>] = format_description!("[year]-[month]-[day]");
+    pub static FORMAT_TIME: &[FormatItem<'static>] =
+        format_description!("[hour]:[minute]:[second]");
+    pub static FORMAT_DATETIME: &[FormatItem<'static>] =
+        format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    pub static FORMAT_DATETIME_TZ: &[FormatItem<'static>] = format_description!(
+        "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour sign:mandatory]:[offset_minute]"
+    );
+    pub static FORMAT_DATETIME_TZ_ISO: &[FormatItem<'static>] = format_description!(
+        "[year]-[month]-[day] [hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
+    );
+}
 
-        fn type_name() -> &'static str {
-            stringify!(Option<DateTime<FixedOffset>>)
+#[cfg(feature = "with-time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-time")))]
+mod with_time {
+    use super::*;
+
+    type_to_box_value!(time::Date, TimeDate);
+    type_to_box_value!(time::Time, TimeTime);
+    type_to_box_value!(PrimitiveDateTime, TimeDateTime);
+    type_to_box_value!(OffsetDateTime, TimeDateTimeWithTimeZone);
+    type_to_array_value!(time::Date);
+    type_to_array_value!(time::Time);
+    type_to_array_value!(PrimitiveDateTime);
+    type_to_array_value!(OffsetDateTime);
+
+    impl ValueTypeDefault for time::Date {
+        fn default() -> Self {
+            time::Date::MIN
+        }
+    }
+
+    impl ValueTypeDefault for time::Time {
+        fn default() -> Self {
+            time::Time::MIDNIGHT
+        }
+    }
+
+    impl ValueTypeDefault for PrimitiveDateTime {
+        fn default() -> Self {
+            PrimitiveDateTime::new(time::Date::MIN, time::Time::MIDNIGHT)
+        }
+    }
+
+    impl ValueTypeDefault for OffsetDateTime {
+        fn default() -> Self {
+            OffsetDateTime::UNIX_EPOCH
         }
     }
 }
@@ -359,6 +686,7 @@ mod with_rust_decimal {
 
     type_to_box_value!(Decimal, Decimal);
     impl_value_type_default!(Decimal);
+    type_to_array_value!(Decimal);
 }
 
 #[cfg(feature = "with-bigdecimal")]
@@ -368,6 +696,7 @@ mod with_bigdecimal {
 
     type_to_box_value!(BigDecimal, BigDecimal);
     impl_value_type_default!(BigDecimal);
+    type_to_array_value!(BigDecimal);
 }
 
 #[cfg(feature = "with-uuid")]
@@ -377,6 +706,117 @@ mod with_uuid {
 
     type_to_box_value!(Uuid, Uuid);
     impl_value_type_default!(Uuid);
+    type_to_array_value!(Uuid);
+}
+
+#[cfg(feature = "with-ipnetwork")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-ipnetwork")))]
+mod with_ipnetwork {
+    use super::*;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    type_to_box_value!(IpNetwork, IpNetwork);
+    type_to_array_value!(IpNetwork);
+
+    impl ValueTypeDefault for IpNetwork {
+        fn default() -> Self {
+            IpNetwork::from(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+        }
+    }
+
+    impl From<IpAddr> for Value {
+        fn from(x: IpAddr) -> Value {
+            Value::IpNetwork(Some(Box::new(IpNetwork::from(x))))
+        }
+    }
+
+    impl From<Option<IpAddr>> for Value {
+        fn from(x: Option<IpAddr>) -> Value {
+            match x {
+                Some(v) => From::<IpAddr>::from(v),
+                None => Value::IpNetwork(None),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "with-mac_address")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-mac_address")))]
+mod with_mac_address {
+    use super::*;
+
+    type_to_box_value!(MacAddress, MacAddress);
+    type_to_array_value!(MacAddress);
+
+    impl ValueTypeDefault for MacAddress {
+        fn default() -> Self {
+            MacAddress::new([0; 6])
+        }
+    }
+}
+
+impl Value {
+    pub fn is_null(&self) -> bool {
+        match self {
+            Self::Bool(None)
+            | Self::TinyInt(None)
+            | Self::SmallInt(None)
+            | Self::Int(None)
+            | Self::BigInt(None)
+            | Self::TinyUnsigned(None)
+            | Self::SmallUnsigned(None)
+            | Self::Unsigned(None)
+            | Self::BigUnsigned(None)
+            | Self::Float(None)
+            | Self::Double(None)
+            | Self::String(None)
+            | Self::Bytes(None)
+            | Self::Array(None) => true,
+            #[cfg(feature = "with-json")]
+            Self::Json(None) => true,
+            #[cfg(feature = "with-chrono")]
+            Self::Date(None)
+            | Self::Time(None)
+            | Self::DateTime(None)
+            | Self::DateTimeUtc(None)
+            | Self::DateTimeLocal(None)
+            | Self::DateTimeWithTimeZone(None) => true,
+            #[cfg(feature = "with-time")]
+            Self::TimeDate(None)
+            | Self::TimeTime(None)
+            | Self::TimeDateTime(None)
+            | Self::TimeDateTimeWithTimeZone(None) => true,
+            #[cfg(feature = "with-uuid")]
+            Self::Uuid(None) => true,
+            #[cfg(feature = "with-rust_decimal")]
+            Self::Decimal(None) => true,
+            #[cfg(feature = "with-bigdecimal")]
+            Self::BigDecimal(None) => true,
+            #[cfg(feature = "with-ipnetwork")]
+            Self::IpNetwork(None) => true,
+            #[cfg(feature = "with-mac_address")]
+            Self::MacAddress(None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Value {
+    pub fn is_array(&self) -> bool {
+        matches!(self, Self::Array(_))
+    }
+    pub fn as_ref_array(&self) -> &Vec<Value> {
+        match self {
+            Self::Array(Some(v)) => v.as_ref(),
+            _ => panic!("not Value::Array"),
+        }
+    }
+    pub fn try_as_ref_array(&self) -> Result<Option<&Vec<Value>>> {
+        match self {
+            Self::Array(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("Array")),
+        }
+    }
 }
 
 impl Value {
@@ -397,6 +837,17 @@ impl Value {
     pub fn as_ref_json(&self) -> &bool {
         panic!("not Value::Json")
     }
+    #[cfg(feature = "with-json")]
+    pub fn try_as_ref_json(&self) -> Result<Option<&Json>> {
+        match self {
+            Self::Json(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("Json")),
+        }
+    }
+    #[cfg(not(feature = "with-json"))]
+    pub fn try_as_ref_json(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("Json"))
+    }
 }
 
 impl Value {
@@ -417,6 +868,17 @@ impl Value {
     pub fn as_ref_date(&self) -> &bool {
         panic!("not Value::Date")
     }
+    #[cfg(feature = "with-chrono")]
+    pub fn try_as_ref_date(&self) -> Result<Option<&NaiveDate>> {
+        match self {
+            Self::Date(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("Date")),
+        }
+    }
+    #[cfg(not(feature = "with-chrono"))]
+    pub fn try_as_ref_date(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("Date"))
+    }
 }
 
 impl Value {
@@ -437,6 +899,17 @@ impl Value {
     pub fn as_ref_time(&self) -> &bool {
         panic!("not Value::Time")
     }
+    #[cfg(feature = "with-chrono")]
+    pub fn try_as_ref_time(&self) -> Result<Option<&NaiveTime>> {
+        match self {
+            Self::Time(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("Time")),
+        }
+    }
+    #[cfg(not(feature = "with-chrono"))]
+    pub fn try_as_ref_time(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("Time"))
+    }
 }
 
 impl Value {
@@ -457,6 +930,79 @@ impl Value {
     pub fn as_ref_date_time(&self) -> &bool {
         panic!("not Value::DateTime")
     }
+    #[cfg(feature = "with-chrono")]
+    pub fn try_as_ref_date_time(&self) -> Result<Option<&NaiveDateTime>> {
+        match self {
+            Self::DateTime(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("DateTime")),
+        }
+    }
+    #[cfg(not(feature = "with-chrono"))]
+    pub fn try_as_ref_date_time(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("DateTime"))
+    }
+}
+
+impl Value {
+    pub fn is_date_time_utc(&self) -> bool {
+        #[cfg(feature = "with-chrono")]
+        return matches!(self, Self::DateTimeUtc(_));
+        #[cfg(not(feature = "with-chrono"))]
+        return false;
+    }
+    #[cfg(feature = "with-chrono")]
+    pub fn as_ref_date_time_utc(&self) -> &DateTime<Utc> {
+        match self {
+            Self::DateTimeUtc(Some(v)) => v.as_ref(),
+            _ => panic!("not Value::DateTimeUtc"),
+        }
+    }
+    #[cfg(not(feature = "with-chrono"))]
+    pub fn as_ref_date_time_utc(&self) -> &bool {
+        panic!("not Value::DateTimeUtc")
+    }
+    #[cfg(feature = "with-chrono")]
+    pub fn try_as_ref_date_time_utc(&self) -> Result<Option<&DateTime<Utc>>> {
+        match self {
+            Self::DateTimeUtc(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("DateTimeUtc")),
+        }
+    }
+    #[cfg(not(feature = "with-chrono"))]
+    pub fn try_as_ref_date_time_utc(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("DateTimeUtc"))
+    }
+}
+
+impl Value {
+    pub fn is_date_time_local(&self) -> bool {
+        #[cfg(feature = "with-chrono")]
+        return matches!(self, Self::DateTimeLocal(_));
+        #[cfg(not(feature = "with-chrono"))]
+        return false;
+    }
+    #[cfg(feature = "with-chrono")]
+    pub fn as_ref_date_time_local(&self) -> &DateTime<Local> {
+        match self {
+            Self::DateTimeLocal(Some(v)) => v.as_ref(),
+            _ => panic!("not Value::DateTimeLocal"),
+        }
+    }
+    #[cfg(not(feature = "with-chrono"))]
+    pub fn as_ref_date_time_local(&self) -> &bool {
+        panic!("not Value::DateTimeLocal")
+    }
+    #[cfg(feature = "with-chrono")]
+    pub fn try_as_ref_date_time_local(&self) -> Result<Option<&DateTime<Local>>> {
+        match self {
+            Self::DateTimeLocal(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("DateTimeLocal")),
+        }
+    }
+    #[cfg(not(feature = "with-chrono"))]
+    pub fn try_as_ref_date_time_local(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("DateTimeLocal"))
+    }
 }
 
 impl Value {
@@ -477,6 +1023,141 @@ impl Value {
     pub fn as_ref_date_time_with_time_zone(&self) -> &bool {
         panic!("not Value::DateTimeWithTimeZone")
     }
+    #[cfg(feature = "with-chrono")]
+    pub fn try_as_ref_date_time_with_time_zone(&self) -> Result<Option<&DateTime<FixedOffset>>> {
+        match self {
+            Self::DateTimeWithTimeZone(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("DateTimeWithTimeZone")),
+        }
+    }
+    #[cfg(not(feature = "with-chrono"))]
+    pub fn try_as_ref_date_time_with_time_zone(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("DateTimeWithTimeZone"))
+    }
+}
+
+impl Value {
+    pub fn is_time_date(&self) -> bool {
+        #[cfg(feature = "with-time")]
+        return matches!(self, Self::TimeDate(_));
+        #[cfg(not(feature = "with-time"))]
+        return false;
+    }
+    #[cfg(feature = "with-time")]
+    pub fn as_ref_time_date(&self) -> &time::Date {
+        match self {
+            Self::TimeDate(Some(v)) => v.as_ref(),
+            _ => panic!("not Value::TimeDate"),
+        }
+    }
+    #[cfg(not(feature = "with-time"))]
+    pub fn as_ref_time_date(&self) -> &bool {
+        panic!("not Value::TimeDate")
+    }
+    #[cfg(feature = "with-time")]
+    pub fn try_as_ref_time_date(&self) -> Result<Option<&time::Date>> {
+        match self {
+            Self::TimeDate(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("TimeDate")),
+        }
+    }
+    #[cfg(not(feature = "with-time"))]
+    pub fn try_as_ref_time_date(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("TimeDate"))
+    }
+}
+
+impl Value {
+    pub fn is_time_time(&self) -> bool {
+        #[cfg(feature = "with-time")]
+        return matches!(self, Self::TimeTime(_));
+        #[cfg(not(feature = "with-time"))]
+        return false;
+    }
+    #[cfg(feature = "with-time")]
+    pub fn as_ref_time_time(&self) -> &time::Time {
+        match self {
+            Self::TimeTime(Some(v)) => v.as_ref(),
+            _ => panic!("not Value::TimeTime"),
+        }
+    }
+    #[cfg(not(feature = "with-time"))]
+    pub fn as_ref_time_time(&self) -> &bool {
+        panic!("not Value::TimeTime")
+    }
+    #[cfg(feature = "with-time")]
+    pub fn try_as_ref_time_time(&self) -> Result<Option<&time::Time>> {
+        match self {
+            Self::TimeTime(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("TimeTime")),
+        }
+    }
+    #[cfg(not(feature = "with-time"))]
+    pub fn try_as_ref_time_time(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("TimeTime"))
+    }
+}
+
+impl Value {
+    pub fn is_time_date_time(&self) -> bool {
+        #[cfg(feature = "with-time")]
+        return matches!(self, Self::TimeDateTime(_));
+        #[cfg(not(feature = "with-time"))]
+        return false;
+    }
+    #[cfg(feature = "with-time")]
+    pub fn as_ref_time_date_time(&self) -> &PrimitiveDateTime {
+        match self {
+            Self::TimeDateTime(Some(v)) => v.as_ref(),
+            _ => panic!("not Value::TimeDateTime"),
+        }
+    }
+    #[cfg(not(feature = "with-time"))]
+    pub fn as_ref_time_date_time(&self) -> &bool {
+        panic!("not Value::TimeDateTime")
+    }
+    #[cfg(feature = "with-time")]
+    pub fn try_as_ref_time_date_time(&self) -> Result<Option<&PrimitiveDateTime>> {
+        match self {
+            Self::TimeDateTime(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("TimeDateTime")),
+        }
+    }
+    #[cfg(not(feature = "with-time"))]
+    pub fn try_as_ref_time_date_time(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("TimeDateTime"))
+    }
+}
+
+impl Value {
+    pub fn is_time_date_time_with_time_zone(&self) -> bool {
+        #[cfg(feature = "with-time")]
+        return matches!(self, Self::TimeDateTimeWithTimeZone(_));
+        #[cfg(not(feature = "with-time"))]
+        return false;
+    }
+    #[cfg(feature = "with-time")]
+    pub fn as_ref_time_date_time_with_time_zone(&self) -> &OffsetDateTime {
+        match self {
+            Self::TimeDateTimeWithTimeZone(Some(v)) => v.as_ref(),
+            _ => panic!("not Value::TimeDateTimeWithTimeZone"),
+        }
+    }
+    #[cfg(not(feature = "with-time"))]
+    pub fn as_ref_time_date_time_with_time_zone(&self) -> &bool {
+        panic!("not Value::TimeDateTimeWithTimeZone")
+    }
+    #[cfg(feature = "with-time")]
+    pub fn try_as_ref_time_date_time_with_time_zone(&self) -> Result<Option<&OffsetDateTime>> {
+        match self {
+            Self::TimeDateTimeWithTimeZone(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("TimeDateTimeWithTimeZone")),
+        }
+    }
+    #[cfg(not(feature = "with-time"))]
+    pub fn try_as_ref_time_date_time_with_time_zone(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("TimeDateTimeWithTimeZone"))
+    }
 }
 
 impl Value {
@@ -502,6 +1183,17 @@ impl Value {
     pub fn as_ref_decimal(&self) -> &bool {
         panic!("not Value::Decimal")
     }
+    #[cfg(feature = "with-rust_decimal")]
+    pub fn try_as_ref_decimal(&self) -> Result<Option<&Decimal>> {
+        match self {
+            Self::Decimal(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("Decimal")),
+        }
+    }
+    #[cfg(not(feature = "with-rust_decimal"))]
+    pub fn try_as_ref_decimal(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("Decimal"))
+    }
     #[cfg(not(feature = "with-rust_decimal"))]
     pub fn decimal_to_f64(&self) -> f64 {
         0.0
@@ -531,6 +1223,17 @@ impl Value {
     pub fn as_ref_big_decimal(&self) -> &bool {
         panic!("not Value::BigDecimal")
     }
+    #[cfg(feature = "with-bigdecimal")]
+    pub fn try_as_ref_big_decimal(&self) -> Result<Option<&BigDecimal>> {
+        match self {
+            Self::BigDecimal(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("BigDecimal")),
+        }
+    }
+    #[cfg(not(feature = "with-bigdecimal"))]
+    pub fn try_as_ref_big_decimal(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("BigDecimal"))
+    }
     #[cfg(not(feature = "with-bigdecimal"))]
     pub fn big_decimal_to_f64(&self) -> f64 {
         0.0
@@ -555,6 +1258,79 @@ impl Value {
     pub fn as_ref_uuid(&self) -> &bool {
         panic!("not Value::Uuid")
     }
+    #[cfg(feature = "with-uuid")]
+    pub fn try_as_ref_uuid(&self) -> Result<Option<&Uuid>> {
+        match self {
+            Self::Uuid(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("Uuid")),
+        }
+    }
+    #[cfg(not(feature = "with-uuid"))]
+    pub fn try_as_ref_uuid(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("Uuid"))
+    }
+}
+
+impl Value {
+    pub fn is_ip_network(&self) -> bool {
+        #[cfg(feature = "with-ipnetwork")]
+        return matches!(self, Self::IpNetwork(_));
+        #[cfg(not(feature = "with-ipnetwork"))]
+        return false;
+    }
+    #[cfg(feature = "with-ipnetwork")]
+    pub fn as_ref_ip_network(&self) -> &IpNetwork {
+        match self {
+            Self::IpNetwork(Some(v)) => v.as_ref(),
+            _ => panic!("not Value::IpNetwork"),
+        }
+    }
+    #[cfg(not(feature = "with-ipnetwork"))]
+    pub fn as_ref_ip_network(&self) -> &bool {
+        panic!("not Value::IpNetwork")
+    }
+    #[cfg(feature = "with-ipnetwork")]
+    pub fn try_as_ref_ip_network(&self) -> Result<Option<&IpNetwork>> {
+        match self {
+            Self::IpNetwork(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("IpNetwork")),
+        }
+    }
+    #[cfg(not(feature = "with-ipnetwork"))]
+    pub fn try_as_ref_ip_network(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("IpNetwork"))
+    }
+}
+
+impl Value {
+    pub fn is_mac_address(&self) -> bool {
+        #[cfg(feature = "with-mac_address")]
+        return matches!(self, Self::MacAddress(_));
+        #[cfg(not(feature = "with-mac_address"))]
+        return false;
+    }
+    #[cfg(feature = "with-mac_address")]
+    pub fn as_ref_mac_address(&self) -> &MacAddress {
+        match self {
+            Self::MacAddress(Some(v)) => v.as_ref(),
+            _ => panic!("not Value::MacAddress"),
+        }
+    }
+    #[cfg(not(feature = "with-mac_address"))]
+    pub fn as_ref_mac_address(&self) -> &bool {
+        panic!("not Value::MacAddress")
+    }
+    #[cfg(feature = "with-mac_address")]
+    pub fn try_as_ref_mac_address(&self) -> Result<Option<&MacAddress>> {
+        match self {
+            Self::MacAddress(v) => Ok(v.as_deref()),
+            _ => Err(self.variant_mismatch("MacAddress")),
+        }
+    }
+    #[cfg(not(feature = "with-mac_address"))]
+    pub fn try_as_ref_mac_address(&self) -> Result<Option<&bool>> {
+        Err(self.variant_mismatch("MacAddress"))
+    }
 }
 
 impl IntoIterator for ValueTuple {
@@ -665,6 +1441,7 @@ pub fn sea_value_to_json_value(value: &Value) -> Json {
         | Value::Double(None)
         | Value::String(None)
         | Value::Bytes(None)
+        | Value::Array(None)
         | Value::Json(None) => Json::Null,
         #[cfg(feature = "with-rust_decimal")]
         Value::Decimal(None) => Json::Null,
@@ -672,6 +1449,10 @@ pub fn sea_value_to_json_value(value: &Value) -> Json {
         Value::BigDecimal(None) => Json::Null,
         #[cfg(feature = "with-uuid")]
         Value::Uuid(None) => Json::Null,
+        #[cfg(feature = "with-ipnetwork")]
+        Value::IpNetwork(None) => Json::Null,
+        #[cfg(feature = "with-mac_address")]
+        Value::MacAddress(None) => Json::Null,
         Value::Bool(Some(b)) => Json::Bool(*b),
         Value::TinyInt(Some(v)) => (*v).into(),
         Value::SmallInt(Some(v)) => (*v).into(),
@@ -685,6 +1466,7 @@ pub fn sea_value_to_json_value(value: &Value) -> Json {
         Value::Double(Some(v)) => (*v).into(),
         Value::String(Some(s)) => Json::String(s.as_ref().clone()),
         Value::Bytes(Some(s)) => Json::String(from_utf8(s).unwrap().to_string()),
+        Value::Array(Some(v)) => Json::Array(v.iter().map(sea_value_to_json_value).collect()),
         Value::Json(Some(v)) => v.as_ref().clone(),
         #[cfg(feature = "with-chrono")]
         Value::Date(_) => CommonSqlQueryBuilder.value_to_string(value).into(),
@@ -693,7 +1475,19 @@ pub fn sea_value_to_json_value(value: &Value) -> Json {
         #[cfg(feature = "with-chrono")]
         Value::DateTime(_) => CommonSqlQueryBuilder.value_to_string(value).into(),
         #[cfg(feature = "with-chrono")]
+        Value::DateTimeUtc(_) => CommonSqlQueryBuilder.value_to_string(value).into(),
+        #[cfg(feature = "with-chrono")]
+        Value::DateTimeLocal(_) => CommonSqlQueryBuilder.value_to_string(value).into(),
+        #[cfg(feature = "with-chrono")]
         Value::DateTimeWithTimeZone(_) => CommonSqlQueryBuilder.value_to_string(value).into(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDate(_) => CommonSqlQueryBuilder.value_to_string(value).into(),
+        #[cfg(feature = "with-time")]
+        Value::TimeTime(_) => CommonSqlQueryBuilder.value_to_string(value).into(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTime(_) => CommonSqlQueryBuilder.value_to_string(value).into(),
+        #[cfg(feature = "with-time")]
+        Value::TimeDateTimeWithTimeZone(_) => CommonSqlQueryBuilder.value_to_string(value).into(),
         #[cfg(feature = "with-rust_decimal")]
         Value::Decimal(Some(v)) => {
             use rust_decimal::prelude::ToPrimitive;
@@ -706,6 +1500,10 @@ pub fn sea_value_to_json_value(value: &Value) -> Json {
         }
         #[cfg(feature = "with-uuid")]
         Value::Uuid(Some(v)) => Json::String(v.to_string()),
+        #[cfg(feature = "with-ipnetwork")]
+        Value::IpNetwork(Some(v)) => Json::String(v.to_string()),
+        #[cfg(feature = "with-mac_address")]
+        Value::MacAddress(Some(v)) => Json::String(v.to_string()),
     }
 }
 
@@ -886,6 +1684,87 @@ mod tests {
         assert_eq!(out, timestamp);
     }
 
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn test_chrono_utc_value() {
+        let timestamp = DateTime::parse_from_rfc3339("2020-01-01T02:02:02+08:00")
+            .unwrap()
+            .with_timezone(&Utc);
+        let value: Value = timestamp.into();
+        assert!(value.is_date_time_with_time_zone());
+        let out: DateTime<Utc> = value.unwrap();
+        assert_eq!(out, timestamp);
+
+        let value = Value::DateTimeUtc(Some(Box::new(timestamp)));
+        let out: DateTime<Utc> = value.unwrap();
+        assert_eq!(out, timestamp);
+
+        let value: Value = Option::<DateTime<Utc>>::None.into();
+        let out: Option<DateTime<Utc>> = value.unwrap();
+        assert_eq!(out, None);
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn test_chrono_local_value() {
+        let timestamp = DateTime::parse_from_rfc3339("2020-01-01T02:02:02+08:00")
+            .unwrap()
+            .with_timezone(&Local);
+        let value: Value = timestamp.into();
+        assert!(value.is_date_time_with_time_zone());
+        let out: DateTime<Local> = value.unwrap();
+        assert_eq!(out, timestamp);
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn test_chrono_generic_timezone_value() {
+        fn into_value<Tz: chrono::TimeZone>(v: Option<DateTime<Tz>>) -> Value {
+            v.into()
+        }
+
+        let timestamp = DateTime::parse_from_rfc3339("2020-01-01T02:02:02+08:00").unwrap();
+        assert_eq!(
+            into_value(Some(timestamp.with_timezone(&Utc))),
+            Value::DateTimeWithTimeZone(Some(Box::new(timestamp.with_timezone(&Utc).into())))
+        );
+        assert_eq!(
+            into_value(Some(timestamp)),
+            Value::DateTimeWithTimeZone(Some(Box::new(timestamp)))
+        );
+        assert_eq!(
+            into_value(None::<DateTime<Utc>>),
+            Value::DateTimeWithTimeZone(None)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn test_chrono_utc_query() {
+        use crate::*;
+
+        let timestamp = DateTime::parse_from_rfc3339("2020-01-01T02:02:02+08:00")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let query = Query::select().expr(Expr::val(timestamp)).to_owned();
+
+        let formatted = "2019-12-31 18:02:02 +00:00";
+
+        assert_eq!(
+            query.to_string(MysqlQueryBuilder),
+            format!("SELECT '{}'", formatted)
+        );
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            format!("SELECT '{}'", formatted)
+        );
+        assert_eq!(
+            query.to_string(SqliteQueryBuilder),
+            "SELECT '2019-12-31 18:02:02+00:00'"
+        );
+    }
+
     #[test]
     #[cfg(feature = "with-chrono")]
     fn test_chrono_query() {
@@ -908,7 +1787,7 @@ mod tests {
         );
         assert_eq!(
             query.to_string(SqliteQueryBuilder),
-            format!("SELECT '{}'", formatted)
+            "SELECT '2020-01-01 02:02:02+08:00'"
         );
     }
 
@@ -921,6 +1800,21 @@ mod tests {
         assert_eq!(out, uuid);
     }
 
+    #[test]
+    #[cfg(feature = "with-uuid")]
+    fn test_uuid_try_as_ref() {
+        let uuid = uuid::Uuid::parse_str("936DA01F9ABD4d9d80C702AF85C822A8").unwrap();
+        assert_eq!(Value::from(uuid).try_as_ref_uuid(), Ok(Some(&uuid)));
+        assert_eq!(Value::Uuid(None).try_as_ref_uuid(), Ok(None));
+        assert_eq!(
+            Value::Int(Some(1)).try_as_ref_uuid(),
+            Err(Error::TypeMismatch {
+                expected: "Uuid",
+                got: "Int".to_owned()
+            })
+        );
+    }
+
     #[test]
     #[cfg(feature = "with-rust_decimal")]
     fn test_decimal_value() {
@@ -932,4 +1826,231 @@ mod tests {
         let out: Decimal = v.unwrap();
         assert_eq!(out.to_string(), num);
     }
+
+    #[test]
+    #[cfg(feature = "with-rust_decimal")]
+    fn test_decimal_query() {
+        use crate::*;
+        use std::str::FromStr;
+
+        let num = Decimal::from_str("-12345.6789").unwrap();
+        let query = Query::select().expr(Expr::val(num)).to_owned();
+
+        assert_eq!(query.to_string(MysqlQueryBuilder), "SELECT -12345.6789");
+        assert_eq!(query.to_string(PostgresQueryBuilder), "SELECT -12345.6789");
+        assert_eq!(query.to_string(SqliteQueryBuilder), "SELECT -12345.6789");
+
+        let value: Value = Option::<Decimal>::None.into();
+        assert_eq!(value, Value::Decimal(None));
+        let out: Option<Decimal> = value.unwrap();
+        assert_eq!(out, None);
+    }
+
+    #[test]
+    #[cfg(feature = "with-bigdecimal")]
+    fn test_big_decimal_value() {
+        use std::str::FromStr;
+
+        let num = "2.02";
+        let val = BigDecimal::from_str(num).unwrap();
+        let v: Value = val.clone().into();
+        let out: BigDecimal = v.unwrap();
+        assert_eq!(out, val);
+    }
+
+    #[test]
+    #[cfg(feature = "with-bigdecimal")]
+    fn test_big_decimal_query() {
+        use crate::*;
+        use std::str::FromStr;
+
+        let num = BigDecimal::from_str("123456789012345678901234567890.123456789").unwrap();
+        let query = Query::select().expr(Expr::val(num)).to_owned();
+
+        let formatted = "SELECT 123456789012345678901234567890.123456789";
+
+        assert_eq!(query.to_string(MysqlQueryBuilder), formatted);
+        assert_eq!(query.to_string(PostgresQueryBuilder), formatted);
+        assert_eq!(query.to_string(SqliteQueryBuilder), formatted);
+    }
+
+    #[test]
+    #[cfg(feature = "with-time")]
+    fn test_time_value() {
+        use time::macros::{date, time};
+
+        let timestamp = date!(2020 - 01 - 01).with_time(time!(2:2:2));
+        let value: Value = timestamp.into();
+        let out: PrimitiveDateTime = value.unwrap();
+        assert_eq!(out, timestamp);
+    }
+
+    #[test]
+    fn test_bytes_query() {
+        use crate::*;
+
+        let bytes = vec![0x00u8, 0x5C, 0x27, 0xAB, 0xFF];
+        let query = Query::select().expr(Expr::val(bytes.clone())).to_owned();
+
+        let mysql = query.to_string(MysqlQueryBuilder);
+        let postgres = query.to_string(PostgresQueryBuilder);
+        let sqlite = query.to_string(SqliteQueryBuilder);
+
+        assert_eq!(mysql, "SELECT x'005C27ABFF'");
+        assert_eq!(postgres, r#"SELECT '\x005C27ABFF'::bytea"#);
+        assert_eq!(sqlite, "SELECT x'005C27ABFF'");
+
+        let decode = |literal: &str| -> Vec<u8> {
+            (0..literal.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&literal[i..i + 2], 16).unwrap())
+                .collect()
+        };
+        assert_eq!(decode(&mysql["SELECT x'".len()..mysql.len() - 1]), bytes);
+        assert_eq!(
+            decode(&postgres["SELECT '\\x".len()..postgres.len() - "'::bytea".len()]),
+            bytes
+        );
+        assert_eq!(decode(&sqlite["SELECT x'".len()..sqlite.len() - 1]), bytes);
+    }
+
+    #[test]
+    #[cfg(feature = "with-time")]
+    fn test_time_query() {
+        use crate::*;
+        use time::macros::{date, datetime, time};
+
+        let query = Query::select()
+            .expr(Expr::val(date!(2020 - 01 - 01)))
+            .expr(Expr::val(time!(2:2:2)))
+            .expr(Expr::val(datetime!(2020-01-01 2:2:2)))
+            .expr(Expr::val(datetime!(2020-01-01 2:2:2 +8)))
+            .to_owned();
+
+        let formatted = "'2020-01-01 02:02:02', '2020-01-01 02:02:02 +08:00'";
+
+        assert_eq!(
+            query.to_string(MysqlQueryBuilder),
+            format!("SELECT DATE '2020-01-01', TIME '02:02:02', {}", formatted)
+        );
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            format!("SELECT DATE '2020-01-01', TIME '02:02:02', {}", formatted)
+        );
+        assert_eq!(
+            query.to_string(SqliteQueryBuilder),
+            "SELECT '2020-01-01', '02:02:02', '2020-01-01 02:02:02', '2020-01-01 02:02:02+08:00'"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-ipnetwork")]
+    fn test_ip_network_value() {
+        use std::net::IpAddr;
+
+        let network: IpNetwork = "192.168.0.0/24".parse().unwrap();
+        let value: Value = network.into();
+        let out: IpNetwork = value.unwrap();
+        assert_eq!(out, network);
+
+        let addr: IpAddr = "::1".parse().unwrap();
+        let value: Value = addr.into();
+        let out: IpNetwork = value.unwrap();
+        assert_eq!(out, IpNetwork::from(addr));
+        assert_eq!(out.prefix(), 128);
+    }
+
+    #[test]
+    #[cfg(feature = "with-mac_address")]
+    fn test_mac_address_query() {
+        use crate::*;
+
+        let mac = MacAddress::new([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]);
+        let value: Value = mac.into();
+        let out: MacAddress = value.clone().unwrap();
+        assert_eq!(out, mac);
+
+        let query = Query::select().expr(Expr::val(value)).to_owned();
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            "SELECT '08:00:2B:01:02:03'"
+        );
+    }
+
+    #[test]
+    fn test_array_value() {
+        let array = vec![1i32, 2, 3];
+        let value: Value = array.clone().into();
+        assert_eq!(
+            value,
+            Value::Array(Some(Box::new(vec![
+                Value::Int(Some(1)),
+                Value::Int(Some(2)),
+                Value::Int(Some(3)),
+            ])))
+        );
+        let out: Vec<i32> = value.unwrap();
+        assert_eq!(out, array);
+
+        let value: Value = Option::<Vec<String>>::None.into();
+        assert_eq!(value, Value::Array(None));
+        let out: Option<Vec<String>> = value.unwrap();
+        assert_eq!(out, None);
+    }
+
+    #[test]
+    fn test_array_query() {
+        use crate::*;
+
+        let query = Query::select()
+            .expr(Expr::val(vec![1i32, 2, 3]))
+            .expr(Expr::val(vec!["a".to_owned(), "b".to_owned()]))
+            .to_owned();
+
+        assert_eq!(
+            query.to_string(PostgresQueryBuilder),
+            "SELECT ARRAY[1, 2, 3], ARRAY['a', 'b']"
+        );
+
+        let (sql, values) = query.build(PostgresQueryBuilder);
+        assert_eq!(sql, "SELECT $1, $2");
+        assert_eq!(
+            values,
+            Values(vec![
+                vec![1i32, 2, 3].into(),
+                vec!["a".to_owned(), "b".to_owned()].into()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_try_unwrap() {
+        let value: Value = 1i32.into();
+        assert_eq!(value.clone().try_unwrap::<i32>(), Ok(1));
+        assert_eq!(i32::try_from(value.clone()), Ok(1));
+        assert_eq!(
+            value.try_unwrap::<String>(),
+            Err(Error::TypeMismatch {
+                expected: "String",
+                got: "Int".to_owned(),
+            })
+        );
+
+        let value: Value = Option::<String>::None.into();
+        assert_eq!(Option::<String>::try_from(value.clone()), Ok(None));
+        assert_eq!(
+            value.try_unwrap::<Option<i64>>(),
+            Err(Error::TypeMismatch {
+                expected: "Option<i64>",
+                got: "String".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected u8, got Int")]
+    fn test_unwrap_type_mismatch() {
+        let value: Value = 1i32.into();
+        let _: u8 = value.unwrap();
+    }
 }
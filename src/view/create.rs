@@ -0,0 +1,166 @@
+use crate::{
+    backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder, SelectStatement,
+};
+
+/// Create a view from a select query
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let view = View::create()
+///     .or_replace()
+///     .view(Alias::new("bold_font"))
+///     .columns(vec![Alias::new("id"), Alias::new("font_name")])
+///     .query(
+///         Query::select()
+///             .columns(vec![Font::Id, Font::Name])
+///             .from(Font::Table)
+///             .and_where(Expr::col(Font::Variant).eq("Bold"))
+///             .to_owned(),
+///     )
+///     .with_check_option()
+///     .to_owned();
+///
+/// assert_eq!(
+///     view.to_string(MysqlQueryBuilder),
+///     r#"CREATE OR REPLACE VIEW `bold_font` (`id`, `font_name`) AS SELECT `id`, `name` FROM `font` WHERE `variant` = 'Bold' WITH CHECK OPTION"#
+/// );
+/// assert_eq!(
+///     view.to_string(PostgresQueryBuilder),
+///     r#"CREATE OR REPLACE VIEW "bold_font" ("id", "font_name") AS SELECT "id", "name" FROM "font" WHERE "variant" = 'Bold' WITH CHECK OPTION"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ViewCreateStatement {
+    pub(crate) view: Option<DynIden>,
+    pub(crate) columns: Vec<DynIden>,
+    pub(crate) query: Option<Box<SelectStatement>>,
+    pub(crate) or_replace: bool,
+    pub(crate) materialized: bool,
+    pub(crate) check_option: bool,
+}
+
+impl Default for ViewCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViewCreateStatement {
+    /// Construct a new [`ViewCreateStatement`]
+    pub fn new() -> Self {
+        Self {
+            view: None,
+            columns: Vec::new(),
+            query: None,
+            or_replace: false,
+            materialized: false,
+            check_option: false,
+        }
+    }
+
+    /// Set view name
+    pub fn view<T>(&mut self, view: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.view = Some(view.into_iden());
+        self
+    }
+
+    /// Add a column alias
+    pub fn column<C>(&mut self, col: C) -> &mut Self
+    where
+        C: IntoIden,
+    {
+        self.columns.push(col.into_iden());
+        self
+    }
+
+    /// Add column aliases
+    pub fn columns<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.columns
+            .extend(cols.into_iter().map(|col| col.into_iden()));
+        self
+    }
+
+    /// Set the query defining the rows of the view
+    pub fn query(&mut self, query: SelectStatement) -> &mut Self {
+        self.query = Some(Box::new(query));
+        self
+    }
+
+    /// Replace the view if it exists. Not supported by Sqlite, nor for materialized views.
+    pub fn or_replace(&mut self) -> &mut Self {
+        self.or_replace = true;
+        self
+    }
+
+    /// Store the rows of the view, to be updated with [`ViewRefreshStatement`]. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let view = View::create()
+    ///     .materialized()
+    ///     .view(Alias::new("font_count"))
+    ///     .query(
+    ///         Query::select()
+    ///             .column(Char::FontId)
+    ///             .expr(Func::count(Expr::col(Char::Id)))
+    ///             .from(Char::Table)
+    ///             .group_by_col(Char::FontId)
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     view.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE MATERIALIZED VIEW "font_count" AS SELECT "font_id", COUNT("id") FROM "character" GROUP BY "font_id""#
+    /// );
+    /// ```
+    pub fn materialized(&mut self) -> &mut Self {
+        self.materialized = true;
+        self
+    }
+
+    /// Reject inserts and updates through the view of rows not visible in the view.
+    /// Not supported by Sqlite.
+    pub fn with_check_option(&mut self) -> &mut Self {
+        self.check_option = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            view: self.view.take(),
+            columns: std::mem::take(&mut self.columns),
+            query: self.query.take(),
+            or_replace: self.or_replace,
+            materialized: self.materialized,
+            check_option: self.check_option,
+        }
+    }
+}
+
+impl SchemaStatementBuilder for ViewCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_create_statement(self, &mut sql);
+        sql.result()
+    }
+}
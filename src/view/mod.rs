@@ -0,0 +1,36 @@
+//! View definition statements.
+//!
+//! # Usage
+//!
+//! - View Create, see [`ViewCreateStatement`]
+//! - Materialized View Refresh, see [`ViewRefreshStatement`]
+
+mod create;
+mod refresh;
+mod shim;
+
+pub use create::*;
+pub use refresh::*;
+
+/// Shorthand for constructing any view statement
+#[derive(Debug, Clone)]
+pub struct View;
+
+/// All available types of view statement
+#[derive(Debug, Clone)]
+pub enum ViewStatement {
+    Create(ViewCreateStatement),
+    Refresh(ViewRefreshStatement),
+}
+
+impl View {
+    /// Construct view [`ViewCreateStatement`]
+    pub fn create() -> ViewCreateStatement {
+        ViewCreateStatement::new()
+    }
+
+    /// Construct view [`ViewRefreshStatement`]
+    pub fn refresh() -> ViewRefreshStatement {
+        ViewRefreshStatement::new()
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Refresh the rows of a materialized view. Postgres only.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// let refresh = View::refresh()
+///     .view(Alias::new("font_count"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     refresh.to_string(PostgresQueryBuilder),
+///     r#"REFRESH MATERIALIZED VIEW "font_count""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ViewRefreshStatement {
+    pub(crate) view: Option<DynIden>,
+    pub(crate) concurrently: bool,
+}
+
+impl Default for ViewRefreshStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViewRefreshStatement {
+    /// Construct a new [`ViewRefreshStatement`]
+    pub fn new() -> Self {
+        Self {
+            view: None,
+            concurrently: false,
+        }
+    }
+
+    /// Set view name
+    pub fn view<T>(&mut self, view: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.view = Some(view.into_iden());
+        self
+    }
+
+    /// Refresh without locking out reads of the view. The view needs a unique index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let refresh = View::refresh()
+    ///     .concurrently()
+    ///     .view(Alias::new("font_count"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     refresh.to_string(PostgresQueryBuilder),
+    ///     r#"REFRESH MATERIALIZED VIEW CONCURRENTLY "font_count""#
+    /// );
+    /// ```
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+}
+
+impl SchemaStatementBuilder for ViewRefreshStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_refresh_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_refresh_statement(self, &mut sql);
+        sql.result()
+    }
+}
@@ -0,0 +1,4 @@
+use crate::impl_schema_statement_builder;
+
+impl_schema_statement_builder!(view_create_statement_builder, ViewCreateStatement);
+impl_schema_statement_builder!(view_refresh_statement_builder, ViewRefreshStatement);
@@ -0,0 +1,31 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Schema::create()
+            .if_not_exists()
+            .name(Alias::new("studio"))
+            .to_string(BigQueryQueryBuilder),
+        "CREATE SCHEMA IF NOT EXISTS `studio`"
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Schema::drop()
+            .name(Alias::new("studio"))
+            .cascade()
+            .to_string(BigQueryQueryBuilder),
+        "DROP SCHEMA `studio` CASCADE"
+    );
+}
+
+#[test]
+#[should_panic(expected = "BigQuery not support database")]
+fn create_2() {
+    Database::create()
+        .name(Alias::new("studio"))
+        .to_string(BigQueryQueryBuilder);
+}
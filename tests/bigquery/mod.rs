@@ -0,0 +1,6 @@
+use sea_query::{tests_cfg::*, *};
+
+mod database;
+mod query;
+mod table;
+mod transaction;
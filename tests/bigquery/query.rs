@@ -0,0 +1,161 @@
+use super::*;
+
+#[test]
+fn select_1() {
+    assert_eq!(
+        Query::select()
+            .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+            .from((Alias::new("my-project"), Alias::new("studio"), Char::Table))
+            .limit(10)
+            .offset(100)
+            .to_string(BigQueryQueryBuilder),
+        "SELECT `character`, `size_w`, `size_h` FROM `my-project.studio.character` LIMIT 10 OFFSET 100"
+    );
+}
+
+#[test]
+fn select_2() {
+    assert_eq!(
+        Query::select()
+            .column((Alias::new("c"), Char::Character))
+            .from_as((Alias::new("studio"), Char::Table), Alias::new("c"))
+            .and_where(Expr::tbl(Alias::new("c"), Char::SizeW).eq(3))
+            .and_where(Expr::tbl(Alias::new("c"), Char::Character).like("A%"))
+            .build(BigQueryQueryBuilder),
+        (
+            "SELECT `c`.`character` FROM `studio.character` AS `c` WHERE `c`.`size_w` = @p1 AND `c`.`character` LIKE @p2".to_owned(),
+            Values(vec![3.into(), "A%".into()])
+        )
+    );
+}
+
+#[test]
+fn select_3() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .and_where(Expr::col(Char::SizeW).eq(3))
+            .and_where(Expr::col(Char::Character).eq("it's"))
+            .to_string(BigQueryQueryBuilder),
+        r#"SELECT `character` FROM `character` WHERE `size_w` = 3 AND `character` = 'it\'s'"#
+    );
+}
+
+#[test]
+fn select_4() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::val(vec![1, 2, 3]))
+            .expr(Expr::tuple(vec![
+                Expr::val(1).into(),
+                Expr::val("A").into()
+            ]))
+            .to_string(BigQueryQueryBuilder),
+        "SELECT [1, 2, 3], (1, 'A')"
+    );
+}
+
+#[test]
+fn select_5() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(
+                Expr::tuple(vec![
+                    Expr::col(Glyph::Aspect).into(),
+                    Expr::col(Glyph::Image).into()
+                ])
+                .in_tuples(vec![(1.5, "A")])
+            )
+            .to_string(BigQueryQueryBuilder),
+        "SELECT `id` FROM `glyph` WHERE (`aspect`, `image`) IN ((1.5, 'A'))"
+    );
+}
+
+#[test]
+fn select_6() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::cast_as(
+                Expr::col(Char::SizeW).into(),
+                ColumnType::Double(None)
+            ))
+            .from(Char::Table)
+            .to_string(BigQueryQueryBuilder),
+        "SELECT CAST(`size_w` AS FLOAT64) FROM `character`"
+    );
+}
+
+#[test]
+fn insert_1() {
+    assert_eq!(
+        Query::insert()
+            .into_table((Alias::new("studio"), Glyph::Table))
+            .columns(vec![Glyph::Image, Glyph::Aspect])
+            .values_panic(vec![vec![0xDEu8, 0xAD].into(), 2.5.into()])
+            .to_string(BigQueryQueryBuilder),
+        "INSERT INTO `studio.glyph` (`image`, `aspect`) VALUES (FROM_HEX('dead'), 2.5)"
+    );
+}
+
+#[test]
+#[should_panic(expected = "BigQuery not support ON CONFLICT")]
+fn insert_2() {
+    Query::insert()
+        .into_table(Glyph::Table)
+        .columns(vec![Glyph::Aspect])
+        .values_panic(vec![2.into()])
+        .on_conflict(
+            OnConflict::column(Glyph::Id)
+                .update_column(Glyph::Aspect)
+                .to_owned(),
+        )
+        .to_string(BigQueryQueryBuilder);
+}
+
+#[test]
+fn update_1() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .values(vec![(Glyph::Aspect, 2.1345.into())])
+            .and_where(Expr::col(Glyph::Id).eq(1))
+            .to_string(BigQueryQueryBuilder),
+        "UPDATE `glyph` SET `aspect` = 2.1345 WHERE `id` = 1"
+    );
+}
+
+#[test]
+fn update_2() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .values(vec![(Glyph::Aspect, 2.1345.into())])
+            .to_string(BigQueryQueryBuilder),
+        "UPDATE `glyph` SET `aspect` = 2.1345 WHERE TRUE"
+    );
+}
+
+#[test]
+fn delete_1() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .to_string(BigQueryQueryBuilder),
+        "DELETE FROM `glyph` WHERE TRUE"
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "ORDER BY and LIMIT in UPDATE and DELETE statements are not supported by this backend"
+)]
+fn delete_2() {
+    Query::delete()
+        .from_table(Glyph::Table)
+        .and_where(Expr::col(Glyph::Id).eq(1))
+        .limit(1)
+        .to_string(BigQueryQueryBuilder);
+}
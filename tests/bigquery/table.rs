@@ -0,0 +1,110 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("my-project.studio.glyph"))
+            .if_not_exists()
+            .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Glyph::Aspect).double())
+            .col(ColumnDef::new(Glyph::Image).string_len(255).default("none"))
+            .col(ColumnDef::new(Alias::new("tags")).array(ColumnType::String(None)))
+            .col(ColumnDef::new(Alias::new("created")).timestamp().not_null())
+            .partition_by(PartitionType::Range, vec![Alias::new("created")])
+            .to_string(BigQueryQueryBuilder),
+        [
+            "CREATE TABLE IF NOT EXISTS `my-project.studio.glyph` (",
+            "`id` INT64 NOT NULL PRIMARY KEY NOT ENFORCED,",
+            "`aspect` FLOAT64,",
+            "`image` STRING(255) DEFAULT 'none',",
+            "`tags` ARRAY<STRING>,",
+            "`created` TIMESTAMP NOT NULL",
+            ") PARTITION BY `created`",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).big_integer().not_null())
+            .col(ColumnDef::new(Char::Character).string().not_null())
+            .primary_key(Index::create().col(Char::Id).col(Char::Character))
+            .to_string(BigQueryQueryBuilder),
+        [
+            "CREATE TABLE `character` (",
+            "`id` INT64 NOT NULL,",
+            "`character` STRING NOT NULL,",
+            "PRIMARY KEY (`id`, `character`) NOT ENFORCED",
+            ")",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "BigQuery not support foreign key")]
+fn create_3() {
+    Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::FontId).integer())
+        .foreign_key(
+            ForeignKey::create()
+                .from(Char::Table, Char::FontId)
+                .to(Font::Table, Font::Id),
+        )
+        .to_string(BigQueryQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "BigQuery not support auto increment")]
+fn create_4() {
+    Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().auto_increment())
+        .to_string(BigQueryQueryBuilder);
+}
+
+#[test]
+fn alter_1() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .add_column(ColumnDef::new(Alias::new("new_col")).big_integer())
+            .modify_column(ColumnDef::new(Font::Id).double())
+            .rename_column(Font::Name, Alias::new("title"))
+            .drop_column(Font::Language)
+            .to_string(BigQueryQueryBuilder),
+        [
+            "ALTER TABLE `font`",
+            "ADD COLUMN `new_col` INT64,",
+            "ALTER COLUMN `id` SET DATA TYPE FLOAT64,",
+            "RENAME COLUMN `name` TO `title`,",
+            "DROP COLUMN `language`",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn rename_1() {
+    assert_eq!(
+        Table::rename()
+            .table(Font::Table, Alias::new("font_new"))
+            .to_string(BigQueryQueryBuilder),
+        "ALTER TABLE `font` RENAME TO `font_new`"
+    );
+}
+
+#[test]
+#[should_panic(expected = "BigQuery not support dropping multiple tables")]
+fn drop_1() {
+    Table::drop()
+        .table(Font::Table)
+        .table(Char::Table)
+        .to_string(BigQueryQueryBuilder);
+}
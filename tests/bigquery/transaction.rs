@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+fn begin_1() {
+    assert_eq!(
+        Transaction::begin().to_string(BigQueryQueryBuilder),
+        "BEGIN TRANSACTION"
+    );
+}
+
+#[test]
+fn rollback_1() {
+    assert_eq!(
+        Transaction::rollback().to_string(BigQueryQueryBuilder),
+        "ROLLBACK TRANSACTION"
+    );
+}
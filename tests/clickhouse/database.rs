@@ -0,0 +1,31 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Database::create()
+            .name(Alias::new("analytics"))
+            .if_not_exists()
+            .to_string(ClickHouseQueryBuilder),
+        "CREATE DATABASE IF NOT EXISTS `analytics`"
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Database::drop()
+            .name(Alias::new("analytics"))
+            .if_exists()
+            .to_string(ClickHouseQueryBuilder),
+        "DROP DATABASE IF EXISTS `analytics`"
+    );
+}
+
+#[test]
+#[should_panic(expected = "ClickHouse not support schema")]
+fn create_schema_1() {
+    Schema::create()
+        .name(Alias::new("reporting"))
+        .to_string(ClickHouseQueryBuilder);
+}
@@ -0,0 +1,7 @@
+use sea_query::{tests_cfg::*, *};
+
+mod database;
+mod query;
+mod role;
+mod table;
+mod transaction;
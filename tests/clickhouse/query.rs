@@ -0,0 +1,344 @@
+use super::*;
+
+#[test]
+fn select_1() {
+    assert_eq!(
+        Query::select()
+            .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+            .from(Char::Table)
+            .limit(10)
+            .offset(100)
+            .to_string(ClickHouseQueryBuilder),
+        "SELECT `character`, `size_w`, `size_h` FROM `character` LIMIT 10 OFFSET 100"
+    );
+}
+
+#[test]
+fn select_2() {
+    assert_eq!(
+        Query::select()
+            .columns(vec![Char::Character, Char::SizeW, Char::SizeH])
+            .from(Char::Table)
+            .final_()
+            .and_where(Expr::col(Char::SizeW).eq(3))
+            .to_string(ClickHouseQueryBuilder),
+        "SELECT `character`, `size_w`, `size_h` FROM `character` FINAL WHERE `size_w` = 3"
+    );
+}
+
+#[test]
+fn select_3() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from_as(Char::Table, Alias::new("c"))
+            .final_()
+            .sample(0.25)
+            .to_string(ClickHouseQueryBuilder),
+        "SELECT `character` FROM `character` AS `c` FINAL SAMPLE 0.25"
+    );
+}
+
+#[test]
+fn select_4() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .sample(10000)
+            .build(ClickHouseQueryBuilder),
+        (
+            "SELECT `character` FROM `character` SAMPLE 10000".to_owned(),
+            Values(vec![])
+        )
+    );
+}
+
+#[test]
+fn select_5() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::val(vec![1, 2, 3]))
+            .to_string(ClickHouseQueryBuilder),
+        "SELECT [1, 2, 3]"
+    );
+}
+
+#[test]
+fn select_6() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(
+                Expr::tuple(vec![
+                    Expr::col(Glyph::Aspect).into(),
+                    Expr::col(Glyph::Image).into()
+                ])
+                .in_tuples(vec![(1.5, "A"), (2.5, "B")])
+            )
+            .to_string(ClickHouseQueryBuilder),
+        "SELECT `id` FROM `glyph` WHERE (`aspect`, `image`) IN ((1.5, 'A'), (2.5, 'B'))"
+    );
+}
+
+#[test]
+fn select_7() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::cast_as(
+                Expr::col(Char::SizeW).into(),
+                ColumnType::BigInteger(None)
+            ))
+            .from(Char::Table)
+            .to_string(ClickHouseQueryBuilder),
+        "SELECT CAST(`size_w` AS Int64) FROM `character`"
+    );
+}
+
+#[test]
+#[should_panic(expected = "FINAL and SAMPLE are not supported by this backend")]
+fn select_8() {
+    Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .final_()
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn select_9() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .sample(0.1)
+            .try_build(PostgresQueryBuilder),
+        Err(error::Error::Unsupported {
+            backend: "Postgres",
+            feature: "FINAL and SAMPLE"
+        })
+    );
+}
+
+#[test]
+fn select_10() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::string_agg_order_by(
+                Expr::col(Char::Character),
+                ",",
+                vec![(Expr::col(Char::Id), Order::Asc)]
+            ))
+            .from(Char::Table)
+            .try_build(ClickHouseQueryBuilder),
+        Err(error::Error::Unsupported {
+            backend: "ClickHouse",
+            feature: "ORDER BY in string aggregation"
+        })
+    );
+}
+
+#[test]
+fn select_11() {
+    assert_eq!(
+        Query::select()
+            .column(Font::Id)
+            .from(Font::Table)
+            .and_where(Expr::full_text_match(
+                vec![Font::Name],
+                "serif",
+                SearchMode::NaturalLanguage
+            ))
+            .try_build(ClickHouseQueryBuilder),
+        Err(error::Error::Unsupported {
+            backend: "ClickHouse",
+            feature: "Full text search"
+        })
+    );
+}
+
+#[test]
+fn insert_1() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image, Glyph::Aspect])
+            .values_panic(vec!["04108048005887010020060000204E0180400400".into(), 2.5.into()])
+            .to_string(ClickHouseQueryBuilder),
+        "INSERT INTO `glyph` (`image`, `aspect`) VALUES ('04108048005887010020060000204E0180400400', 2.5)"
+    );
+}
+
+#[test]
+fn insert_2() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image])
+            .values_panic(vec![vec![1, 2].into()])
+            .build(ClickHouseQueryBuilder),
+        (
+            "INSERT INTO `glyph` (`image`) VALUES (?)".to_owned(),
+            Values(vec![vec![1, 2].into()])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "ClickHouse not support ON CONFLICT")]
+fn insert_3() {
+    Query::insert()
+        .into_table(Glyph::Table)
+        .columns(vec![Glyph::Aspect])
+        .values_panic(vec![2.into()])
+        .on_conflict(
+            OnConflict::column(Glyph::Id)
+                .update_column(Glyph::Aspect)
+                .to_owned(),
+        )
+        .to_string(ClickHouseQueryBuilder);
+}
+
+#[test]
+fn update_1() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .values(vec![
+                (Glyph::Aspect, 2.1345.into()),
+                (Glyph::Image, "235m".into())
+            ])
+            .and_where(Expr::col(Glyph::Id).eq(1))
+            .to_string(ClickHouseQueryBuilder),
+        "ALTER TABLE `glyph` UPDATE `aspect` = 2.1345, `image` = '235m' WHERE `id` = 1"
+    );
+}
+
+#[test]
+fn update_2() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .values(vec![(Glyph::Aspect, 2.1345.into())])
+            .build(ClickHouseQueryBuilder),
+        (
+            "ALTER TABLE `glyph` UPDATE `aspect` = ? WHERE 1".to_owned(),
+            Values(vec![2.1345.into()])
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "ClickHouse not support ORDER BY and LIMIT in UPDATE")]
+fn update_3() {
+    Query::update()
+        .table(Glyph::Table)
+        .values(vec![(Glyph::Aspect, 2.1345.into())])
+        .limit(1)
+        .to_string(ClickHouseQueryBuilder);
+}
+
+#[test]
+fn update_4() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value(Glyph::Aspect, 2.1345.into())
+            .from(Font::Table)
+            .try_build(ClickHouseQueryBuilder),
+        Err(error::Error::Unsupported {
+            backend: "ClickHouse",
+            feature: "UPDATE FROM"
+        })
+    );
+}
+
+#[test]
+fn update_5() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value(Glyph::Aspect, 2.1345.into())
+            .limit(1)
+            .try_build(ClickHouseQueryBuilder),
+        Err(error::Error::Unsupported {
+            backend: "ClickHouse",
+            feature: "ORDER BY and LIMIT in UPDATE"
+        })
+    );
+}
+
+#[test]
+fn update_6() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value(Glyph::Aspect, 2.1345.into())
+            .returning_all()
+            .try_build(ClickHouseQueryBuilder),
+        Err(error::Error::Unsupported {
+            backend: "ClickHouse",
+            feature: "RETURNING"
+        })
+    );
+}
+
+#[test]
+fn delete_1() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .and_where(Expr::col(Glyph::Id).eq(1))
+            .to_string(ClickHouseQueryBuilder),
+        "ALTER TABLE `glyph` DELETE WHERE `id` = 1"
+    );
+}
+
+#[test]
+fn delete_2() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .to_string(ClickHouseQueryBuilder),
+        "ALTER TABLE `glyph` DELETE WHERE 1"
+    );
+}
+
+#[test]
+#[should_panic(expected = "ClickHouse not support RETURNING")]
+fn delete_3() {
+    Query::delete()
+        .from_table(Glyph::Table)
+        .returning_all()
+        .to_string(ClickHouseQueryBuilder);
+}
+
+#[test]
+fn delete_4() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .using(Font::Table)
+            .try_build(ClickHouseQueryBuilder),
+        Err(error::Error::Unsupported {
+            backend: "ClickHouse",
+            feature: "DELETE USING"
+        })
+    );
+}
+
+#[test]
+fn delete_5() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .order_by(Glyph::Id, Order::Asc)
+            .limit(1)
+            .try_build(ClickHouseQueryBuilder),
+        Err(error::Error::Unsupported {
+            backend: "ClickHouse",
+            feature: "ORDER BY and LIMIT in DELETE"
+        })
+    );
+}
@@ -0,0 +1,22 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        User::create()
+            .name(Alias::new("reader"))
+            .if_not_exists()
+            .password("it's secret")
+            .to_string(ClickHouseQueryBuilder),
+        r#"CREATE USER IF NOT EXISTS `reader` IDENTIFIED BY 'it\'s secret'"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "ClickHouse not support role option")]
+fn create_2() {
+    Role::create()
+        .name(Alias::new("reader"))
+        .option(RoleOption::Login(true))
+        .to_string(ClickHouseQueryBuilder);
+}
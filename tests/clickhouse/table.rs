@@ -0,0 +1,152 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).big_integer().not_null())
+            .col(ColumnDef::new(Glyph::Aspect).double().not_null())
+            .col(ColumnDef::new(Glyph::Image).text())
+            .engine("MergeTree")
+            .order_by(vec![Glyph::Id, Glyph::Aspect])
+            .to_string(ClickHouseQueryBuilder),
+        [
+            "CREATE TABLE `glyph` (",
+            "`id` Int64 NOT NULL,",
+            "`aspect` Float64 NOT NULL,",
+            "`image` String",
+            ") ENGINE = MergeTree ORDER BY (`id`, `aspect`)",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        Table::create()
+            .table(Char::Table)
+            .if_not_exists()
+            .col(ColumnDef::new(Char::Id).integer().not_null())
+            .col(ColumnDef::new(Char::Character).string().default("A"))
+            .col(ColumnDef::new(Char::FontId).array(ColumnType::Integer(None)))
+            .col(ColumnDef::new(Alias::new("created_at")).timestamp_len(3).not_null())
+            .primary_key(Index::create().col(Char::Id))
+            .engine("ReplacingMergeTree(`created_at`)")
+            .order_by(vec![Char::Id])
+            .partition_by(PartitionType::Range, vec![Alias::new("created_at")])
+            .to_string(ClickHouseQueryBuilder),
+        [
+            "CREATE TABLE IF NOT EXISTS `character` (",
+            "`id` Int32 NOT NULL,",
+            "`character` String DEFAULT 'A',",
+            "`font_id` Array(Int32),",
+            "`created_at` DateTime64(3) NOT NULL,",
+            "PRIMARY KEY (`id`)",
+            ") ENGINE = ReplacingMergeTree(`created_at`) ORDER BY (`id`) PARTITION BY (`created_at`)",
+        ].join(" ")
+    );
+}
+
+#[test]
+fn create_3() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Name).string().not_null())
+            .col(ColumnDef::new(Font::Variant).enumeration(
+                Alias::new("font_variant"),
+                vec![Alias::new("regular"), Alias::new("it's")]
+            ))
+            .col(ColumnDef::new(Font::Language).integer().generated(
+                Func::char_length(Expr::col(Font::Name)),
+                GeneratedStorage::Virtual
+            ))
+            .engine("MergeTree")
+            .order_by(Vec::<Font>::new())
+            .to_string(ClickHouseQueryBuilder),
+        [
+            "CREATE TABLE `font` (",
+            "`name` String NOT NULL,",
+            r#"`variant` Enum('regular', 'it\'s'),"#,
+            "`language` Int32 ALIAS CHAR_LENGTH(`name`)",
+            ") ENGINE = MergeTree ORDER BY tuple()",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "ClickHouse not support auto increment")]
+fn create_4() {
+    Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().auto_increment())
+        .to_string(ClickHouseQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "ORDER BY table option is not supported by this backend")]
+fn create_5() {
+    Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer())
+        .order_by(vec![Glyph::Id])
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn alter_1() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .add_column(
+                ColumnDef::new(Alias::new("new_col"))
+                    .integer()
+                    .not_null()
+                    .default(100)
+            )
+            .rename_column(Alias::new("new_col"), Alias::new("new_column"))
+            .drop_column(Font::Language)
+            .to_string(ClickHouseQueryBuilder),
+        [
+            "ALTER TABLE `font`",
+            "ADD COLUMN `new_col` Int32 NOT NULL DEFAULT 100,",
+            "RENAME COLUMN `new_col` TO `new_column`,",
+            "DROP COLUMN `language`",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_2() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .drop_column_default(Font::Name)
+            .to_string(ClickHouseQueryBuilder),
+        "ALTER TABLE `font` MODIFY COLUMN `name` REMOVE DEFAULT"
+    );
+}
+
+#[test]
+fn rename_1() {
+    assert_eq!(
+        Table::rename()
+            .table(Font::Table, Alias::new("font_new"))
+            .to_string(ClickHouseQueryBuilder),
+        "RENAME TABLE `font` TO `font_new`"
+    );
+}
+
+#[test]
+fn truncate_1() {
+    assert_eq!(
+        Table::truncate()
+            .table(Font::Table)
+            .to_string(ClickHouseQueryBuilder),
+        "TRUNCATE TABLE `font`"
+    );
+}
@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn begin_1() {
+    assert_eq!(
+        Transaction::begin().to_string(ClickHouseQueryBuilder),
+        "BEGIN TRANSACTION"
+    );
+}
+
+#[test]
+fn commit_1() {
+    assert_eq!(
+        Transaction::commit().to_string(ClickHouseQueryBuilder),
+        "COMMIT"
+    );
+}
+
+#[test]
+#[should_panic(expected = "ClickHouse not support savepoint")]
+fn savepoint_1() {
+    Transaction::savepoint(Alias::new("before_import")).to_string(ClickHouseQueryBuilder);
+}
@@ -0,0 +1,7 @@
+/// Row of an introspection query, as read from the database
+pub fn row(values: &[Option<&str>]) -> Vec<Option<String>> {
+    values
+        .iter()
+        .map(|value| value.map(str::to_owned))
+        .collect()
+}
@@ -17,3 +17,89 @@ fn insert_values_1() {
         }
     );
 }
+
+#[test]
+fn insert_select_from_1() {
+    let mut insert = Query::insert();
+    let result = insert
+        .into_table(Glyph::Table)
+        .columns(vec![Glyph::Image, Glyph::Aspect])
+        .select_from(
+            Query::select()
+                .column(Glyph::Image)
+                .from(Glyph::Table)
+                .to_owned(),
+        );
+
+    assert_eq!(
+        result.unwrap_err(),
+        Error::ColValNumMismatch {
+            col_len: 2,
+            val_len: 1,
+        }
+    );
+}
+
+#[test]
+fn value_try_unwrap_1() {
+    let value: Value = "hello".into();
+
+    assert_eq!(
+        value.try_unwrap::<i64>().unwrap_err(),
+        Error::TypeMismatch {
+            expected: "i64",
+            got: "String".to_owned(),
+        }
+    );
+}
+
+#[test]
+fn value_try_unwrap_2() {
+    #[derive(Debug, PartialEq)]
+    struct Name(String);
+
+    impl ValueTypeDefault for Name {
+        fn default() -> Self {
+            Name(String::new())
+        }
+    }
+
+    impl ValueType for Name {
+        fn try_unwrap(v: Value) -> Result<Self> {
+            Ok(Name(v.try_unwrap()?))
+        }
+
+        fn type_name() -> &'static str {
+            "Name"
+        }
+    }
+
+    let value: Value = "hello".into();
+
+    assert_eq!(value.try_unwrap::<Name>(), Ok(Name("hello".to_owned())));
+    assert_eq!(
+        Value::Int(Some(1)).try_unwrap::<Name>(),
+        Err(Error::TypeMismatch {
+            expected: "String",
+            got: "Int".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn select_paginate_1() {
+    let mut select = Query::select();
+    let result = select.column(Glyph::Id).from(Glyph::Table).paginate(
+        Keyset::asc(vec![Glyph::Aspect, Glyph::Id])
+            .after(vec![1.into()])
+            .to_owned(),
+    );
+
+    assert_eq!(
+        result.unwrap_err(),
+        Error::ColValNumMismatch {
+            col_len: 2,
+            val_len: 1,
+        }
+    );
+}
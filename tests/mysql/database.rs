@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Database::create()
+            .if_not_exists()
+            .name(Alias::new("tenant_1"))
+            .charset("utf8mb4")
+            .collate("utf8mb4_unicode_ci")
+            .to_string(MysqlQueryBuilder),
+        r#"CREATE DATABASE IF NOT EXISTS `tenant_1` CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support database owner")]
+fn create_2() {
+    Database::create()
+        .name(Alias::new("tenant_1"))
+        .owner(Alias::new("tenant_1_owner"))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn create_3() {
+    assert_eq!(
+        Schema::create()
+            .if_not_exists()
+            .name(Alias::new("tenant_1"))
+            .to_string(MysqlQueryBuilder),
+        r#"CREATE SCHEMA IF NOT EXISTS `tenant_1`"#
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Database::drop()
+            .name(Alias::new("tenant_1"))
+            .to_string(MysqlQueryBuilder),
+        r#"DROP DATABASE `tenant_1`"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support CASCADE on schema")]
+fn drop_2() {
+    Schema::drop()
+        .name(Alias::new("tenant_1"))
+        .cascade()
+        .to_string(MysqlQueryBuilder);
+}
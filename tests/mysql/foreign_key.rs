@@ -10,7 +10,7 @@ fn create_1() {
             .on_delete(ForeignKeyAction::Cascade)
             .on_update(ForeignKeyAction::Cascade)
             .to_string(MysqlQueryBuilder),
-        vec![
+        [
             "ALTER TABLE `character`",
             "ADD CONSTRAINT `FK_2e303c3a712662f1fc2a4d0aad6`",
             "FOREIGN KEY (`font_id`) REFERENCES `font` (`id`)",
@@ -20,6 +20,17 @@ fn create_1() {
     );
 }
 
+#[test]
+#[should_panic(expected = "Mysql not support deferrable foreign key")]
+fn create_2() {
+    ForeignKey::create()
+        .name("FK_character_font")
+        .from(Char::Table, Char::FontId)
+        .to(Font::Table, Font::Id)
+        .deferrable(ForeignKeyDeferrable::InitiallyDeferred)
+        .to_string(MysqlQueryBuilder);
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -30,3 +41,13 @@ fn drop_1() {
         "ALTER TABLE `character` DROP FOREIGN KEY `FK_2e303c3a712662f1fc2a4d0aad6`"
     );
 }
+
+#[test]
+#[should_panic(expected = "Mysql not support IF EXISTS on foreign key")]
+fn drop_2() {
+    ForeignKey::drop()
+        .if_exists()
+        .name("FK_2e303c3a712662f1fc2a4d0aad6")
+        .table(Char::Table)
+        .to_string(MysqlQueryBuilder);
+}
@@ -52,6 +52,134 @@ fn create_4() {
     );
 }
 
+#[test]
+#[should_panic(expected = "Mysql not support IF NOT EXISTS on index")]
+fn create_5() {
+    Index::create()
+        .if_not_exists()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(Glyph::Aspect)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support partial index")]
+fn create_6() {
+    Index::create()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(Glyph::Aspect)
+        .and_where(Expr::col(Glyph::Aspect).gt(0))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn create_7() {
+    assert_eq!(
+        Index::create()
+            .name("idx-glyph-image")
+            .table(Glyph::Table)
+            .col((Glyph::Image, 64))
+            .col(Expr::col(Glyph::Aspect).mul(2))
+            .to_string(MysqlQueryBuilder),
+        "CREATE INDEX `idx-glyph-image` ON `glyph` (`image` (64), (`aspect` * 2))"
+    );
+}
+
+#[test]
+fn create_8() {
+    assert_eq!(
+        Index::create()
+            .spatial()
+            .name("idx-glyph-image")
+            .table(Glyph::Table)
+            .col(Glyph::Image)
+            .to_string(MysqlQueryBuilder),
+        "CREATE SPATIAL INDEX `idx-glyph-image` ON `glyph` (`image`)"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support GIN index")]
+fn create_9() {
+    Index::create()
+        .index_type(IndexType::Gin)
+        .name("idx-glyph-image")
+        .table(Glyph::Table)
+        .col(Glyph::Image)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "INCLUDE is not supported by this backend")]
+fn create_10() {
+    Index::create()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(Glyph::Aspect)
+        .include(Glyph::Id)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn create_11() {
+    assert_eq!(
+        Index::create()
+            .unique()
+            .name("idx-glyph-image")
+            .table(Glyph::Table)
+            .col((Glyph::Image, 64))
+            .algorithm(IndexAlgorithm::Inplace)
+            .lock(IndexLock::None)
+            .to_string(MysqlQueryBuilder),
+        "CREATE UNIQUE INDEX `idx-glyph-image` ON `glyph` (`image` (64)) ALGORITHM=INPLACE LOCK=NONE"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support CONCURRENTLY on index")]
+fn create_12() {
+    Index::create()
+        .concurrently()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(Glyph::Aspect)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn create_13() {
+    assert_eq!(
+        Index::create()
+            .name("idx-glyph-image-aspect")
+            .table(Glyph::Table)
+            .col(
+                IndexColumn::new(Glyph::Image)
+                    .prefix(10)
+                    .order(IndexOrder::Desc)
+                    .to_owned()
+            )
+            .col(Glyph::Aspect)
+            .to_string(MysqlQueryBuilder),
+        "CREATE INDEX `idx-glyph-image-aspect` ON `glyph` (`image` (10) DESC, `aspect`)"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Operator class is not supported by this backend")]
+fn create_14() {
+    Index::create()
+        .name("idx-glyph-image")
+        .table(Glyph::Table)
+        .col(
+            IndexColumn::new(Glyph::Image)
+                .opclass(Alias::new("text_pattern_ops"))
+                .to_owned(),
+        )
+        .to_string(MysqlQueryBuilder);
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -62,3 +190,33 @@ fn drop_1() {
         "DROP INDEX `idx-glyph-aspect` ON `glyph`"
     );
 }
+
+#[test]
+#[should_panic(expected = "Mysql not support IF EXISTS on index")]
+fn drop_2() {
+    Index::drop()
+        .if_exists()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn drop_3() {
+    assert_eq!(
+        Index::drop()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .lock(IndexLock::Shared)
+            .to_string(MysqlQueryBuilder),
+        "DROP INDEX `idx-glyph-aspect` ON `glyph` LOCK=SHARED"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support REINDEX")]
+fn reindex_1() {
+    Index::reindex()
+        .name("idx-glyph-aspect")
+        .to_string(MysqlQueryBuilder);
+}
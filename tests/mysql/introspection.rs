@@ -0,0 +1,157 @@
+use super::*;
+
+#[test]
+fn tables_query_1() {
+    assert_eq!(
+        MysqlQueryBuilder
+            .tables_query(None)
+            .to_string(MysqlQueryBuilder),
+        [
+            "SELECT `table_name` FROM `information_schema`.`tables`",
+            "WHERE `table_schema` = DATABASE() AND `table_type` = 'BASE TABLE'",
+            "ORDER BY `table_name` ASC",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn columns_query_1() {
+    assert_eq!(
+        MysqlQueryBuilder
+            .columns_query(Some("shop"), "glyph")
+            .build(MysqlQueryBuilder),
+        (
+            [
+                "SELECT `column_name`, `column_type`, `is_nullable`, `column_default`, `extra`",
+                "FROM `information_schema`.`columns`",
+                "WHERE `table_schema` = ? AND `table_name` = ?",
+                "ORDER BY `ordinal_position` ASC",
+            ]
+            .join(" "),
+            Values(vec!["shop".into(), "glyph".into()])
+        )
+    );
+}
+
+#[test]
+fn indexes_query_1() {
+    assert_eq!(
+        MysqlQueryBuilder
+            .indexes_query(None, "glyph")
+            .to_string(MysqlQueryBuilder),
+        [
+            "SELECT `index_name`, CAST(`non_unique` = 0 AS char),",
+            "CAST(`index_name` = 'PRIMARY' AS char), `column_name`",
+            "FROM `information_schema`.`statistics`",
+            "WHERE `table_schema` = DATABASE() AND `table_name` = 'glyph'",
+            "ORDER BY `index_name` ASC, `seq_in_index` ASC",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn foreign_keys_query_1() {
+    assert_eq!(
+        MysqlQueryBuilder
+            .foreign_keys_query(None, "glyph")
+            .to_string(MysqlQueryBuilder),
+        [
+            "SELECT `kcu`.`constraint_name`, `kcu`.`column_name`,",
+            "`kcu`.`referenced_table_name`, `kcu`.`referenced_column_name`,",
+            "`rc`.`update_rule`, `rc`.`delete_rule`",
+            "FROM `information_schema`.`key_column_usage` AS `kcu`",
+            "INNER JOIN `information_schema`.`referential_constraints` AS `rc`",
+            "ON (`rc`.`constraint_schema` = `kcu`.`constraint_schema`)",
+            "AND (`rc`.`constraint_name` = `kcu`.`constraint_name`)",
+            "AND (`rc`.`table_name` = `kcu`.`table_name`)",
+            "WHERE `kcu`.`table_schema` = DATABASE() AND `kcu`.`table_name` = 'glyph'",
+            "ORDER BY `kcu`.`constraint_name` ASC, `kcu`.`ordinal_position` ASC",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn parse_table_1() {
+    let columns = vec![
+        row(&[
+            Some("id"),
+            Some("int"),
+            Some("NO"),
+            None,
+            Some("auto_increment"),
+        ]),
+        row(&[
+            Some("name"),
+            Some("varchar(255)"),
+            Some("NO"),
+            Some("Sans"),
+            Some(""),
+        ]),
+        row(&[Some("font_id"), Some("int"), Some("YES"), None, Some("")]),
+        row(&[
+            Some("active"),
+            Some("tinyint(1)"),
+            Some("YES"),
+            Some("1"),
+            Some(""),
+        ]),
+        row(&[
+            Some("size"),
+            Some("int unsigned"),
+            Some("YES"),
+            None,
+            Some(""),
+        ]),
+        row(&[
+            Some("updated_at"),
+            Some("timestamp"),
+            Some("YES"),
+            Some("CURRENT_TIMESTAMP"),
+            Some("DEFAULT_GENERATED on update CURRENT_TIMESTAMP"),
+        ]),
+    ];
+    let indexes = vec![
+        row(&[Some("fk-glyph-font"), Some("0"), Some("0"), Some("font_id")]),
+        row(&[Some("idx-glyph-name"), Some("1"), Some("0"), Some("name")]),
+        row(&[
+            Some("idx-glyph-name"),
+            Some("1"),
+            Some("0"),
+            Some("font_id"),
+        ]),
+        row(&[Some("PRIMARY"), Some("1"), Some("1"), Some("id")]),
+    ];
+    let foreign_keys = vec![row(&[
+        Some("fk-glyph-font"),
+        Some("font_id"),
+        Some("font"),
+        Some("id"),
+        Some("CASCADE"),
+        Some("RESTRICT"),
+    ])];
+
+    assert_eq!(
+        MysqlQueryBuilder
+            .parse_table("glyph", &columns, &indexes, &foreign_keys)
+            .unwrap()
+            .to_string(MysqlQueryBuilder),
+        [
+            "CREATE TABLE `glyph` (",
+            "`id` int NOT NULL AUTO_INCREMENT PRIMARY KEY,",
+            "`name` varchar(255) NOT NULL DEFAULT 'Sans',",
+            "`font_id` int,",
+            "`active` bool DEFAULT '1',",
+            "`size` int unsigned,",
+            "`updated_at` timestamp DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,",
+            "KEY `fk-glyph-font` (`font_id`),",
+            "UNIQUE KEY `idx-glyph-name` (`name`, `font_id`),",
+            "CONSTRAINT `fk-glyph-font` FOREIGN KEY (`font_id`) REFERENCES `font` (`id`)",
+            "ON DELETE RESTRICT ON UPDATE CASCADE",
+            ")",
+        ]
+        .join(" ")
+    );
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        User::create()
+            .if_not_exists()
+            .name(Alias::new("app"))
+            .host("%")
+            .option(RoleOption::ConnectionLimit(10))
+            .password("s3cret")
+            .to_string(MysqlQueryBuilder),
+        r#"CREATE USER IF NOT EXISTS `app`@'%' IDENTIFIED BY 's3cret' WITH MAX_USER_CONNECTIONS 10"#
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        Role::create()
+            .name(Alias::new("reader"))
+            .to_string(MysqlQueryBuilder),
+        r#"CREATE ROLE `reader`"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support role option Superuser(true)")]
+fn create_3() {
+    Role::create()
+        .name(Alias::new("admin"))
+        .option(RoleOption::Superuser(true))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        User::drop()
+            .if_exists()
+            .name(Alias::new("app"))
+            .host("localhost")
+            .to_string(MysqlQueryBuilder),
+        r#"DROP USER IF EXISTS `app`@'localhost'"#
+    );
+}
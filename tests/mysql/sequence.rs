@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+#[should_panic(expected = "Mysql not support sequence")]
+fn create_1() {
+    Sequence::create()
+        .name(Alias::new("glyph_id_seq"))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "NextVal function is not supported by this backend")]
+fn nextval_1() {
+    Query::select()
+        .expr(Func::nextval(Alias::new("glyph_id_seq")))
+        .to_string(MysqlQueryBuilder);
+}
@@ -18,7 +18,7 @@ fn create_1() {
             .character_set("utf8mb4")
             .collate("utf8mb4_unicode_ci")
             .to_string(MysqlQueryBuilder),
-        vec![
+        [
             "CREATE TABLE `glyph` (",
             "`id` int(11) NOT NULL AUTO_INCREMENT PRIMARY KEY,",
             "`aspect` double NOT NULL,",
@@ -48,7 +48,7 @@ fn create_2() {
             .character_set("utf8mb4")
             .collate("utf8mb4_unicode_ci")
             .to_string(MysqlQueryBuilder),
-        vec![
+        [
             "CREATE TABLE `font` (",
             "`id` int(11) NOT NULL AUTO_INCREMENT PRIMARY KEY,",
             "`name` varchar(255) NOT NULL,",
@@ -94,7 +94,7 @@ fn create_3() {
             .character_set("utf8mb4")
             .collate("utf8mb4_unicode_ci")
             .to_string(MysqlQueryBuilder),
-        vec![
+        [
             "CREATE TABLE IF NOT EXISTS `character` (",
             "`id` int(11) NOT NULL AUTO_INCREMENT PRIMARY KEY,",
             "`font_size` int(11) NOT NULL,",
@@ -123,7 +123,7 @@ fn create_4() {
                     .extra("ANYTHING I WANT TO SAY".to_owned())
             )
             .to_string(MysqlQueryBuilder),
-        vec![
+        [
             "CREATE TABLE `glyph` (",
             "`id` int NOT NULL ANYTHING I WANT TO SAY",
             ")",
@@ -140,7 +140,7 @@ fn create_5() {
             .col(ColumnDef::new(Glyph::Id).integer().not_null())
             .index(Index::create().unique().name("idx-glyph-id").col(Glyph::Id))
             .to_string(MysqlQueryBuilder),
-        vec![
+        [
             "CREATE TABLE `glyph` (",
             "`id` int NOT NULL,",
             "UNIQUE KEY `idx-glyph-id` (`id`)",
@@ -150,6 +150,268 @@ fn create_5() {
     );
 }
 
+#[test]
+fn create_6() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null())
+            .col(
+                ColumnDef::new(Font::Variant)
+                    .string_len(16)
+                    .not_null()
+                    .check(Expr::col(Font::Variant).is_in(vec!["Regular", "Bold"]))
+            )
+            .col(ColumnDef::new(Font::Language).string_len(8))
+            .check(
+                Expr::col(Font::Language)
+                    .is_null()
+                    .or(Expr::col(Font::Language).ne(""))
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            r#"CREATE TABLE `font` ("#,
+            r#"`id` int NOT NULL,"#,
+            r#"`variant` varchar(16) NOT NULL CHECK (`variant` IN ('Regular', 'Bold')),"#,
+            r#"`language` varchar(8),"#,
+            r#"CHECK ((`language` IS NULL) OR (`language` <> ''))"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_7() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(ColumnDef::new(Glyph::Image).json())
+            .col(
+                ColumnDef::new(Alias::new("image_type"))
+                    .string_len(16)
+                    .generated(
+                        Expr::col(Glyph::Image).cast_json_field("$.type"),
+                        GeneratedStorage::Virtual
+                    )
+            )
+            .index(
+                Index::create()
+                    .name("idx-glyph-image_type")
+                    .col(Alias::new("image_type"))
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            r#"CREATE TABLE `glyph` ("#,
+            r#"`id` int NOT NULL,"#,
+            r#"`image` json,"#,
+            r#"`image_type` varchar(16) GENERATED ALWAYS AS (`image` ->> '$.type') VIRTUAL,"#,
+            r#"KEY `idx-glyph-image_type` (`image_type`)"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_8() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .uuid()
+                    .not_null()
+                    .default_expr(Expr::cust("UUID_TO_BIN(UUID())"))
+            )
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp()
+                    .not_null()
+                    .default_expr(Expr::current_timestamp())
+            )
+            .col(
+                ColumnDef::new(Glyph::Aspect)
+                    .double()
+                    .default_expr(Expr::val(1).add(0.5))
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            r#"CREATE TABLE `glyph` ("#,
+            r#"`id` binary(16) NOT NULL DEFAULT (UUID_TO_BIN(UUID())),"#,
+            r#"`created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,"#,
+            r#"`aspect` double DEFAULT (1 + 0.5)"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_9() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(
+                ColumnDef::new(Font::Id)
+                    .integer()
+                    .not_null()
+                    .identity(Identity::by_default().start(1000).to_owned())
+                    .primary_key()
+            )
+            .col(ColumnDef::new(Font::Name).string().not_null())
+            .engine("InnoDB")
+            .to_string(MysqlQueryBuilder),
+        [
+            r#"CREATE TABLE `font` ("#,
+            r#"`id` int NOT NULL AUTO_INCREMENT PRIMARY KEY,"#,
+            r#"`name` varchar(255) NOT NULL"#,
+            r#") ENGINE=InnoDB AUTO_INCREMENT=1000"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support identity increment")]
+fn create_10() {
+    Table::create()
+        .table(Font::Table)
+        .col(
+            ColumnDef::new(Font::Id)
+                .integer()
+                .identity(Identity::by_default().increment(2).to_owned()),
+        )
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn create_11() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null())
+            .col(
+                ColumnDef::new(Font::Variant)
+                    .enumeration(
+                        Alias::new("font_variant"),
+                        vec![Alias::new("regular"), Alias::new("bold"), Alias::new("it's")]
+                    )
+                    .not_null()
+                    .default("regular")
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            r#"CREATE TABLE `font` ("#,
+            r#"`id` int NOT NULL,"#,
+            r#"`variant` varchar(255) CHECK (`variant` IN ('regular', 'bold', 'it\'s')) NOT NULL DEFAULT 'regular'"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support array column type")]
+fn create_12() {
+    Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Alias::new("tags")).array(ColumnType::Text))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn create_13() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("staging"))
+            .temporary()
+            .if_not_exists()
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .to_string(MysqlQueryBuilder),
+        [
+            r#"CREATE TEMPORARY TABLE IF NOT EXISTS `staging` ("#,
+            r#"`id` int NOT NULL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_14() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("staging"))
+            .temporary()
+            .as_select(
+                Query::select()
+                    .columns(vec![Glyph::Id, Glyph::Image])
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Aspect).gt(2))
+                    .to_owned()
+            )
+            .to_string(MysqlQueryBuilder),
+        r#"CREATE TEMPORARY TABLE `staging` AS SELECT `id`, `image` FROM `glyph` WHERE `aspect` > 2"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "WITH NO DATA is not supported by this backend")]
+fn create_15() {
+    Table::create()
+        .table(Alias::new("char_snapshot"))
+        .as_select(
+            Query::select()
+                .column(Char::Id)
+                .from(Char::Table)
+                .to_owned(),
+        )
+        .with_no_data()
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn create_16() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null())
+            .col(ColumnDef::new(Font::Variant).string().not_null())
+            .engine("InnoDB")
+            .partition_by(PartitionType::List, vec![Font::Variant])
+            .add_partition(
+                Alias::new("regular"),
+                PartitionValues::list(vec![Expr::val("Regular"), Expr::val("Light")])
+            )
+            .add_partition(
+                Alias::new("bold"),
+                PartitionValues::list(vec![Expr::val("Bold")])
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            "CREATE TABLE `font` (",
+            "`id` int NOT NULL,",
+            "`variant` varchar(255) NOT NULL",
+            ") ENGINE=InnoDB PARTITION BY LIST COLUMNS (`variant`) (",
+            "PARTITION `regular` VALUES IN ('Regular', 'Light'),",
+            "PARTITION `bold` VALUES IN ('Bold')",
+            ")",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "PARTITION OF is not supported by this backend")]
+fn create_17() {
+    Table::create()
+        .table(Alias::new("font_bold"))
+        .partition_of(Font::Table, PartitionValues::list(vec![Expr::val("Bold")]))
+        .to_string(MysqlQueryBuilder);
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -172,6 +434,15 @@ fn truncate_1() {
     );
 }
 
+#[test]
+#[should_panic(expected = "Mysql not support truncating multiple tables")]
+fn truncate_2() {
+    Table::truncate()
+        .table(Glyph::Table)
+        .table(Char::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
 #[test]
 fn alter_1() {
     assert_eq!(
@@ -240,3 +511,174 @@ fn alter_5() {
 fn alter_6() {
     Table::alter().to_string(MysqlQueryBuilder);
 }
+
+#[test]
+fn alter_7() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .add_check_constraint(
+                Alias::new("font_name_not_empty"),
+                Expr::col(Font::Name).ne("")
+            )
+            .to_string(MysqlQueryBuilder),
+        r#"ALTER TABLE `font` ADD CONSTRAINT `font_name_not_empty` CHECK (`name` <> '')"#
+    );
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .drop_constraint(Alias::new("font_name_not_empty"))
+            .to_string(MysqlQueryBuilder),
+        r#"ALTER TABLE `font` DROP CONSTRAINT `font_name_not_empty`"#
+    );
+}
+
+#[test]
+fn alter_8() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .add_column(ColumnDef::new(Alias::new("new_col")).integer())
+            .rename_column(Font::Variant, Alias::new("style"))
+            .drop_column(Alias::new("old_col"))
+            .to_string(MysqlQueryBuilder),
+        [
+            "ALTER TABLE `font`",
+            "ADD COLUMN `new_col` int,",
+            "RENAME COLUMN `variant` TO `style`,",
+            "DROP COLUMN `old_col`",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_9() {
+    assert_eq!(
+        Table::alter()
+            .table(Glyph::Table)
+            .modify_column_using(
+                ColumnDef::new(Glyph::Aspect).integer().not_null(),
+                Expr::cust("round(`aspect`)")
+            )
+            .to_string(MysqlQueryBuilder),
+        "ALTER TABLE `glyph` MODIFY COLUMN `aspect` int NOT NULL"
+    );
+}
+
+#[test]
+fn alter_10() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .change_column(
+                Font::Name,
+                ColumnDef::new(Alias::new("title"))
+                    .string_len(100)
+                    .not_null()
+                    .default("")
+            )
+            .to_string(MysqlQueryBuilder),
+        "ALTER TABLE `font` CHANGE COLUMN `name` `title` varchar(100) NOT NULL DEFAULT ''"
+    );
+}
+
+#[test]
+fn diff_1() {
+    let current = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Char::Character).string())
+        .col(ColumnDef::new(Char::FontId).integer())
+        .index(
+            Index::create()
+                .name("idx-character-character")
+                .col(Char::Character),
+        )
+        .foreign_key(
+            ForeignKey::create()
+                .name("FK_character_font")
+                .from(Char::Table, Char::FontId)
+                .to(Font::Table, Font::Id),
+        )
+        .to_owned();
+    let desired = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Char::Character).string())
+        .col(ColumnDef::new(Char::FontId).integer().not_null())
+        .index(
+            Index::create()
+                .name("idx-character-font_id")
+                .unique()
+                .col(Char::FontId),
+        )
+        .foreign_key(
+            ForeignKey::create()
+                .name("FK_character_font")
+                .from(Char::Table, Char::FontId)
+                .to(Font::Table, Font::Id)
+                .on_delete(ForeignKeyAction::Cascade),
+        )
+        .to_owned();
+
+    assert_eq!(
+        current.diff(&desired).unwrap().build(MysqlQueryBuilder),
+        vec![
+            "ALTER TABLE `character` DROP FOREIGN KEY `FK_character_font`",
+            "DROP INDEX `idx-character-character` ON `character`",
+            "ALTER TABLE `character` MODIFY COLUMN `font_id` int NOT NULL",
+            "CREATE UNIQUE INDEX `idx-character-font_id` ON `character` (`font_id`)",
+            [
+                "ALTER TABLE `character` ADD CONSTRAINT `FK_character_font`",
+                "FOREIGN KEY (`font_id`) REFERENCES `font` (`id`) ON DELETE CASCADE",
+            ]
+            .join(" ")
+            .as_str(),
+        ]
+    );
+}
+
+#[test]
+fn diff_2() {
+    let current = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string().not_null().default(""))
+        .to_owned();
+    let desired = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().primary_key().not_null())
+        .col(ColumnDef::new(Font::Name).string().default("").not_null())
+        .to_owned();
+
+    let diff = current.diff(&desired).unwrap();
+    assert!(diff.is_empty());
+    assert!(diff.build(MysqlQueryBuilder).is_empty());
+}
+
+#[test]
+fn diff_3() {
+    let current = Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+        .to_owned();
+    let desired = Table::create()
+        .table(Glyph::Table)
+        .col(
+            ColumnDef::new(Glyph::Id)
+                .big_integer()
+                .not_null()
+                .primary_key(),
+        )
+        .to_owned();
+
+    assert_eq!(
+        current.diff(&desired).unwrap().build(MysqlQueryBuilder),
+        vec!["ALTER TABLE `glyph` MODIFY COLUMN `id` bigint NOT NULL"]
+    );
+    assert_eq!(
+        Table::create().diff(&Table::create()).unwrap_err(),
+        error::Error::NoTableName
+    );
+}
@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn begin_1() {
+    assert_eq!(
+        Transaction::begin()
+            .access_mode(TransactionAccessMode::ReadWrite)
+            .to_string(MysqlQueryBuilder),
+        "START TRANSACTION READ WRITE"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support isolation level on START TRANSACTION")]
+fn begin_2() {
+    Transaction::begin()
+        .isolation_level(TransactionIsolationLevel::Serializable)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn set_1() {
+    assert_eq!(
+        Transaction::set()
+            .isolation_level(TransactionIsolationLevel::ReadUncommitted)
+            .to_string(MysqlQueryBuilder),
+        "SET TRANSACTION ISOLATION LEVEL READ UNCOMMITTED"
+    );
+}
+
+#[test]
+fn savepoint_1() {
+    assert_eq!(
+        Transaction::savepoint(Alias::new("sp1")).to_string(MysqlQueryBuilder),
+        "SAVEPOINT `sp1`"
+    );
+}
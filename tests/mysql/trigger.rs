@@ -0,0 +1,60 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Trigger::create()
+            .name(Alias::new("glyph_update"))
+            .timing(TriggerTiming::Before)
+            .event(TriggerEvent::Update(vec![]))
+            .table(Glyph::Table)
+            .statement(
+                Query::insert()
+                    .into_table(Font::Table)
+                    .columns(vec![Font::Name])
+                    .values_panic(vec!["Unknown".into()])
+                    .to_owned()
+            )
+            .statement(
+                Query::delete()
+                    .from_table(Char::Table)
+                    .and_where(
+                        Expr::col(Char::FontId)
+                            .into_simple_expr()
+                            .equals(Expr::cust("OLD.id"))
+                    )
+                    .to_owned()
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            "CREATE TRIGGER `glyph_update` BEFORE UPDATE ON `glyph` FOR EACH ROW BEGIN",
+            "INSERT INTO `font` (`name`) VALUES ('Unknown');",
+            "DELETE FROM `character` WHERE `font_id` = OLD.id;",
+            "END",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mysql not support multiple trigger events")]
+fn create_2() {
+    Trigger::create()
+        .name(Alias::new("glyph_change"))
+        .timing(TriggerTiming::After)
+        .event(TriggerEvent::Insert)
+        .event(TriggerEvent::Delete)
+        .table(Glyph::Table)
+        .statement(Query::delete().from_table(Char::Table).to_owned())
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Trigger::drop()
+            .name(Alias::new("glyph_update"))
+            .to_string(MysqlQueryBuilder),
+        "DROP TRIGGER `glyph_update`"
+    );
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        View::create()
+            .view(Alias::new("glyph_aspect"))
+            .column(Alias::new("aspect"))
+            .query(
+                Query::select()
+                    .column(Glyph::Aspect)
+                    .from(Glyph::Table)
+                    .to_owned()
+            )
+            .to_string(MysqlQueryBuilder),
+        "CREATE VIEW `glyph_aspect` (`aspect`) AS SELECT `aspect` FROM `glyph`"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Materialized view is not supported by this backend")]
+fn create_2() {
+    View::create()
+        .materialized()
+        .view(Alias::new("glyph_aspect"))
+        .query(
+            Query::select()
+                .column(Glyph::Aspect)
+                .from(Glyph::Table)
+                .to_owned(),
+        )
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "REFRESH MATERIALIZED VIEW is not supported by this backend")]
+fn refresh_1() {
+    View::refresh()
+        .view(Alias::new("glyph_aspect"))
+        .to_string(MysqlQueryBuilder);
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Database::create()
+            .name(Alias::new("tenant_1"))
+            .owner(Alias::new("tenant_1_owner"))
+            .charset("UTF8")
+            .collate("en_US.utf8")
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE DATABASE "tenant_1" OWNER "tenant_1_owner" ENCODING 'UTF8' LC_COLLATE 'en_US.utf8'"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Postgres not support IF NOT EXISTS on database")]
+fn create_2() {
+    Database::create()
+        .if_not_exists()
+        .name(Alias::new("tenant_1"))
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn create_3() {
+    assert_eq!(
+        Schema::create()
+            .if_not_exists()
+            .name(Alias::new("tenant_1"))
+            .authorization(Alias::new("tenant_1_owner"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE SCHEMA IF NOT EXISTS "tenant_1" AUTHORIZATION "tenant_1_owner""#
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Database::drop()
+            .if_exists()
+            .name(Alias::new("tenant_1"))
+            .to_string(PostgresQueryBuilder),
+        r#"DROP DATABASE IF EXISTS "tenant_1""#
+    );
+}
+
+#[test]
+fn drop_2() {
+    assert_eq!(
+        Schema::drop()
+            .name(Alias::new("tenant_1"))
+            .cascade()
+            .to_string(PostgresQueryBuilder),
+        r#"DROP SCHEMA "tenant_1" CASCADE"#
+    );
+}
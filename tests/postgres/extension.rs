@@ -0,0 +1,35 @@
+use sea_query::{extension::postgres::Extension, Alias, PostgresQueryBuilder};
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Extension::create()
+            .if_not_exists()
+            .name(Alias::new("uuid-ossp"))
+            .schema(Alias::new("public"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE EXTENSION IF NOT EXISTS "uuid-ossp" WITH SCHEMA "public""#
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        Extension::create()
+            .name(Alias::new("postgis"))
+            .version("3.4.0")
+            .cascade()
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE EXTENSION "postgis" VERSION '3.4.0' CASCADE"#
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Extension::drop()
+            .name(Alias::new("postgis"))
+            .to_string(PostgresQueryBuilder),
+        r#"DROP EXTENSION "postgis""#
+    );
+}
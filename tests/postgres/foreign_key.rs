@@ -10,7 +10,7 @@ fn create_1() {
             .on_delete(ForeignKeyAction::Cascade)
             .on_update(ForeignKeyAction::Cascade)
             .to_string(PostgresQueryBuilder),
-        vec![
+        [
             r#"ALTER TABLE "character" ADD CONSTRAINT "FK_2e303c3a712662f1fc2a4d0aad6""#,
             r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
             r#"ON DELETE CASCADE ON UPDATE CASCADE"#,
@@ -19,6 +19,26 @@ fn create_1() {
     );
 }
 
+#[test]
+fn create_2() {
+    assert_eq!(
+        ForeignKey::create()
+            .name("FK_character_font")
+            .from(Char::Table, Char::FontId)
+            .to(Font::Table, Font::Id)
+            .match_type(ForeignKeyMatch::Full)
+            .on_delete(ForeignKeyAction::SetNull)
+            .deferrable(ForeignKeyDeferrable::InitiallyImmediate)
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "character" ADD CONSTRAINT "FK_character_font""#,
+            r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id") MATCH FULL"#,
+            r#"ON DELETE SET NULL DEFERRABLE INITIALLY IMMEDIATE"#,
+        ]
+        .join(" ")
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -29,3 +49,15 @@ fn drop_1() {
         r#"ALTER TABLE "character" DROP CONSTRAINT "FK_2e303c3a712662f1fc2a4d0aad6""#
     );
 }
+
+#[test]
+fn drop_2() {
+    assert_eq!(
+        ForeignKey::drop()
+            .if_exists()
+            .name("FK_2e303c3a712662f1fc2a4d0aad6")
+            .table(Char::Table)
+            .to_string(PostgresQueryBuilder),
+        r#"ALTER TABLE "character" DROP CONSTRAINT IF EXISTS "FK_2e303c3a712662f1fc2a4d0aad6""#
+    );
+}
@@ -39,6 +39,136 @@ fn create_3() {
     );
 }
 
+#[test]
+fn create_4() {
+    assert_eq!(
+        Index::create()
+            .if_not_exists()
+            .unique()
+            .name("idx-glyph-image")
+            .table(Glyph::Table)
+            .col(Glyph::Image)
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE UNIQUE INDEX IF NOT EXISTS "idx-glyph-image" ON "glyph" ("image")"#
+    );
+}
+
+#[test]
+fn create_5() {
+    assert_eq!(
+        Index::create()
+            .unique()
+            .name("idx-glyph-image")
+            .table(Glyph::Table)
+            .col(Glyph::Image)
+            .and_where(Expr::col(Glyph::Image).is_not_null())
+            .and_where(Expr::col(Glyph::Aspect).gt(0))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE UNIQUE INDEX "idx-glyph-image" ON "glyph" ("image") WHERE "image" IS NOT NULL AND "aspect" > 0"#
+    );
+}
+
+#[test]
+fn create_6() {
+    assert_eq!(
+        Index::create()
+            .unique()
+            .name("idx-glyph-image-id")
+            .table(Glyph::Table)
+            .col(Expr::col(Glyph::Image).cast_json_field("id"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE UNIQUE INDEX "idx-glyph-image-id" ON "glyph" (("image" ->> 'id'))"#
+    );
+}
+
+#[test]
+fn create_7() {
+    assert_eq!(
+        Index::create()
+            .index_type(IndexType::Brin)
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE INDEX "idx-glyph-aspect" ON "glyph" USING BRIN ("aspect")"#
+    );
+}
+
+#[test]
+fn create_8() {
+    assert_eq!(
+        Index::create()
+            .spatial()
+            .name("idx-glyph-image")
+            .table(Glyph::Table)
+            .col(Glyph::Image)
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE INDEX "idx-glyph-image" ON "glyph" USING GIST ("image")"#
+    );
+}
+
+#[test]
+fn create_9() {
+    assert_eq!(
+        Index::create()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .include(Glyph::Id)
+            .and_where(Expr::col(Glyph::Aspect).gt(0))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE INDEX "idx-glyph-aspect" ON "glyph" ("aspect") INCLUDE ("id") WHERE "aspect" > 0"#
+    );
+}
+
+#[test]
+fn create_10() {
+    assert_eq!(
+        Index::create()
+            .concurrently()
+            .if_not_exists()
+            .unique()
+            .name("idx-glyph-image")
+            .table(Glyph::Table)
+            .col(Glyph::Image)
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS "idx-glyph-image" ON "glyph" ("image")"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "ALGORITHM and LOCK are not supported by this backend")]
+fn create_11() {
+    Index::create()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(Glyph::Aspect)
+        .algorithm(IndexAlgorithm::Inplace)
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn create_12() {
+    assert_eq!(
+        Index::create()
+            .name("idx-glyph-image-aspect")
+            .table(Glyph::Table)
+            .col(
+                IndexColumn::new(Expr::col(Glyph::Image).cast_json_field("name"))
+                    .opclass(Alias::new("text_pattern_ops"))
+                    .to_owned()
+            )
+            .col(
+                IndexColumn::new(Glyph::Aspect)
+                    .order(IndexOrder::Asc)
+                    .nulls(NullOrdering::First)
+                    .to_owned()
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE INDEX "idx-glyph-image-aspect" ON "glyph" (("image" ->> 'name') text_pattern_ops, "aspect" ASC NULLS FIRST)"#
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -48,3 +178,37 @@ fn drop_1() {
         r#"DROP INDEX "idx-glyph-aspect""#
     );
 }
+
+#[test]
+fn drop_2() {
+    assert_eq!(
+        Index::drop()
+            .if_exists()
+            .name("idx-glyph-aspect")
+            .to_string(PostgresQueryBuilder),
+        r#"DROP INDEX IF EXISTS "idx-glyph-aspect""#
+    );
+}
+
+#[test]
+fn drop_3() {
+    assert_eq!(
+        Index::drop()
+            .concurrently()
+            .if_exists()
+            .name("idx-glyph-aspect")
+            .to_string(PostgresQueryBuilder),
+        r#"DROP INDEX CONCURRENTLY IF EXISTS "idx-glyph-aspect""#
+    );
+}
+
+#[test]
+fn reindex_1() {
+    assert_eq!(
+        Index::reindex()
+            .concurrently()
+            .table(Glyph::Table)
+            .to_string(PostgresQueryBuilder),
+        r#"REINDEX TABLE CONCURRENTLY "glyph""#
+    );
+}
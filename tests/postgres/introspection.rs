@@ -0,0 +1,298 @@
+use super::*;
+
+#[test]
+fn tables_query_1() {
+    assert_eq!(
+        PostgresQueryBuilder
+            .tables_query(Some("public"))
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT CAST("table_name" AS text) FROM "information_schema"."tables""#,
+            r#"WHERE "table_schema" = 'public' AND "table_type" = 'BASE TABLE'"#,
+            r#"ORDER BY "table_name" ASC"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn columns_query_1() {
+    assert_eq!(
+        PostgresQueryBuilder
+            .columns_query(None, "font")
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT CAST("a"."attname" AS text),"#,
+            r#"CAST(format_type("a"."atttypid", "a"."atttypmod") AS text),"#,
+            r#"CAST("a"."attnotnull" AS text),"#,
+            r#"CAST(pg_get_expr("d"."adbin", "d"."adrelid") AS text),"#,
+            r#"CAST("a"."attidentity" AS text)"#,
+            r#"FROM "pg_catalog"."pg_attribute" AS "a""#,
+            r#"INNER JOIN "pg_catalog"."pg_class" AS "c" ON "c"."oid" = "a"."attrelid""#,
+            r#"INNER JOIN "pg_catalog"."pg_namespace" AS "n" ON "n"."oid" = "c"."relnamespace""#,
+            r#"LEFT JOIN "pg_catalog"."pg_attrdef" AS "d""#,
+            r#"ON ("d"."adrelid" = "a"."attrelid") AND ("d"."adnum" = "a"."attnum")"#,
+            r#"WHERE "n"."nspname" = current_schema() AND "c"."relname" = 'font'"#,
+            r#"AND "a"."attnum" > 0 AND NOT "a"."attisdropped""#,
+            r#"ORDER BY "a"."attnum" ASC"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn indexes_query_1() {
+    assert_eq!(
+        PostgresQueryBuilder
+            .indexes_query(None, "font")
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"SELECT CAST("i"."relname" AS text), CAST("ix"."indisunique" AS text),"#,
+            r#"CAST("ix"."indisprimary" AS text), CAST("a"."attname" AS text)"#,
+            r#"FROM "pg_catalog"."pg_index" AS "ix""#,
+            r#"INNER JOIN "pg_catalog"."pg_class" AS "t" ON "t"."oid" = "ix"."indrelid""#,
+            r#"INNER JOIN "pg_catalog"."pg_class" AS "i" ON "i"."oid" = "ix"."indexrelid""#,
+            r#"INNER JOIN "pg_catalog"."pg_namespace" AS "n" ON "n"."oid" = "t"."relnamespace""#,
+            r#"INNER JOIN "pg_catalog"."pg_attribute" AS "a""#,
+            r#"ON ("a"."attrelid" = "t"."oid") AND ("a"."attnum" = ANY("ix"."indkey"))"#,
+            r#"WHERE "n"."nspname" = current_schema() AND "t"."relname" = 'font'"#,
+            r#"AND "ix"."indexprs" IS NULL AND "ix"."indpred" IS NULL"#,
+            r#"ORDER BY "i"."relname" ASC,"#,
+            r#"array_position(CAST("ix"."indkey" AS int2[]), "a"."attnum") ASC"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn foreign_keys_query_1() {
+    let (sql, values) = PostgresQueryBuilder
+        .foreign_keys_query(None, "character")
+        .build(PostgresQueryBuilder);
+
+    assert!(sql.starts_with(
+        r#"SELECT CAST("rc"."constraint_name" AS text), CAST("kcu"."column_name" AS text),"#
+    ));
+    assert!(sql.ends_with(
+        r#"WHERE "kcu"."table_schema" = current_schema() AND "kcu"."table_name" = $1 ORDER BY "rc"."constraint_name" ASC, "kcu"."ordinal_position" ASC"#
+    ));
+    assert_eq!(values, Values(vec!["character".into()]));
+}
+
+#[test]
+fn parse_table_1() {
+    let columns = vec![
+        row(&[Some("id"), Some("integer"), Some("true"), None, Some("a")]),
+        row(&[Some("code"), Some("text"), Some("true"), None, Some("")]),
+        row(&[
+            Some("font_id"),
+            Some("integer"),
+            Some("false"),
+            None,
+            Some(""),
+        ]),
+        row(&[
+            Some("size"),
+            Some("numeric(10,2)"),
+            Some("false"),
+            None,
+            Some(""),
+        ]),
+        row(&[
+            Some("at"),
+            Some("timestamp(3) with time zone"),
+            Some("false"),
+            Some("now()"),
+            Some(""),
+        ]),
+        row(&[Some("tags"), Some("text[]"), Some("false"), None, Some("")]),
+    ];
+    let indexes = vec![
+        row(&[
+            Some("ch_code_key"),
+            Some("true"),
+            Some("false"),
+            Some("code"),
+        ]),
+        row(&[Some("ch_pkey"), Some("true"), Some("true"), Some("id")]),
+        row(&[Some("ch_pkey"), Some("true"), Some("true"), Some("code")]),
+        row(&[Some("idx_ch"), Some("false"), Some("false"), Some("size")]),
+        row(&[
+            Some("idx_ch"),
+            Some("false"),
+            Some("false"),
+            Some("font_id"),
+        ]),
+    ];
+    let foreign_keys = vec![row(&[
+        Some("ch_font_id_fkey"),
+        Some("font_id"),
+        Some("font"),
+        Some("id"),
+        Some("NO ACTION"),
+        Some("SET NULL"),
+    ])];
+
+    let table = PostgresQueryBuilder
+        .parse_table("ch", &columns, &indexes, &foreign_keys)
+        .unwrap();
+
+    let desired = Table::create()
+        .table(Alias::new("ch"))
+        .col(
+            ColumnDef::new(Alias::new("id"))
+                .integer()
+                .not_null()
+                .identity(Identity::always()),
+        )
+        .col(ColumnDef::new(Alias::new("code")).text().not_null())
+        .col(ColumnDef::new(Alias::new("font_id")).integer())
+        .col(ColumnDef::new(Alias::new("size")).decimal_len(10, 2))
+        .col(
+            ColumnDef::new(Alias::new("at"))
+                .timestamp_with_time_zone_len(3)
+                .default_expr(Expr::cust("now()")),
+        )
+        .col(ColumnDef::new(Alias::new("tags")).array(ColumnType::Text))
+        .index(
+            Index::create()
+                .name("ch_code_key")
+                .unique()
+                .col(Alias::new("code")),
+        )
+        .primary_key(
+            Index::create()
+                .col(Alias::new("id"))
+                .col(Alias::new("code")),
+        )
+        .index(
+            Index::create()
+                .name("idx_ch")
+                .col(Alias::new("size"))
+                .col(Alias::new("font_id")),
+        )
+        .foreign_key(
+            ForeignKey::create()
+                .name("ch_font_id_fkey")
+                .from_col(Alias::new("font_id"))
+                .to(Alias::new("font"), Alias::new("id"))
+                .on_delete(ForeignKeyAction::SetNull),
+        )
+        .to_owned();
+
+    assert!(table.diff(&desired).unwrap().is_empty());
+}
+
+#[test]
+fn parse_table_2() {
+    let columns = vec![
+        row(&[
+            Some("id"),
+            Some("integer"),
+            Some("true"),
+            Some("nextval('font_id_seq'::regclass)"),
+            Some(""),
+        ]),
+        row(&[
+            Some("name"),
+            Some("character varying(255)"),
+            Some("true"),
+            Some("'Sans'::character varying"),
+            Some(""),
+        ]),
+    ];
+    let indexes = vec![row(&[
+        Some("font_pkey"),
+        Some("true"),
+        Some("true"),
+        Some("id"),
+    ])];
+
+    let table = PostgresQueryBuilder
+        .parse_table("font", &columns, &indexes, &[])
+        .unwrap();
+
+    assert_eq!(
+        table.to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "font" ("#,
+            r#""id" serial NOT NULL PRIMARY KEY,"#,
+            r#""name" varchar(255) NOT NULL DEFAULT ('Sans'::character varying)"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+
+    let desired = Table::create()
+        .table(Alias::new("font"))
+        .col(
+            ColumnDef::new(Alias::new("id"))
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(Alias::new("name"))
+                .string_len(255)
+                .not_null()
+                .default_expr(Expr::cust("'Sans'::character varying")),
+        )
+        .col(ColumnDef::new(Alias::new("variant")).string())
+        .to_owned();
+
+    assert_eq!(
+        table
+            .diff(&desired)
+            .unwrap()
+            .to_string(PostgresQueryBuilder),
+        r#"ALTER TABLE "font" ADD COLUMN "variant" varchar"#
+    );
+}
+
+#[test]
+fn parse_table_3() {
+    let columns = vec![row(&[
+        Some("id"),
+        Some("integer"),
+        Some("maybe"),
+        None,
+        Some(""),
+    ])];
+
+    assert_eq!(
+        PostgresQueryBuilder
+            .parse_table("font", &columns, &[], &[])
+            .err(),
+        Some(error::Error::Introspection(
+            "maybe is not a boolean".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn parse_table_4() {
+    let columns = vec![row(&[
+        Some("id"),
+        Some("integer"),
+        Some("true"),
+        None,
+        Some("a"),
+    ])];
+
+    let table = PostgresQueryBuilder
+        .parse_table("font", &columns, &[], &[])
+        .unwrap();
+    let identity = table.get_columns()[0]
+        .get_column_spec()
+        .iter()
+        .find_map(|spec| match spec {
+            ColumnSpec::Identity(identity) => Some(identity),
+            _ => None,
+        })
+        .unwrap();
+
+    assert!(identity.is_always());
+    assert_eq!(identity.get_start(), None);
+    assert_eq!(identity.get_increment(), None);
+}
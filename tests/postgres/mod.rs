@@ -1,8 +1,20 @@
+use common::*;
 use sea_query::{tests_cfg::*, *};
 
+#[path = "../common/mod.rs"]
+mod common;
+
+mod database;
+mod extension;
 mod foreign_key;
 mod index;
+mod introspection;
 #[allow(deprecated)]
 mod query;
+mod role;
+mod sequence;
 mod table;
+mod transaction;
+mod trigger;
 mod types;
+mod view;
@@ -496,7 +496,7 @@ fn select_34a() {
             )
             .or_having(Expr::col(Glyph::Aspect).gt(32))
             .to_string(PostgresQueryBuilder),
-        vec![
+        [
             r#"SELECT "aspect", MAX("image") FROM "glyph" GROUP BY "aspect""#,
             r#"HAVING (("aspect" > 2) OR ("aspect" < 8))"#,
             r#"OR (("aspect" > 12) AND ("aspect" < 18))"#,
@@ -526,7 +526,7 @@ fn select_34b() {
                     .or(Expr::col(Glyph::Aspect).lt(28))
             )
             .to_string(PostgresQueryBuilder),
-        vec![
+        [
             r#"SELECT "aspect", MAX("image") FROM "glyph" GROUP BY "aspect""#,
             r#"HAVING (("aspect" > 2) OR ("aspect" < 8))"#,
             r#"AND (("aspect" > 22) OR ("aspect" < 28))"#,
@@ -679,105 +679,1476 @@ fn select_43() {
 }
 
 #[test]
-#[allow(clippy::approx_constant)]
-fn insert_2() {
+fn select_44() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns(vec![Glyph::Image, Glyph::Aspect,])
-            .values_panic(vec![
-                "04108048005887010020060000204E0180400400".into(),
-                3.1415.into(),
-            ])
+        Query::select()
+            .column(Glyph::Image)
+            .expr_window_as(
+                Expr::col(Glyph::Aspect).max(),
+                WindowStatement::partition_by(Glyph::Image)
+                    .order_by(Glyph::Id, Order::Asc)
+                    .frame_start(FrameType::Range, Frame::UnboundedPreceding)
+                    .to_owned(),
+                Alias::new("m"),
+            )
+            .from(Glyph::Table)
             .to_string(PostgresQueryBuilder),
-        r#"INSERT INTO "glyph" ("image", "aspect") VALUES ('04108048005887010020060000204E0180400400', 3.1415)"#
+        r#"SELECT "image", MAX("aspect") OVER (PARTITION BY "image" ORDER BY "id" ASC RANGE UNBOUNDED PRECEDING) AS "m" FROM "glyph""#
     );
 }
 
 #[test]
-#[allow(clippy::approx_constant)]
-fn insert_3() {
+fn select_45() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns(vec![Glyph::Image, Glyph::Aspect,])
-            .values_panic(vec![
-                "04108048005887010020060000204E0180400400".into(),
-                3.1415.into(),
+        Query::select()
+            .column(Glyph::Image)
+            .expr_window_name(Expr::cust("ROW_NUMBER()"), Alias::new("w"))
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Aspect).gt(2))
+            .window(
+                Alias::new("w"),
+                WindowStatement::new()
+                    .order_by(Glyph::Aspect, Order::Desc)
+                    .frame_between(FrameType::Rows, Frame::CurrentRow, Frame::Following(2))
+                    .to_owned(),
+            )
+            .order_by(Glyph::Id, Order::Asc)
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "image", ROW_NUMBER() OVER "w" FROM "glyph" WHERE "aspect" > 2 WINDOW "w" AS (ORDER BY "aspect" DESC ROWS BETWEEN CURRENT ROW AND 2 FOLLOWING) ORDER BY "id" ASC"#
+    );
+}
+
+#[test]
+fn select_46() {
+    let query = Query::select()
+        .column(Alias::new("image"))
+        .from(Alias::new("wide"))
+        .inner_join(
+            Alias::new("tall"),
+            Expr::tbl(Alias::new("wide"), Alias::new("id"))
+                .equals(Alias::new("tall"), Alias::new("id")),
+        )
+        .to_owned()
+        .with(
+            Query::with()
+                .cte(
+                    CommonTableExpression::new()
+                        .table_name(Alias::new("wide"))
+                        .columns(vec![Alias::new("id"), Alias::new("image")])
+                        .query(
+                            Query::select()
+                                .columns(vec![Glyph::Id, Glyph::Image])
+                                .from(Glyph::Table)
+                                .and_where(Expr::col(Glyph::Aspect).gt(2))
+                                .to_owned(),
+                        )
+                        .to_owned(),
+                )
+                .cte(
+                    CommonTableExpression::new()
+                        .table_name(Alias::new("tall"))
+                        .column(Alias::new("id"))
+                        .query(
+                            Query::select()
+                                .column(Glyph::Id)
+                                .from(Glyph::Table)
+                                .and_where(Expr::col(Glyph::Aspect).lt(1))
+                                .to_owned(),
+                        )
+                        .to_owned(),
+                )
+                .to_owned(),
+        );
+
+    assert_eq!(
+        query.to_string(PostgresQueryBuilder),
+        r#"WITH "wide" ("id", "image") AS (SELECT "id", "image" FROM "glyph" WHERE "aspect" > 2), "tall" ("id") AS (SELECT "id" FROM "glyph" WHERE "aspect" < 1) SELECT "image" FROM "wide" INNER JOIN "tall" ON "wide"."id" = "tall"."id""#
+    );
+}
+
+#[test]
+fn select_47() {
+    let query = Query::select()
+        .column(Alias::new("id"))
+        .from(Alias::new("tree"))
+        .to_owned()
+        .with(
+            Query::with()
+                .recursive(true)
+                .cte(
+                    CommonTableExpression::new()
+                        .table_name(Alias::new("tree"))
+                        .columns(vec![Alias::new("id"), Alias::new("parent_id")])
+                        .recursive_query(
+                            Query::select()
+                                .columns(vec![Alias::new("id"), Alias::new("parent_id")])
+                                .from(Alias::new("category"))
+                                .and_where(Expr::col(Alias::new("id")).eq(1))
+                                .to_owned(),
+                            UnionType::Distinct,
+                            Query::select()
+                                .column((Alias::new("category"), Alias::new("id")))
+                                .column((Alias::new("category"), Alias::new("parent_id")))
+                                .from(Alias::new("category"))
+                                .inner_join(
+                                    Alias::new("tree"),
+                                    Expr::tbl(Alias::new("category"), Alias::new("parent_id"))
+                                        .equals(Alias::new("tree"), Alias::new("id")),
+                                )
+                                .to_owned(),
+                        )
+                        .to_owned(),
+                )
+                .to_owned(),
+        );
+
+    assert_eq!(
+        query.to_string(PostgresQueryBuilder),
+        r#"WITH RECURSIVE "tree" ("id", "parent_id") AS (SELECT "id", "parent_id" FROM "category" WHERE "id" = 1 UNION SELECT "category"."id", "category"."parent_id" FROM "category" INNER JOIN "tree" ON "category"."parent_id" = "tree"."id") SELECT "id" FROM "tree""#
+    );
+}
+
+#[test]
+fn select_48() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Aspect).gt(1))
+            .unions(vec![
+                (
+                    UnionType::Intersect,
+                    Query::select()
+                        .column(Glyph::Id)
+                        .from(Glyph::Table)
+                        .and_where(Expr::col(Glyph::Image).like("A%"))
+                        .to_owned(),
+                ),
+                (
+                    UnionType::Except,
+                    Query::select()
+                        .column(Glyph::Id)
+                        .from(Glyph::Table)
+                        .and_where(Expr::col(Glyph::Id).eq(3))
+                        .to_owned(),
+                ),
             ])
-            .values_panic(vec![Value::String(None), 2.1345.into(),])
+            .order_by(Glyph::Id, Order::Desc)
+            .limit(5)
+            .offset(2)
             .to_string(PostgresQueryBuilder),
-        r#"INSERT INTO "glyph" ("image", "aspect") VALUES ('04108048005887010020060000204E0180400400', 3.1415), (NULL, 2.1345)"#
+        r#"SELECT "id" FROM "glyph" WHERE "aspect" > 1 INTERSECT (SELECT "id" FROM "glyph" WHERE "image" LIKE 'A%') EXCEPT (SELECT "id" FROM "glyph" WHERE "id" = 3) ORDER BY "id" DESC LIMIT 5 OFFSET 2"#
     );
 }
 
 #[test]
-#[cfg(feature = "with-chrono")]
-fn insert_4() {
+fn select_49() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns(vec![Glyph::Image])
-            .values_panic(vec![chrono::NaiveDateTime::from_timestamp(0, 0).into()])
+        Query::select()
+            .column(Char::Character)
+            .from(Char::Table)
+            .and_where(Expr::exists(
+                Query::select()
+                    .column(Font::Id)
+                    .from(Font::Table)
+                    .and_where(Expr::tbl(Font::Table, Font::Id).equals(Char::Table, Char::FontId))
+                    .to_owned(),
+            ))
+            .and_where(
+                Expr::col(Char::SizeW).not_in_subquery(
+                    Query::select()
+                        .expr(Expr::col(Glyph::Aspect).max())
+                        .from(Glyph::Table)
+                        .to_owned(),
+                )
+            )
             .to_string(PostgresQueryBuilder),
-        "INSERT INTO \"glyph\" (\"image\") VALUES ('1970-01-01 00:00:00')"
+        r#"SELECT "character" FROM "character" WHERE EXISTS (SELECT "id" FROM "font" WHERE "font"."id" = "character"."font_id") AND "size_w" NOT IN (SELECT MAX("aspect") FROM "glyph")"#
     );
 }
 
 #[test]
-#[cfg(feature = "with-uuid")]
-fn insert_5() {
+fn select_50() {
     assert_eq!(
-        Query::insert()
-            .into_table(Glyph::Table)
-            .columns(vec![Glyph::Image])
-            .values_panic(vec![uuid::Uuid::nil().into()])
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(
+                Expr::col(Glyph::Aspect).lte_all(
+                    Query::select()
+                        .column(Glyph::Aspect)
+                        .from(Glyph::Table)
+                        .and_where(Expr::col(Glyph::Image).like("A%"))
+                        .to_owned(),
+                )
+            )
+            .and_where(
+                Expr::col(Glyph::Id).ne_any(
+                    Query::select()
+                        .column(Glyph::Id)
+                        .from(Glyph::Table)
+                        .to_owned(),
+                )
+            )
             .to_string(PostgresQueryBuilder),
-        "INSERT INTO \"glyph\" (\"image\") VALUES ('00000000-0000-0000-0000-000000000000')"
+        r#"SELECT "id" FROM "glyph" WHERE "aspect" <= ALL (SELECT "aspect" FROM "glyph" WHERE "image" LIKE 'A%') AND "id" <> ANY (SELECT "id" FROM "glyph")"#
     );
 }
 
 #[test]
-fn update_1() {
+fn select_51() {
+    let (statement, values) = Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .and_where(
+            Expr::col(Glyph::Id).eq_any(Expr::val(Value::Array(Some(Box::new(vec![
+                1.into(),
+                2.into(),
+            ]))))),
+        )
+        .build(PostgresQueryBuilder);
+
     assert_eq!(
-        Query::update()
-            .table(Glyph::Table)
-            .values(vec![
-                (Glyph::Aspect, 2.1345.into()),
-                (
-                    Glyph::Image,
-                    "24B0E11951B03B07F8300FD003983F03F0780060".into()
-                ),
+        statement,
+        r#"SELECT "id" FROM "glyph" WHERE "id" = ANY($1)"#
+    );
+    assert_eq!(
+        values,
+        Values(vec![Value::Array(Some(Box::new(vec![1.into(), 2.into()])))])
+    );
+}
+
+#[test]
+fn select_52() {
+    assert_eq!(
+        Query::select()
+            .distinct()
+            .column(Glyph::Image)
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Aspect).gt(1))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT DISTINCT "image" FROM "glyph" WHERE "aspect" > 1"#
+    );
+}
+
+#[test]
+fn select_53() {
+    assert_eq!(
+        Query::select()
+            .distinct_on(vec![
+                (Glyph::Table, Glyph::Image),
+                (Glyph::Table, Glyph::Aspect)
             ])
-            .and_where(Expr::col(Glyph::Id).eq(1))
+            .columns(vec![Glyph::Id, Glyph::Image])
+            .from(Glyph::Table)
+            .order_by(Glyph::Image, Order::Asc)
             .to_string(PostgresQueryBuilder),
-        r#"UPDATE "glyph" SET "aspect" = 2.1345, "image" = '24B0E11951B03B07F8300FD003983F03F0780060' WHERE "id" = 1"#
+        r#"SELECT DISTINCT ON ("glyph"."image", "glyph"."aspect") "id", "image" FROM "glyph" ORDER BY "image" ASC"#
     );
 }
 
 #[test]
-fn update_3() {
+fn select_54() {
     assert_eq!(
-        Query::update()
-            .table(Glyph::Table)
-            .value_expr(Glyph::Aspect, Expr::cust("60 * 24 * 24"))
-            .values(vec![(
-                Glyph::Image,
-                "24B0E11951B03B07F8300FD003983F03F0780060".into()
-            ),])
-            .and_where(Expr::col(Glyph::Id).eq(1))
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .order_by_with_nulls(Glyph::Aspect, Order::Asc, NullOrdering::First)
+            .order_by(Glyph::Image, Order::Desc)
             .to_string(PostgresQueryBuilder),
-        r#"UPDATE "glyph" SET "aspect" = 60 * 24 * 24, "image" = '24B0E11951B03B07F8300FD003983F03F0780060' WHERE "id" = 1"#
+        r#"SELECT "id" FROM "glyph" ORDER BY "aspect" ASC NULLS FIRST, "image" DESC"#
     );
 }
 
 #[test]
-fn delete_1() {
+fn select_55() {
     assert_eq!(
-        Query::delete()
-            .from_table(Glyph::Table)
-            .and_where(Expr::col(Glyph::Id).eq(1))
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .order_by_field(Glyph::Id, vec![4.into(), 5.into(), 1.into()])
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "glyph" ORDER BY CASE WHEN "id" = $1 THEN 0 WHEN "id" = $2 THEN 1 WHEN "id" = $3 THEN 2 ELSE 3 END ASC"#.to_owned(),
+            Values(vec![Value::Int(Some(4)), Value::Int(Some(5)), Value::Int(Some(1))])
+        )
+    );
+}
+
+#[test]
+fn select_56() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .column(Font::Name)
+            .from(Char::Table)
+            .cross_join(Font::Table)
+            .and_where(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
             .to_string(PostgresQueryBuilder),
-        r#"DELETE FROM "glyph" WHERE "id" = 1"#
+        r#"SELECT "character", "name" FROM "character" CROSS JOIN "font" WHERE "character"."font_id" = "font"."id""#
+    );
+}
+
+#[test]
+fn select_57() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .column(Font::Name)
+            .from(Char::Table)
+            .full_outer_join(
+                Font::Table,
+                Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id)
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "character", "name" FROM "character" FULL OUTER JOIN "font" ON "character"."font_id" = "font"."id""#
+    );
+}
+
+#[test]
+fn select_58() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Image)
+            .from(Glyph::Table)
+            .join_using(
+                JoinType::LeftJoin,
+                Alias::new("glyph_variant"),
+                vec![Glyph::Id, Glyph::Aspect]
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "image" FROM "glyph" LEFT JOIN "glyph_variant" USING ("id", "aspect")"#
+    );
+}
+
+#[test]
+fn select_59() {
+    let glyph_count = Alias::new("glyph_count");
+    let cnt = Alias::new("cnt");
+    assert_eq!(
+        Query::select()
+            .column((Font::Table, Font::Name))
+            .column((glyph_count.clone(), cnt.clone()))
+            .from(Font::Table)
+            .join_subquery(
+                JoinType::InnerJoin,
+                Query::select()
+                    .column(Char::FontId)
+                    .expr_as(Func::count(Expr::col(Char::Id)), cnt.clone())
+                    .from(Char::Table)
+                    .and_where(Expr::col(Char::SizeW).gt(10))
+                    .group_by_col(Char::FontId)
+                    .take(),
+                glyph_count.clone(),
+                Expr::tbl(Font::Table, Font::Id).equals(glyph_count.clone(), Char::FontId)
+            )
+            .and_where(Expr::tbl(glyph_count, cnt).gt(2))
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "font"."name", "glyph_count"."cnt" FROM "font" INNER JOIN (SELECT "font_id", COUNT("id") AS "cnt" FROM "character" WHERE "size_w" > $1 GROUP BY "font_id") AS "glyph_count" ON "font"."id" = "glyph_count"."font_id" WHERE "glyph_count"."cnt" > $2"#.to_owned(),
+            Values(vec![Value::Int(Some(10)), Value::Int(Some(2))])
+        )
+    );
+}
+
+#[test]
+fn select_60() {
+    let v = Alias::new("v");
+    assert_eq!(
+        Query::select()
+            .column((v.clone(), Glyph::Id))
+            .from_values(
+                vec![
+                    Values(vec![1.into(), "A".into()]),
+                    Values(vec![2.into(), "B".into()]),
+                ],
+                v.clone(),
+                vec![Glyph::Id, Glyph::Image]
+            )
+            .and_where(Expr::not_exists(
+                Query::select()
+                    .column(Glyph::Id)
+                    .from(Glyph::Table)
+                    .and_where(Expr::tbl(Glyph::Table, Glyph::Id).equals(v.clone(), Glyph::Id))
+                    .and_where(Expr::tbl(Glyph::Table, Glyph::Image).equals(v, Glyph::Image))
+                    .take()
+            ))
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "v"."id" FROM (VALUES ($1, $2), ($3, $4)) AS "v" ("id", "image") WHERE NOT EXISTS (SELECT "id" FROM "glyph" WHERE "glyph"."id" = "v"."id" AND "glyph"."image" = "v"."image")"#.to_owned(),
+            Values(vec![
+                Value::Int(Some(1)),
+                Value::String(Some(Box::new("A".to_owned()))),
+                Value::Int(Some(2)),
+                Value::String(Some(Box::new("B".to_owned())))
+            ])
+        )
+    );
+}
+
+#[test]
+fn select_61() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(
+                Expr::tuple(vec![Expr::col(Glyph::Aspect).into(), Expr::col(Glyph::Id).into()])
+                    .into_simple_expr()
+                    .less_than(Expr::tuple(vec![Expr::val(2.5).into(), Expr::val(100).into()]))
+            )
+            .and_where(
+                Expr::tuple(vec![Expr::col(Glyph::Aspect).into(), Expr::col(Glyph::Image).into()])
+                    .not_in_tuples(vec![(1.5, "A")])
+            )
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "glyph" WHERE ("aspect", "id") < ($1, $2) AND ("aspect", "image") NOT IN (($3, $4))"#.to_owned(),
+            Values(vec![
+                Value::Double(Some(2.5)),
+                Value::Int(Some(100)),
+                Value::Double(Some(1.5)),
+                Value::String(Some(Box::new("A".to_owned())))
+            ])
+        )
+    );
+}
+
+#[test]
+fn select_62() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(
+                Expr::tuple(vec![
+                    Expr::col(Glyph::Aspect).into(),
+                    Expr::col(Glyph::Id).into()
+                ])
+                .in_tuples(Vec::<(i32, i32)>::new())
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "id" FROM "glyph" WHERE 1 = 2"#
+    );
+}
+
+#[test]
+fn select_63() {
+    assert_eq!(
+        Query::select()
+            .columns(vec![Glyph::Id, Glyph::Image])
+            .from(Glyph::Table)
+            .and_where(Expr::col(Glyph::Image).is_not_null())
+            .paginate(
+                Keyset::desc(vec![Glyph::Aspect, Glyph::Id])
+                    .before(vec![2.5.into(), 10.into()])
+                    .to_owned()
+            )
+            .unwrap()
+            .limit(5)
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id", "image" FROM "glyph" WHERE "image" IS NOT NULL AND ("aspect", "id") > ($1, $2) ORDER BY "aspect" ASC, "id" ASC LIMIT $3"#.to_owned(),
+            Values(vec![
+                Value::Double(Some(2.5)),
+                Value::Int(Some(10)),
+                Value::BigUnsigned(Some(5))
+            ])
+        )
+    );
+}
+
+#[test]
+fn select_64() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(
+                Expr::cast_as(Expr::col(Glyph::Image).into(), ColumnType::String(Some(4)))
+                    .eq("24B")
+            )
+            .and_where(
+                Expr::cast_as(
+                    Expr::col(Glyph::Aspect).into(),
+                    ColumnType::Decimal(Some((10, 2)))
+                )
+                .gt(1.5)
+            )
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT "id" FROM "glyph" WHERE CAST("image" AS varchar(4)) = $1 AND CAST("aspect" AS decimal(10, 2)) > $2"#.to_owned(),
+            Values(vec![
+                Value::String(Some(Box::new("24B".to_owned()))),
+                Value::Double(Some(1.5))
+            ])
+        )
+    );
+}
+
+#[test]
+fn select_65() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::coalesce(vec![
+                Func::null_if(Expr::col(Glyph::Image), Expr::val("")),
+                Expr::val("N/A").into()
+            ]))
+            .expr(Func::greatest(vec![
+                Expr::col(Glyph::Aspect),
+                Expr::val(1)
+            ]))
+            .expr(Func::least(vec![Expr::col(Glyph::Aspect), Expr::val(10)]))
+            .from(Glyph::Table)
+            .build(PostgresQueryBuilder),
+        (
+            r#"SELECT COALESCE(NULLIF("image", $1), $2), GREATEST("aspect", $3), LEAST("aspect", $4) FROM "glyph""#.to_owned(),
+            Values(vec![
+                Value::String(Some(Box::new("".to_owned()))),
+                Value::String(Some(Box::new("N/A".to_owned()))),
+                Value::Int(Some(1)),
+                Value::Int(Some(10))
+            ])
+        )
+    );
+}
+
+#[test]
+fn select_66() {
+    assert_eq!(
+        Query::select()
+            .column(Char::FontId)
+            .expr_as(
+                Func::count_distinct(Expr::col(Char::Character)),
+                Alias::new("characters")
+            )
+            .expr(Func::avg_distinct(Expr::col(Char::SizeW)))
+            .from(Char::Table)
+            .group_by_col(Char::FontId)
+            .and_having(Expr::expr(Func::sum_distinct(Expr::col(Char::SizeH))).gt(10))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "font_id", COUNT(DISTINCT "character") AS "characters", AVG(DISTINCT "size_w") FROM "character" GROUP BY "font_id" HAVING SUM(DISTINCT "size_h") > 10"#
+    );
+}
+
+#[test]
+fn select_67() {
+    assert_eq!(
+        Query::select()
+            .columns(vec![Glyph::Aspect, Glyph::Image])
+            .expr(Func::count(Expr::col(Glyph::Id)))
+            .from(Glyph::Table)
+            .group_by_col(Glyph::Aspect)
+            .group_by_grouping_sets(vec![vec![Glyph::Image], vec![]])
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "aspect", "image", COUNT("id") FROM "glyph" GROUP BY "aspect", GROUPING SETS (("image"), ())"#
+    );
+}
+
+#[test]
+fn select_68() {
+    assert_eq!(
+        Query::select()
+            .column(Char::FontId)
+            .expr(Func::string_agg_order_by(
+                Expr::col(Char::Character),
+                "; ",
+                vec![
+                    (Expr::col(Char::SizeW), Order::Desc),
+                    (Expr::col(Char::SizeH), Order::Asc),
+                ],
+            ))
+            .from(Char::Table)
+            .and_where(Expr::col(Char::FontId).is_not_null())
+            .group_by_col(Char::FontId)
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "font_id", STRING_AGG("character", '; ' ORDER BY "size_w" DESC, "size_h" ASC) FROM "character" WHERE "font_id" IS NOT NULL GROUP BY "font_id""#
+    );
+}
+
+#[test]
+fn select_69() {
+    let (statement, values) = Query::select()
+        .expr(Func::json_build_object(vec![
+            ("id", Expr::col(Glyph::Id).into_simple_expr()),
+            (
+                "size",
+                Func::json_extract(
+                    Expr::col(Alias::new("metadata")),
+                    vec!["size", "line height"],
+                ),
+            ),
+        ]))
+        .from(Glyph::Table)
+        .and_where(Expr::col(Alias::new("metadata")).has_key("size"))
+        .and_where(Expr::expr(Expr::col(Alias::new("metadata")).cast_json_field("name")).eq("A"))
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT JSON_BUILD_OBJECT($1, "id", $2, JSONB_EXTRACT_PATH("metadata", $3, $4)) FROM "glyph" WHERE "metadata" ? $5 AND "metadata" ->> $6 = $7"#
+    );
+    assert_eq!(
+        values.0,
+        vec![
+            "id".into(),
+            "size".into(),
+            "size".into(),
+            "line height".into(),
+            "size".into(),
+            "name".into(),
+            "A".into(),
+        ]
+    );
+}
+
+#[test]
+fn select_70() {
+    let (statement, values) = Query::update()
+        .table(Glyph::Table)
+        .value_expr(
+            Alias::new("metadata"),
+            Func::json_set(
+                Expr::col(Alias::new("metadata")),
+                vec!["size", "line height"],
+                Expr::val(12),
+            ),
+        )
+        .and_where(
+            Expr::expr(Expr::col(Alias::new("metadata")).get_json_path("{size}")).is_not_null(),
+        )
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"UPDATE "glyph" SET "metadata" = JSONB_SET("metadata", $1, $2) WHERE ("metadata" #> $3) IS NOT NULL"#
+    );
+    assert_eq!(
+        values.0,
+        vec![
+            Value::Array(Some(Box::new(vec!["size".into(), "line height".into()]))),
+            12i32.into(),
+            "{size}".into()
+        ]
+    );
+}
+
+#[test]
+fn select_71() {
+    let (statement, values) = Query::select()
+        .column(Char::FontId)
+        .expr(PgFunc::array_agg(Expr::col(Char::Character)))
+        .from(Char::Table)
+        .and_where(
+            Expr::col(Alias::new("tags")).overlap(Expr::val(Value::Array(Some(Box::new(vec![
+                "serif".into(),
+                "mono".into(),
+            ]))))),
+        )
+        .and_where(Expr::col(Alias::new("tags")).subscript(1).ne("hidden"))
+        .and_where(Expr::val("bold").eq_any(Expr::col(Alias::new("tags"))))
+        .group_by_col(Char::FontId)
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT "font_id", ARRAY_AGG("character") FROM "character" WHERE "tags" && $1 AND "tags"[1] <> $2 AND $3 = ANY("tags") GROUP BY "font_id""#
+    );
+    assert_eq!(
+        values.0,
+        vec![
+            Value::Array(Some(Box::new(vec!["serif".into(), "mono".into()]))),
+            "hidden".into(),
+            "bold".into(),
+        ]
+    );
+}
+
+#[test]
+fn select_72() {
+    assert_eq!(
+        Query::select()
+            .expr(PgFunc::unnest(Expr::col(Alias::new("tags"))))
+            .from(Font::Table)
+            .and_where(Expr::col(Alias::new("tags")).contains(Expr::cust("ARRAY['serif']")))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT UNNEST("tags") FROM "font" WHERE "tags" @> ARRAY['serif']"#
+    );
+}
+
+#[test]
+fn select_73() {
+    let (statement, values) = Query::select()
+        .column(Font::Id)
+        .from(Font::Table)
+        .and_where(Expr::full_text_match(
+            vec![Font::Name, Font::Variant],
+            "serif & !bold",
+            SearchMode::Boolean,
+        ))
+        .and_where(Expr::col(Font::Language).eq("en"))
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT "id" FROM "font" WHERE TO_TSVECTOR(CONCAT_WS(' ', "name", "variant")) @@ TO_TSQUERY($1) AND "language" = $2"#
+    );
+    assert_eq!(values.0, vec!["serif & !bold".into(), "en".into()]);
+}
+
+#[test]
+fn select_74() {
+    let (statement, values) = Query::select()
+        .column(Font::Id)
+        .from(Font::Table)
+        .cond_where(
+            Cond::all()
+                .add(
+                    Cond::any()
+                        .add(Expr::col(Font::Name).ends_with("100%"))
+                        .add(Expr::col(Font::Variant).contains_text("a!b")),
+                )
+                .add(Expr::col(Font::Language).ilike("EN%")),
+        )
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT "id" FROM "font" WHERE ("name" LIKE $1 ESCAPE $2 OR "variant" LIKE $3 ESCAPE $4) AND "language" ILIKE $5"#
+    );
+    assert_eq!(
+        values.0,
+        vec![
+            "%100!%".into(),
+            "!".into(),
+            "%a!!b%".into(),
+            "!".into(),
+            "EN%".into(),
+        ]
+    );
+}
+
+#[test]
+fn select_75() {
+    let (statement, values) = Query::select()
+        .column(Font::Id)
+        .from(Font::Table)
+        .and_where(Expr::col(Font::Name).regexp("^[A-Z]"))
+        .and_where(Expr::col(Font::Variant).not_iregexp("bold|black"))
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT "id" FROM "font" WHERE "name" ~ $1 AND "variant" !~* $2"#
+    );
+    assert_eq!(values.0, vec!["^[A-Z]".into(), "bold|black".into()]);
+}
+
+#[test]
+fn select_76() {
+    let (statement, values) = Query::select()
+        .column(Char::Id)
+        .from(Char::Table)
+        .cond_where(
+            Cond::any()
+                .add(Expr::col(Char::FontId).is_distinct_from(Expr::val(5)))
+                .add(Expr::col(Char::SizeW).is_not_distinct_from(Expr::col(Char::SizeH))),
+        )
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT "id" FROM "character" WHERE "font_id" IS DISTINCT FROM $1 OR "size_w" IS NOT DISTINCT FROM "size_h""#
+    );
+    assert_eq!(values.0, vec![5i32.into()]);
+}
+
+#[test]
+fn select_77() {
+    let (statement, values) = Query::select()
+        .expr(Expr::col(Glyph::Aspect).bit_xor(3))
+        .expr(Expr::col(Glyph::Aspect).right_shift(2))
+        .from(Glyph::Table)
+        .and_where(Expr::expr(Expr::col(Glyph::Aspect).bit_and(4)).eq(4))
+        .and_where(Expr::expr(Expr::col(Glyph::Aspect).bit_not().bit_or(Expr::val(1))).ne(0))
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT "aspect" # $1, "aspect" >> $2 FROM "glyph" WHERE "aspect" & $3 = $4 AND ~ "aspect" | $5 <> $6"#
+    );
+    assert_eq!(
+        values.0,
+        vec![
+            3i32.into(),
+            2i32.into(),
+            4i32.into(),
+            4i32.into(),
+            1i32.into(),
+            0i32.into()
+        ]
+    );
+}
+
+#[test]
+fn select_78() {
+    let (statement, values) = Query::select()
+        .column(Font::Id)
+        .from(Font::Table)
+        .and_where(
+            Expr::expr(
+                Expr::col(Font::Name)
+                    .concat(Expr::val("-"))
+                    .concat(Expr::col(Font::Variant))
+                    .concat(Expr::val("!")),
+            )
+            .eq("Arial-Bold!"),
+        )
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT "id" FROM "font" WHERE "name" || $1 || "variant" || $2 = $3"#
+    );
+    assert_eq!(values.0, vec!["-".into(), "!".into(), "Arial-Bold!".into()]);
+}
+
+#[test]
+fn select_79() {
+    let language: Option<&str> = Some("en");
+    let variant: Option<&str> = None;
+    let (statement, values) = Query::select()
+        .table_column(Font::Table, Font::Name)
+        .expr(Func::count(Expr::tbl(Char::Table, Char::Id)))
+        .from(Char::Table)
+        .left_join(
+            Font::Table,
+            Cond::all()
+                .add(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+                .add_option(language.map(|l| Expr::tbl(Font::Table, Font::Language).eq(l)))
+                .add_option(variant.map(|v| Expr::tbl(Font::Table, Font::Variant).eq(v))),
+        )
+        .cond_where(
+            Cond::any()
+                .add(
+                    Cond::all()
+                        .add(Expr::tbl(Char::Table, Char::SizeW).gt(10))
+                        .add(Expr::tbl(Char::Table, Char::SizeH).gt(10)),
+                )
+                .add(
+                    Cond::all()
+                        .add(Expr::tbl(Char::Table, Char::SizeW).lt(2))
+                        .add(Expr::tbl(Char::Table, Char::SizeH).lt(2)),
+                ),
+        )
+        .group_by_columns(vec![(Font::Table, Font::Name)])
+        .cond_having(
+            Cond::any()
+                .add(Expr::expr(Func::count(Expr::tbl(Char::Table, Char::Id))).gt(5))
+                .add(Expr::tbl(Font::Table, Font::Name).is_null()),
+        )
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT "font"."name", COUNT("character"."id") FROM "character" LEFT JOIN "font" ON "character"."font_id" = "font"."id" AND "font"."language" = $1 WHERE ("character"."size_w" > $2 AND "character"."size_h" > $3) OR ("character"."size_w" < $4 AND "character"."size_h" < $5) GROUP BY "font"."name" HAVING COUNT("character"."id") > $6 OR "font"."name" IS NULL"#
+    );
+    assert_eq!(
+        values.0,
+        vec![
+            "en".into(),
+            10i32.into(),
+            10i32.into(),
+            2i32.into(),
+            2i32.into(),
+            5i32.into(),
+        ]
+    );
+}
+
+#[test]
+fn select_80() {
+    let (statement, values) = sea_query::Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .and_where(Expr::col(Glyph::Aspect).gt(2))
+        .and_where(Expr::cust_with_values(
+            "image LIKE ? AND LENGTH(image) < ?",
+            vec![Value::from("A%"), Value::from(10)],
+        ))
+        .and_where(Expr::col(Glyph::Id).ne(5))
+        .build(sea_query::PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT "id" FROM "glyph" WHERE "aspect" > $1 AND image LIKE $2 AND LENGTH(image) < $3 AND "id" <> $4"#
+    );
+    assert_eq!(
+        values.0,
+        vec![
+            Value::Int(Some(2)),
+            Value::String(Some(Box::new("A%".to_owned()))),
+            Value::Int(Some(10)),
+            Value::Int(Some(5))
+        ]
+    );
+}
+
+#[test]
+fn select_81() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::cast_as(
+                Expr::val("{1,2}").into(),
+                ColumnType::Array(Box::new(ColumnType::Integer(None)))
+            ))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT CAST('{1,2}' AS integer[])"#
+    );
+}
+
+#[test]
+fn select_82() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::nextval(Alias::new("glyph_id_seq")))
+            .expr(Func::setval(Alias::new("font_id_seq"), Expr::val(100)))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT NEXTVAL('glyph_id_seq'), SETVAL('font_id_seq', 100)"#
+    );
+}
+
+#[test]
+fn select_83() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Character)
+            .from_as(
+                (Alias::new("studio"), Alias::new("public"), Char::Table),
+                Alias::new("c")
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT "character" FROM "studio"."public"."character" AS "c""#
+    );
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn insert_2() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image, Glyph::Aspect,])
+            .values_panic(vec![
+                "04108048005887010020060000204E0180400400".into(),
+                3.1415.into(),
+            ])
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("image", "aspect") VALUES ('04108048005887010020060000204E0180400400', 3.1415)"#
+    );
+}
+
+#[test]
+#[allow(clippy::approx_constant)]
+fn insert_3() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image, Glyph::Aspect,])
+            .values_panic(vec![
+                "04108048005887010020060000204E0180400400".into(),
+                3.1415.into(),
+            ])
+            .values_panic(vec![Value::String(None), 2.1345.into(),])
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("image", "aspect") VALUES ('04108048005887010020060000204E0180400400', 3.1415), (NULL, 2.1345)"#
+    );
+}
+
+#[test]
+#[cfg(feature = "with-chrono")]
+fn insert_4() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image])
+            .values_panic(vec![chrono::NaiveDateTime::from_timestamp(0, 0).into()])
+            .to_string(PostgresQueryBuilder),
+        "INSERT INTO \"glyph\" (\"image\") VALUES ('1970-01-01 00:00:00')"
+    );
+}
+
+#[test]
+#[cfg(feature = "with-uuid")]
+fn insert_5() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image])
+            .values_panic(vec![uuid::Uuid::nil().into()])
+            .to_string(PostgresQueryBuilder),
+        "INSERT INTO \"glyph\" (\"image\") VALUES ('00000000-0000-0000-0000-000000000000')"
+    );
+}
+
+#[test]
+#[cfg(feature = "with-chrono")]
+fn insert_6() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image, Glyph::Aspect])
+            .values_panic(vec![
+                chrono::NaiveDate::from_ymd(2020, 1, 1).into(),
+                chrono::NaiveTime::from_hms(2, 2, 2).into(),
+            ])
+            .to_string(PostgresQueryBuilder),
+        "INSERT INTO \"glyph\" (\"image\", \"aspect\") VALUES (DATE '2020-01-01', TIME '02:02:02')"
+    );
+}
+
+#[test]
+fn insert_7() {
+    let (statement, values) = Query::insert()
+        .into_table(Glyph::Table)
+        .columns(vec![Glyph::Id, Glyph::Image])
+        .values_panic(vec![None::<i64>.into(), None::<String>.into()])
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"INSERT INTO "glyph" ("id", "image") VALUES ($1, $2)"#
+    );
+    assert_eq!(
+        values,
+        Values(vec![Value::BigInt(None), Value::String(None)])
+    );
+    assert_ne!(values.0[0], values.0[1]);
+}
+
+#[test]
+fn insert_8() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Id, Glyph::Aspect, Glyph::Image])
+            .values_panic(vec![1.into(), 2.5.into(), "B".into()])
+            .on_conflict(
+                OnConflict::columns(vec![Glyph::Id, Glyph::Image])
+                    .target_and_where(Expr::col(Glyph::Aspect).gt(0))
+                    .update_column(Glyph::Image)
+                    .update_value(Glyph::Aspect, Expr::col(Glyph::Aspect).mul(2))
+                    .to_owned(),
+            )
+            .returning_col(Glyph::Id)
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("id", "aspect", "image") VALUES (1, 2.5, 'B') ON CONFLICT ("id", "image") WHERE "aspect" > 0 DO UPDATE SET "image" = "excluded"."image", "aspect" = "aspect" * 2 RETURNING "id""#
+    );
+}
+
+#[test]
+fn insert_9() {
+    let (statement, values) = Query::insert()
+        .into_table(Glyph::Table)
+        .columns(vec![Glyph::Aspect, Glyph::Image])
+        .select_from(
+            Query::select()
+                .expr(Expr::col(Char::SizeW).add(1))
+                .column(Char::Character)
+                .from(Char::Table)
+                .and_where(Expr::col(Char::FontId).eq(5))
+                .to_owned(),
+        )
+        .unwrap()
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"INSERT INTO "glyph" ("aspect", "image") SELECT "size_w" + $1, "character" FROM "character" WHERE "font_id" = $2"#
+    );
+    assert_eq!(
+        values,
+        Values(vec![Value::Int(Some(1)), Value::Int(Some(5))])
+    );
+}
+
+#[test]
+fn insert_10() {
+    let (statement, values) = Query::insert()
+        .into_table(Glyph::Table)
+        .columns(vec![Glyph::Aspect, Glyph::Image])
+        .exprs_panic(vec![
+            Expr::val(1).into_simple_expr(),
+            Query::select()
+                .expr(Expr::col(Glyph::Image).max())
+                .from(Glyph::Table)
+                .to_owned()
+                .into(),
+        ])
+        .exprs_panic(vec![
+            Expr::subquery(
+                Query::select()
+                    .expr(Expr::col(Glyph::Aspect).max())
+                    .from(Glyph::Table)
+                    .to_owned(),
+            )
+            .add(1),
+            Expr::val("A").into_simple_expr(),
+        ])
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"INSERT INTO "glyph" ("aspect", "image") VALUES ($1, (SELECT MAX("image") FROM "glyph")), ((SELECT MAX("aspect") FROM "glyph") + $2, $3)"#
+    );
+    assert_eq!(
+        values,
+        Values(vec![
+            Value::Int(Some(1)),
+            Value::Int(Some(1)),
+            Value::String(Some(Box::new("A".to_owned())))
+        ])
+    );
+}
+
+#[test]
+fn insert_11() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .or_default_values()
+            .returning_col(Glyph::Id)
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" DEFAULT VALUES RETURNING "id""#
+    );
+}
+
+#[test]
+fn insert_12() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image])
+            .values_panic(vec!["A".into()])
+            .or_default_values()
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("image") VALUES ('A')"#
+    );
+}
+
+#[test]
+fn insert_13() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Id, Glyph::Aspect, Glyph::Image])
+            .values_panic(vec![1.into(), 2.5.into(), "B".into()])
+            .on_conflict(
+                OnConflict::column(Glyph::Id)
+                    .update_values(vec![
+                        (Glyph::Aspect, Expr::excluded(Glyph::Aspect).mul(2)),
+                        (Glyph::Image, Expr::excluded(Glyph::Image).into()),
+                    ])
+                    .action_and_where(
+                        Expr::excluded(Glyph::Aspect)
+                            .into_simple_expr()
+                            .greater_than(Expr::tbl(Glyph::Table, Glyph::Aspect)),
+                    )
+                    .to_owned(),
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("id", "aspect", "image") VALUES (1, 2.5, 'B') ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect" * 2, "image" = "excluded"."image" WHERE "excluded"."aspect" > "glyph"."aspect""#
+    );
+}
+
+#[test]
+fn insert_14() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image])
+            .values_panic(vec!["C:\\fonts\\it's".into()])
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("image") VALUES ('C:\fonts\it''s')"#
+    );
+}
+
+#[test]
+fn insert_15() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Image])
+            .values_panic(vec![vec![0xDEu8, 0xAD, 0xBE, 0xEF].into()])
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("image") VALUES ('\xDEADBEEF'::bytea)"#
+    );
+}
+
+#[test]
+fn update_1() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .values(vec![
+                (Glyph::Aspect, 2.1345.into()),
+                (
+                    Glyph::Image,
+                    "24B0E11951B03B07F8300FD003983F03F0780060".into()
+                ),
+            ])
+            .and_where(Expr::col(Glyph::Id).eq(1))
+            .to_string(PostgresQueryBuilder),
+        r#"UPDATE "glyph" SET "aspect" = 2.1345, "image" = '24B0E11951B03B07F8300FD003983F03F0780060' WHERE "id" = 1"#
+    );
+}
+
+#[test]
+fn update_3() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value_expr(Glyph::Aspect, Expr::cust("60 * 24 * 24"))
+            .values(vec![(
+                Glyph::Image,
+                "24B0E11951B03B07F8300FD003983F03F0780060".into()
+            ),])
+            .and_where(Expr::col(Glyph::Id).eq(1))
+            .to_string(PostgresQueryBuilder),
+        r#"UPDATE "glyph" SET "aspect" = 60 * 24 * 24, "image" = '24B0E11951B03B07F8300FD003983F03F0780060' WHERE "id" = 1"#
+    );
+}
+
+#[test]
+fn update_4() {
+    let (statement, values) = Query::update()
+        .table(Glyph::Table)
+        .value(Glyph::Aspect, 0.into())
+        .and_where(
+            Expr::col(Glyph::Id).in_subquery(
+                Query::select()
+                    .column(Alias::new("id"))
+                    .from(Alias::new("cte"))
+                    .to_owned(),
+            ),
+        )
+        .to_owned()
+        .with(
+            Query::with()
+                .cte(
+                    CommonTableExpression::new()
+                        .table_name(Alias::new("cte"))
+                        .query(
+                            Query::select()
+                                .column(Glyph::Id)
+                                .from(Glyph::Table)
+                                .and_where(Expr::col(Glyph::Image).like("A%"))
+                                .to_owned(),
+                        )
+                        .to_owned(),
+                )
+                .to_owned(),
+        )
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"WITH "cte" AS (SELECT "id" FROM "glyph" WHERE "image" LIKE $1) UPDATE "glyph" SET "aspect" = $2 WHERE "id" IN (SELECT "id" FROM "cte")"#
+    );
+    assert_eq!(
+        values,
+        Values(vec![
+            Value::String(Some(Box::new("A%".to_owned()))),
+            Value::Int(Some(0))
+        ])
+    );
+}
+
+#[test]
+fn update_5() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .from(TableRef::SubQuery(
+                Query::select()
+                    .column(Char::FontId)
+                    .expr_as(Expr::col(Char::SizeW).max(), Alias::new("w"))
+                    .from(Char::Table)
+                    .group_by_col(Char::FontId)
+                    .to_owned(),
+                Alias::new("m").into_iden(),
+            ))
+            .value_expr(
+                Glyph::Aspect,
+                Expr::tbl(Alias::new("m"), Alias::new("w")).into_simple_expr()
+            )
+            .and_where(Expr::tbl(Glyph::Table, Glyph::Id).equals(Alias::new("m"), Char::FontId))
+            .to_string(PostgresQueryBuilder),
+        r#"UPDATE "glyph" SET "aspect" = "m"."w" FROM (SELECT "font_id", MAX("size_w") AS "w" FROM "character" GROUP BY "font_id") AS "m" WHERE "glyph"."id" = "m"."font_id""#
+    );
+}
+
+#[test]
+#[should_panic(expected = "ORDER BY and LIMIT in UPDATE and DELETE statements are not supported")]
+fn update_6() {
+    Query::update()
+        .table(Glyph::Table)
+        .value(Glyph::Aspect, 0.into())
+        .order_by(Glyph::Id, Order::Asc)
+        .limit(1)
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn update_7() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value(Glyph::Aspect, 0.into())
+            .order_by(Glyph::Id, Order::Asc)
+            .try_build(PostgresQueryBuilder),
+        Err(error::Error::Unsupported {
+            backend: "Postgres",
+            feature: "ORDER BY and LIMIT in UPDATE"
+        })
+    );
+}
+
+#[test]
+fn delete_1() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .and_where(Expr::col(Glyph::Id).eq(1))
+            .to_string(PostgresQueryBuilder),
+        r#"DELETE FROM "glyph" WHERE "id" = 1"#
+    );
+}
+
+#[test]
+fn delete_2() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .and_where(Expr::col(Glyph::Aspect).lt(1))
+            .returning(
+                Query::select()
+                    .column(Glyph::Id)
+                    .expr_as(Expr::col(Glyph::Image), Alias::new("img"))
+                    .to_owned(),
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"DELETE FROM "glyph" WHERE "aspect" < 1 RETURNING "id", "image" AS "img""#
+    );
+}
+
+#[test]
+fn delete_3() {
+    assert_eq!(
+        Query::delete()
+            .from_table(TableRef::TableAlias(
+                Glyph::Table.into_iden(),
+                Alias::new("g").into_iden(),
+            ))
+            .using(Char::Table)
+            .using(Font::Table)
+            .and_where(Expr::tbl(Alias::new("g"), Glyph::Id).equals(Char::Table, Char::Id))
+            .and_where(Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id))
+            .to_string(PostgresQueryBuilder),
+        r#"DELETE FROM "glyph" AS "g" USING "character", "font" WHERE "g"."id" = "character"."id" AND "character"."font_id" = "font"."id""#
+    );
+}
+
+#[test]
+#[should_panic(expected = "ORDER BY and LIMIT in UPDATE and DELETE statements are not supported")]
+fn delete_4() {
+    Query::delete()
+        .from_table(Glyph::Table)
+        .limit(1)
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn delete_5() {
+    let min_aspect: Option<f64> = Some(2.5);
+    let image_prefix: Option<&str> = Some("A%");
+    let id: Option<i32> = None;
+    let (statement, values) = Query::delete()
+        .from_table(Glyph::Table)
+        .apply_if(min_aspect, |q, v| {
+            q.and_where(Expr::col(Glyph::Aspect).gt(v));
+        })
+        .apply_if(image_prefix, |q, v| {
+            q.and_where(Expr::col(Glyph::Image).like(v));
+        })
+        .apply_if(id, |q, v| {
+            q.and_where(Expr::col(Glyph::Id).eq(v));
+        })
+        .build(PostgresQueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"DELETE FROM "glyph" WHERE "aspect" > $1 AND "image" LIKE $2"#
+    );
+    assert_eq!(values.0, vec![2.5f64.into(), "A%".into()]);
+}
+
+#[test]
+#[should_panic(expected = "ON CONFLICT is not supported by this backend")]
+fn insert_16() {
+    Query::insert()
+        .into_table(Glyph::Table)
+        .columns(vec![Glyph::Id, Glyph::Image])
+        .values_panic(vec![1.into(), "B".into()])
+        .on_conflict(OnConflict::column(Glyph::Id).do_nothing().to_owned())
+        .to_string(PostgresQueryBuilder.version(9, 4));
+}
+
+#[test]
+fn select_84() {
+    let (statement, values) = Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .and_where(Expr::col(Char::SizeW).is_in(vec![3, 4]))
+        .and_where(Expr::col(Char::Character).like("A%"))
+        .build_named(PostgresQueryBuilder, "@");
+
+    assert_eq!(
+        statement,
+        r#"SELECT "character" FROM "character" WHERE "size_w" IN (@p1, @p2) AND "character" LIKE @p3"#
+    );
+    assert_eq!(
+        values.into_iter().collect::<Vec<_>>(),
+        vec![
+            ("p1".to_owned(), Value::Int(Some(3))),
+            ("p2".to_owned(), Value::Int(Some(4))),
+            (
+                "p3".to_owned(),
+                Value::String(Some(Box::new("A%".to_owned())))
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot order by the position in an empty list of values")]
+fn select_85() {
+    Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .order_by_field(Glyph::Id, vec![]);
+}
+
+#[test]
+fn delete_6() {
+    assert_eq!(
+        Query::delete()
+            .from_table(Glyph::Table)
+            .limit(1)
+            .try_build(PostgresQueryBuilder),
+        Err(error::Error::Unsupported {
+            backend: "Postgres",
+            feature: "ORDER BY and LIMIT in DELETE"
+        })
     );
 }
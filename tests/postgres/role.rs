@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Role::create()
+            .name(Alias::new("app"))
+            .login()
+            .password("s3cret")
+            .option(RoleOption::ConnectionLimit(10))
+            .option(RoleOption::ValidUntil("2030-01-01".to_owned()))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE ROLE "app" LOGIN PASSWORD 's3cret' CONNECTION LIMIT 10 VALID UNTIL '2030-01-01'"#
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        Role::create()
+            .name(Alias::new("admin"))
+            .option(RoleOption::Login(false))
+            .option(RoleOption::Superuser(true))
+            .option(RoleOption::CreateDb(true))
+            .option(RoleOption::CreateRole(true))
+            .option(RoleOption::Inherit(false))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE ROLE "admin" NOLOGIN SUPERUSER CREATEDB CREATEROLE NOINHERIT"#
+    );
+}
+
+#[test]
+fn create_3() {
+    assert_eq!(
+        User::create()
+            .name(Alias::new("app"))
+            .password("s3cret")
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE USER "app" PASSWORD 's3cret'"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Postgres not support IF NOT EXISTS on role")]
+fn create_4() {
+    Role::create()
+        .if_not_exists()
+        .name(Alias::new("app"))
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        User::drop()
+            .if_exists()
+            .name(Alias::new("app"))
+            .to_string(PostgresQueryBuilder),
+        r#"DROP USER IF EXISTS "app""#
+    );
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Sequence::create()
+            .name(Alias::new("glyph_id_seq"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE SEQUENCE "glyph_id_seq""#
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        Sequence::create()
+            .name(Alias::new("glyph_id_seq"))
+            .increment_by(-1)
+            .max_value(1000)
+            .no_min_value()
+            .start_with(1000)
+            .cycle(true)
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE SEQUENCE "glyph_id_seq" INCREMENT BY -1 MAXVALUE 1000 NO MINVALUE START WITH 1000 CYCLE"#
+    );
+}
+
+#[test]
+fn alter_1() {
+    assert_eq!(
+        Sequence::alter()
+            .name(Alias::new("glyph_id_seq"))
+            .restart()
+            .cache(20)
+            .to_string(PostgresQueryBuilder),
+        r#"ALTER SEQUENCE "glyph_id_seq" RESTART CACHE 20"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "No alter option found")]
+fn alter_2() {
+    Sequence::alter()
+        .name(Alias::new("glyph_id_seq"))
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Sequence::drop()
+            .name(Alias::new("glyph_id_seq"))
+            .to_string(PostgresQueryBuilder),
+        r#"DROP SEQUENCE "glyph_id_seq""#
+    );
+}
@@ -15,7 +15,7 @@ fn create_1() {
             .col(ColumnDef::new(Glyph::Aspect).double().not_null())
             .col(ColumnDef::new(Glyph::Image).text())
             .to_string(PostgresQueryBuilder),
-        vec![
+        [
             r#"CREATE TABLE "glyph" ("#,
             r#""id" serial NOT NULL PRIMARY KEY,"#,
             r#""aspect" double precision NOT NULL,"#,
@@ -42,7 +42,7 @@ fn create_2() {
             .col(ColumnDef::new(Font::Variant).string_len(255).not_null())
             .col(ColumnDef::new(Font::Language).string_len(255).not_null())
             .to_string(PostgresQueryBuilder),
-        vec![
+        [
             r#"CREATE TABLE "font" ("#,
             r#""id" serial NOT NULL PRIMARY KEY,"#,
             r#""name" varchar NOT NULL,"#,
@@ -85,7 +85,7 @@ fn create_3() {
                     .on_update(ForeignKeyAction::Cascade)
             )
             .to_string(PostgresQueryBuilder),
-        vec![
+        [
             r#"CREATE TABLE IF NOT EXISTS "character" ("#,
             r#""id" serial NOT NULL PRIMARY KEY,"#,
             r#""font_size" integer NOT NULL,"#,
@@ -109,7 +109,7 @@ fn create_4() {
             .table(Glyph::Table)
             .col(ColumnDef::new(Glyph::Image).custom(Glyph::Aspect))
             .to_string(PostgresQueryBuilder),
-        vec![r#"CREATE TABLE "glyph" ("#, r#""image" aspect"#, r#")"#,].join(" ")
+        [r#"CREATE TABLE "glyph" ("#, r#""image" aspect"#, r#")"#].join(" ")
     );
 }
 
@@ -121,7 +121,7 @@ fn create_5() {
             .col(ColumnDef::new(Glyph::Image).json())
             .col(ColumnDef::new(Glyph::Aspect).json_binary())
             .to_string(PostgresQueryBuilder),
-        vec![
+        [
             r#"CREATE TABLE "glyph" ("#,
             r#""image" json,"#,
             r#""aspect" jsonb"#,
@@ -143,7 +143,7 @@ fn create_6() {
                     .extra("ANYTHING I WANT TO SAY".to_owned())
             )
             .to_string(PostgresQueryBuilder),
-        vec![
+        [
             r#"CREATE TABLE "glyph" ("#,
             r#""id" integer NOT NULL ANYTHING I WANT TO SAY"#,
             r#")"#,
@@ -152,6 +152,346 @@ fn create_6() {
     );
 }
 
+#[test]
+fn create_7() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Image).inet())
+            .col(ColumnDef::new(Glyph::Aspect).cidr())
+            .col(ColumnDef::new(Glyph::Id).mac_address())
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "glyph" ("#,
+            r#""image" inet,"#,
+            r#""aspect" cidr,"#,
+            r#""id" macaddr"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_8() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null())
+            .col(
+                ColumnDef::new(Font::Variant)
+                    .string_len(16)
+                    .not_null()
+                    .check(Expr::col(Font::Variant).is_in(vec!["Regular", "Bold"]))
+            )
+            .col(ColumnDef::new(Font::Language).string_len(8))
+            .check(
+                Expr::col(Font::Language)
+                    .is_null()
+                    .or(Expr::col(Font::Language).ne(""))
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "font" ("#,
+            r#""id" integer NOT NULL,"#,
+            r#""variant" varchar(16) NOT NULL CHECK ("variant" IN ('Regular', 'Bold')),"#,
+            r#""language" varchar(8),"#,
+            r#"CHECK (("language" IS NULL) OR ("language" <> ''))"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_9() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(ColumnDef::new(Glyph::Image).json_binary())
+            .col(ColumnDef::new(Alias::new("image_type")).string().generated(
+                Expr::col(Glyph::Image).cast_json_field("type"),
+                GeneratedStorage::Stored
+            ))
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "glyph" ("#,
+            r#""id" integer NOT NULL,"#,
+            r#""image" jsonb,"#,
+            r#""image_type" varchar GENERATED ALWAYS AS ("image" ->> 'type') STORED"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_10() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .uuid()
+                    .not_null()
+                    .default_expr(Expr::cust("gen_random_uuid()"))
+                    .primary_key()
+            )
+            .col(
+                ColumnDef::new(Alias::new("expires_at"))
+                    .timestamp_with_time_zone()
+                    .not_null()
+                    .default_expr(Expr::cust("now() + interval '1 day'"))
+            )
+            .col(
+                ColumnDef::new(Alias::new("created_on"))
+                    .date()
+                    .default_expr(Expr::current_date())
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "glyph" ("#,
+            r#""id" uuid NOT NULL DEFAULT (gen_random_uuid()) PRIMARY KEY,"#,
+            r#""expires_at" timestamp with time zone NOT NULL DEFAULT (now() + interval '1 day'),"#,
+            r#""created_on" date DEFAULT CURRENT_DATE"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_11() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(
+                ColumnDef::new(Font::Id)
+                    .big_integer()
+                    .not_null()
+                    .identity(Identity::by_default().start(10).increment(5).to_owned())
+                    .primary_key()
+            )
+            .col(
+                ColumnDef::new(Font::Name)
+                    .string()
+                    .not_null()
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "font" ("#,
+            r#""id" bigint NOT NULL GENERATED BY DEFAULT AS IDENTITY (START WITH 10 INCREMENT BY 5) PRIMARY KEY,"#,
+            r#""name" varchar NOT NULL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_12() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null())
+            .col(
+                ColumnDef::new(Font::Variant)
+                    .enumeration(
+                        Alias::new("font_variant"),
+                        vec![
+                            Alias::new("regular"),
+                            Alias::new("bold"),
+                            Alias::new("it's")
+                        ]
+                    )
+                    .not_null()
+                    .default("regular")
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "font" ("#,
+            r#""id" integer NOT NULL,"#,
+            r#""variant" "font_variant" NOT NULL DEFAULT 'regular'"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_13() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(
+                ColumnDef::new(Alias::new("tags"))
+                    .array(ColumnType::String(Some(32)))
+                    .not_null()
+                    .default_expr(Expr::cust("'{}'"))
+            )
+            .col(ColumnDef::new(Alias::new("scores")).array(ColumnType::Double(None)))
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "glyph" ("#,
+            r#""id" integer NOT NULL,"#,
+            r#""tags" varchar(32)[] NOT NULL DEFAULT ('{}'),"#,
+            r#""scores" double precision[]"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Postgres not support virtual generated column")]
+fn create_14() {
+    Table::create()
+        .table(Glyph::Table)
+        .col(
+            ColumnDef::new(Alias::new("size"))
+                .integer()
+                .generated(Expr::col(Glyph::Aspect).add(1), GeneratedStorage::Virtual),
+        )
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn create_15() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("staging"))
+            .temporary()
+            .if_not_exists()
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TEMPORARY TABLE IF NOT EXISTS "staging" ("#,
+            r#""id" integer NOT NULL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_16() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("staging"))
+            .temporary()
+            .as_select(
+                Query::select()
+                    .columns(vec![Glyph::Id, Glyph::Image])
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Aspect).gt(2))
+                    .to_owned()
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TEMPORARY TABLE "staging" AS SELECT "id", "image" FROM "glyph" WHERE "aspect" > 2"#
+    );
+}
+
+#[test]
+fn create_17() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("staging"))
+            .unlogged()
+            .as_select(
+                Query::select()
+                    .column(Font::Name)
+                    .from(Font::Table)
+                    .to_owned()
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE UNLOGGED TABLE "staging" AS SELECT "name" FROM "font""#
+    );
+}
+
+#[test]
+fn create_18() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("char_snapshot"))
+            .if_not_exists()
+            .as_select(
+                Query::select()
+                    .columns(vec![Char::Id, Char::Character])
+                    .from(Char::Table)
+                    .and_where(Expr::col(Char::FontSize).gte(12))
+                    .to_owned()
+            )
+            .with_no_data()
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TABLE IF NOT EXISTS "char_snapshot" AS SELECT "id", "character" FROM "character" WHERE "font_size" >= 12 WITH NO DATA"#
+    );
+}
+
+#[test]
+fn create_19() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null())
+            .col(ColumnDef::new(Font::Variant).string().not_null())
+            .partition_by(PartitionType::List, vec![Font::Variant])
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "font" ("#,
+            r#""id" integer NOT NULL,"#,
+            r#""variant" varchar NOT NULL"#,
+            r#") PARTITION BY LIST ("variant")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_20() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("font_bold"))
+            .partition_of(
+                Font::Table,
+                PartitionValues::list(vec![Expr::val("Bold"), Expr::val("Black")])
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TABLE "font_bold" PARTITION OF "font" FOR VALUES IN ('Bold', 'Black')"#
+    );
+}
+
+#[test]
+fn create_21() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("glyph_0"))
+            .if_not_exists()
+            .partition_of(Glyph::Table, PartitionValues::modulus(4, 0))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TABLE IF NOT EXISTS "glyph_0" PARTITION OF "glyph" FOR VALUES WITH (MODULUS 4, REMAINDER 0)"#
+    );
+}
+
+#[test]
+fn create_22() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("font_other"))
+            .partition_of(Font::Table, PartitionValues::Default)
+            .col(ColumnDef::new(Font::Name).check(Expr::col(Font::Name).ne("")))
+            .partition_by(PartitionType::Hash, vec![Font::Id])
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "font_other" PARTITION OF "font" ("#,
+            r#""name" CHECK ("name" <> '')"#,
+            r#") DEFAULT PARTITION BY HASH ("id")"#,
+        ]
+        .join(" ")
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -174,6 +514,19 @@ fn truncate_1() {
     );
 }
 
+#[test]
+fn truncate_2() {
+    assert_eq!(
+        Table::truncate()
+            .table(Glyph::Table)
+            .table(Char::Table)
+            .continue_identity()
+            .restrict()
+            .to_string(PostgresQueryBuilder),
+        r#"TRUNCATE TABLE "glyph", "character" CONTINUE IDENTITY RESTRICT"#
+    );
+}
+
 #[test]
 fn alter_1() {
     assert_eq!(
@@ -201,7 +554,7 @@ fn alter_2() {
                     .default(999)
             )
             .to_string(PostgresQueryBuilder),
-        vec![
+        [
             r#"ALTER TABLE "font""#,
             r#"ALTER COLUMN "new_col" TYPE bigint,"#,
             r#"ALTER COLUMN "new_col" SET DEFAULT 999"#,
@@ -247,3 +600,257 @@ fn alter_5() {
 fn alter_6() {
     Table::alter().to_string(PostgresQueryBuilder);
 }
+
+#[test]
+fn alter_7() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .add_check_constraint(
+                Alias::new("font_name_not_empty"),
+                Expr::col(Font::Name).ne("")
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"ALTER TABLE "font" ADD CONSTRAINT "font_name_not_empty" CHECK ("name" <> '')"#
+    );
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .drop_constraint(Alias::new("font_name_not_empty"))
+            .to_string(PostgresQueryBuilder),
+        r#"ALTER TABLE "font" DROP CONSTRAINT "font_name_not_empty""#
+    );
+}
+
+#[test]
+fn alter_8() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .modify_column(
+                ColumnDef::new(Alias::new("new_col"))
+                    .integer()
+                    .check(Expr::col(Alias::new("new_col")).gte(0))
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "font""#,
+            r#"ALTER COLUMN "new_col" TYPE integer,"#,
+            r#"ADD CHECK ("new_col" >= 0)"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_9() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .modify_column(
+                ColumnDef::new(Alias::new("updated_at"))
+                    .timestamp()
+                    .default_expr(Expr::current_timestamp())
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "font""#,
+            r#"ALTER COLUMN "updated_at" TYPE timestamp,"#,
+            r#"ALTER COLUMN "updated_at" SET DEFAULT CURRENT_TIMESTAMP"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_10() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .modify_column(
+                ColumnDef::new(Font::Id)
+                    .integer()
+                    .identity(Identity::always())
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "font""#,
+            r#"ALTER COLUMN "id" TYPE integer,"#,
+            r#"ALTER COLUMN "id" ADD GENERATED ALWAYS AS IDENTITY"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_11() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .add_column(ColumnDef::new(Alias::new("new_col")).integer())
+            .rename_column(Font::Variant, Alias::new("style"))
+            .drop_column(Alias::new("old_col"))
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "font" ADD COLUMN "new_col" integer, DROP COLUMN "old_col";"#,
+            r#"ALTER TABLE "font" RENAME COLUMN "variant" TO "style""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_12() {
+    assert_eq!(
+        Table::alter()
+            .table(Glyph::Table)
+            .modify_column_using(
+                ColumnDef::new(Glyph::Aspect).integer(),
+                Expr::cust(r#"round("aspect")"#)
+            )
+            .modify_column_using(
+                ColumnDef::new(Glyph::Image).json_binary(),
+                Expr::cust(r#"to_jsonb("image")"#)
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "glyph""#,
+            r#"ALTER COLUMN "aspect" TYPE integer USING round("aspect"),"#,
+            r#"ALTER COLUMN "image" TYPE jsonb USING to_jsonb("image")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_13() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .change_column(
+                Font::Name,
+                ColumnDef::new(Alias::new("title")).string_len(100)
+            )
+            .drop_column(Font::Variant)
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "font" DROP COLUMN "variant";"#,
+            r#"ALTER TABLE "font" RENAME COLUMN "name" TO "title""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Postgres does not support generated columns before 12")]
+fn create_23() {
+    Table::create()
+        .table(Glyph::Table)
+        .col(
+            ColumnDef::new(Alias::new("size"))
+                .integer()
+                .generated(Expr::col(Glyph::Aspect).add(1), GeneratedStorage::Stored),
+        )
+        .to_string(PostgresQueryBuilder.version(11, 0));
+}
+
+#[test]
+fn create_24() {
+    let table = Table::create()
+        .table(Glyph::Table)
+        .col(
+            ColumnDef::new(Glyph::Id)
+                .integer()
+                .identity(Identity::always()),
+        )
+        .to_owned();
+
+    assert_eq!(
+        table.try_build(PostgresQueryBuilder.version(9, 6)),
+        Err(error::Error::Unsupported {
+            backend: "Postgres",
+            feature: "Identity columns"
+        })
+    );
+    assert_eq!(
+        table.try_build(PostgresQueryBuilder.version(10, 0)),
+        Ok(r#"CREATE TABLE "glyph" ( "id" integer GENERATED ALWAYS AS IDENTITY )"#.to_owned())
+    );
+}
+
+#[test]
+fn alter_14() {
+    assert_eq!(
+        Table::alter()
+            .table(Glyph::Table)
+            .add_column(
+                ColumnDef::new(Alias::new("size"))
+                    .integer()
+                    .generated(Expr::col(Glyph::Aspect).add(1), GeneratedStorage::Stored),
+            )
+            .try_build(PostgresQueryBuilder.version(11, 0)),
+        Err(error::Error::Unsupported {
+            backend: "Postgres",
+            feature: "Generated columns"
+        })
+    );
+}
+
+#[test]
+fn diff_1() {
+    let current = Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Glyph::Aspect).integer())
+        .col(ColumnDef::new(Alias::new("picture")).string())
+        .to_owned();
+    let desired = Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Glyph::Aspect).double().not_null())
+        .col(ColumnDef::new(Glyph::Image).string())
+        .to_owned();
+
+    let diff = current.diff(&desired).unwrap();
+    assert_eq!(
+        diff.to_string(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "glyph""#,
+            r#"ALTER COLUMN "aspect" TYPE double precision, ALTER COLUMN "aspect" SET NOT NULL,"#,
+            r#"ADD COLUMN "image" varchar,"#,
+            r#"DROP COLUMN "picture""#,
+        ]
+        .join(" ")
+    );
+    assert!(matches!(
+        diff.statements().as_slice(),
+        [SchemaStatement::TableStatement(TableStatement::Alter(_))]
+    ));
+}
+
+#[test]
+fn diff_2() {
+    let current = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string().not_null().default("x"))
+        .to_owned();
+    let desired = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string())
+        .to_owned();
+
+    assert_eq!(
+        current
+            .diff(&desired)
+            .unwrap()
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "font""#,
+            r#"ALTER COLUMN "name" TYPE varchar, ALTER COLUMN "name" DROP NOT NULL,"#,
+            r#"ALTER COLUMN "name" DROP DEFAULT"#,
+        ]
+        .join(" ")
+    );
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn begin_1() {
+    assert_eq!(
+        Transaction::begin().to_string(PostgresQueryBuilder),
+        "BEGIN"
+    );
+}
+
+#[test]
+fn begin_2() {
+    assert_eq!(
+        Transaction::begin()
+            .isolation_level(TransactionIsolationLevel::ReadCommitted)
+            .to_string(PostgresQueryBuilder),
+        "BEGIN ISOLATION LEVEL READ COMMITTED"
+    );
+}
+
+#[test]
+fn set_1() {
+    assert_eq!(
+        Transaction::set()
+            .access_mode(TransactionAccessMode::ReadOnly)
+            .to_string(PostgresQueryBuilder),
+        "SET TRANSACTION READ ONLY"
+    );
+}
+
+#[test]
+fn rollback_1() {
+    assert_eq!(
+        Transaction::rollback().to_string(PostgresQueryBuilder),
+        "ROLLBACK"
+    );
+}
+
+#[test]
+fn rollback_2() {
+    assert_eq!(
+        Transaction::rollback_to(Alias::new("sp1")).to_string(PostgresQueryBuilder),
+        r#"ROLLBACK TO SAVEPOINT "sp1""#
+    );
+}
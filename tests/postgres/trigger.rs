@@ -0,0 +1,39 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Trigger::create()
+            .name(Alias::new("glyph_truncate"))
+            .timing(TriggerTiming::Before)
+            .event(TriggerEvent::Truncate)
+            .table(Glyph::Table)
+            .for_each_statement()
+            .execute_function(Alias::new("log_truncate"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TRIGGER "glyph_truncate" BEFORE TRUNCATE ON "glyph" FOR EACH STATEMENT EXECUTE FUNCTION "log_truncate"()"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Postgres not support trigger body")]
+fn create_2() {
+    Trigger::create()
+        .name(Alias::new("glyph_delete"))
+        .timing(TriggerTiming::After)
+        .event(TriggerEvent::Delete)
+        .table(Glyph::Table)
+        .statement(Query::delete().from_table(Char::Table).to_owned())
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Trigger::drop()
+            .name(Alias::new("glyph_truncate"))
+            .table(Glyph::Table)
+            .to_string(PostgresQueryBuilder),
+        r#"DROP TRIGGER "glyph_truncate" ON "glyph""#
+    );
+}
@@ -47,6 +47,17 @@ fn drop_3() {
     );
 }
 
+#[test]
+fn drop_4() {
+    assert_eq!(
+        Type::drop()
+            .names(vec![Font::Table, Font::Variant])
+            .cascade()
+            .to_string(PostgresQueryBuilder),
+        r#"DROP TYPE "font", "variant" CASCADE"#
+    );
+}
+
 #[test]
 fn alter_1() {
     assert_eq!(
@@ -0,0 +1,65 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        View::create()
+            .or_replace()
+            .view(Alias::new("glyph_aspect"))
+            .query(
+                Query::select()
+                    .column(Glyph::Aspect)
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Aspect).gt(2))
+                    .to_owned()
+            )
+            .with_check_option()
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE OR REPLACE VIEW "glyph_aspect" AS SELECT "aspect" FROM "glyph" WHERE "aspect" > 2 WITH CHECK OPTION"#
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        View::create()
+            .materialized()
+            .view(Alias::new("glyph_aspect"))
+            .columns(vec![Alias::new("id"), Alias::new("aspect")])
+            .query(
+                Query::select()
+                    .columns(vec![Glyph::Id, Glyph::Aspect])
+                    .from(Glyph::Table)
+                    .to_owned()
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE MATERIALIZED VIEW "glyph_aspect" ("id", "aspect") AS SELECT "id", "aspect" FROM "glyph""#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Postgres not support OR REPLACE on materialized view")]
+fn create_3() {
+    View::create()
+        .or_replace()
+        .materialized()
+        .view(Alias::new("glyph_aspect"))
+        .query(
+            Query::select()
+                .column(Glyph::Aspect)
+                .from(Glyph::Table)
+                .to_owned(),
+        )
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn refresh_1() {
+    assert_eq!(
+        View::refresh()
+            .concurrently()
+            .view(Alias::new("glyph_aspect"))
+            .to_string(PostgresQueryBuilder),
+        r#"REFRESH MATERIALIZED VIEW CONCURRENTLY "glyph_aspect""#
+    );
+}
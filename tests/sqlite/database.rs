@@ -0,0 +1,17 @@
+use super::*;
+
+#[test]
+#[should_panic(expected = "Sqlite not support database")]
+fn create_1() {
+    Database::create()
+        .name(Alias::new("tenant_1"))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support schema")]
+fn create_2() {
+    Schema::create()
+        .name(Alias::new("tenant_1"))
+        .to_string(SqliteQueryBuilder);
+}
@@ -26,6 +26,88 @@ fn create_2() {
     );
 }
 
+#[test]
+fn create_3() {
+    assert_eq!(
+        Index::create()
+            .if_not_exists()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .to_string(SqliteQueryBuilder),
+        "CREATE INDEX IF NOT EXISTS `idx-glyph-aspect` ON `glyph` (`aspect`)"
+    );
+}
+
+#[test]
+fn create_4() {
+    assert_eq!(
+        Index::create()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .cond_where(
+                Cond::any()
+                    .add(Expr::col(Glyph::Aspect).lt(1))
+                    .add(Expr::col(Glyph::Aspect).gt(10))
+            )
+            .to_string(SqliteQueryBuilder),
+        "CREATE INDEX `idx-glyph-aspect` ON `glyph` (`aspect`) WHERE `aspect` < 1 OR `aspect` > 10"
+    );
+}
+
+#[test]
+fn create_5() {
+    assert_eq!(
+        Index::create()
+            .name("idx-font-name")
+            .table(Font::Table)
+            .col((
+                Func::cust(Alias::new("lower")).args(vec![Expr::col(Font::Name)]),
+                IndexOrder::Asc
+            ))
+            .col(Font::Id)
+            .to_string(SqliteQueryBuilder),
+        "CREATE INDEX `idx-font-name` ON `font` ((lower(`name`)) ASC, `id`)"
+    );
+}
+
+#[test]
+#[should_panic(expected = "INCLUDE is not supported by this backend")]
+fn create_6() {
+    Index::create()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(Glyph::Aspect)
+        .include(Glyph::Id)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support CONCURRENTLY on index")]
+fn create_7() {
+    Index::create()
+        .concurrently()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(Glyph::Aspect)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "NULLS FIRST and NULLS LAST are not supported by this backend")]
+fn create_8() {
+    Index::create()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(
+            IndexColumn::new(Glyph::Aspect)
+                .nulls(NullOrdering::Last)
+                .to_owned(),
+        )
+        .to_string(SqliteQueryBuilder);
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -36,3 +118,13 @@ fn drop_1() {
         "DROP INDEX `idx-glyph-aspect` ON `glyph`"
     );
 }
+
+#[test]
+fn reindex_1() {
+    assert_eq!(
+        Index::reindex()
+            .name("idx-glyph-aspect")
+            .to_string(SqliteQueryBuilder),
+        "REINDEX `idx-glyph-aspect`"
+    );
+}
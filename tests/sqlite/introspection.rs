@@ -0,0 +1,145 @@
+use super::*;
+
+#[test]
+fn tables_query_1() {
+    assert_eq!(
+        SqliteQueryBuilder
+            .tables_query(None)
+            .to_string(SqliteQueryBuilder),
+        [
+            "SELECT `name` FROM `sqlite_master`",
+            "WHERE `type` = 'table' AND `name` NOT LIKE 'sqlite_%'",
+            "ORDER BY `name` ASC",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn indexes_query_1() {
+    assert_eq!(
+        SqliteQueryBuilder
+            .indexes_query(Some("main"), "character")
+            .to_string(SqliteQueryBuilder),
+        [
+            "SELECT `il`.`name`, CAST(`il`.`unique` AS text), CAST(`il`.`origin` = 'pk' AS text), `ii`.`name`",
+            "FROM `pragma_index_list` AS `il`",
+            "INNER JOIN `pragma_index_info` AS `ii` ON (`ii`.`arg` = `il`.`name`) AND (`ii`.`schema` = 'main')",
+            "WHERE `il`.`arg` = 'character' AND `il`.`schema` = 'main'",
+            "ORDER BY `il`.`name` ASC, `ii`.`seqno` ASC",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn foreign_keys_query_1() {
+    assert_eq!(
+        SqliteQueryBuilder
+            .foreign_keys_query(None, "character")
+            .to_string(SqliteQueryBuilder),
+        [
+            "SELECT CAST(`id` AS text), `from`, `table`, `to`, `on_update`, `on_delete`",
+            "FROM `pragma_foreign_key_list` WHERE `arg` = 'character'",
+            "ORDER BY `id` ASC, `seq` ASC",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn parse_table_1() {
+    let columns = vec![
+        row(&[Some("id"), Some("INTEGER"), Some("1"), None, Some("1")]),
+        row(&[Some("font_id"), Some("INTEGER"), Some("0"), None, Some("0")]),
+        row(&[Some("code"), Some("TEXT"), Some("0"), Some("''"), Some("2")]),
+        row(&[
+            Some("size"),
+            Some("numeric(10,2)"),
+            Some("0"),
+            None,
+            Some("0"),
+        ]),
+    ];
+    let indexes = vec![
+        row(&[Some("idx-ch"), Some("0"), Some("0"), Some("font_id")]),
+        row(&[Some("idx-ch"), Some("0"), Some("0"), Some("size")]),
+        row(&[Some("idx-expr"), Some("0"), Some("0"), None]),
+        row(&[
+            Some("sqlite_autoindex_ch_1"),
+            Some("1"),
+            Some("0"),
+            Some("code"),
+        ]),
+        row(&[
+            Some("sqlite_autoindex_ch_2"),
+            Some("1"),
+            Some("1"),
+            Some("id"),
+        ]),
+        row(&[
+            Some("sqlite_autoindex_ch_2"),
+            Some("1"),
+            Some("1"),
+            Some("code"),
+        ]),
+    ];
+    let foreign_keys = vec![row(&[
+        Some("0"),
+        Some("font_id"),
+        Some("font"),
+        Some("id"),
+        Some("NO ACTION"),
+        Some("CASCADE"),
+    ])];
+
+    let table = SqliteQueryBuilder
+        .parse_table("ch", &columns, &indexes, &foreign_keys)
+        .unwrap();
+
+    let desired = Table::create()
+        .table(Alias::new("ch"))
+        .col(ColumnDef::new(Alias::new("id")).integer().not_null())
+        .col(ColumnDef::new(Alias::new("font_id")).integer())
+        .col(
+            ColumnDef::new(Alias::new("code"))
+                .text()
+                .default_expr(Expr::cust("''")),
+        )
+        .col(ColumnDef::new(Alias::new("size")).decimal_len(10, 2))
+        .index(
+            Index::create()
+                .name("idx-ch")
+                .col(Alias::new("font_id"))
+                .col(Alias::new("size")),
+        )
+        .index(Index::create().unique().col(Alias::new("code")))
+        .primary_key(
+            Index::create()
+                .col(Alias::new("id"))
+                .col(Alias::new("code")),
+        )
+        .foreign_key(
+            ForeignKey::create()
+                .from_col(Alias::new("font_id"))
+                .to(Alias::new("font"), Alias::new("id"))
+                .on_delete(ForeignKeyAction::Cascade),
+        )
+        .to_owned();
+
+    assert!(table.diff(&desired).unwrap().is_empty());
+}
+
+#[test]
+fn parse_table_2() {
+    let columns = vec![row(&[Some("id"), Some("integer"), Some("1"), None])];
+
+    assert_eq!(
+        SqliteQueryBuilder
+            .parse_table("ch", &columns, &[], &[])
+            .err(),
+        Some(error::Error::Introspection(
+            "expected at least 5 columns, got 4".to_owned()
+        ))
+    );
+}
@@ -1,7 +1,16 @@
+use common::*;
 use sea_query::{tests_cfg::*, *};
 
+#[path = "../common/mod.rs"]
+mod common;
+
+mod database;
 mod foreign_key;
 mod index;
+mod introspection;
 #[allow(deprecated)]
 mod query;
+mod role;
 mod table;
+mod transaction;
+mod trigger;
@@ -0,0 +1,9 @@
+use super::*;
+
+#[test]
+#[should_panic(expected = "Sqlite not support role")]
+fn create_1() {
+    User::create()
+        .name(Alias::new("app"))
+        .to_string(SqliteQueryBuilder);
+}
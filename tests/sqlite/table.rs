@@ -15,7 +15,7 @@ fn create_1() {
             .col(ColumnDef::new(Glyph::Aspect).double().not_null())
             .col(ColumnDef::new(Glyph::Image).text())
             .to_string(SqliteQueryBuilder),
-        vec![
+        [
             "CREATE TABLE `glyph` (",
             "`id` integer NOT NULL PRIMARY KEY AUTOINCREMENT,",
             "`aspect` real NOT NULL,",
@@ -42,7 +42,7 @@ fn create_2() {
             .col(ColumnDef::new(Font::Variant).string().not_null())
             .col(ColumnDef::new(Font::Language).string().not_null())
             .to_string(SqliteQueryBuilder),
-        vec![
+        [
             "CREATE TABLE `font` (",
             "`id` integer NOT NULL PRIMARY KEY AUTOINCREMENT,",
             "`name` text NOT NULL,",
@@ -84,7 +84,7 @@ fn create_3() {
                     .on_update(ForeignKeyAction::Cascade)
             )
             .to_string(SqliteQueryBuilder),
-        vec![
+        [
             "CREATE TABLE IF NOT EXISTS `character` (",
             "`id` integer NOT NULL PRIMARY KEY AUTOINCREMENT,",
             "`font_size` integer NOT NULL,",
@@ -99,6 +99,221 @@ fn create_3() {
     );
 }
 
+#[test]
+fn create_4() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null())
+            .col(
+                ColumnDef::new(Font::Variant)
+                    .string_len(16)
+                    .not_null()
+                    .check(Expr::col(Font::Variant).is_in(vec!["Regular", "Bold"]))
+            )
+            .col(ColumnDef::new(Font::Language).string_len(8))
+            .check(
+                Expr::col(Font::Language)
+                    .is_null()
+                    .or(Expr::col(Font::Language).ne(""))
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"CREATE TABLE `font` ("#,
+            r#"`id` integer NOT NULL,"#,
+            r#"`variant` text(16) NOT NULL CHECK (`variant` IN ('Regular', 'Bold')),"#,
+            r#"`language` text(8),"#,
+            r#"CHECK ((`language` IS NULL) OR (`language` <> ''))"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_5() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(ColumnDef::new(Glyph::Aspect).double().not_null())
+            .col(
+                ColumnDef::new(Alias::new("aspect_rounded"))
+                    .integer()
+                    .generated(Expr::cust("ROUND(`aspect`)"), GeneratedStorage::Virtual)
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"CREATE TABLE `glyph` ("#,
+            r#"`id` integer NOT NULL,"#,
+            r#"`aspect` real NOT NULL,"#,
+            r#"`aspect_rounded` integer GENERATED ALWAYS AS (ROUND(`aspect`)) VIRTUAL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_6() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp()
+                    .not_null()
+                    .default_expr(Expr::current_timestamp())
+            )
+            .col(
+                ColumnDef::new(Glyph::Image)
+                    .text()
+                    .default_expr(Expr::cust("hex(randomblob(16))"))
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"CREATE TABLE `glyph` ("#,
+            r#"`created_at` text NOT NULL DEFAULT CURRENT_TIMESTAMP,"#,
+            r#"`image` text DEFAULT (hex(randomblob(16)))"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_7() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(
+                ColumnDef::new(Font::Id)
+                    .integer()
+                    .not_null()
+                    .identity(Identity::always())
+                    .primary_key()
+            )
+            .col(ColumnDef::new(Font::Name).text().not_null())
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"CREATE TABLE `font` ("#,
+            r#"`id` integer NOT NULL PRIMARY KEY AUTOINCREMENT,"#,
+            r#"`name` text NOT NULL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support identity start or increment")]
+fn create_8() {
+    Table::create()
+        .table(Font::Table)
+        .col(
+            ColumnDef::new(Font::Id)
+                .integer()
+                .identity(Identity::by_default().start(10).to_owned()),
+        )
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn create_9() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null())
+            .col(
+                ColumnDef::new(Font::Variant)
+                    .enumeration(
+                        Alias::new("font_variant"),
+                        vec![Alias::new("regular"), Alias::new("bold"), Alias::new("it's")]
+                    )
+                    .not_null()
+                    .default("regular")
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"CREATE TABLE `font` ("#,
+            r#"`id` integer NOT NULL,"#,
+            r#"`variant` text CHECK (`variant` IN ('regular', 'bold', 'it''s')) NOT NULL DEFAULT 'regular'"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support array column type")]
+fn create_10() {
+    Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Alias::new("tags")).array(ColumnType::Text))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn create_11() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("staging"))
+            .temporary()
+            .if_not_exists()
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"CREATE TEMPORARY TABLE IF NOT EXISTS `staging` ("#,
+            r#"`id` integer NOT NULL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_12() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("staging"))
+            .temporary()
+            .as_select(
+                Query::select()
+                    .columns(vec![Glyph::Id, Glyph::Image])
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Aspect).gt(2))
+                    .to_owned()
+            )
+            .to_string(SqliteQueryBuilder),
+        r#"CREATE TEMPORARY TABLE `staging` AS SELECT `id`, `image` FROM `glyph` WHERE `aspect` > 2"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "WITH NO DATA is not supported by this backend")]
+fn create_13() {
+    Table::create()
+        .table(Alias::new("char_snapshot"))
+        .as_select(
+            Query::select()
+                .column(Char::Id)
+                .from(Char::Table)
+                .to_owned(),
+        )
+        .with_no_data()
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support table partitioning")]
+fn create_14() {
+    Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().not_null())
+        .partition_by(PartitionType::Hash, vec![Glyph::Id])
+        .to_string(SqliteQueryBuilder);
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -117,7 +332,57 @@ fn truncate_1() {
         Table::truncate()
             .table(Font::Table)
             .to_string(SqliteQueryBuilder),
-        "TRUNCATE TABLE `font`"
+        "DELETE FROM `font`"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support truncate options")]
+fn truncate_2() {
+    Table::truncate()
+        .table(Font::Table)
+        .restart_identity()
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn create_15() {
+    assert_eq!(
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Char::FontId).integer())
+            .foreign_key(
+                ForeignKey::create()
+                    .from(Char::Table, Char::FontId)
+                    .to(Font::Table, Font::Id)
+                    .match_type(ForeignKeyMatch::Simple)
+                    .on_delete(ForeignKeyAction::SetDefault)
+                    .deferrable(ForeignKeyDeferrable::InitiallyDeferred)
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            "CREATE TABLE `character` (",
+            "`id` integer NOT NULL PRIMARY KEY,",
+            "`font_id` integer,",
+            "FOREIGN KEY (`font_id`) REFERENCES `font` (`id`) MATCH SIMPLE",
+            "ON DELETE SET DEFAULT DEFERRABLE INITIALLY DEFERRED",
+            ")",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_16() {
+    let schema_builder: Box<dyn SchemaBuilder> = Box::new(SqliteQueryBuilder);
+
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .to_string_any(schema_builder.as_ref()),
+        "CREATE TABLE `glyph` ( `id` integer NOT NULL )"
     );
 }
 
@@ -181,3 +446,115 @@ fn alter_5() {
 fn alter_6() {
     Table::alter().to_string(SqliteQueryBuilder);
 }
+
+#[test]
+#[should_panic(expected = "Sqlite not support adding table constraint")]
+fn alter_7() {
+    Table::alter()
+        .table(Font::Table)
+        .add_check_constraint(
+            Alias::new("font_name_not_empty"),
+            Expr::col(Font::Name).ne(""),
+        )
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn alter_8() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .add_column(ColumnDef::new(Alias::new("new_col")).integer())
+            .rename_column(Font::Variant, Alias::new("style"))
+            .to_string(SqliteQueryBuilder),
+        [
+            "ALTER TABLE `font` ADD COLUMN `new_col` integer;",
+            "ALTER TABLE `font` RENAME COLUMN `variant` TO `style`",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support modifying table column")]
+fn alter_9() {
+    Table::alter()
+        .table(Glyph::Table)
+        .modify_column_using(
+            ColumnDef::new(Glyph::Aspect).integer(),
+            Expr::cust("round(`aspect`)"),
+        )
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn alter_10() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .change_column(
+                Font::Name,
+                ColumnDef::new(Alias::new("title")).string_len(100)
+            )
+            .to_string(SqliteQueryBuilder),
+        "ALTER TABLE `font` RENAME COLUMN `name` TO `title`"
+    );
+}
+
+#[test]
+fn rebuild_1() {
+    let character = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Char::Character).string())
+        .col(ColumnDef::new(Char::FontSize).string())
+        .col(ColumnDef::new(Char::FontId).integer())
+        .to_owned();
+
+    assert_eq!(
+        TableRebuildStatement::new(&character)
+            .alter(
+                Table::alter()
+                    .rename_column(Char::Character, Alias::new("glyph"))
+                    .modify_column_using(
+                        ColumnDef::new(Char::FontSize).integer().not_null(),
+                        Expr::cust("CAST(`font_size` AS integer)")
+                    )
+                    .add_column(ColumnDef::new(Char::SizeW).integer())
+            )
+            .add_foreign_key(
+                ForeignKey::create()
+                    .name("FK_character_font")
+                    .from(Char::Table, Char::FontId)
+                    .to(Font::Table, Font::Id)
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            "ALTER TABLE `character` RENAME TO `character_old`;",
+            "CREATE TABLE `character` (",
+            "`id` integer NOT NULL PRIMARY KEY,",
+            "`glyph` text,",
+            "`font_size` integer NOT NULL,",
+            "`font_id` integer,",
+            "`size_w` integer,",
+            "FOREIGN KEY (`font_id`) REFERENCES `font` (`id`)",
+            ");",
+            "INSERT INTO `character` (`id`, `glyph`, `font_size`, `font_id`)",
+            "SELECT `id`, `character`, CAST(`font_size` AS integer), `font_id` FROM `character_old`;",
+            "DROP TABLE `character_old`",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Column size_h not found")]
+fn rebuild_2() {
+    TableRebuildStatement::new(
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer()),
+    )
+    .alter(Table::alter().drop_column(Char::SizeH))
+    .to_string(SqliteQueryBuilder);
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+fn begin_1() {
+    assert_eq!(Transaction::begin().to_string(SqliteQueryBuilder), "BEGIN");
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support transaction modes")]
+fn begin_2() {
+    Transaction::begin()
+        .access_mode(TransactionAccessMode::ReadOnly)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support SET TRANSACTION")]
+fn set_1() {
+    Transaction::set()
+        .isolation_level(TransactionIsolationLevel::Serializable)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn rollback_1() {
+    assert_eq!(
+        TransactionStatement::from(Transaction::begin()).to_string(SqliteQueryBuilder),
+        "BEGIN"
+    );
+    assert_eq!(
+        Transaction::rollback().to_string(SqliteQueryBuilder),
+        "ROLLBACK"
+    );
+}
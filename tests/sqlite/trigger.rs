@@ -0,0 +1,43 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Trigger::create()
+            .name(Alias::new("glyph_image"))
+            .timing(TriggerTiming::After)
+            .event(TriggerEvent::Update(vec![Glyph::Image.into_iden()]))
+            .table(Glyph::Table)
+            .when(Expr::cust("NEW.image IS NOT OLD.image"))
+            .statement(
+                Query::update()
+                    .table(Glyph::Table)
+                    .value(Glyph::Aspect, 0.into())
+                    .and_where(
+                        Expr::col(Glyph::Id)
+                            .into_simple_expr()
+                            .equals(Expr::cust("NEW.id"))
+                    )
+                    .to_owned()
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            "CREATE TRIGGER `glyph_image` AFTER UPDATE OF `image` ON `glyph`",
+            "WHEN NEW.image IS NOT OLD.image",
+            "BEGIN UPDATE `glyph` SET `aspect` = 0 WHERE `id` = NEW.id; END",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support trigger function")]
+fn create_2() {
+    Trigger::create()
+        .name(Alias::new("glyph_audit"))
+        .timing(TriggerTiming::After)
+        .event(TriggerEvent::Insert)
+        .table(Glyph::Table)
+        .execute_function(Alias::new("audit_glyph"))
+        .to_string(SqliteQueryBuilder);
+}
@@ -0,0 +1,33 @@
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        View::create()
+            .view(Alias::new("glyph_aspect"))
+            .columns(vec![Alias::new("id"), Alias::new("aspect")])
+            .query(
+                Query::select()
+                    .columns(vec![Glyph::Id, Glyph::Aspect])
+                    .from(Glyph::Table)
+                    .to_owned()
+            )
+            .to_string(SqliteQueryBuilder),
+        "CREATE VIEW `glyph_aspect` (`id`, `aspect`) AS SELECT `id`, `aspect` FROM `glyph`"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sqlite not support OR REPLACE on view")]
+fn create_2() {
+    View::create()
+        .or_replace()
+        .view(Alias::new("glyph_aspect"))
+        .query(
+            Query::select()
+                .column(Glyph::Aspect)
+                .from(Glyph::Table)
+                .to_owned(),
+        )
+        .to_string(SqliteQueryBuilder);
+}